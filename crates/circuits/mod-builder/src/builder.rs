@@ -114,8 +114,8 @@ impl ExprBuilder {
         self.finalized = true;
         self.needs_setup = needs_setup;
 
-        // We don't support multi-op chip that doesn't need setup right now.
-        assert!(needs_setup || self.num_flags == 0);
+        // Without setup, flags are plain boolean selectors. Whether they can be used to select
+        // opcodes is up to the chip, see `FieldExpressionCoreAir`.
 
         // setup the defalut flag if needed
         if needs_setup && self.num_flags == 0 {
@@ -54,6 +54,8 @@ impl FieldExpressionCoreAir {
         local_opcode_idx: Vec<usize>,
         opcode_flag_idx: Vec<usize>,
     ) -> Self {
+        // We don't support multi-op chip that doesn't need setup right now.
+        assert!(expr.needs_setup() || expr.builder.num_flags == 0);
         let opcode_flag_idx = if opcode_flag_idx.is_empty() && expr.needs_setup() {
             // single op chip that needs setup, so there is only one default flag, must be 0.
             vec![0]
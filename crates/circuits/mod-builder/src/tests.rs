@@ -342,6 +342,38 @@ fn test_select2() {
     .expect("Verification failed");
 }
 
+#[test]
+fn test_select_without_setup() {
+    let prime = secp256k1_coord_prime();
+    let (range_checker, builder) = setup(&prime);
+    let builder = make_addsub_chip(builder);
+
+    // Flags are plain selectors when there is no setup.
+    let expr = FieldExpr::new(builder, range_checker.bus(), false);
+    let width = BaseAir::<BabyBear>::width(&expr);
+
+    let x = generate_random_biguint(&prime);
+    let y = generate_random_biguint(&prime);
+    let inputs = vec![x.clone(), y];
+    let flags = vec![false, false];
+
+    let mut row = BabyBear::zero_vec(width);
+    expr.generate_subrow((&range_checker, inputs, flags), &mut row);
+    let FieldExprCols { vars, .. } = expr.load_vars(&row);
+    assert_eq!(vars.len(), 1);
+    let generated = evaluate_biguint(&vars[0], LIMB_BITS);
+    assert_eq!(generated, x);
+
+    let trace = RowMajorMatrix::new(row, width);
+    let range_trace = range_checker.generate_trace();
+
+    BabyBearBlake3Engine::run_simple_test_no_pis_fast(
+        any_rap_arc_vec![expr, range_checker.air],
+        vec![trace, range_trace],
+    )
+    .expect("Verification failed");
+}
+
 fn test_symbolic_limbs(expr: SymbolicExpr, expected_q: usize, expected_carry: usize) {
     let prime = secp256k1_coord_prime();
     let (q, carry) = expr.constraint_limbs(&prime, LIMB_BITS, 32);
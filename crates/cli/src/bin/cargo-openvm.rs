@@ -1,5 +1,7 @@
 use cargo_openvm::{
-    commands::{BenchCmd, BuildCmd, EvmProvingSetupCmd, KeygenCmd, ProveCmd, RunCmd, VerifyCmd},
+    commands::{
+        BenchCmd, BuildCmd, DebugCmd, EvmProvingSetupCmd, KeygenCmd, ProveCmd, RunCmd, VerifyCmd,
+    },
     OPENVM_VERSION_MESSAGE,
 };
 use clap::{Parser, Subcommand};
@@ -25,6 +27,7 @@ pub struct VmCli {
 pub enum VmCliCommands {
     Bench(BenchCmd),
     Build(BuildCmd),
+    Debug(DebugCmd),
     Keygen(KeygenCmd),
     Prove(ProveCmd),
     Run(RunCmd),
@@ -40,6 +43,7 @@ async fn main() -> Result<()> {
     match command {
         VmCliCommands::Bench(cmd) => cmd.run(),
         VmCliCommands::Build(cmd) => cmd.run(),
+        VmCliCommands::Debug(cmd) => cmd.run(),
         VmCliCommands::Run(cmd) => cmd.run(),
         VmCliCommands::Keygen(cmd) => cmd.run(),
         VmCliCommands::Prove(cmd) => cmd.run(),
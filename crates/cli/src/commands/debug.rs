@@ -0,0 +1,178 @@
+use std::{
+    io::{stdin, stdout, BufRead, Write},
+    path::PathBuf,
+};
+
+use clap::Parser;
+use eyre::Result;
+use openvm_circuit::arch::{
+    debugger::{Breakpoints, DebugCommand, DebugState, VmDebugger},
+    VmConfig,
+};
+use openvm_sdk::{config::SdkVmConfig, fs::read_exe_from_file};
+use openvm_stark_sdk::{openvm_stark_backend::p3_field::PrimeField32, p3_baby_bear::BabyBear};
+
+use crate::{
+    default::{DEFAULT_APP_CONFIG_PATH, DEFAULT_APP_EXE_PATH},
+    util::{read_config_toml_or_default, read_to_stdin, Input},
+};
+
+type F = BabyBear;
+
+const HELP: &str = "\
+Commands:
+  s, step                      execute one instruction
+  c, continue                  run until the next breakpoint
+  b, break <pc|symbol>         add a breakpoint
+  d, delete <pc>               remove a breakpoint
+  l, list                      list the breakpoints
+  i, info                      show the current pc, function and instruction
+  r, regs                      show the RISC-V registers
+  x <address space> <pointer> [len]
+                               read memory cells
+  q, quit                      stop the execution
+  h, help                      show this message";
+
+#[derive(Parser)]
+#[command(
+    name = "debug",
+    about = "Run an OpenVM program in an interactive debugger"
+)]
+pub struct DebugCmd {
+    #[clap(long, action, help = "Path to OpenVM executable", default_value = DEFAULT_APP_EXE_PATH)]
+    exe: PathBuf,
+
+    #[clap(long, action, help = "Path to app config TOML file", default_value = DEFAULT_APP_CONFIG_PATH)]
+    config: PathBuf,
+
+    #[clap(long, value_parser, help = "Input to OpenVM program")]
+    input: Option<Input>,
+
+    #[clap(
+        long = "break",
+        help = "Breakpoint on a pc or a function symbol. Symbols require an executable built with the function-span feature. Stops at the first instruction if no breakpoint is given"
+    )]
+    breakpoints: Vec<String>,
+}
+
+impl DebugCmd {
+    pub fn run(&self) -> Result<()> {
+        let exe = read_exe_from_file(&self.exe)?;
+        let app_config = read_config_toml_or_default(&self.config)?;
+        let mut debugger = VmDebugger::new(app_config.app_vm_config, exe);
+        for breakpoint in &self.breakpoints {
+            add_breakpoint(&mut debugger.breakpoints, breakpoint)?;
+        }
+        if self.breakpoints.is_empty() {
+            debugger.breakpoints.set_single_step(true);
+        }
+
+        let (_, summary) = debugger.run(read_to_stdin(&self.input)?, repl::<SdkVmConfig>)?;
+        println!(
+            "Program exited with code {} after {} cycles in {} segment(s)",
+            summary.exit_code, summary.num_cycles, summary.num_segments
+        );
+        Ok(())
+    }
+}
+
+fn repl<VC: VmConfig<F>>(breakpoints: &mut Breakpoints, state: &DebugState<F, VC>) -> DebugCommand {
+    print_location(state);
+    let mut lines = stdin().lock().lines();
+    loop {
+        print!("(openvm) ");
+        stdout().flush().unwrap();
+        let Some(Ok(line)) = lines.next() else {
+            return DebugCommand::Quit;
+        };
+        let args: Vec<&str> = line.split_whitespace().collect();
+        match args.as_slice() {
+            ["s" | "step"] => return DebugCommand::Step,
+            ["c" | "continue"] => return DebugCommand::Continue,
+            ["q" | "quit"] => return DebugCommand::Quit,
+            ["b" | "break", breakpoint] => {
+                if let Err(e) = add_breakpoint(breakpoints, breakpoint) {
+                    println!("{e}");
+                }
+            }
+            ["d" | "delete", pc] => match parse_u32(pc) {
+                Ok(pc) if breakpoints.remove(pc) => println!("Removed breakpoint at {pc:#x}"),
+                Ok(pc) => println!("No breakpoint at {pc:#x}"),
+                Err(e) => println!("{e}"),
+            },
+            ["l" | "list"] => {
+                for pc in breakpoints.iter() {
+                    println!("{pc:#x}");
+                }
+            }
+            ["i" | "info"] => print_location(state),
+            ["r" | "regs"] => {
+                for (i, value) in state.registers().iter().enumerate() {
+                    println!("x{i:<2} = {value:#010x}");
+                }
+            }
+            ["x", address_space, pointer, len @ ..] if len.len() <= 1 => {
+                let parsed = (
+                    parse_u32(address_space),
+                    parse_u32(pointer),
+                    len.first().map_or(Ok(1), |len| parse_u32(len)),
+                );
+                match parsed {
+                    (Ok(address_space), Ok(pointer), Ok(len)) => {
+                        match state.read_memory(address_space, pointer, len as usize) {
+                            Ok(cells) => {
+                                for (i, cell) in cells.iter().enumerate() {
+                                    println!(
+                                        "[{address_space}][{:#x}] = {}",
+                                        pointer + i as u32,
+                                        cell.as_canonical_u32()
+                                    );
+                                }
+                            }
+                            Err(e) => println!("{e}"),
+                        }
+                    }
+                    (Err(e), _, _) | (_, Err(e), _) | (_, _, Err(e)) => println!("{e}"),
+                }
+            }
+            [] => {}
+            _ => println!("{HELP}"),
+        }
+    }
+}
+
+fn print_location<VC: VmConfig<F>>(state: &DebugState<F, VC>) {
+    let function = state
+        .function()
+        .map_or(String::new(), |bound| format!(" in {}", bound.name));
+    println!(
+        "Stopped at pc {:#x}{function} after {} cycles",
+        state.pc(),
+        state.num_cycles()
+    );
+    if let Some(instruction) = state.instruction() {
+        println!("  {:?}", instruction);
+    }
+}
+
+fn add_breakpoint(breakpoints: &mut Breakpoints, breakpoint: &str) -> Result<()> {
+    let pc = match parse_u32(breakpoint) {
+        Ok(pc) => {
+            breakpoints.add(pc);
+            pc
+        }
+        Err(_) => breakpoints
+            .add_symbol(breakpoint)
+            .ok_or_else(|| eyre::eyre!("Unknown symbol {breakpoint}"))?,
+    };
+    println!("Breakpoint at {pc:#x}");
+    Ok(())
+}
+
+fn parse_u32(s: &str) -> Result<u32> {
+    let value = match s.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16)?,
+        None => s.parse()?,
+    };
+    Ok(value)
+}
@@ -4,6 +4,9 @@ pub use bench::*;
 mod build;
 pub use build::*;
 
+mod debug;
+pub use debug::*;
+
 mod keygen;
 pub use keygen::*;
 
@@ -5,13 +5,14 @@ use eyre::{eyre, Result};
 use openvm_sdk::{
     fs::{
         read_app_proof_from_file, read_app_vk_from_file, read_evm_proof_from_file,
-        read_evm_verifier_from_file,
+        read_evm_verifier_from_file, read_exe_from_file,
     },
     Sdk,
 };
 
 use crate::default::{
-    DEFAULT_APP_PROOF_PATH, DEFAULT_APP_VK_PATH, DEFAULT_EVM_PROOF_PATH, DEFAULT_VERIFIER_PATH,
+    DEFAULT_APP_EXE_PATH, DEFAULT_APP_PROOF_PATH, DEFAULT_APP_VK_PATH, DEFAULT_EVM_PROOF_PATH,
+    DEFAULT_VERIFIER_PATH,
 };
 
 #[derive(Parser)]
@@ -27,6 +28,9 @@ enum VerifySubCommand {
         #[clap(long, action, help = "Path to app verifying key", default_value = DEFAULT_APP_VK_PATH)]
         app_vk: PathBuf,
 
+        #[clap(long, action, help = "Path to OpenVM executable", default_value = DEFAULT_APP_EXE_PATH)]
+        exe: PathBuf,
+
         #[clap(long, action, help = "Path to app proof", default_value = DEFAULT_APP_PROOF_PATH)]
         proof: PathBuf,
     },
@@ -39,19 +43,22 @@ enum VerifySubCommand {
 impl VerifyCmd {
     pub fn run(&self) -> Result<()> {
         match &self.command {
-            VerifySubCommand::App { app_vk, proof } => {
+            VerifySubCommand::App { app_vk, exe, proof } => {
                 let app_vk = read_app_vk_from_file(app_vk)?;
+                let app_exe = read_exe_from_file(exe)?;
+                let committed_exe = Sdk.commit_app_exe(app_vk.fri_params, app_exe)?;
                 let app_proof = read_app_proof_from_file(proof)?;
-                Sdk.verify_app_proof(&app_vk, &app_proof)?;
+                Sdk.verify_app_proof(&app_vk, &committed_exe, &app_proof)?;
             }
             VerifySubCommand::Evm { proof } => {
                 let evm_verifier = read_evm_verifier_from_file(DEFAULT_VERIFIER_PATH).map_err(|e| {
                     eyre::eyre!("Failed to read EVM verifier: {}\nPlease run 'cargo openvm evm-proving-setup' first", e)
                 })?;
                 let evm_proof = read_evm_proof_from_file(proof)?;
-                if !Sdk.verify_evm_proof(&evm_verifier, &evm_proof) {
-                    return Err(eyre!("EVM proof verification failed"));
-                }
+                let gas_used = Sdk
+                    .verify_evm_proof(&evm_verifier, &evm_proof)
+                    .map_err(|e| eyre!("EVM proof verification failed: {}", e))?;
+                println!("EVM proof verified, gas used: {}", gas_used);
             }
         }
         Ok(())
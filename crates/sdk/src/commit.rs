@@ -7,7 +7,7 @@ use openvm_circuit::{
         VmConfig,
     },
     system::{
-        memory::{memory_image_to_equipartition, tree::MemoryNode},
+        memory::{dimensions::MemoryDimensions, memory_image_to_equipartition, tree::MemoryNode},
         program::trace::VmCommittedExe,
     },
 };
@@ -63,12 +63,7 @@ impl AppExecutionCommit<F> {
             .commit
             .into();
 
-        let init_memory_commit = MemoryNode::tree_from_memory(
-            memory_dimensions,
-            &memory_image_to_equipartition(app_exe.exe.init_memory.clone()),
-            &hasher,
-        )
-        .hash();
+        let init_memory_commit = compute_init_memory_commit(memory_dimensions, &app_exe.exe);
         let mut padded_pc_start = [F::ZERO; DIGEST_SIZE];
         padded_pc_start[0] = F::from_canonical_u32(app_exe.exe.pc_start);
         let app_hash = hasher.hash(&app_program_commit);
@@ -92,6 +87,20 @@ impl AppExecutionCommit<F> {
     }
 }
 
+/// Merkle root of the initial memory image of `exe`, i.e. the memory root that the first
+/// segment of an execution of `exe` starts from.
+pub fn compute_init_memory_commit(
+    memory_dimensions: MemoryDimensions,
+    exe: &VmExe<F>,
+) -> [F; DIGEST_SIZE] {
+    MemoryNode::tree_from_memory(
+        memory_dimensions,
+        &memory_image_to_equipartition(exe.init_memory.clone()),
+        &vm_poseidon2_hasher(),
+    )
+    .hash()
+}
+
 pub(crate) fn babybear_digest_to_bn254(digest: &[F; DIGEST_SIZE]) -> Bn254Fr {
     let mut ret = Bn254Fr::ZERO;
     let order = Bn254Fr::from_canonical_u32(BabyBear::ORDER_U32);
@@ -15,9 +15,10 @@ use openvm_circuit::{
     derive::{AnyEnum, InstructionExecutor},
 };
 use openvm_ecc_circuit::{
+    TwistedEdwardsExtension, TwistedEdwardsExtensionExecutor, TwistedEdwardsExtensionPeriphery,
     WeierstrassExtension, WeierstrassExtensionExecutor, WeierstrassExtensionPeriphery,
 };
-use openvm_ecc_transpiler::EccTranspilerExtension;
+use openvm_ecc_transpiler::{EccTranspilerExtension, EdwardsTranspilerExtension};
 use openvm_keccak256_circuit::{Keccak256, Keccak256Executor, Keccak256Periphery};
 use openvm_keccak256_transpiler::Keccak256TranspilerExtension;
 use openvm_native_circuit::{Native, NativeExecutor, NativePeriphery};
@@ -32,13 +33,19 @@ use openvm_rv32im_circuit::{
 use openvm_rv32im_transpiler::{
     Rv32ITranspilerExtension, Rv32IoTranspilerExtension, Rv32MTranspilerExtension,
 };
+use openvm_sha256_circuit::{Sha256, Sha256Executor, Sha256Periphery};
+use openvm_sha256_transpiler::Sha256TranspilerExtension;
 use openvm_stark_backend::p3_field::PrimeField32;
 use openvm_transpiler::transpiler::Transpiler;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::F;
 
+/// The builder and deserialization both raise the range checker size in `system` to what the
+/// extensions need, so that [VmConfig::system] is the config the circuit is built with.
 #[derive(Builder, Clone, Debug, Serialize, Deserialize)]
+#[builder(finish_fn(name = build_unchecked, vis = ""))]
+#[serde(remote = "Self")]
 pub struct SdkVmConfig {
     #[serde(default)]
     pub system: SdkSystemConfig,
@@ -46,6 +53,7 @@ pub struct SdkVmConfig {
     pub rv32i: Option<UnitStruct>,
     pub io: Option<UnitStruct>,
     pub keccak: Option<UnitStruct>,
+    pub sha256: Option<UnitStruct>,
     pub native: Option<UnitStruct>,
 
     pub rv32m: Option<Rv32M>,
@@ -54,6 +62,7 @@ pub struct SdkVmConfig {
     pub fp2: Option<Fp2Extension>,
     pub pairing: Option<PairingExtension>,
     pub ecc: Option<WeierstrassExtension>,
+    pub edwards: Option<TwistedEdwardsExtension>,
 }
 
 #[derive(ChipUsageGetter, Chip, InstructionExecutor, From, AnyEnum)]
@@ -67,6 +76,8 @@ pub enum SdkVmConfigExecutor<F: PrimeField32> {
     #[any_enum]
     Keccak(Keccak256Executor<F>),
     #[any_enum]
+    Sha256(Sha256Executor<F>),
+    #[any_enum]
     Native(NativeExecutor<F>),
     #[any_enum]
     Rv32m(Rv32MExecutor<F>),
@@ -80,6 +91,8 @@ pub enum SdkVmConfigExecutor<F: PrimeField32> {
     Pairing(PairingExtensionExecutor<F>),
     #[any_enum]
     Ecc(WeierstrassExtensionExecutor<F>),
+    #[any_enum]
+    Edwards(TwistedEdwardsExtensionExecutor<F>),
 }
 
 #[derive(From, ChipUsageGetter, Chip, AnyEnum)]
@@ -93,6 +106,8 @@ pub enum SdkVmConfigPeriphery<F: PrimeField32> {
     #[any_enum]
     Keccak(Keccak256Periphery<F>),
     #[any_enum]
+    Sha256(Sha256Periphery<F>),
+    #[any_enum]
     Native(NativePeriphery<F>),
     #[any_enum]
     Rv32m(Rv32MPeriphery<F>),
@@ -106,9 +121,41 @@ pub enum SdkVmConfigPeriphery<F: PrimeField32> {
     Pairing(PairingExtensionPeriphery<F>),
     #[any_enum]
     Ecc(WeierstrassExtensionPeriphery<F>),
+    #[any_enum]
+    Edwards(TwistedEdwardsExtensionPeriphery<F>),
+}
+
+impl<S: sdk_vm_config_builder::IsComplete> SdkVmConfigBuilder<S> {
+    pub fn build(self) -> SdkVmConfig {
+        self.build_unchecked().with_extension_range_checker_bits()
+    }
+}
+
+impl Serialize for SdkVmConfig {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Calls the derived implementation.
+        SdkVmConfig::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SdkVmConfig {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Calls the derived implementation.
+        SdkVmConfig::deserialize(deserializer).map(Self::with_extension_range_checker_bits)
+    }
 }
 
 impl SdkVmConfig {
+    /// Raises `memory_config.decomp`, the number of bits of the range checker, to the number
+    /// required by the extensions.
+    fn with_extension_range_checker_bits(mut self) -> Self {
+        if let Some(ref modular) = self.modular {
+            let memory_config = &mut self.system.config.memory_config;
+            memory_config.decomp = memory_config.decomp.max(modular.range_checker_bits());
+        }
+        self
+    }
+
     pub fn transpiler(&self) -> Transpiler<F> {
         let mut transpiler = Transpiler::default();
         if self.rv32i.is_some() {
@@ -120,6 +167,9 @@ impl SdkVmConfig {
         if self.keccak.is_some() {
             transpiler = transpiler.with_extension(Keccak256TranspilerExtension);
         }
+        if self.sha256.is_some() {
+            transpiler = transpiler.with_extension(Sha256TranspilerExtension);
+        }
         if self.rv32m.is_some() {
             transpiler = transpiler.with_extension(Rv32MTranspilerExtension);
         }
@@ -138,6 +188,9 @@ impl SdkVmConfig {
         if self.ecc.is_some() {
             transpiler = transpiler.with_extension(EccTranspilerExtension);
         }
+        if self.edwards.is_some() {
+            transpiler = transpiler.with_extension(EdwardsTranspilerExtension);
+        }
         transpiler
     }
 }
@@ -168,6 +221,9 @@ impl<F: PrimeField32> VmConfig<F> for SdkVmConfig {
         if self.keccak.is_some() {
             complex = complex.extend(&Keccak256)?;
         }
+        if self.sha256.is_some() {
+            complex = complex.extend(&Sha256)?;
+        }
         if self.native.is_some() {
             complex = complex.extend(&Native)?;
         }
@@ -204,6 +260,9 @@ impl<F: PrimeField32> VmConfig<F> for SdkVmConfig {
         if let Some(ref ecc) = self.ecc {
             complex = complex.extend(ecc)?;
         }
+        if let Some(ref edwards) = self.edwards {
+            complex = complex.extend(edwards)?;
+        }
 
         Ok(complex)
     }
@@ -251,6 +310,12 @@ impl From<Keccak256> for UnitStruct {
     }
 }
 
+impl From<Sha256> for UnitStruct {
+    fn from(_: Sha256) -> Self {
+        UnitStruct {}
+    }
+}
+
 impl From<Native> for UnitStruct {
     fn from(_: Native) -> Self {
         UnitStruct {}
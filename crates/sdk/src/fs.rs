@@ -1,5 +1,6 @@
 use std::{
-    fs::{create_dir_all, read, write},
+    fs::{create_dir_all, read, write, File},
+    io::{BufReader, BufWriter, ErrorKind, Read, Write},
     path::Path,
 };
 
@@ -7,6 +8,8 @@ use eyre::Result;
 use openvm_circuit::arch::{instructions::exe::VmExe, VmConfig};
 use openvm_native_recursion::halo2::{wrapper::EvmVerifier, EvmProof};
 use serde::{de::DeserializeOwned, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
 
 use crate::{
     keygen::{AggProvingKey, AppProvingKey, AppVerifyingKey},
@@ -15,59 +18,82 @@ use crate::{
 };
 
 pub fn read_exe_from_file<P: AsRef<Path>>(path: P) -> Result<VmExe<F>> {
-    read_from_file_bitcode(path)
+    Ok(read_artifact_from_file(path, ArtifactKind::Exe)?)
 }
 
 pub fn write_exe_to_file<P: AsRef<Path>>(exe: VmExe<F>, path: P) -> Result<()> {
-    write_to_file_bitcode(path, exe)
+    Ok(write_artifact_to_file(path, ArtifactKind::Exe, &exe)?)
 }
 
 pub fn read_app_pk_from_file<VC: VmConfig<F>, P: AsRef<Path>>(
     path: P,
 ) -> Result<AppProvingKey<VC>> {
-    read_from_file_bitcode(path)
+    Ok(read_artifact_from_file(path, ArtifactKind::AppProvingKey)?)
 }
 
 pub fn write_app_pk_to_file<VC: VmConfig<F>, P: AsRef<Path>>(
     app_pk: AppProvingKey<VC>,
     path: P,
 ) -> Result<()> {
-    write_to_file_bitcode(path, app_pk)
+    Ok(write_artifact_to_file(
+        path,
+        ArtifactKind::AppProvingKey,
+        &app_pk,
+    )?)
 }
 
 pub fn read_app_vk_from_file<P: AsRef<Path>>(path: P) -> Result<AppVerifyingKey> {
-    read_from_file_bitcode(path)
+    Ok(read_artifact_from_file(
+        path,
+        ArtifactKind::AppVerifyingKey,
+    )?)
 }
 
 pub fn write_app_vk_to_file<P: AsRef<Path>>(app_vk: AppVerifyingKey, path: P) -> Result<()> {
-    write_to_file_bitcode(path, app_vk)
+    Ok(write_artifact_to_file(
+        path,
+        ArtifactKind::AppVerifyingKey,
+        &app_vk,
+    )?)
 }
 
 pub fn read_app_proof_from_file<P: AsRef<Path>>(path: P) -> Result<ContinuationVmProof<SC>> {
-    read_from_file_bitcode(path)
+    Ok(read_artifact_from_file(path, ArtifactKind::AppProof)?)
 }
 
 pub fn write_app_proof_to_file<P: AsRef<Path>>(
     proof: ContinuationVmProof<SC>,
     path: P,
 ) -> Result<()> {
-    write_to_file_bitcode(path, proof)
+    Ok(write_artifact_to_file(
+        path,
+        ArtifactKind::AppProof,
+        &proof,
+    )?)
 }
 
 pub fn read_agg_pk_from_file<P: AsRef<Path>>(path: P) -> Result<AggProvingKey> {
-    read_from_file_bitcode(path)
+    Ok(read_artifact_from_file(path, ArtifactKind::AggProvingKey)?)
 }
 
 pub fn write_agg_pk_to_file<P: AsRef<Path>>(agg_pk: AggProvingKey, path: P) -> Result<()> {
-    write_to_file_bitcode(path, agg_pk)
+    Ok(write_artifact_to_file(
+        path,
+        ArtifactKind::AggProvingKey,
+        &agg_pk,
+    )?)
 }
 
 pub fn read_evm_proof_from_file<P: AsRef<Path>>(path: P) -> Result<EvmProof> {
-    read_from_file_bitcode(path)
+    Ok(read_artifact_from_file(path, ArtifactKind::EvmProof)?)
 }
 
 pub fn write_evm_proof_to_file<P: AsRef<Path>>(proof: EvmProof, path: P) -> Result<()> {
-    write_to_file_bitcode(path, proof)
+    Ok(write_artifact_to_file(
+        path,
+        ArtifactKind::EvmProof,
+        &proof,
+    )?)
 }
 
 pub fn read_evm_verifier_from_file<P: AsRef<Path>>(path: P) -> Result<EvmVerifier> {
@@ -78,21 +104,223 @@ pub fn write_evm_verifier_to_file<P: AsRef<Path>>(verifier: EvmVerifier, path: P
     write_to_file_bytes(path, verifier)
 }
 
-pub(crate) fn read_from_file_bitcode<T: DeserializeOwned, P: AsRef<Path>>(path: P) -> Result<T> {
-    let data = std::fs::read(path)?;
-    let ret = bitcode::deserialize(&data)?;
-    Ok(ret)
+/// Magic bytes at the start of every artifact file written by this module.
+pub const ARTIFACT_MAGIC: [u8; 8] = *b"OPENVM\0\x01";
+/// Version of the artifact container format. Bump it whenever the header layout changes.
+pub const ARTIFACT_FORMAT_VERSION: u32 = 1;
+/// Version of this SDK. Artifacts are only read by the SDK version which wrote them, because the
+/// serialization of keys and proofs is not stable across versions.
+pub const SDK_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Kind of the artifact stored in a file, so that e.g. a proof is not read as a proving key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ArtifactKind {
+    Exe = 1,
+    AppProvingKey = 2,
+    AppVerifyingKey = 3,
+    AppProof = 4,
+    AggProvingKey = 5,
+    EvmProof = 6,
+}
+
+impl ArtifactKind {
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(Self::Exe),
+            2 => Some(Self::AppProvingKey),
+            3 => Some(Self::AppVerifyingKey),
+            4 => Some(Self::AppProof),
+            5 => Some(Self::AggProvingKey),
+            6 => Some(Self::EvmProof),
+            _ => None,
+        }
+    }
+}
+
+/// Header of an artifact file. The file layout is:
+/// - [ARTIFACT_MAGIC],
+/// - the artifact kind as 1 byte,
+/// - the format version as a little-endian `u32`,
+/// - the length of the SDK version string as a little-endian `u16`, followed by the string,
+/// - the payload length as a little-endian `u64`,
+/// - the SHA-256 checksum of the payload,
+/// - the payload, which is the `bitcode` serialization of the artifact.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArtifactHeader {
+    pub kind: ArtifactKind,
+    pub format_version: u32,
+    pub sdk_version: String,
+    pub payload_len: u64,
+    pub checksum: [u8; 32],
+}
+
+#[derive(Error, Debug)]
+pub enum ArtifactError {
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("not an OpenVM artifact file (it may have been written by an older SDK)")]
+    BadMagic,
+
+    #[error("unknown artifact kind {0}")]
+    UnknownKind(u8),
+
+    #[error("artifact kind mismatch (expected: {expected:?}, actual: {actual:?})")]
+    KindMismatch {
+        expected: ArtifactKind,
+        actual: ArtifactKind,
+    },
+
+    #[error("unsupported artifact format version (expected: {expected}, actual: {actual})")]
+    UnsupportedFormatVersion { expected: u32, actual: u32 },
+
+    #[error("artifact was written by SDK version {actual}, but this is SDK version {expected}")]
+    SdkVersionMismatch { expected: String, actual: String },
+
+    #[error("payload length mismatch (header: {expected}, actual: {actual})")]
+    PayloadLengthMismatch { expected: u64, actual: u64 },
+
+    #[error("payload checksum mismatch")]
+    ChecksumMismatch,
+
+    #[error("bitcode error: {0}")]
+    Bitcode(#[from] bitcode::Error),
 }
 
-pub(crate) fn write_to_file_bitcode<T: Serialize, P: AsRef<Path>>(path: P, data: T) -> Result<()> {
-    let bytes = bitcode::serialize(&data)?;
+impl ArtifactHeader {
+    fn new(kind: ArtifactKind, payload: &[u8]) -> Self {
+        Self {
+            kind,
+            format_version: ARTIFACT_FORMAT_VERSION,
+            sdk_version: SDK_VERSION.to_string(),
+            payload_len: payload.len() as u64,
+            checksum: Sha256::digest(payload).into(),
+        }
+    }
+
+    fn write_to(&self, writer: &mut impl Write) -> Result<(), ArtifactError> {
+        let sdk_version_len =
+            u16::try_from(self.sdk_version.len()).expect("SDK version string should fit in u16");
+        writer.write_all(&ARTIFACT_MAGIC)?;
+        writer.write_all(&[self.kind as u8])?;
+        writer.write_all(&self.format_version.to_le_bytes())?;
+        writer.write_all(&sdk_version_len.to_le_bytes())?;
+        writer.write_all(self.sdk_version.as_bytes())?;
+        writer.write_all(&self.payload_len.to_le_bytes())?;
+        writer.write_all(&self.checksum)?;
+        Ok(())
+    }
+
+    /// Reads the header from `reader`, leaving it at the start of the payload. Only checks that
+    /// the header is well-formed and has a supported format version.
+    fn read_from(reader: &mut impl Read) -> Result<Self, ArtifactError> {
+        let magic: [u8; 8] = read_array(reader).map_err(|e| match e.kind() {
+            ErrorKind::UnexpectedEof => ArtifactError::BadMagic,
+            _ => e.into(),
+        })?;
+        if magic != ARTIFACT_MAGIC {
+            return Err(ArtifactError::BadMagic);
+        }
+        let [kind] = read_array(reader)?;
+        let kind = ArtifactKind::from_u8(kind).ok_or(ArtifactError::UnknownKind(kind))?;
+        let format_version = u32::from_le_bytes(read_array(reader)?);
+        if format_version != ARTIFACT_FORMAT_VERSION {
+            return Err(ArtifactError::UnsupportedFormatVersion {
+                expected: ARTIFACT_FORMAT_VERSION,
+                actual: format_version,
+            });
+        }
+        let sdk_version_len = u16::from_le_bytes(read_array(reader)?);
+        let mut sdk_version = vec![0; sdk_version_len as usize];
+        reader.read_exact(&mut sdk_version)?;
+        let sdk_version = String::from_utf8_lossy(&sdk_version).into_owned();
+        let payload_len = u64::from_le_bytes(read_array(reader)?);
+        let checksum = read_array(reader)?;
+        Ok(Self {
+            kind,
+            format_version,
+            sdk_version,
+            payload_len,
+            checksum,
+        })
+    }
+
+    /// Checks that an artifact with this header can be read as `kind` by this SDK.
+    pub fn check(&self, kind: ArtifactKind) -> Result<(), ArtifactError> {
+        if self.kind != kind {
+            return Err(ArtifactError::KindMismatch {
+                expected: kind,
+                actual: self.kind,
+            });
+        }
+        if self.sdk_version != SDK_VERSION {
+            return Err(ArtifactError::SdkVersionMismatch {
+                expected: SDK_VERSION.to_string(),
+                actual: self.sdk_version.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Reads only the header of the artifact file at `path`, without reading the payload. This is
+/// cheap even for large proving keys.
+pub fn read_artifact_header_from_file<P: AsRef<Path>>(
+    path: P,
+) -> Result<ArtifactHeader, ArtifactError> {
+    let mut reader = BufReader::new(File::open(path)?);
+    ArtifactHeader::read_from(&mut reader)
+}
+
+/// Reads an artifact of the given `kind` from `path`. The header is checked before the payload
+/// is read, and the payload is checked against the checksum before it is deserialized.
+pub fn read_artifact_from_file<T: DeserializeOwned, P: AsRef<Path>>(
+    path: P,
+    kind: ArtifactKind,
+) -> Result<T, ArtifactError> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let header = ArtifactHeader::read_from(&mut reader)?;
+    header.check(kind)?;
+    let mut payload = Vec::new();
+    reader.read_to_end(&mut payload)?;
+    if payload.len() as u64 != header.payload_len {
+        return Err(ArtifactError::PayloadLengthMismatch {
+            expected: header.payload_len,
+            actual: payload.len() as u64,
+        });
+    }
+    if Sha256::digest(&payload).as_slice() != header.checksum {
+        return Err(ArtifactError::ChecksumMismatch);
+    }
+    Ok(bitcode::deserialize(&payload)?)
+}
+
+/// Writes `data` to `path` as an artifact of the given `kind`, creating the parent directories
+/// if needed.
+pub fn write_artifact_to_file<T: Serialize, P: AsRef<Path>>(
+    path: P,
+    kind: ArtifactKind,
+    data: &T,
+) -> Result<(), ArtifactError> {
+    let payload = bitcode::serialize(data)?;
+    let header = ArtifactHeader::new(kind, &payload);
     if let Some(parent) = path.as_ref().parent() {
         create_dir_all(parent)?;
     }
-    write(path, bytes)?;
+    let mut writer = BufWriter::new(File::create(path)?);
+    header.write_to(&mut writer)?;
+    writer.write_all(&payload)?;
+    writer.flush()?;
     Ok(())
 }
 
+fn read_array<const N: usize>(reader: &mut impl Read) -> std::io::Result<[u8; N]> {
+    let mut buf = [0; N];
+    reader.read_exact(&mut buf)?;
+    Ok(buf)
+}
+
 pub(crate) fn read_from_file_bytes<T: From<Vec<u8>>, P: AsRef<Path>>(path: P) -> Result<T> {
     let bytes = read(path)?;
     Ok(T::from(bytes))
@@ -108,3 +336,53 @@ pub(crate) fn write_to_file_bytes<T: Into<Vec<u8>>, P: AsRef<Path>>(
     write(path, data.into())?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::remove_file, path::PathBuf};
+
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("openvm-sdk-{}-{name}", std::process::id()))
+    }
+
+    #[test]
+    fn test_artifact_roundtrip_and_errors() {
+        let path = temp_path("artifact");
+        let data = vec![1u32, 2, 3];
+        write_artifact_to_file(&path, ArtifactKind::AppProof, &data).unwrap();
+
+        let header = read_artifact_header_from_file(&path).unwrap();
+        assert_eq!(header.kind, ArtifactKind::AppProof);
+        assert_eq!(header.format_version, ARTIFACT_FORMAT_VERSION);
+        assert_eq!(header.sdk_version, SDK_VERSION);
+        let read_data: Vec<u32> = read_artifact_from_file(&path, ArtifactKind::AppProof).unwrap();
+        assert_eq!(read_data, data);
+
+        match read_artifact_from_file::<Vec<u32>, _>(&path, ArtifactKind::AppProvingKey) {
+            Err(ArtifactError::KindMismatch {
+                expected: ArtifactKind::AppProvingKey,
+                actual: ArtifactKind::AppProof,
+            }) => {}
+            res => panic!("Expected kind mismatch, got {res:?}"),
+        }
+
+        // Corrupt the last byte of the payload.
+        let mut bytes = read(&path).unwrap();
+        *bytes.last_mut().unwrap() ^= 1;
+        write(&path, &bytes).unwrap();
+        match read_artifact_from_file::<Vec<u32>, _>(&path, ArtifactKind::AppProof) {
+            Err(ArtifactError::ChecksumMismatch) => {}
+            res => panic!("Expected checksum mismatch, got {res:?}"),
+        }
+
+        // A file written without a header, e.g. by an older SDK.
+        write(&path, bitcode::serialize(&data).unwrap()).unwrap();
+        match read_artifact_header_from_file(&path) {
+            Err(ArtifactError::BadMagic) => {}
+            res => panic!("Expected bad magic, got {res:?}"),
+        }
+        remove_file(&path).unwrap();
+    }
+}
@@ -1,6 +1,6 @@
 extern crate core;
 
-use std::{fs::read, panic::catch_unwind, path::Path, sync::Arc};
+use std::{fs::read, path::Path, sync::Arc};
 
 use commit::commit_app_exe;
 use config::AppConfig;
@@ -16,20 +16,19 @@ use openvm_circuit::{
 use openvm_native_recursion::{
     halo2::{
         utils::Halo2ParamsReader,
-        wrapper::{EvmVerifier, Halo2WrapperProvingKey},
+        wrapper::{
+            EvmVerificationError, EvmVerifier, Halo2WrapperProvingKey, NativeVerificationError,
+        },
         EvmProof,
     },
     types::InnerConfig,
 };
-use openvm_stark_backend::engine::StarkEngine;
 use openvm_stark_sdk::{
     config::{
-        baby_bear_poseidon2::{BabyBearPoseidon2Config, BabyBearPoseidon2Engine},
-        baby_bear_poseidon2_root::BabyBearPoseidon2RootConfig,
-        FriParameters,
+        baby_bear_poseidon2::BabyBearPoseidon2Config,
+        baby_bear_poseidon2_root::BabyBearPoseidon2RootConfig, FriParameters,
     },
-    engine::StarkFriEngine,
-    openvm_stark_backend::{verifier::VerificationError, Chip},
+    openvm_stark_backend::{prover::types::Proof, Chip},
     p3_baby_bear::BabyBear,
 };
 use openvm_transpiler::{
@@ -39,10 +38,15 @@ use openvm_transpiler::{
     FromElf,
 };
 use prover::vm::ContinuationVmProof;
+use verifier::{
+    app::AppProofVerificationError,
+    root::{types::RootVmVerifierPvs, RootProofVerificationError},
+};
 
 pub mod commit;
 pub mod config;
 pub mod prover;
+pub mod public_values;
 pub mod static_verifier;
 
 pub mod keygen;
@@ -54,7 +58,7 @@ pub mod fs;
 
 use crate::{
     config::AggConfig,
-    keygen::AggProvingKey,
+    keygen::{AggProvingKey, RootVerifierProvingKey},
     prover::{AppProver, ContinuationProver},
 };
 
@@ -115,7 +119,7 @@ impl Sdk {
             &vm.config.system().memory_config.memory_dimensions(),
             vm.config.system().num_public_values,
             final_memory.as_ref().unwrap(),
-        );
+        )?;
         Ok(public_values)
     }
 
@@ -152,17 +156,15 @@ impl Sdk {
         Ok(proof)
     }
 
+    /// Verifies an App VM proof of an execution of `app_exe`, including the continuation checks
+    /// between segments. See [verifier::app::verify_app_proof].
     pub fn verify_app_proof(
         &self,
         app_vk: &AppVerifyingKey,
+        app_exe: &NonRootCommittedExe,
         proof: &ContinuationVmProof<SC>,
-    ) -> Result<(), VerificationError> {
-        let e = BabyBearPoseidon2Engine::new(app_vk.fri_params);
-        for seg_proof in &proof.per_segment {
-            e.verify(&app_vk.app_vm_vk, seg_proof)?
-        }
-        // TODO: verify continuation.
-        Ok(())
+    ) -> Result<(), AppProofVerificationError> {
+        verifier::app::verify_app_proof(app_vk, app_exe, proof)
     }
 
     pub fn agg_keygen(
@@ -201,11 +203,46 @@ impl Sdk {
         Ok(evm_verifier)
     }
 
-    pub fn verify_evm_proof(&self, evm_verifier: &EvmVerifier, evm_proof: &EvmProof) -> bool {
-        // FIXME: we should return the concrete error.
-        catch_unwind(|| {
-            Halo2WrapperProvingKey::evm_verify(evm_verifier, evm_proof);
-        })
-        .is_ok()
+    /// Verifies `evm_proof` with `evm_verifier` in a local EVM and returns the gas used by the
+    /// verification call.
+    pub fn verify_evm_proof(
+        &self,
+        evm_verifier: &EvmVerifier,
+        evm_proof: &EvmProof,
+    ) -> Result<u64, EvmVerificationError> {
+        Halo2WrapperProvingKey::evm_verify(evm_verifier, evm_proof)
+    }
+
+    /// Same as [Self::verify_evm_proof], but the verification call is limited to `gas_limit`.
+    pub fn verify_evm_proof_with_gas_limit(
+        &self,
+        evm_verifier: &EvmVerifier,
+        evm_proof: &EvmProof,
+        gas_limit: u64,
+    ) -> Result<u64, EvmVerificationError> {
+        Halo2WrapperProvingKey::evm_verify_with_gas_limit(evm_verifier, evm_proof, gas_limit)
+    }
+
+    /// Verifies a proof of the root verifier natively and returns its public values. See
+    /// [verifier::root::verify_root_proof].
+    pub fn verify_root_proof(
+        &self,
+        root_verifier_pk: &RootVerifierProvingKey,
+        proof: &Proof<RootSC>,
+    ) -> Result<RootVmVerifierPvs<F>, RootProofVerificationError> {
+        verifier::root::verify_root_proof(root_verifier_pk, proof)
+    }
+
+    /// Verifies `evm_proof` natively with the Halo2 KZG verifier. Unlike [Self::verify_evm_proof],
+    /// this needs neither an EVM verifier contract nor an EVM.
+    pub fn verify_evm_proof_natively(
+        &self,
+        reader: &impl Halo2ParamsReader,
+        agg_pk: &AggProvingKey,
+        evm_proof: &EvmProof,
+    ) -> Result<(), NativeVerificationError> {
+        let wrapper = &agg_pk.halo2_pk.wrapper;
+        let params = reader.read_params(wrapper.pinning.metadata.config_params.k);
+        wrapper.native_verify(&params, evm_proof)
     }
 }
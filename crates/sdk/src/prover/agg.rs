@@ -9,7 +9,9 @@ use openvm_stark_sdk::{
     config::baby_bear_poseidon2::BabyBearPoseidon2Engine, engine::StarkFriEngine,
     openvm_stark_backend::prover::types::Proof,
 };
-use tracing::info_span;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use tracing::{info_span, Span};
 
 // #[cfg(feature = "bench-metrics")]
 use super::vm::types::VmProvingKey;
@@ -30,6 +32,64 @@ const DEFAULT_NUM_CHILDREN_LEAF: usize = 2;
 const DEFAULT_NUM_CHILDREN_INTERNAL: usize = 2;
 const DEFAULT_MAX_INTERNAL_WRAPPER_LAYERS: usize = 4;
 
+/// Scheduling of the proofs of sibling nodes of the aggregation tree, which are independent of
+/// each other.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AggProofScheduler {
+    /// Maximum number of sibling nodes proven at the same time. With `1`, the nodes are proven
+    /// one after another. Requires the `parallel` feature to have an effect.
+    pub max_concurrent_proofs: usize,
+    /// Number of threads shared by all concurrent proofs, including the parallelism within each
+    /// proof, so that concurrent proofs do not oversubscribe the machine. `None` uses the global
+    /// rayon thread pool.
+    pub num_threads: Option<usize>,
+}
+
+impl Default for AggProofScheduler {
+    fn default() -> Self {
+        Self {
+            max_concurrent_proofs: 1,
+            num_threads: None,
+        }
+    }
+}
+
+impl AggProofScheduler {
+    /// Proves all `inputs` with `prove`, which is given the index of the input within `inputs`.
+    /// The proofs are returned in the order of the inputs.
+    fn prove_all<I: Send, P: Send>(
+        &self,
+        inputs: Vec<I>,
+        prove: impl Fn(usize, I) -> P + Sync,
+    ) -> Vec<P> {
+        #[cfg(feature = "parallel")]
+        if self.max_concurrent_proofs > 1 {
+            let prove_batches = || {
+                let mut proofs = Vec::with_capacity(inputs.len());
+                let mut inputs = inputs.into_iter().enumerate().peekable();
+                while inputs.peek().is_some() {
+                    let batch: Vec<_> = inputs.by_ref().take(self.max_concurrent_proofs).collect();
+                    proofs.par_extend(batch.into_par_iter().map(|(idx, input)| prove(idx, input)));
+                }
+                proofs
+            };
+            return match self.num_threads {
+                Some(num_threads) => rayon::ThreadPoolBuilder::new()
+                    .num_threads(num_threads)
+                    .build()
+                    .expect("failed to build aggregation thread pool")
+                    .install(prove_batches),
+                None => prove_batches(),
+            };
+        }
+        inputs
+            .into_iter()
+            .enumerate()
+            .map(|(idx, input)| prove(idx, input))
+            .collect()
+    }
+}
+
 pub struct AggStarkProver {
     leaf_prover: LeafProver,
     internal_prover: VmLocalProver<SC, NativeConfig, BabyBearPoseidon2Engine>,
@@ -37,12 +97,14 @@ pub struct AggStarkProver {
 
     pub num_children_internal: usize,
     pub max_internal_wrapper_layers: usize,
+    pub scheduler: AggProofScheduler,
 
     pub profile: bool,
 }
 pub struct LeafProver {
     prover: VmLocalProver<SC, NativeConfig, BabyBearPoseidon2Engine>,
     pub num_children_leaf: usize,
+    pub scheduler: AggProofScheduler,
     pub profile: bool,
 }
 
@@ -63,6 +125,7 @@ impl AggStarkProver {
             root_prover,
             num_children_internal: DEFAULT_NUM_CHILDREN_INTERNAL,
             max_internal_wrapper_layers: DEFAULT_MAX_INTERNAL_WRAPPER_LAYERS,
+            scheduler: AggProofScheduler::default(),
             profile: false,
         }
     }
@@ -82,6 +145,17 @@ impl AggStarkProver {
         self
     }
 
+    /// Sets the scheduler of both the leaf and the internal proofs.
+    pub fn set_scheduler(&mut self, scheduler: AggProofScheduler) -> &mut Self {
+        self.scheduler = scheduler;
+        self.leaf_prover.scheduler = scheduler;
+        self
+    }
+    pub fn with_scheduler(mut self, scheduler: AggProofScheduler) -> Self {
+        self.set_scheduler(scheduler);
+        self
+    }
+
     pub fn set_profile(&mut self, profile: bool) -> &mut Self {
         self.profile = profile;
         self.leaf_prover.profile = profile;
@@ -142,24 +216,30 @@ impl AggStarkProver {
                 &proofs,
                 self.num_children_internal,
             );
-            proofs = info_span!("internal verifier", group = "internal").in_scope(|| {
+            proofs = info_span!(
+                "internal verifier",
+                group = "internal",
+                num_children_internal = self.num_children_internal
+            )
+            .in_scope(|| {
                 #[cfg(feature = "bench-metrics")]
                 metrics::counter!("fri.log_blowup")
                     .absolute(self.internal_prover.pk.fri_params.log_blowup as u64);
-                internal_inputs
-                    .into_iter()
-                    .map(|input| {
-                        internal_node_idx += 1;
-                        info_span!(
-                            "Internal verifier proof",
-                            idx = internal_node_idx,
-                            hgt = internal_node_height
-                        )
-                        .in_scope(|| {
-                            single_segment_prove(&self.internal_prover, input.write(), self.profile)
-                        })
+                // Spans entered on other threads need an explicit parent.
+                let parent = Span::current();
+                let first_node_idx = internal_node_idx + 1;
+                internal_node_idx += internal_inputs.len() as isize;
+                self.scheduler.prove_all(internal_inputs, |i, input| {
+                    info_span!(
+                        parent: &parent,
+                        "Internal verifier proof",
+                        idx = first_node_idx + i as isize,
+                        hgt = internal_node_height
+                    )
+                    .in_scope(|| {
+                        single_segment_prove(&self.internal_prover, input.write(), self.profile)
                     })
-                    .collect()
+                })
             });
             internal_node_height += 1;
         }
@@ -207,6 +287,7 @@ impl LeafProver {
         Self {
             prover,
             num_children_leaf: DEFAULT_NUM_CHILDREN_LEAF,
+            scheduler: AggProofScheduler::default(),
             profile: false,
         }
     }
@@ -214,12 +295,21 @@ impl LeafProver {
         self.num_children_leaf = num_children_leaf;
         self
     }
+    pub fn with_scheduler(mut self, scheduler: AggProofScheduler) -> Self {
+        self.scheduler = scheduler;
+        self
+    }
     pub fn with_profile(mut self) -> Self {
         self.profile = true;
         self
     }
     pub fn generate_proof(&self, app_proofs: &ContinuationVmProof<SC>) -> Vec<Proof<SC>> {
-        info_span!("leaf verifier", group = "leaf").in_scope(|| {
+        info_span!(
+            "leaf verifier",
+            group = "leaf",
+            num_children_leaf = self.num_children_leaf
+        )
+        .in_scope(|| {
             #[cfg(feature = "bench-metrics")]
             metrics::counter!("fri.log_blowup")
                 .absolute(self.prover.pk.fri_params.log_blowup as u64);
@@ -227,15 +317,19 @@ impl LeafProver {
                 app_proofs,
                 self.num_children_leaf,
             );
-            leaf_inputs
-                .into_iter()
-                .enumerate()
-                .map(|(leaf_node_idx, input)| {
-                    info_span!("leaf verifier proof", idx = leaf_node_idx).in_scope(|| {
-                        single_segment_prove(&self.prover, input.write_to_stream(), self.profile)
-                    })
+            // Spans entered on other threads need an explicit parent.
+            let parent = Span::current();
+            self.scheduler
+                .prove_all(leaf_inputs, |leaf_node_idx, input| {
+                    info_span!(parent: &parent, "leaf verifier proof", idx = leaf_node_idx)
+                        .in_scope(|| {
+                            single_segment_prove(
+                                &self.prover,
+                                input.write_to_stream(),
+                                self.profile,
+                            )
+                        })
                 })
-                .collect::<Vec<_>>()
         })
     }
 }
@@ -261,3 +355,31 @@ fn heights_le(a: &[usize], b: &[usize]) -> bool {
     assert_eq!(a.len(), b.len());
     a.iter().zip(b.iter()).all(|(a, b)| a <= b)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::AggProofScheduler;
+
+    #[test]
+    fn test_agg_proof_scheduler_keeps_order() {
+        let inputs: Vec<usize> = (0..11).collect();
+        let expected: Vec<_> = inputs.iter().map(|x| x * x).collect();
+        for scheduler in [
+            AggProofScheduler::default(),
+            AggProofScheduler {
+                max_concurrent_proofs: 4,
+                num_threads: None,
+            },
+            AggProofScheduler {
+                max_concurrent_proofs: 3,
+                num_threads: Some(2),
+            },
+        ] {
+            let proofs = scheduler.prove_all(inputs.clone(), |idx, x| {
+                assert_eq!(idx, x);
+                x * x
+            });
+            assert_eq!(proofs, expected);
+        }
+    }
+}
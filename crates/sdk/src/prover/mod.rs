@@ -71,6 +71,12 @@ impl<VC> ContinuationProver<VC> {
         self
     }
 
+    /// Sets how the proofs of sibling nodes of the aggregation tree are scheduled.
+    pub fn set_agg_scheduler(&mut self, scheduler: AggProofScheduler) -> &mut Self {
+        self.stark_prover.set_agg_scheduler(scheduler);
+        self
+    }
+
     pub fn generate_proof_for_evm(&self, input: StdIn) -> EvmProof
     where
         VC: VmConfig<F>,
@@ -5,7 +5,10 @@ use openvm_stark_backend::{prover::types::Proof, Chip};
 
 use crate::{
     keygen::{AggStarkProvingKey, AppProvingKey},
-    prover::{agg::AggStarkProver, app::AppProver},
+    prover::{
+        agg::{AggProofScheduler, AggStarkProver},
+        app::AppProver,
+    },
     NonRootCommittedExe, RootSC, StdIn, F, SC,
 };
 
@@ -46,6 +49,11 @@ impl<VC> StarkProver<VC> {
         self.app_prover.set_program_name(program_name);
         self
     }
+    /// Sets how the proofs of sibling nodes of the aggregation tree are scheduled.
+    pub fn set_agg_scheduler(&mut self, scheduler: AggProofScheduler) -> &mut Self {
+        self.agg_prover.set_scheduler(scheduler);
+        self
+    }
     pub fn generate_proof_for_outer_recursion(&self, input: StdIn) -> Proof<RootSC>
     where
         VC: VmConfig<F>,
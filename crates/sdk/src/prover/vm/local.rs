@@ -3,8 +3,8 @@ use std::{marker::PhantomData, sync::Arc};
 use async_trait::async_trait;
 use openvm_circuit::{
     arch::{
-        hasher::poseidon2::vm_poseidon2_hasher, Streams, VirtualMachine, VmComplexTraceHeights,
-        VmConfig,
+        hasher::poseidon2::vm_poseidon2_hasher, ExecutionError, SegmentCheckpoint, Streams,
+        VirtualMachine, VmComplexTraceHeights, VmConfig, VmExecutionCheckpoints, VmMemoryState,
     },
     system::{memory::tree::public_values::UserPublicValuesProof, program::trace::VmCommittedExe},
 };
@@ -59,14 +59,19 @@ where
     }
 }
 
-impl<SC: StarkGenericConfig, VC: VmConfig<Val<SC>>, E: StarkFriEngine<SC>> ContinuationVmProver<SC>
-    for VmLocalProver<SC, VC, E>
+impl<SC: StarkGenericConfig, VC: VmConfig<Val<SC>>, E: StarkFriEngine<SC>> VmLocalProver<SC, VC, E>
 where
     Val<SC>: PrimeField32,
     VC::Executor: Chip<SC>,
     VC::Periphery: Chip<SC>,
 {
-    fn prove(&self, input: impl Into<Streams<Val<SC>>>) -> ContinuationVmProof<SC> {
+    /// Executes the program without proving and returns the checkpoint of every segment. Each
+    /// segment can then be proven on its own with [Self::prove_segment], e.g. by a different
+    /// worker, and the proofs combined with [Self::continuation_proof].
+    pub fn execute_checkpoints(
+        &self,
+        input: impl Into<Streams<Val<SC>>>,
+    ) -> VmExecutionCheckpoints<Val<SC>> {
         assert!(self.pk.vm_config.system().continuation_enabled);
         let e = E::new(self.pk.fri_params);
         let vm = VirtualMachine::new_with_overridden_trace_heights(
@@ -74,20 +79,65 @@ where
             self.pk.vm_config.clone(),
             self.overridden_heights.clone(),
         );
-        let results = vm
-            .execute_and_generate_with_cached_program(self.committed_exe.clone(), input)
-            .unwrap();
+        vm.execute_checkpoints(self.committed_exe.exe.clone(), input)
+            .unwrap()
+    }
+
+    /// Proves the segment starting at `checkpoint`.
+    pub fn prove_segment(&self, checkpoint: SegmentCheckpoint<Val<SC>>) -> Proof<SC> {
+        let e = E::new(self.pk.fri_params);
+        let vm = VirtualMachine::new_with_overridden_trace_heights(
+            e,
+            self.pk.vm_config.clone(),
+            self.overridden_heights.clone(),
+        );
+        vm.prove_segment_from_checkpoint(&self.pk.vm_pk, self.committed_exe.clone(), checkpoint)
+            .unwrap()
+    }
+
+    /// Combines the proofs of all segments, in order, and the final memory state returned by
+    /// [Self::execute_checkpoints] into a [ContinuationVmProof]. Returns an error if the guest
+    /// revealed a public value out of bounds.
+    pub fn continuation_proof(
+        &self,
+        per_segment: Vec<Proof<SC>>,
+        final_memory: &VmMemoryState<Val<SC>>,
+    ) -> Result<ContinuationVmProof<SC>, ExecutionError> {
         let user_public_values = UserPublicValuesProof::compute(
             self.pk.vm_config.system().memory_config.memory_dimensions(),
             self.pk.vm_config.system().num_public_values,
             &vm_poseidon2_hasher(),
-            results.final_memory.as_ref().unwrap(),
-        );
-        let per_segment = vm.prove(&self.pk.vm_pk, results);
-        ContinuationVmProof {
+            final_memory,
+        )?;
+        Ok(ContinuationVmProof {
             per_segment,
             user_public_values,
-        }
+        })
+    }
+}
+
+impl<SC: StarkGenericConfig, VC: VmConfig<Val<SC>>, E: StarkFriEngine<SC>> ContinuationVmProver<SC>
+    for VmLocalProver<SC, VC, E>
+where
+    Val<SC>: PrimeField32,
+    VC::Executor: Chip<SC>,
+    VC::Periphery: Chip<SC>,
+{
+    fn prove(&self, input: impl Into<Streams<Val<SC>>>) -> ContinuationVmProof<SC> {
+        assert!(self.pk.vm_config.system().continuation_enabled);
+        let e = E::new(self.pk.fri_params);
+        let vm = VirtualMachine::new_with_overridden_trace_heights(
+            e,
+            self.pk.vm_config.clone(),
+            self.overridden_heights.clone(),
+        );
+        // Each segment is proven as soon as it has been executed, so that the traces of all
+        // segments are never held in memory at once.
+        let proof = vm
+            .prove_streaming(&self.pk.vm_pk, self.committed_exe.clone(), input)
+            .unwrap();
+        self.continuation_proof(proof.per_segment, proof.final_memory.as_ref().unwrap())
+            .unwrap()
     }
 }
 
@@ -0,0 +1,83 @@
+use openvm_stark_backend::p3_field::PrimeField32;
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+
+use crate::F;
+
+#[derive(Error, Debug)]
+pub enum PublicValuesDecodeError {
+    #[error("public value {index} is not a byte: {value}")]
+    NotAByte { index: usize, value: u32 },
+
+    #[error("failed to deserialize public values: {0}")]
+    Deserialize(#[from] openvm::serde::Error),
+}
+
+/// Decodes user public values into `T` with `openvm::serde`. Each public value is one byte of
+/// the serialized data, in the order they were revealed by the guest. Trailing zero public
+/// values are ignored.
+///
+/// The public values should come from a verified proof, e.g. the
+/// [UserPublicValuesProof](openvm_circuit::system::memory::tree::public_values::UserPublicValuesProof)
+/// of a proof accepted by [Sdk::verify_app_proof](crate::Sdk::verify_app_proof).
+pub fn decode_public_values<T: DeserializeOwned>(
+    public_values: &[F],
+) -> Result<T, PublicValuesDecodeError> {
+    let mut bytes = public_values
+        .iter()
+        .enumerate()
+        .map(|(index, v)| {
+            let value = v.as_canonical_u32();
+            u8::try_from(value).map_err(|_| PublicValuesDecodeError::NotAByte { index, value })
+        })
+        .collect::<Result<Vec<u8>, _>>()?;
+    // `openvm::serde` reads whole words.
+    bytes.resize(bytes.len().next_multiple_of(4), 0);
+    Ok(openvm::serde::from_slice(&bytes)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use openvm_stark_backend::p3_field::AbstractField;
+    use serde::{Deserialize, Serialize};
+
+    use super::{decode_public_values, PublicValuesDecodeError};
+    use crate::F;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Output {
+        a: u32,
+        b: [u8; 3],
+        c: u64,
+    }
+
+    #[test]
+    fn test_decode_public_values() {
+        let output = Output {
+            a: 7,
+            b: [1, 2, 3],
+            c: u64::MAX,
+        };
+        let words = openvm::serde::to_vec(&output).unwrap();
+        let mut public_values: Vec<F> = words
+            .into_iter()
+            .flat_map(|w| w.to_le_bytes())
+            .map(F::from_canonical_u8)
+            .collect();
+        // Unused public values are zero.
+        public_values.resize(64, F::ZERO);
+        assert_eq!(
+            decode_public_values::<Output>(&public_values).unwrap(),
+            output
+        );
+
+        public_values[0] = F::from_canonical_u32(256);
+        assert!(matches!(
+            decode_public_values::<Output>(&public_values),
+            Err(PublicValuesDecodeError::NotAByte {
+                index: 0,
+                value: 256
+            })
+        ));
+    }
+}
@@ -0,0 +1,177 @@
+use std::borrow::Borrow;
+
+use openvm_circuit::{
+    arch::{
+        hasher::poseidon2::vm_poseidon2_hasher, ExitCode, CONNECTOR_AIR_ID, MERKLE_AIR_ID,
+        PROGRAM_CACHED_TRACE_INDEX,
+    },
+    system::{
+        connector::{VmConnectorPvs, DEFAULT_SUSPEND_EXIT_CODE},
+        memory::{merkle::MemoryMerklePvs, tree::public_values::UserPublicValuesProofError},
+    },
+};
+use openvm_native_compiler::ir::DIGEST_SIZE;
+use openvm_stark_sdk::{
+    config::baby_bear_poseidon2::BabyBearPoseidon2Engine,
+    engine::StarkFriEngine,
+    openvm_stark_backend::{
+        engine::StarkEngine, p3_field::PrimeField32, prover::types::Proof,
+        verifier::VerificationError,
+    },
+};
+use thiserror::Error;
+
+use crate::{
+    commit::compute_init_memory_commit, keygen::AppVerifyingKey, prover::vm::ContinuationVmProof,
+    NonRootCommittedExe, F, SC,
+};
+
+/// Reasons an App VM continuation proof can be rejected. `segment` is the index of the first
+/// segment proof in [ContinuationVmProof::per_segment] which breaks the continuation.
+#[derive(Error, Debug)]
+pub enum AppProofVerificationError {
+    #[error("proof has no segments")]
+    NoSegments,
+
+    #[error("segment {segment}: stark verification error: {error}")]
+    StarkError {
+        segment: usize,
+        #[source]
+        error: VerificationError,
+    },
+
+    #[error("segment {segment}: missing public values of AIR {air_id}")]
+    MissingAir { segment: usize, air_id: usize },
+
+    #[error("segment {segment}: program commit does not match the committed exe")]
+    ProgramCommitMismatch { segment: usize },
+
+    #[error("segment {segment}: initial pc mismatch (expected: {expected}, actual: {actual})")]
+    InitialPcMismatch {
+        segment: usize,
+        expected: u32,
+        actual: u32,
+    },
+
+    #[error("segment {segment}: initial memory root mismatch")]
+    InitialMemoryRootMismatch { segment: usize },
+
+    #[error("segment {segment}: terminated before the last segment")]
+    UnexpectedTermination { segment: usize },
+
+    #[error("segment {segment}: last segment did not terminate")]
+    DidNotTerminate { segment: usize },
+
+    #[error("segment {segment}: exit code mismatch (expected: {expected}, actual: {actual})")]
+    ExitCodeMismatch {
+        segment: usize,
+        expected: u32,
+        actual: u32,
+    },
+
+    #[error("user public values proof error: {0}")]
+    UserPublicValues(#[from] UserPublicValuesProofError),
+}
+
+/// Verifies every segment proof of `proof` and checks that the segments form a single
+/// successful execution of `app_exe`:
+/// - every segment runs the program committed in `app_exe`,
+/// - the first segment starts from `app_exe`'s `pc_start` and initial memory root,
+/// - each segment starts from the final pc and final memory root of the previous one,
+/// - only the last segment terminates, and it does so with exit code 0,
+/// - the user public values are in the final memory state of the last segment.
+pub fn verify_app_proof(
+    app_vk: &AppVerifyingKey,
+    app_exe: &NonRootCommittedExe,
+    proof: &ContinuationVmProof<SC>,
+) -> Result<(), AppProofVerificationError> {
+    if proof.per_segment.is_empty() {
+        return Err(AppProofVerificationError::NoSegments);
+    }
+    let e = BabyBearPoseidon2Engine::new(app_vk.fri_params);
+    let program_commit = app_exe.get_program_commit();
+
+    let mut expected_pc = app_exe.exe.pc_start;
+    let mut expected_memory_root =
+        compute_init_memory_commit(app_vk.memory_dimensions, &app_exe.exe);
+    let last_segment = proof.per_segment.len() - 1;
+
+    for (segment, seg_proof) in proof.per_segment.iter().enumerate() {
+        e.verify(&app_vk.app_vm_vk, seg_proof)
+            .map_err(|error| AppProofVerificationError::StarkError { segment, error })?;
+
+        if seg_proof
+            .commitments
+            .main_trace
+            .get(PROGRAM_CACHED_TRACE_INDEX)
+            != Some(&program_commit)
+        {
+            return Err(AppProofVerificationError::ProgramCommitMismatch { segment });
+        }
+
+        let connector_pvs: &VmConnectorPvs<F> =
+            air_public_values(seg_proof, segment, CONNECTOR_AIR_ID)?.borrow();
+        let initial_pc = connector_pvs.initial_pc.as_canonical_u32();
+        if initial_pc != expected_pc {
+            return Err(AppProofVerificationError::InitialPcMismatch {
+                segment,
+                expected: expected_pc,
+                actual: initial_pc,
+            });
+        }
+        expected_pc = connector_pvs.final_pc.as_canonical_u32();
+
+        let memory_pvs: &MemoryMerklePvs<F, DIGEST_SIZE> =
+            air_public_values(seg_proof, segment, MERKLE_AIR_ID)?.borrow();
+        if memory_pvs.initial_root != expected_memory_root {
+            return Err(AppProofVerificationError::InitialMemoryRootMismatch { segment });
+        }
+        expected_memory_root = memory_pvs.final_root;
+
+        let is_terminate = connector_pvs.is_terminate.as_canonical_u32() != 0;
+        let exit_code = connector_pvs.exit_code.as_canonical_u32();
+        if segment != last_segment {
+            if is_terminate {
+                return Err(AppProofVerificationError::UnexpectedTermination { segment });
+            }
+            if exit_code != DEFAULT_SUSPEND_EXIT_CODE {
+                return Err(AppProofVerificationError::ExitCodeMismatch {
+                    segment,
+                    expected: DEFAULT_SUSPEND_EXIT_CODE,
+                    actual: exit_code,
+                });
+            }
+        } else {
+            if !is_terminate {
+                return Err(AppProofVerificationError::DidNotTerminate { segment });
+            }
+            if exit_code != ExitCode::Success as u32 {
+                return Err(AppProofVerificationError::ExitCodeMismatch {
+                    segment,
+                    expected: ExitCode::Success as u32,
+                    actual: exit_code,
+                });
+            }
+        }
+    }
+
+    proof.user_public_values.verify(
+        &vm_poseidon2_hasher(),
+        app_vk.memory_dimensions,
+        expected_memory_root,
+    )?;
+    Ok(())
+}
+
+fn air_public_values(
+    proof: &Proof<SC>,
+    segment: usize,
+    air_id: usize,
+) -> Result<&[F], AppProofVerificationError> {
+    proof
+        .per_air
+        .iter()
+        .find(|air_proof_data| air_proof_data.air_id == air_id)
+        .map(|air_proof_data| air_proof_data.public_values.as_slice())
+        .ok_or(AppProofVerificationError::MissingAir { segment, air_id })
+}
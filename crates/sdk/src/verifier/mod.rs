@@ -4,6 +4,7 @@ use openvm_native_compiler::ir::DIGEST_SIZE;
 
 use crate::{config::AggStarkConfig, verifier::common::types::VmVerifierPvs};
 
+pub mod app;
 pub mod common;
 pub mod internal;
 pub mod leaf;
@@ -23,9 +23,12 @@ use crate::{
     C, F, SC,
 };
 
+mod native;
 pub mod types;
 mod vars;
 
+pub use native::*;
+
 /// Config to generate Root VM verifier program.
 pub struct RootVmVerifierConfig {
     pub leaf_fri_params: FriParameters,
@@ -0,0 +1,92 @@
+use std::borrow::Borrow;
+
+use openvm_circuit::{arch::PROGRAM_CACHED_TRACE_INDEX, system::connector::VmConnectorPvs};
+use openvm_stark_sdk::{
+    config::baby_bear_poseidon2_root::BabyBearPoseidon2RootEngine,
+    engine::StarkFriEngine,
+    openvm_stark_backend::{
+        engine::StarkEngine, p3_field::PrimeField32, prover::types::Proof,
+        verifier::VerificationError,
+    },
+};
+use thiserror::Error;
+
+use crate::{keygen::RootVerifierProvingKey, verifier::root::types::RootVmVerifierPvs, RootSC, F};
+
+/// Reasons a proof of the root verifier can be rejected.
+#[derive(Error, Debug)]
+pub enum RootProofVerificationError {
+    #[error("stark verification error: {0}")]
+    StarkError(#[from] VerificationError),
+
+    #[error("missing public values of AIR {air_id}")]
+    MissingAir { air_id: usize },
+
+    #[error("program commit does not match the root verifier program")]
+    ProgramCommitMismatch,
+
+    #[error("initial pc mismatch (expected: 0, actual: {0})")]
+    InitialPcMismatch(u32),
+
+    #[error("root verifier did not terminate")]
+    DidNotTerminate,
+
+    #[error("exit code mismatch (expected: 0, actual: {0})")]
+    ExitCodeMismatch(u32),
+}
+
+/// Verifies a proof of the root verifier natively. This does the same checks as the static
+/// verifier circuit:
+/// - the STARK proof verifies against the root verifier's verifying key,
+/// - the proof runs the root verifier program,
+/// - the root verifier runs in a single segment which starts from pc 0 and exits with code 0.
+///
+/// Returns the public values of the root verifier. Callers should check that `exe_commit` and
+/// `leaf_verifier_commit` are the ones of the App VM program they expect.
+pub fn verify_root_proof(
+    root_verifier_pk: &RootVerifierProvingKey,
+    proof: &Proof<RootSC>,
+) -> Result<RootVmVerifierPvs<F>, RootProofVerificationError> {
+    let vm_pk = &root_verifier_pk.vm_pk;
+    let e = BabyBearPoseidon2RootEngine::new(vm_pk.fri_params);
+    e.verify(&vm_pk.vm_pk.get_vk(), proof)?;
+
+    // Program AIR is the only AIR with a cached trace. The cached trace index doesn't change
+    // after reordering.
+    if proof.commitments.main_trace.get(PROGRAM_CACHED_TRACE_INDEX)
+        != Some(&root_verifier_pk.root_committed_exe.get_program_commit())
+    {
+        return Err(RootProofVerificationError::ProgramCommitMismatch);
+    }
+
+    // The AIRs of the root verifier are reordered by trace height.
+    let special_air_ids = root_verifier_pk.air_id_permutation().get_special_air_ids();
+    let connector_pvs: &VmConnectorPvs<F> =
+        air_public_values(proof, special_air_ids.connector_air_id)?.borrow();
+    let initial_pc = connector_pvs.initial_pc.as_canonical_u32();
+    if initial_pc != 0 {
+        return Err(RootProofVerificationError::InitialPcMismatch(initial_pc));
+    }
+    if connector_pvs.is_terminate.as_canonical_u32() != 1 {
+        return Err(RootProofVerificationError::DidNotTerminate);
+    }
+    let exit_code = connector_pvs.exit_code.as_canonical_u32();
+    if exit_code != 0 {
+        return Err(RootProofVerificationError::ExitCodeMismatch(exit_code));
+    }
+
+    let public_values = air_public_values(proof, special_air_ids.public_values_air_id)?;
+    Ok(RootVmVerifierPvs::from_flatten(public_values.to_vec()))
+}
+
+fn air_public_values(
+    proof: &Proof<RootSC>,
+    air_id: usize,
+) -> Result<&[F], RootProofVerificationError> {
+    proof
+        .per_air
+        .iter()
+        .find(|air_proof_data| air_proof_data.air_id == air_id)
+        .map(|air_proof_data| air_proof_data.public_values.as_slice())
+        .ok_or(RootProofVerificationError::MissingAir { air_id })
+}
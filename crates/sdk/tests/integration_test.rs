@@ -6,18 +6,32 @@ use openvm_circuit::{
         hasher::poseidon2::vm_poseidon2_hasher, ExecutionError, SingleSegmentVmExecutor,
         SystemConfig, VmConfig, VmExecutor,
     },
-    system::{memory::tree::public_values::UserPublicValuesProof, program::trace::VmCommittedExe},
+    system::{
+        memory::tree::public_values::{UserPublicValuesProof, UserPublicValuesProofError},
+        program::trace::VmCommittedExe,
+    },
 };
 use openvm_native_circuit::{Native, NativeConfig};
 use openvm_native_compiler::{conversion::CompilerOptions, prelude::*};
-use openvm_native_recursion::{halo2::utils::CacheHalo2ParamsReader, types::InnerConfig};
+use openvm_native_recursion::{
+    halo2::{
+        utils::CacheHalo2ParamsReader,
+        wrapper::{EvmVerificationError, NativeVerificationError},
+    },
+    types::InnerConfig,
+};
 use openvm_rv32im_transpiler::{Rv32ITranspilerExtension, Rv32MTranspilerExtension};
 use openvm_sdk::{
+    commit::AppExecutionCommit,
     config::{AggConfig, AggStarkConfig, AppConfig, Halo2Config},
     keygen::AppProvingKey,
+    prover::StarkProver,
+    public_values::decode_public_values,
     verifier::{
+        app::AppProofVerificationError,
         common::types::VmVerifierPvs,
         leaf::types::{LeafVmVerifierInput, UserPublicValuesRootProof},
+        root::RootProofVerificationError,
     },
     Sdk, StdIn,
 };
@@ -186,7 +200,8 @@ fn test_public_values_and_leaf_verification() {
         NUM_PUB_VALUES,
         &vm_poseidon2_hasher(),
         app_vm_result.final_memory.as_ref().unwrap(),
-    );
+    )
+    .unwrap();
     let pv_root_proof = UserPublicValuesRootProof::extract(&pv_proof);
 
     // Verify the last segment with the correct public values root proof.
@@ -252,6 +267,91 @@ fn test_public_values_and_leaf_verification() {
     }
 }
 
+#[test]
+fn test_app_proof_continuation_verification() {
+    let app_log_blowup = 3;
+    let app_config = small_test_app_config(app_log_blowup);
+    let app_pk = Arc::new(Sdk.app_keygen(app_config).unwrap());
+    let app_vk = app_pk.get_vk();
+    let app_committed_exe = app_committed_exe_for_test(app_log_blowup);
+
+    let proof = Sdk
+        .generate_app_proof(app_pk, app_committed_exe.clone(), StdIn::default())
+        .unwrap();
+    let num_segments = proof.per_segment.len();
+    assert!(num_segments > 2);
+    Sdk.verify_app_proof(&app_vk, &app_committed_exe, &proof)
+        .unwrap();
+    // The test program doesn't reveal anything.
+    let public_values: [u32; NUM_PUB_VALUES / 4] =
+        decode_public_values(&proof.user_public_values.public_values).unwrap();
+    assert_eq!(public_values, [0; NUM_PUB_VALUES / 4]);
+
+    // Failure: the user public values are not the ones in the final memory state.
+    {
+        let mut wrong_proof = proof.clone();
+        wrong_proof.user_public_values.public_values[0] += F::ONE;
+        match Sdk.verify_app_proof(&app_vk, &app_committed_exe, &wrong_proof) {
+            Err(AppProofVerificationError::UserPublicValues(
+                UserPublicValuesProofError::UserPublicValuesCommitMismatch,
+            )) => {}
+            res => panic!("Expected public values commit mismatch, got {res:?}"),
+        }
+    }
+
+    // Failure: the first segment is missing, so the proof doesn't start from `pc_start`.
+    {
+        let mut wrong_proof = proof.clone();
+        wrong_proof.per_segment.remove(0);
+        match Sdk.verify_app_proof(&app_vk, &app_committed_exe, &wrong_proof) {
+            Err(AppProofVerificationError::InitialPcMismatch { segment: 0, .. }) => {}
+            res => panic!("Expected initial pc mismatch at segment 0, got {res:?}"),
+        }
+    }
+
+    // Failure: a middle segment is missing, so the segments don't chain.
+    {
+        let mut wrong_proof = proof.clone();
+        wrong_proof.per_segment.remove(1);
+        match Sdk.verify_app_proof(&app_vk, &app_committed_exe, &wrong_proof) {
+            Err(AppProofVerificationError::InitialPcMismatch { segment: 1, .. })
+            | Err(AppProofVerificationError::InitialMemoryRootMismatch { segment: 1 }) => {}
+            res => panic!("Expected broken continuation at segment 1, got {res:?}"),
+        }
+    }
+
+    // Failure: the last segment is missing, so the execution doesn't terminate.
+    {
+        let mut wrong_proof = proof.clone();
+        wrong_proof.per_segment.pop();
+        match Sdk.verify_app_proof(&app_vk, &app_committed_exe, &wrong_proof) {
+            Err(AppProofVerificationError::DidNotTerminate { segment }) => {
+                assert_eq!(segment, num_segments - 2);
+            }
+            res => panic!("Expected non-terminating last segment, got {res:?}"),
+        }
+    }
+
+    // Failure: the proof is for a different exe.
+    {
+        let other_program = {
+            let mut builder = Builder::<C>::default();
+            builder.halt();
+            builder.compile_isa()
+        };
+        let other_exe = Sdk
+            .commit_app_exe(
+                standard_fri_params_with_100_bits_conjectured_security(app_log_blowup),
+                other_program.into(),
+            )
+            .unwrap();
+        match Sdk.verify_app_proof(&app_vk, &other_exe, &proof) {
+            Err(AppProofVerificationError::ProgramCommitMismatch { segment: 0 }) => {}
+            res => panic!("Expected program commit mismatch at segment 0, got {res:?}"),
+        }
+    }
+}
+
 #[test]
 fn test_e2e_proof_generation_and_verification() {
     let app_log_blowup = 1;
@@ -265,16 +365,92 @@ fn test_e2e_proof_generation_and_verification() {
         .generate_snark_verifier_contract(&params_reader, &agg_pk)
         .unwrap();
 
+    let app_pk = Arc::new(app_pk);
+    let app_committed_exe = app_committed_exe_for_test(app_log_blowup);
+
+    // The root proof verifies natively and commits to the App VM exe.
+    let root_proof = StarkProver::new(
+        app_pk.clone(),
+        app_committed_exe.clone(),
+        agg_pk.agg_stark_pk.clone(),
+    )
+    .generate_proof_for_outer_recursion(StdIn::default());
+    let root_pvs = Sdk
+        .verify_root_proof(&agg_pk.agg_stark_pk.root_verifier_pk, &root_proof)
+        .unwrap();
+    let expected_commit = AppExecutionCommit::compute(
+        &app_pk.app_vm_pk.vm_config,
+        &app_committed_exe,
+        &app_pk.leaf_committed_exe,
+    );
+    assert_eq!(root_pvs.exe_commit, expected_commit.exe_commit);
+    assert_eq!(
+        root_pvs.leaf_verifier_commit,
+        expected_commit.leaf_vm_verifier_commit
+    );
+
+    // Failure: the root proof is tampered with.
+    {
+        let mut invalid_root_proof = root_proof.clone();
+        invalid_root_proof.per_air[0].public_values[0] += F::ONE;
+        match Sdk.verify_root_proof(&agg_pk.agg_stark_pk.root_verifier_pk, &invalid_root_proof) {
+            Err(RootProofVerificationError::StarkError(_)) => {}
+            res => panic!(
+                "Expected stark verification error, got {:?}",
+                res.map(|_| ())
+            ),
+        }
+    }
+
     let evm_proof = Sdk
         .generate_evm_proof(
             &params_reader,
-            Arc::new(app_pk),
-            app_committed_exe_for_test(app_log_blowup),
-            agg_pk,
+            app_pk,
+            app_committed_exe,
+            agg_pk.clone(),
             StdIn::default(),
         )
         .unwrap();
-    assert!(Sdk.verify_evm_proof(&evm_verifier, &evm_proof));
+    Sdk.verify_evm_proof_natively(&params_reader, &agg_pk, &evm_proof)
+        .unwrap();
+    let gas_used = Sdk.verify_evm_proof(&evm_verifier, &evm_proof).unwrap();
+    assert!(gas_used > 0);
+
+    // Failure: the verification call runs out of gas.
+    match Sdk.verify_evm_proof_with_gas_limit(&evm_verifier, &evm_proof, gas_used - 1) {
+        Err(EvmVerificationError::OutOfGas { gas_limit, .. }) => {
+            assert_eq!(gas_limit, gas_used - 1)
+        }
+        res => panic!("Expected out of gas, got {res:?}"),
+    }
+
+    // Failure: the proof is truncated.
+    let mut malformed_proof = evm_proof.clone();
+    malformed_proof
+        .proof
+        .truncate(malformed_proof.proof.len() - 1);
+    match Sdk.verify_evm_proof(&evm_verifier, &malformed_proof) {
+        Err(EvmVerificationError::MalformedCalldata(_)) => {}
+        res => panic!("Expected malformed calldata, got {res:?}"),
+    }
+    let mut malformed_proof = evm_proof.clone();
+    malformed_proof.instances[0].pop();
+    match Sdk.verify_evm_proof_natively(&params_reader, &agg_pk, &malformed_proof) {
+        Err(NativeVerificationError::MalformedProof(_)) => {}
+        res => panic!("Expected malformed proof, got {res:?}"),
+    }
+
+    // Failure: the proof is invalid.
+    let mut invalid_proof = evm_proof.clone();
+    invalid_proof.proof[0] ^= 1;
+    match Sdk.verify_evm_proof(&evm_verifier, &invalid_proof) {
+        Err(EvmVerificationError::InvalidProof { .. }) => {}
+        res => panic!("Expected invalid proof, got {res:?}"),
+    }
+    match Sdk.verify_evm_proof_natively(&params_reader, &agg_pk, &invalid_proof) {
+        Err(NativeVerificationError::InvalidProof(_)) => {}
+        res => panic!("Expected invalid proof, got {res:?}"),
+    }
 }
 
 #[test]
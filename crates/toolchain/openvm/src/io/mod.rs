@@ -7,7 +7,7 @@ use core::fmt::Write;
 
 #[cfg(target_os = "zkvm")]
 use openvm_rv32im_guest::{hint_input, hint_store_u32};
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Serialize};
 
 #[cfg(not(target_os = "zkvm"))]
 use crate::host::{hint_input, read_n_bytes, read_u32};
@@ -84,6 +84,12 @@ pub(crate) fn read_vec_by_len(len: usize) -> Vec<u8> {
 }
 
 /// Publish `x` as the `index`-th u32 output.
+///
+/// The output must fit in the `num_public_values` bytes configured in the VM. In single segment
+/// mode, execution fails with `PublicValueIndexOutOfBounds` otherwise. In continuation mode, the
+/// output is a plain memory write which is not bounds checked during execution, and extracting
+/// the public values afterwards, e.g. to generate the proof, fails with
+/// `FinalPublicValueIndexOutOfBounds`.
 #[allow(unused_variables)]
 pub fn reveal(x: u32, index: usize) {
     let byte_index = (index * 4) as u32;
@@ -93,6 +99,32 @@ pub fn reveal(x: u32, index: usize) {
     println!("reveal {} at byte location {}", x, index * 4);
 }
 
+/// Publish `words` as u32 outputs `0..words.len()`. See [reveal] for what happens if `words`
+/// needs more than the `num_public_values` bytes configured in the VM.
+pub fn reveal_u32_slice(words: &[u32]) {
+    for (index, &x) in words.iter().enumerate() {
+        reveal(x, index);
+    }
+}
+
+/// Publish `bytes` as public values `0..bytes.len()`. The last word is padded with zero bytes.
+/// Like [reveal], the padded bytes must fit in the configured `num_public_values`.
+pub fn reveal_bytes(bytes: &[u8]) {
+    for (index, chunk) in bytes.chunks(4).enumerate() {
+        let mut word = [0u8; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        reveal(u32::from_le_bytes(word), index);
+    }
+}
+
+/// Serialize `value` with [crate::serde] and publish it from the first public value on.
+/// The host can decode the public values with `openvm_sdk::public_values::decode_public_values`.
+/// The serialized `value` must fit in the configured `num_public_values`, as for [reveal].
+pub fn commit<T: Serialize>(value: &T) {
+    let words = crate::serde::to_vec(value).expect("failed to serialize value");
+    reveal_u32_slice(&words);
+}
+
 /// Print a UTF-8 string to stdout on host machine for debugging purposes.
 #[allow(unused_variables)]
 pub fn print<S: AsRef<str>>(s: S) {
@@ -3,6 +3,8 @@ use num_bigint_dig::{
     traits::ModInverse,
     {BigUint, Sign, ToBigInt},
 };
+#[cfg(not(target_os = "zkvm"))]
+use num_traits::{One, Zero};
 
 #[inline]
 #[cfg(not(target_os = "zkvm"))]
@@ -28,3 +30,43 @@ pub fn uint_mod_inverse(x: &BigUint, modulus: &BigUint) -> BigUint {
     .to_biguint()
     .unwrap()
 }
+
+#[cfg(not(target_os = "zkvm"))]
+/// Find a square root of BigUint 'x' modulo the prime 'modulus' with the Tonelli-Shanks
+/// algorithm, or `None` if 'x' is not a quadratic residue. 'non_qr' must be a quadratic
+/// non-residue modulo 'modulus'.
+pub fn uint_mod_sqrt(x: &BigUint, modulus: &BigUint, non_qr: &BigUint) -> Option<BigUint> {
+    let x = x % modulus;
+    if x.is_zero() {
+        return Some(x);
+    }
+    // modulus - 1 = q * 2^s with q odd
+    let mut q = modulus - BigUint::one();
+    let mut s = 0;
+    while (&q & BigUint::one()).is_zero() {
+        q >>= 1;
+        s += 1;
+    }
+    let mut m = s;
+    let mut c = non_qr.modpow(&q, modulus);
+    let mut t = x.modpow(&q, modulus);
+    let mut r = x.modpow(&((&q + BigUint::one()) >> 1), modulus);
+    while !t.is_one() {
+        // find the least i with t^(2^i) = 1
+        let mut i = 0;
+        let mut t_pow = t.clone();
+        while !t_pow.is_one() {
+            t_pow = &t_pow * &t_pow % modulus;
+            i += 1;
+            if i == m {
+                return None;
+            }
+        }
+        let b = c.modpow(&(BigUint::one() << (m - i - 1)), modulus);
+        m = i;
+        c = &b * &b % modulus;
+        t = t * &c % modulus;
+        r = r * b % modulus;
+    }
+    Some(r)
+}
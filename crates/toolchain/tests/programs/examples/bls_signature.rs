@@ -0,0 +1,170 @@
+#![cfg_attr(not(feature = "std"), no_main)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::hint::black_box;
+
+use hex_literal::hex;
+use openvm_pairing_guest::bls12_381::{
+    signature::{
+        min_pk::{
+            aggregate_verify, batch_verify, fast_aggregate_verify, verify,
+            verify_proof_of_possession, PublicKey, Signature, DST_POP,
+        },
+        BlsError,
+    },
+    Bls12_381Fp,
+};
+
+openvm::entry!(main);
+
+openvm_algebra_moduli_setup::moduli_init! {
+    "0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab",
+    "0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"
+}
+
+openvm_algebra_complex_macros::complex_init! {
+    Bls12_381Fp2 { mod_idx = 0 },
+}
+
+openvm_ecc_sw_setup::sw_init! {
+    Bls12_381Fp,
+}
+
+pub fn main() {
+    setup_all_moduli();
+    setup_all_complex_extensions();
+    setup_all_curves();
+
+    // Keys and messages from the Ethereum consensus spec BLS tests:
+    // https://github.com/ethereum/consensus-spec-tests/tree/master/tests/general/phase0/bls
+    let pk0 = PublicKey::from_bytes(&hex!(
+        "a491d1b0ecd9bb917989f0e74f0dea0422eac4a873e5e2644f368dffb9a6e20fd6e10c1b77654d067c0618f6e5a7f79a"
+    ))
+    .unwrap();
+    let pk1 = PublicKey::from_bytes(&hex!(
+        "b301803f8b5ac4a1133581fc676dfedc60d891dd5fa99028805e5ea5b08d3491af75d0707adab3b70c6a6a580217bf81"
+    ))
+    .unwrap();
+    let pk2 = PublicKey::from_bytes(&hex!(
+        "b53d21a4cfd562c469cc81514d4ce5a6b577d8403d32a394dc265dd190b47fa9f829fdd7963afdf972e5e77854051f6f"
+    ))
+    .unwrap();
+    let msg0 = [0x00u8; 32];
+    let msg1 = [0x56u8; 32];
+    let msg2 = [0xabu8; 32];
+
+    // Signatures of msg_i under pk_i
+    let sig0_bytes = hex!(
+        "b6ed936746e01f8ecf281f020953fbf1f01debd5657c4a383940b020b26507f6076334f91e2366c96e9ab279fb5158090352ea1c5b0c9274504f4f0e7053af24802e51e4568d164fe986834f41e55c8e850ce1f98458c0cfc9ab380b55285a55"
+    );
+    let sig0 = Signature::from_bytes(&sig0_bytes).unwrap();
+    let sig1 = Signature::from_bytes(&hex!(
+        "af1390c3c47acdb37131a51216da683c509fce0e954328a59f93aebda7e4ff974ba208d9a4a2a2389f892a9d418d618418dd7f7a6bc7aa0da999a9d3a5b815bc085e14fd001f6a1948768a3f4afefc8b8240dda329f984cb345c6363272ba4fe"
+    ))
+    .unwrap();
+    let sig2 = Signature::from_bytes(&hex!(
+        "ae82747ddeefe4fd64cf9cedb9b04ae3e8a43420cd255e3c7cd06a8d88b7c7f8638543719981c5d16fa3527c468c25f0026704a6951bde891360c7e8d12ddee0559004ccdbe6046b55bae1b257ee97f7cdb955773d7cf29adf3ccbb9975e4eb9"
+    ))
+    .unwrap();
+    assert_eq!(sig0.to_bytes(), sig0_bytes);
+
+    verify(&pk0, black_box(&msg0), &sig0, DST_POP).unwrap();
+    verify(&pk1, black_box(&msg1), &sig1, DST_POP).unwrap();
+    // Wrong message
+    assert_eq!(
+        verify(&pk0, black_box(&msg1), &sig0, DST_POP),
+        Err(BlsError::VerificationFailed)
+    );
+    // Wrong public key
+    assert_eq!(
+        verify(&pk1, black_box(&msg0), &sig0, DST_POP),
+        Err(BlsError::VerificationFailed)
+    );
+    // Tampered signature: flipping the sort flag negates the point
+    let mut bad_sig = sig0_bytes;
+    bad_sig[0] ^= 1 << 5;
+    let bad_sig = Signature::from_bytes(&bad_sig).unwrap();
+    assert_eq!(
+        verify(&pk0, black_box(&msg0), &bad_sig, DST_POP),
+        Err(BlsError::VerificationFailed)
+    );
+    // Points on the curves but outside the prime order subgroups
+    let mut bad_sig = [0u8; 96];
+    bad_sig[0] = 0xa0;
+    bad_sig[95] = 2;
+    assert_eq!(
+        Signature::from_bytes(&bad_sig).err(),
+        Some(BlsError::NotInSubgroup)
+    );
+    let mut bad_pk = [0u8; 48];
+    bad_pk[0] = 0x80;
+    bad_pk[47] = 4;
+    assert_eq!(
+        PublicKey::from_bytes(&bad_pk).err(),
+        Some(BlsError::NotInSubgroup)
+    );
+
+    // Aggregate signature of msg_i under pk_i
+    let public_keys = [pk0.clone(), pk1.clone(), pk2];
+    let msgs: [&[u8]; 3] = [&msg0, &msg1, &msg2];
+    let aggregate = Signature::from_bytes(&hex!(
+        "9104e74b9dfd3ad502f25d6a5ef57db0ed7d9a0e00f3500586d8ce44231212542fcfaf87840539b398bf07626705cf1105d246ca1062c6c2e1a53029a0f790ed5e3cb1f52f8234dc5144c45fc847c0cd37a92d68e7c5ba7c648a8a339f171244"
+    ))
+    .unwrap();
+    aggregate_verify(&public_keys, black_box(&msgs), &aggregate, DST_POP).unwrap();
+    let wrong_msgs: [&[u8]; 3] = [&msg0, &msg2, &msg1];
+    assert_eq!(
+        aggregate_verify(&public_keys, black_box(&wrong_msgs), &aggregate, DST_POP),
+        Err(BlsError::VerificationFailed)
+    );
+
+    // Aggregate signature of msg0 under all public keys
+    let fast_aggregate = Signature::from_bytes(&hex!(
+        "9683b3e6701f9a4b706709577963110043af78a5b41991b998475a3d3fd62abf35ce03b33908418efc95a058494a8ae504354b9f626231f6b3f3c849dfdeaf5017c4780e2aee1850ceaf4b4d9ce70971a3d2cfcd97b7e5ecf6759f8da5f76d31"
+    ))
+    .unwrap();
+    fast_aggregate_verify(&public_keys, black_box(&msg0), &fast_aggregate, DST_POP).unwrap();
+    assert_eq!(
+        fast_aggregate_verify(
+            &public_keys[..2],
+            black_box(&msg0),
+            &fast_aggregate,
+            DST_POP
+        ),
+        Err(BlsError::VerificationFailed)
+    );
+
+    // Batch verification
+    let signatures = [sig0.clone(), sig1, sig2];
+    batch_verify(&public_keys, black_box(&msgs), &signatures, DST_POP).unwrap();
+    assert_eq!(
+        batch_verify(&public_keys, black_box(&wrong_msgs), &signatures, DST_POP),
+        Err(BlsError::VerificationFailed)
+    );
+    // Mismatched lengths
+    assert_eq!(
+        batch_verify(&public_keys[..2], &msgs, &signatures, DST_POP),
+        Err(BlsError::InvalidInputLength)
+    );
+
+    // Proofs of possession
+    let proof0 = Signature::from_bytes(&hex!(
+        "b803eb0ed93ea10224a73b6b9c725796be9f5fefd215ef7a5b97234cc956cf6870db6127b7e4d824ec62276078e787db05584ce1adbf076bc0808ca0f15b73d59060254b25393d95dfc7abe3cda566842aaedf50bbb062aae1bbb6ef3b1f77e1"
+    ))
+    .unwrap();
+    let proof1 = Signature::from_bytes(&hex!(
+        "88bb31b27eae23038e14f9d9d1b628a39f5881b5278c3c6f0249f81ba0deb1f68aa5f8847854d6554051aa810fdf1cdb02df4af7a5647b1aa4afb60ec6d446ee17af24a8a50876ffdaf9bf475038ec5f8ebeda1c1c6a3220293e23b13a9a5d26"
+    ))
+    .unwrap();
+    verify_proof_of_possession(&pk0, &proof0).unwrap();
+    verify_proof_of_possession(&pk1, &proof1).unwrap();
+    assert_eq!(
+        verify_proof_of_possession(&pk1, &proof0),
+        Err(BlsError::VerificationFailed)
+    );
+    // A signature is not a proof of possession, because of the domain separation tag
+    assert_eq!(
+        verify_proof_of_possession(&pk0, &sig0),
+        Err(BlsError::VerificationFailed)
+    );
+}
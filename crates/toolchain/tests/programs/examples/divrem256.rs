@@ -0,0 +1,38 @@
+#![cfg_attr(not(feature = "std"), no_main)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use openvm_bigint_guest::{I256, U256};
+openvm::entry!(main);
+
+pub fn main() {
+    let a = U256::from_u64(u64::MAX) * U256::from_u64(1_000_000_007) + U256::from_u32(12345);
+    let b = U256::from_u64(u64::MAX);
+    assert_eq!(&a / &b, U256::from_u64(1_000_000_007));
+    assert_eq!(&a % &b, U256::from_u32(12345));
+    assert_eq!(&a / &b * &b + &a % &b, a);
+
+    // Division is not commutative
+    assert_eq!(U256::from_u32(7) / &U256::from_u32(2), U256::from_u32(3));
+    assert_eq!(&U256::from_u32(2) / U256::from_u32(7), U256::ZERO);
+
+    // Division by zero
+    assert_eq!(&a / &U256::ZERO, U256::MAX);
+    assert_eq!(&a % &U256::ZERO, a);
+
+    // Signed division rounds towards zero and the remainder has the sign of the dividend
+    let seven = I256::from_i8(7);
+    let minus_seven = I256::from_i8(-7);
+    let two = I256::from_i8(2);
+    let minus_two = I256::from_i8(-2);
+    assert_eq!(&minus_seven / &two, I256::from_i8(-3));
+    assert_eq!(&minus_seven % &two, I256::from_i8(-1));
+    assert_eq!(&seven / &minus_two, I256::from_i8(-3));
+    assert_eq!(&seven % &minus_two, I256::from_i8(1));
+    assert_eq!(&minus_seven / &minus_two, I256::from_i8(3));
+
+    // Signed overflow and division by zero
+    assert_eq!(&I256::MIN / &I256::from_i8(-1), I256::MIN);
+    assert_eq!(&I256::MIN % &I256::from_i8(-1), I256::ZERO);
+    assert_eq!(&seven / &I256::ZERO, I256::from_i8(-1));
+    assert_eq!(&seven % &I256::ZERO, seven);
+}
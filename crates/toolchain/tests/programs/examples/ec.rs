@@ -3,12 +3,14 @@
 
 use core::hint::black_box;
 
+use hex_literal::hex;
 use openvm_algebra_guest::IntMod;
 use openvm_ecc_guest::{
     k256::{Secp256k1Coord, Secp256k1Point, Secp256k1Scalar},
-    msm, Group,
+    msm,
+    weierstrass::{IntrinsicCurve, WeierstrassPoint},
+    Group,
 };
-use hex_literal::hex;
 
 openvm::entry!(main);
 
@@ -86,8 +88,17 @@ pub fn main() {
     let y5 = Secp256k1Coord::from_le_bytes(&hex!(
         "9E272F746DA7BED171E522610212B6AEEAAFDB2AD9F4B530B8E1B27293B19B2C"
     ));
-    let result = msm(&[scalar], &[p1]);
+    let result = msm(&[scalar.clone()], &[p1.clone()]);
+    if result.x != x5 || result.y != y5 {
+        panic!();
+    }
+
+    // The same product with the EC_SCALAR_MUL instruction.
+    let result = k256::Secp256k1::scalar_mul(&scalar, &p1);
     if result.x != x5 || result.y != y5 {
         panic!();
     }
+    if !Secp256k1Point::scalar_mul_nonidentity(&p1, &[0u8; 32]).is_identity() {
+        panic!();
+    }
 }
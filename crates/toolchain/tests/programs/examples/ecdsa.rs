@@ -3,6 +3,11 @@
 
 use core::{hint::black_box, ptr::slice_from_raw_parts};
 
+use hex_literal::hex;
+use k256::{
+    ecdsa::{self, signature::hazmat::PrehashVerifier, RecoveryId},
+    Secp256k1,
+};
 use openvm_ecc_guest::{
     algebra::IntMod,
     ecdsa::VerifyingKey,
@@ -10,11 +15,6 @@ use openvm_ecc_guest::{
     weierstrass::WeierstrassPoint,
 };
 use openvm_keccak256_guest::keccak256;
-use hex_literal::hex;
-use k256::{
-    ecdsa::{self, RecoveryId},
-    Secp256k1,
-};
 openvm::entry!(main);
 
 openvm_algebra_moduli_setup::moduli_init! {
@@ -43,10 +43,8 @@ pub fn main() {
     let recovered_key =
         VerifyingKey::<Secp256k1>::recover_from_prehash_noverify(&prehash, &signature, recid);
 
-    let expected_key = ecdsa::VerifyingKey::from_sec1_bytes(&hex!(
-        "0200866db99873b09fc2fb1e3ba549b156e96d1a567e3284f5f0e859a83320cb8b"
-    ))
-    .unwrap();
+    let compressed_key = hex!("0200866db99873b09fc2fb1e3ba549b156e96d1a567e3284f5f0e859a83320cb8b");
+    let expected_key = ecdsa::VerifyingKey::from_sec1_bytes(&compressed_key).unwrap();
     // sec1 encoding, the first byte is for compression flag
     let expected_key = expected_key.to_encoded_point(false);
     let public_key = recovered_key.as_affine();
@@ -58,4 +56,22 @@ pub fn main() {
     recovered_key
         .verify_prehashed(&prehash, &signature)
         .unwrap();
+
+    // SEC1 round trips, where decoding the compressed key uses the decompression hint
+    assert_eq!(recovered_key.to_sec1_bytes(true), compressed_key);
+    assert_eq!(recovered_key.to_sec1_bytes(false), expected_key.as_bytes());
+    let decoded_key = VerifyingKey::<Secp256k1>::from_sec1_bytes(&compressed_key).unwrap();
+    assert_eq!(decoded_key.as_affine(), recovered_key.as_affine());
+    let decoded_key = VerifyingKey::<Secp256k1>::from_sec1_bytes(expected_key.as_bytes()).unwrap();
+    assert_eq!(decoded_key.as_affine(), recovered_key.as_affine());
+    assert!(VerifyingKey::<Secp256k1>::from_sec1_bytes(&compressed_key[1..]).is_err());
+    // x = 5 is not the x-coordinate of any point, since 5^3 + 7 is not a square
+    let mut invalid_key = [0u8; 33];
+    invalid_key[0] = 0x02;
+    invalid_key[32] = 5;
+    assert!(VerifyingKey::<Secp256k1>::from_sec1_bytes(black_box(&invalid_key)).is_err());
+
+    // RustCrypto compatible verification
+    let signature = ecdsa::Signature::from_slice(&signature).unwrap();
+    decoded_key.verify_prehash(&prehash, &signature).unwrap();
 }
@@ -0,0 +1,81 @@
+#![cfg_attr(not(feature = "std"), no_main)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::hint::black_box;
+
+use hex_literal::hex;
+use openvm_ecc_guest::{
+    algebra::IntMod,
+    ecdsa::VerifyingKey,
+    p256::{P256Coord, P256Point},
+    weierstrass::WeierstrassPoint,
+    CyclicGroup, Group,
+};
+use p256::NistP256;
+
+openvm::entry!(main);
+
+openvm_algebra_moduli_setup::moduli_init! {
+    "0xFFFFFFFF 00000001 00000000 00000000 00000000 FFFFFFFF FFFFFFFF FFFFFFFF",
+    "0xFFFFFFFF 00000000 FFFFFFFF FFFFFFFF BCE6FAAD A7179E84 F3B9CAC2 FC632551"
+}
+
+openvm_ecc_sw_setup::sw_init! {
+    P256Coord,
+}
+
+pub fn main() {
+    setup_all_moduli();
+    setup_all_curves();
+
+    // Doubling uses the `a = -3` coefficient of the curve.
+    // Testing data from: http://point-at-infinity.org/ecc/nisttv
+    let g = black_box(P256Point::GENERATOR);
+    let g2 = g.double();
+    assert_eq!(
+        g2.x().to_be_bytes(),
+        hex!("7CF27B188D034F7E8A52380304B51AC3C08969E277F21B35A60B48FC47669978")
+    );
+    assert_eq!(
+        g2.y().to_be_bytes(),
+        hex!("07775510DB8ED040293D9AC69F7430DBBA7DADE63CE982299E04B79D227873D1")
+    );
+    let g3 = &g2 + &g;
+    assert_eq!(&g3 - &g2, g);
+    assert!((&g + &P256Point::NEG_GENERATOR).is_identity());
+
+    // ECDSA test vector with SHA-256 and message "sample" from
+    // https://datatracker.ietf.org/doc/html/rfc6979#appendix-A.2.5
+    let public_key = P256Point::from_xy_unchecked(
+        P256Coord::from_be_bytes(&hex!(
+            "60FED4BA255A9D31C961EB74C6356D68C049B8923B61FA6CE669622E60F29FB6"
+        )),
+        P256Coord::from_be_bytes(&hex!(
+            "7903FE1008B8BC99A41AE9E95628BC64F2F1B20C2D7E9F5177A3C294D4462299"
+        )),
+    );
+    let verifying_key = VerifyingKey::<NistP256>::from_affine(public_key).unwrap();
+    // sha256("sample")
+    let prehash = hex!("af2bdbe1aa9b6ec1e2ade1d694f41fc71a831d0268e9891562113d8a62add1bf");
+    let signature = hex!(
+        "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8"
+    );
+    verifying_key
+        .verify_prehashed(black_box(&prehash), &signature)
+        .unwrap();
+
+    let mut wrong_prehash = prehash;
+    wrong_prehash[0] ^= 1;
+    assert!(verifying_key
+        .verify_prehashed(black_box(&wrong_prehash), &signature)
+        .is_err());
+
+    // SEC1 round trip of the compressed public key, whose `y` coordinate is odd
+    let compressed_key = hex!("0360FED4BA255A9D31C961EB74C6356D68C049B8923B61FA6CE669622E60F29FB6");
+    assert_eq!(verifying_key.to_sec1_bytes(true), compressed_key);
+    let decoded_key = VerifyingKey::<NistP256>::from_sec1_bytes(&compressed_key).unwrap();
+    assert_eq!(decoded_key.as_affine(), verifying_key.as_affine());
+    decoded_key
+        .verify_prehashed(black_box(&prehash), &signature)
+        .unwrap();
+}
@@ -0,0 +1,57 @@
+#![cfg_attr(not(feature = "std"), no_main)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::hint::black_box;
+
+use hex_literal::hex;
+use openvm_ecc_guest::{
+    ed25519::{verify, Ed25519Coord, Ed25519Point},
+    CyclicGroup, Group,
+};
+
+openvm::entry!(main);
+
+openvm_algebra_moduli_setup::moduli_init! {
+    "0x7FFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFED",
+    "0x10000000 00000000 00000000 00000000 14DEF9DE A2F79CD6 5812631A 5CF5D3ED"
+}
+
+openvm_ecc_te_setup::te_init! {
+    Ed25519Coord,
+}
+
+pub fn main() {
+    setup_all_moduli();
+    setup_all_te_curves();
+
+    // Group law
+    let g = black_box(Ed25519Point::GENERATOR);
+    let g2 = g.double();
+    assert_eq!(&g2 - &g, g);
+    assert!((&g + &Ed25519Point::NEG_GENERATOR).is_identity());
+    assert_eq!(&g + &Ed25519Point::IDENTITY, g);
+
+    // Point decompression
+    let decompressed = Ed25519Point::from_compressed(&hex!(
+        "5866666666666666666666666666666666666666666666666666666666666666"
+    ))
+    .unwrap();
+    assert_eq!(decompressed, g);
+
+    // Test vectors from https://datatracker.ietf.org/doc/html/rfc8032#section-7.1
+    // TEST 1
+    let public_key = hex!("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a");
+    let signature = hex!(
+        "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b"
+    );
+    verify(&public_key, black_box(b""), &signature).unwrap();
+
+    // TEST 2
+    let public_key = hex!("3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c");
+    let signature = hex!(
+        "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00"
+    );
+    verify(&public_key, black_box(&[0x72]), &signature).unwrap();
+    // Wrong message
+    assert!(verify(&public_key, black_box(&[0x73]), &signature).is_err());
+}
@@ -0,0 +1,95 @@
+#![cfg_attr(not(feature = "std"), no_main)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use hex_literal::hex;
+use openvm_ecc_guest::{
+    algebra::IntMod,
+    hash_to_curve::{encode_to_curve, hash_to_curve, Sha256},
+    k256::{Secp256k1Coord, Secp256k1Point},
+    p256::{P256Coord, P256Point},
+    weierstrass::WeierstrassPoint,
+};
+
+openvm::entry!(main);
+
+openvm_algebra_moduli_setup::moduli_init! {
+    "0xFFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFE FFFFFC2F",
+    "0xFFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFE BAAEDCE6 AF48A03B BFD25E8C D0364141",
+    "0xFFFFFFFF 00000001 00000000 00000000 00000000 FFFFFFFF FFFFFFFF FFFFFFFF",
+    "0xFFFFFFFF 00000000 FFFFFFFF FFFFFFFF BCE6FAAD A7179E84 F3B9CAC2 FC632551"
+}
+
+openvm_ecc_sw_setup::sw_init! {
+    Secp256k1Coord,
+    P256Coord,
+}
+
+// Test vectors from https://datatracker.ietf.org/doc/html/rfc9380#appendix-J
+pub fn main() {
+    setup_all_moduli();
+    setup_all_curves();
+
+    let dst = b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_RO_";
+    for (msg, x, y) in [
+        (
+            &b""[..],
+            hex!("c1cae290e291aee617ebaef1be6d73861479c48b841eaba9b7b5852ddfeb1346"),
+            hex!("64fa678e07ae116126f08b022a94af6de15985c996c3a91b64c406a960e51067"),
+        ),
+        (
+            &b"abc"[..],
+            hex!("3377e01eab42db296b512293120c6cee72b6ecf9f9205760bd9ff11fb3cb2c4b"),
+            hex!("7f95890f33efebd1044d382a01b1bee0900fb6116f94688d487c6c7b9c8371f6"),
+        ),
+    ] {
+        let p: Secp256k1Point = hash_to_curve::<_, Sha256>(msg, dst);
+        assert_eq!(p.x(), &Secp256k1Coord::from_be_bytes(&x));
+        assert_eq!(p.y(), &Secp256k1Coord::from_be_bytes(&y));
+    }
+    let p: Secp256k1Point =
+        encode_to_curve::<_, Sha256>(b"", b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_NU_");
+    assert_eq!(
+        p.x(),
+        &Secp256k1Coord::from_be_bytes(&hex!(
+            "a4792346075feae77ac3b30026f99c1441b4ecf666ded19b7522cf65c4c55c5b"
+        ))
+    );
+    assert_eq!(
+        p.y(),
+        &Secp256k1Coord::from_be_bytes(&hex!(
+            "62c59e2a6aeed1b23be5883e833912b08ba06be7f57c0e9cdc663f31639ff3a7"
+        ))
+    );
+
+    let dst = b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_RO_";
+    for (msg, x, y) in [
+        (
+            &b""[..],
+            hex!("2c15230b26dbc6fc9a37051158c95b79656e17a1a920b11394ca91c44247d3e4"),
+            hex!("8a7a74985cc5c776cdfe4b1f19884970453912e9d31528c060be9ab5c43e8415"),
+        ),
+        (
+            &b"abc"[..],
+            hex!("0bb8b87485551aa43ed54f009230450b492fead5f1cc91658775dac4a3388a0f"),
+            hex!("5c41b3d0731a27a7b14bc0bf0ccded2d8751f83493404c84a88e71ffd424212e"),
+        ),
+    ] {
+        let p: P256Point = hash_to_curve::<_, Sha256>(msg, dst);
+        assert_eq!(p.x(), &P256Coord::from_be_bytes(&x));
+        assert_eq!(p.y(), &P256Coord::from_be_bytes(&y));
+    }
+    let p: P256Point =
+        encode_to_curve::<_, Sha256>(b"", b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_NU_");
+    assert_eq!(
+        p.x(),
+        &P256Coord::from_be_bytes(&hex!(
+            "f871caad25ea3b59c16cf87c1894902f7e7b2c822c3d3f73596c5ace8ddd14d1"
+        ))
+    );
+    assert_eq!(
+        p.y(),
+        &P256Coord::from_be_bytes(&hex!(
+            "87b9ae23335bee057b99bac1e68588b18b5691af476234b8971bc4f011ddc99b"
+        ))
+    );
+}
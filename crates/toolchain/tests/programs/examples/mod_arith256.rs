@@ -0,0 +1,32 @@
+#![cfg_attr(not(feature = "std"), no_main)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use openvm_bigint_guest::U256;
+openvm::entry!(main);
+
+pub fn main() {
+    let one = U256::from_u8(1);
+    let max = U256::MAX;
+    let max_minus_one = &max - &one;
+
+    // The intermediate sum and product do not overflow
+    assert_eq!(max.addmod(&max, &max), U256::ZERO);
+    assert_eq!(max.addmod(&one, &max), one);
+    assert_eq!(max.addmod(&max, &max_minus_one), U256::from_u8(2));
+    assert_eq!(max.mulmod(&max, &max_minus_one), one);
+
+    // 2^256 = 2 (mod 7)
+    let two_128 = &one << &U256::from_u32(128);
+    assert_eq!(two_128.mulmod(&two_128, &U256::from_u8(7)), U256::from_u8(2));
+
+    // Agrees with wrapping arithmetic when nothing overflows
+    let a = U256::from_u64(0xdead_beef_1234_5678);
+    let b = U256::from_u64(0xcafe_babe_8765_4321);
+    let n = U256::from_u64(1_000_000_007);
+    assert_eq!(a.mulmod(&b, &n), &(&a * &b) % &n);
+    assert_eq!(a.addmod(&b, &n), &(&a + &b) % &n);
+
+    // A zero modulus gives zero
+    assert_eq!(a.addmod(&b, &U256::ZERO), U256::ZERO);
+    assert_eq!(a.mulmod(&b, &U256::ZERO), U256::ZERO);
+}
@@ -0,0 +1,36 @@
+#![cfg_attr(not(feature = "std"), no_main)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use openvm_algebra_guest::IntMod;
+
+openvm::entry!(main);
+
+openvm_algebra_moduli_setup::moduli_declare! {
+    Secp256k1Coord { modulus = "0xFFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFE FFFFFC2F" },
+}
+
+openvm_algebra_moduli_setup::moduli_init! {
+    "0xFFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFE FFFFFC2F",
+}
+
+pub fn main() {
+    setup_all_moduli();
+
+    let x = Secp256k1Coord::from_u32(123456789);
+    let mut exp = [0u8; 32];
+    assert_eq!(x.pow(&exp), Secp256k1Coord::ONE);
+
+    let mut expected = Secp256k1Coord::ONE;
+    for i in 1..20u8 {
+        expected *= &x;
+        exp[0] = i;
+        assert_eq!(x.pow(&exp), expected);
+    }
+
+    // Fermat's little theorem: x^(p - 1) = 1 and x^(p - 2) = x^(-1)
+    let mut exp = Secp256k1Coord::MODULUS;
+    exp[0] -= 1;
+    assert_eq!(x.pow(&exp), Secp256k1Coord::ONE);
+    exp[0] -= 1;
+    assert_eq!(x.pow(&exp) * &x, Secp256k1Coord::ONE);
+}
@@ -89,6 +89,41 @@ mod bls12_381 {
     }
 }
 
+#[cfg(feature = "bls12_377")]
+mod bls12_377 {
+    use alloc::format;
+
+    use openvm_algebra_guest::IntMod;
+    use openvm_pairing_guest::bls12_377::{Bls12_377, Fp, Fp2};
+
+    use super::*;
+
+    openvm_algebra_moduli_setup::moduli_init! {
+        "0x01ae3a4617c510eac63b05c06ca1493b1a22d9f300f5138f1ef3622fba094800170b5d44300000008508c00000000001",
+        "0x12ab655e9a2ca55660b44d1e5c37b00159aa76fed00000010a11800000000001"
+    }
+
+    // `Fp2` of BLS12-377 is not a complex extension, so there is no `complex_init!`.
+    pub fn test_pairing_check(io: &[u8]) {
+        setup_0();
+        let s0 = &io[0..48 * 2];
+        let s1 = &io[48 * 2..48 * 4];
+        let q0 = &io[48 * 4..48 * 8];
+        let q1 = &io[48 * 8..48 * 12];
+
+        let s0_cast = unsafe { &*(s0.as_ptr() as *const AffinePoint<Fp>) };
+        let s1_cast = unsafe { &*(s1.as_ptr() as *const AffinePoint<Fp>) };
+        let q0_cast = unsafe { &*(q0.as_ptr() as *const AffinePoint<Fp2>) };
+        let q1_cast = unsafe { &*(q1.as_ptr() as *const AffinePoint<Fp2>) };
+
+        let f = Bls12_377::pairing_check(
+            &[s0_cast.clone(), s1_cast.clone()],
+            &[q0_cast.clone(), q1_cast.clone()],
+        );
+        assert_eq!(f, Ok(()));
+    }
+}
+
 pub fn main() {
     #[allow(unused_variables)]
     let io = read_vec();
@@ -96,6 +131,7 @@ pub fn main() {
     cfg_match! {
         cfg(feature = "bn254") => { bn254::test_pairing_check(&io); }
         cfg(feature = "bls12_381") => { bls12_381::test_pairing_check(&io); }
+        cfg(feature = "bls12_377") => { bls12_377::test_pairing_check(&io); }
         _ => { panic!("No curve feature enabled") }
     }
 }
@@ -0,0 +1,23 @@
+#![cfg_attr(not(feature = "std"), no_main)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use openvm::io::commit;
+use serde::{Deserialize, Serialize};
+
+openvm::entry!(main);
+
+#[derive(Serialize, Deserialize)]
+struct Output {
+    a: u32,
+    b: [u8; 3],
+    c: u64,
+}
+
+pub fn main() {
+    let output = Output {
+        a: core::hint::black_box(123),
+        b: [1, 2, 3],
+        c: core::hint::black_box(u64::MAX),
+    };
+    commit(&output);
+}
@@ -0,0 +1,126 @@
+#![cfg_attr(not(feature = "std"), no_main)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use hex_literal::hex;
+use openvm_algebra_guest::rsa::{verify_pkcs1v15, verify_pss, RsaError, Sha256};
+
+openvm::entry!(main);
+
+openvm_algebra_moduli_setup::moduli_declare! {
+    Rsa2048 { modulus = "0xC2E44BD8 155B521C 0945F9C3 ACA4EAFA 9A3811E9 49AED3DE 53ED5B4E 37A6C80E
+                  747B896A 13D8D01F EF8DFF00 D82E8376 ECD5828B 323E181C 375185EC D05E5422
+                  0FC6CBD0 D57B912C 1A2F83D4 7691BC44 148C9E68 1E002949 2ED09E42 8D571FF3
+                  68928972 58F27E2D 1B1C8DB8 DA7EE4B5 91C2CB4D 4B02229C BB7192D7 A1768301
+                  E045EF71 D0BB09AE B3759159 25F5D047 7B2C4D2C A985A8D1 CB2CD7AE 5D95F869
+                  9D711407 5C40A6F0 2E87A318 D27887C7 D22D91E7 AEDEAD9D 51297763 32B3352D
+                  1F00ED4D F276AF8C FAE3E422 4643C15C 03DB5F46 AB13C0E9 F0E34B17 0CFFD050
+                  38D47FCF F9B43C9A 77E53A4A 756ABCC7 74F1E01E 6B8D1CFA FF489562 D6B71E69" },
+    Rsa4096 { modulus = "0x97C10BE8 94DC9097 DA0CB1B7 29F58A8F F534D14F B2BFBE29 D67B62A8 C662FCB6
+                  E1F0DA40 DEA79383 A78E7052 FA3D8497 59B59005 E4E5E6CF 28D08322 108417CA
+                  5AF219C5 36A2AC1B D50ADBB1 E260BD45 39A64ADC E4EF399C A493273F 6B7E2550
+                  6BC88D89 325C3560 DF4201A6 4E1FE470 BE31BD96 B05D9B18 8E55773A 606F7F55
+                  96BBA71F 8222E123 34560283 DB472755 82570C09 F29667F9 75B873C7 16CF474B
+                  71274F5A 7E2897D0 90B528BE F331B073 C2F3BB95 93CAAAAC 5AB0CE50 CC504BAC
+                  F310AD31 CCB57CBE 9233BDC8 C983C0C5 E50F8407 70834C95 12A7C100 17C637A1
+                  21CD44BD D085641E A877B744 7D18F335 F0C15560 72B3FC83 84EC5B26 AF5F48CC
+                  747A841A B3E93464 AF7DD58B DA9D3C9F 31DFEC21 D4F9486A 19FA9901 81F7824F
+                  4F9FDCED 06CC1422 9A6E57EC 6352295E 766BEBA1 BE48F13B CE24CE28 FBA28AC8
+                  63717D2D BDFA5BD7 A6A0FF46 609A4F7A AB012D16 6AD395EB 24FBD51B A8BF6657
+                  FE39BD41 7E7E96BD 0C2FC798 CFFDBEBF C250AF86 7ED39A5E 44FCF5D5 40E967DC
+                  D1A2396A 27E324B7 35545D82 F3C1BE01 AE6476B8 A4FB7582 E08312FB 00C836D6
+                  1527FFEF 31201F76 43BA8849 B2937797 865ACD0A D7A6EB8B 05199742 75F61190
+                  BAEC2991 6AD9AB9A C76FFF7E 5A543F50 B04288E4 E710CC53 0F72C626 6BBD7570
+                  2341DF31 6EF0B7E9 2434BCC0 78F2448A B2FB5E3F 8076E4B3 694BD2CF E893E977" },
+}
+
+openvm_algebra_moduli_setup::moduli_init! {
+    "0xC2E44BD8 155B521C 0945F9C3 ACA4EAFA 9A3811E9 49AED3DE 53ED5B4E 37A6C80E
+     747B896A 13D8D01F EF8DFF00 D82E8376 ECD5828B 323E181C 375185EC D05E5422
+     0FC6CBD0 D57B912C 1A2F83D4 7691BC44 148C9E68 1E002949 2ED09E42 8D571FF3
+     68928972 58F27E2D 1B1C8DB8 DA7EE4B5 91C2CB4D 4B02229C BB7192D7 A1768301
+     E045EF71 D0BB09AE B3759159 25F5D047 7B2C4D2C A985A8D1 CB2CD7AE 5D95F869
+     9D711407 5C40A6F0 2E87A318 D27887C7 D22D91E7 AEDEAD9D 51297763 32B3352D
+     1F00ED4D F276AF8C FAE3E422 4643C15C 03DB5F46 AB13C0E9 F0E34B17 0CFFD050
+     38D47FCF F9B43C9A 77E53A4A 756ABCC7 74F1E01E 6B8D1CFA FF489562 D6B71E69",
+    "0x97C10BE8 94DC9097 DA0CB1B7 29F58A8F F534D14F B2BFBE29 D67B62A8 C662FCB6
+     E1F0DA40 DEA79383 A78E7052 FA3D8497 59B59005 E4E5E6CF 28D08322 108417CA
+     5AF219C5 36A2AC1B D50ADBB1 E260BD45 39A64ADC E4EF399C A493273F 6B7E2550
+     6BC88D89 325C3560 DF4201A6 4E1FE470 BE31BD96 B05D9B18 8E55773A 606F7F55
+     96BBA71F 8222E123 34560283 DB472755 82570C09 F29667F9 75B873C7 16CF474B
+     71274F5A 7E2897D0 90B528BE F331B073 C2F3BB95 93CAAAAC 5AB0CE50 CC504BAC
+     F310AD31 CCB57CBE 9233BDC8 C983C0C5 E50F8407 70834C95 12A7C100 17C637A1
+     21CD44BD D085641E A877B744 7D18F335 F0C15560 72B3FC83 84EC5B26 AF5F48CC
+     747A841A B3E93464 AF7DD58B DA9D3C9F 31DFEC21 D4F9486A 19FA9901 81F7824F
+     4F9FDCED 06CC1422 9A6E57EC 6352295E 766BEBA1 BE48F13B CE24CE28 FBA28AC8
+     63717D2D BDFA5BD7 A6A0FF46 609A4F7A AB012D16 6AD395EB 24FBD51B A8BF6657
+     FE39BD41 7E7E96BD 0C2FC798 CFFDBEBF C250AF86 7ED39A5E 44FCF5D5 40E967DC
+     D1A2396A 27E324B7 35545D82 F3C1BE01 AE6476B8 A4FB7582 E08312FB 00C836D6
+     1527FFEF 31201F76 43BA8849 B2937797 865ACD0A D7A6EB8B 05199742 75F61190
+     BAEC2991 6AD9AB9A C76FFF7E 5A543F50 B04288E4 E710CC53 0F72C626 6BBD7570
+     2341DF31 6EF0B7E9 2434BCC0 78F2448A B2FB5E3F 8076E4B3 694BD2CF E893E977",
+}
+
+const MESSAGE: &[u8] = b"OpenVM RSA signature verification";
+const EXPONENT: u32 = 65537;
+
+const PKCS1V15_SIGNATURE_2048: [u8; 256] = hex!(
+    "40c10141f61eb95a9a061ea043a9888061731aa4fc8120ee4ca59f495bc1915a"
+    "42fe6155b6ac985c04083d2770ab1ee1709c242d12b03d631dbca9acb78f5ad4"
+    "00de9a693d778b56cbd228c0c63bdac16090410006601bc4c6ee8275eb543df5"
+    "08c434ee01ab23f1d30fa7dc1bf2a223ed9f091f36e66b7c1f1a7acc5896728d"
+    "0e6cb057352eecf78e1cf309d86291103f1b6885e3813558f38c09000beea5c7"
+    "3be2cc04b0550f4669896e335cd4066a2d90218134306a65f54286a5af648ed7"
+    "357f9ef64397b24f3d79eaacc80046974611ed6e9f75653ae8a0b3e7a0fa329e"
+    "594fe0df9daad5c32fbd690b19e9d1cf75201e834dfa2e76f93dde4fb1342b24"
+);
+
+const PSS_SIGNATURE_2048: [u8; 256] = hex!(
+    "97a67806844fda2104daf0c2305b017062c979aa1d131e23a112dfb3c4a57eec"
+    "3b7bdff2f04ea414939036744085d3e4ff1b6d671aa5cc5de3ec0daefe347400"
+    "88f69b2ebabb3234debab20073ef234e48c2a5f47eab829029885bac832ba195"
+    "4d79167bb875ab761f5916052813eeb43b1fb0cebba4eb33322e38c9f27dba70"
+    "9e49153abacc6ae94f4a99195d030aee1f69dee70068c88a3a08ee697c5df536"
+    "a3c067d53ee2ce0deea731162ca9debba18acee4cf111ebe9fbb8273f819485c"
+    "2ab6eb3659c05802d61f80d5d8fdf2aa27520a6785a2d3dd4541e03268554dd2"
+    "cd9e554c15acbef4c8445b6d3d8aa0392f7bf703f8db113d27f35ca6022794a4"
+);
+
+const PKCS1V15_SIGNATURE_4096: [u8; 512] = hex!(
+    "955ea5de6d315161c302676f855496bf7873cfd486c7b3a4605c063d407a918c"
+    "84a6b6bd22b39269b60e7fb145cc2ede03548a6f5ff0be6e05cce719d8cadf2c"
+    "bdde9c1c53e2ca6391aacb96420018ee263495655df083f8f5b1b8304b6be7f4"
+    "0eb7bb59a35a776a339bf1a97ea20df56d1c995748fe4f6d21f3f4a74d8b4ba8"
+    "9152ff40f02dd70f17c1e1bf3c124b743931c8cda81ba2c772fa47b631fb1a60"
+    "03c8807a94b56aed42f7ebe2290571875b8dbce8a9f4f0184e52a4c2b10afa4f"
+    "dfd66e4d238a6aa76d55c8e33ac20eb8564200a957bbd7876a1f19a624e24f91"
+    "e498ea50ed148169f98a0e64e97a9e777339b2a691a3eb0c96682036fe292194"
+    "4af975ba0ff9b75878cd0b37cacbeeaedd6eabb04a988d27935caf203b8e59f6"
+    "ba842358b3808b1494895996b7c6f7fba82dd1197811162b02ee7e7ec9a87363"
+    "856c7697490808f43a4adbad0f5779c7c968f17fd5e734876414b25543bf1082"
+    "34765bb8bdc38263f16ca4467a7c6fc02ecfd021179461cfcb1493e4754f5414"
+    "e8c72ebf1ed1742eca496369f42f14ed3e5d5b79719e0df4c4d664902a664668"
+    "27ed90e60e6faaf53361bb9b0fb97ad1ae6d6b969802eebd17bc41a42be99840"
+    "ccaa8984dcb26224615b10313ba35316b6f8393d21941d753b427a0e4764fc3c"
+    "30a745b6068bda28f4dce94920e0e488e3f437faf7ec7174486b9838581d8453"
+);
+
+pub fn main() {
+    setup_all_moduli();
+
+    verify_pkcs1v15::<Rsa2048, Sha256>(EXPONENT, MESSAGE, &PKCS1V15_SIGNATURE_2048).unwrap();
+    verify_pss::<Rsa2048, Sha256>(EXPONENT, MESSAGE, &PSS_SIGNATURE_2048, 32).unwrap();
+    verify_pkcs1v15::<Rsa4096, Sha256>(EXPONENT, MESSAGE, &PKCS1V15_SIGNATURE_4096).unwrap();
+
+    assert_eq!(
+        verify_pkcs1v15::<Rsa2048, Sha256>(EXPONENT, b"another message", &PKCS1V15_SIGNATURE_2048),
+        Err(RsaError::VerificationFailed)
+    );
+    assert_eq!(
+        verify_pss::<Rsa2048, Sha256>(EXPONENT, b"another message", &PSS_SIGNATURE_2048, 32),
+        Err(RsaError::VerificationFailed)
+    );
+    assert_eq!(
+        verify_pkcs1v15::<Rsa2048, Sha256>(EXPONENT, MESSAGE, &PKCS1V15_SIGNATURE_2048[1..]),
+        Err(RsaError::InvalidSignature)
+    );
+}
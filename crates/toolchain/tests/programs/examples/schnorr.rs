@@ -0,0 +1,90 @@
+#![cfg_attr(not(feature = "std"), no_main)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::hint::black_box;
+
+use hex_literal::hex;
+use openvm_ecc_guest::{
+    k256::Secp256k1Coord,
+    schnorr::{batch_verify, lift_x, verify},
+};
+
+openvm::entry!(main);
+
+openvm_algebra_moduli_setup::moduli_init! {
+    "0xFFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFE FFFFFC2F",
+    "0xFFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFE BAAEDCE6 AF48A03B BFD25E8C D0364141"
+}
+
+openvm_ecc_sw_setup::sw_init! {
+    Secp256k1Coord,
+}
+
+pub fn main() {
+    setup_all_moduli();
+    setup_all_curves();
+
+    // Test vector 0 from https://github.com/bitcoin/bips/blob/master/bip-0340/test-vectors.csv
+    let pk0 = hex!("F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9");
+    let msg0 = [0u8; 32];
+    let sig0 = hex!(
+        "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA821525F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0"
+    );
+    verify(&pk0, black_box(&msg0), &sig0).unwrap();
+
+    let pk1 = hex!("dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659");
+    let msg1 = hex!("243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89");
+    let sig1 = hex!(
+        "eb8eadc001fa1f3d08f19db7027ddb0affa61c0357d4b577f8bb1978837382c85ae9ccc675360d9055cb2a2bda001bc5c62df9b5ed936caccfd00b169ede131d"
+    );
+    verify(&pk1, black_box(&msg1), &sig1).unwrap();
+
+    // Messages of other lengths
+    let pk2 = hex!("f01d6b9018ab421dd410404cb869072065522bf85734008f105cf385a023a80f");
+    let sig2 = hex!(
+        "a9bcff722fdac0cd892222598d7ad74601aa31a8ff2eacda469ce78ac7a03336cf6893382c90d9ecfe65ffc14a803060d3324eea5ee5bee44dcef76975c9d5f7"
+    );
+    verify(&pk2, black_box(b"abc"), &sig2).unwrap();
+
+    let pk3 = hex!("54006a630e70fca8d45a696d7dba3a1cb7d81cc830cfce3be0f634373aac3f0a");
+    let sig3 = hex!(
+        "0ed529ac131436185357d2e6fc1716ac56bc16e2d8768528aa5128a53116a80a5cab9c19d278eb47d8a15beae3a9da84d1ea38b137a54d2c4d79502449a4bafa"
+    );
+    verify(&pk3, black_box(b""), &sig3).unwrap();
+
+    // Wrong message
+    assert!(verify(&pk2, black_box(b"abd"), &sig2).is_err());
+    // Wrong public key
+    assert!(verify(&pk1, black_box(&msg0), &sig0).is_err());
+    // Tampered s
+    let mut bad_sig = sig1;
+    bad_sig[63] ^= 1;
+    assert!(verify(&pk1, black_box(&msg1), &bad_sig).is_err());
+    // r not less than the field modulus
+    let mut bad_sig = sig1;
+    bad_sig[..32].copy_from_slice(&hex!(
+        "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F"
+    ));
+    assert!(verify(&pk1, black_box(&msg1), &bad_sig).is_err());
+    // s not less than the curve order
+    let mut bad_sig = sig1;
+    bad_sig[32..].copy_from_slice(&hex!(
+        "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141"
+    ));
+    assert!(verify(&pk1, black_box(&msg1), &bad_sig).is_err());
+    // Public key not on the curve: 5^3 + 7 is not a square
+    let mut bad_pk = [0u8; 32];
+    bad_pk[31] = 5;
+    assert!(lift_x(&bad_pk).is_none());
+    assert!(verify(&bad_pk, black_box(&msg1), &sig1).is_err());
+
+    // Batch verification
+    let public_keys = [pk0, pk1, pk2, pk3];
+    let signatures = [sig0, sig1, sig2, sig3];
+    let msgs: [&[u8]; 4] = [&msg0, &msg1, b"abc", b""];
+    batch_verify(&public_keys, black_box(&msgs), &signatures).unwrap();
+    let wrong_msgs: [&[u8]; 4] = [&msg0, &msg1, b"abd", b""];
+    assert!(batch_verify(&public_keys, black_box(&wrong_msgs), &signatures).is_err());
+    // Mismatched lengths
+    assert!(batch_verify(&public_keys[..3], &msgs[..3], &signatures).is_err());
+}
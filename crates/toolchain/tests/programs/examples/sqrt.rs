@@ -0,0 +1,40 @@
+#![cfg_attr(not(feature = "std"), no_main)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use openvm_algebra_guest::{IntMod, Sqrt};
+
+openvm::entry!(main);
+
+openvm_algebra_moduli_setup::moduli_declare! {
+    // 998244353 - 1 = 119 * 2^23, so this needs the full Tonelli-Shanks algorithm
+    Mod998244353 { modulus = "998244353" },
+    Secp256k1Coord { modulus = "0xFFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFE FFFFFC2F" },
+}
+
+openvm_algebra_moduli_setup::moduli_init! {
+    "998244353",
+    "0xFFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFE FFFFFC2F",
+}
+
+pub fn main() {
+    setup_all_moduli();
+
+    // 3 generates the multiplicative group, so it is not a square
+    let non_qr = Mod998244353::from_u8(3);
+    for i in 0..16u32 {
+        let x = Mod998244353::from_u32(i * 1000 + 7);
+        let root = x.square().sqrt().unwrap();
+        assert!(root == x || root == -&x);
+        assert_eq!((x.square() * &non_qr).sqrt(), None);
+    }
+    assert_eq!(Mod998244353::ZERO.sqrt(), Some(Mod998244353::ZERO));
+
+    // The modulus is 3 mod 4, so -1 is not a square
+    let minus_one = -Secp256k1Coord::ONE;
+    for i in 1..8u32 {
+        let x = Secp256k1Coord::from_u32(i * 12345);
+        let root = x.square().sqrt().unwrap();
+        assert!(root == x || root == -&x);
+        assert_eq!((x.square() * &minus_one).sqrt(), None);
+    }
+}
@@ -12,6 +12,9 @@ use openvm_rv32im_circuit::{Rv32IConfig, Rv32ImConfig};
 use openvm_rv32im_transpiler::{
     Rv32ITranspilerExtension, Rv32IoTranspilerExtension, Rv32MTranspilerExtension,
 };
+use openvm_sdk::public_values::decode_public_values;
+use openvm_sha256_circuit::Sha256Rv32Config;
+use openvm_sha256_transpiler::Sha256TranspilerExtension;
 use openvm_stark_sdk::{openvm_stark_backend::p3_field::AbstractField, p3_baby_bear::BabyBear};
 use openvm_transpiler::{elf::ELF_DEFAULT_MAX_NUM_PUBLIC_VALUES, transpiler::Transpiler, FromElf};
 use test_case::test_case;
@@ -133,7 +136,7 @@ fn test_reveal_runtime() -> Result<()> {
         ELF_DEFAULT_MAX_NUM_PUBLIC_VALUES,
         &hasher,
         &final_memory,
-    );
+    )?;
     assert_eq!(
         pv_proof.public_values,
         [123, 0, 456, 0u32, 0u32, 0u32, 0u32, 0u32]
@@ -145,6 +148,44 @@ fn test_reveal_runtime() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_reveal_commit_runtime() -> Result<()> {
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Output {
+        a: u32,
+        b: [u8; 3],
+        c: u64,
+    }
+
+    let elf = build_example_program("reveal_commit")?;
+    let exe = VmExe::from_elf(
+        elf,
+        Transpiler::<F>::default()
+            .with_extension(Rv32ITranspilerExtension)
+            .with_extension(Rv32MTranspilerExtension)
+            .with_extension(Rv32IoTranspilerExtension),
+    )?;
+    let config = Rv32IConfig::default();
+    let executor = VmExecutor::<F, _>::new(config.clone());
+    let final_memory = executor.execute(exe, vec![])?.unwrap();
+    let pv_proof = UserPublicValuesProof::compute(
+        config.system.memory_config.memory_dimensions(),
+        ELF_DEFAULT_MAX_NUM_PUBLIC_VALUES,
+        &vm_poseidon2_hasher(),
+        &final_memory,
+    )?;
+    let output: Output = decode_public_values(&pv_proof.public_values)?;
+    assert_eq!(
+        output,
+        Output {
+            a: 123,
+            b: [1, 2, 3],
+            c: u64::MAX,
+        }
+    );
+    Ok(())
+}
+
 #[test]
 fn test_keccak256_runtime() -> Result<()> {
     let elf = build_example_program("keccak")?;
@@ -161,6 +202,22 @@ fn test_keccak256_runtime() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_sha256_runtime() -> Result<()> {
+    let elf = build_example_program("sha256")?;
+    let openvm_exe = VmExe::from_elf(
+        elf,
+        Transpiler::<F>::default()
+            .with_extension(Sha256TranspilerExtension)
+            .with_extension(Rv32ITranspilerExtension)
+            .with_extension(Rv32MTranspilerExtension)
+            .with_extension(Rv32IoTranspilerExtension),
+    )?;
+    let executor = VmExecutor::<F, Sha256Rv32Config>::new(Sha256Rv32Config::default());
+    executor.execute(openvm_exe, vec![])?;
+    Ok(())
+}
+
 #[test]
 fn test_print_runtime() -> Result<()> {
     let elf = build_example_program("print")?;
@@ -211,6 +268,38 @@ fn test_matrix_power_signed_runtime() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_divrem256_runtime() -> Result<()> {
+    let elf = build_example_program("divrem256")?;
+    let openvm_exe = VmExe::from_elf(
+        elf,
+        Transpiler::<F>::default()
+            .with_extension(Rv32ITranspilerExtension)
+            .with_extension(Rv32MTranspilerExtension)
+            .with_extension(Rv32IoTranspilerExtension)
+            .with_extension(Int256TranspilerExtension),
+    )?;
+    let config = Int256Rv32Config::default();
+    new_air_test_with_min_segments(config, openvm_exe, vec![], 1, true);
+    Ok(())
+}
+
+#[test]
+fn test_mod_arith256_runtime() -> Result<()> {
+    let elf = build_example_program("mod_arith256")?;
+    let openvm_exe = VmExe::from_elf(
+        elf,
+        Transpiler::<F>::default()
+            .with_extension(Rv32ITranspilerExtension)
+            .with_extension(Rv32MTranspilerExtension)
+            .with_extension(Rv32IoTranspilerExtension)
+            .with_extension(Int256TranspilerExtension),
+    )?;
+    let config = Int256Rv32Config::default();
+    new_air_test_with_min_segments(config, openvm_exe, vec![], 1, true);
+    Ok(())
+}
+
 #[test]
 fn test_tiny_mem_test_runtime() -> Result<()> {
     let elf = build_example_program_with_features("tiny-mem-test", ["heap-embedded-alloc"])?;
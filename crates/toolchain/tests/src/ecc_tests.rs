@@ -2,6 +2,7 @@ use std::str::FromStr;
 
 use derive_more::derive::From;
 use eyre::Result;
+use hex_literal::hex;
 use num_bigint_dig::BigUint;
 use openvm_algebra_circuit::{
     ModularExtension, ModularExtensionExecutor, ModularExtensionPeriphery, Rv32ModularConfig,
@@ -18,10 +19,11 @@ use openvm_circuit::{
 };
 use openvm_circuit_primitives_derive::{Chip, ChipUsageGetter};
 use openvm_ecc_circuit::{
-    CurveConfig, Rv32WeierstrassConfig, WeierstrassExtension, WeierstrassExtensionExecutor,
-    WeierstrassExtensionPeriphery, SECP256K1_CONFIG,
+    CurveConfig, Rv32EdwardsConfig, Rv32WeierstrassConfig, WeierstrassExtension,
+    WeierstrassExtensionExecutor, WeierstrassExtensionPeriphery, ED25519_CONFIG, P256_CONFIG,
+    SECP256K1_CONFIG,
 };
-use openvm_ecc_transpiler::EccTranspilerExtension;
+use openvm_ecc_transpiler::{EccTranspilerExtension, EdwardsTranspilerExtension};
 use openvm_keccak256_circuit::{Keccak256, Keccak256Executor, Keccak256Periphery};
 use openvm_keccak256_transpiler::Keccak256TranspilerExtension;
 use openvm_rv32im_circuit::{
@@ -31,6 +33,8 @@ use openvm_rv32im_circuit::{
 use openvm_rv32im_transpiler::{
     Rv32ITranspilerExtension, Rv32IoTranspilerExtension, Rv32MTranspilerExtension,
 };
+use openvm_sha256_circuit::{Sha256, Sha256Executor, Sha256Periphery};
+use openvm_sha256_transpiler::Sha256TranspilerExtension;
 use openvm_stark_backend::p3_field::{AbstractField, PrimeField32};
 use openvm_stark_sdk::p3_baby_bear::BabyBear;
 use openvm_transpiler::{transpiler::Transpiler, FromElf};
@@ -75,6 +79,41 @@ fn test_modular_runtime() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_sqrt_runtime() -> Result<()> {
+    let elf = build_example_program("sqrt")?;
+    let openvm_exe = VmExe::from_elf(
+        elf,
+        Transpiler::<F>::default()
+            .with_extension(Rv32ITranspilerExtension)
+            .with_extension(Rv32MTranspilerExtension)
+            .with_extension(Rv32IoTranspilerExtension)
+            .with_extension(ModularTranspilerExtension),
+    )?;
+    let config = Rv32ModularConfig::new(vec![
+        BigUint::from_str("998244353").unwrap(),
+        SECP256K1_CONFIG.modulus.clone(),
+    ]);
+    new_air_test_with_min_segments(config, openvm_exe, vec![], 1, false);
+    Ok(())
+}
+
+#[test]
+fn test_mod_exp_runtime() -> Result<()> {
+    let elf = build_example_program("mod_exp")?;
+    let openvm_exe = VmExe::from_elf(
+        elf,
+        Transpiler::<F>::default()
+            .with_extension(Rv32ITranspilerExtension)
+            .with_extension(Rv32MTranspilerExtension)
+            .with_extension(Rv32IoTranspilerExtension)
+            .with_extension(ModularTranspilerExtension),
+    )?;
+    let config = Rv32ModularConfig::new(vec![SECP256K1_CONFIG.modulus.clone()]);
+    new_air_test_with_min_segments(config, openvm_exe, vec![], 1, false);
+    Ok(())
+}
+
 #[test]
 fn test_complex_runtime() -> Result<()> {
     let elf = build_example_program("complex")?;
@@ -194,6 +233,123 @@ impl Rv32ModularKeccak256Config {
     }
 }
 
+#[derive(Clone, Debug, VmConfig, Serialize, Deserialize)]
+pub struct Rv32WeierstrassSha256Config {
+    #[system]
+    pub system: SystemConfig,
+    #[extension]
+    pub base: Rv32I,
+    #[extension]
+    pub mul: Rv32M,
+    #[extension]
+    pub io: Rv32Io,
+    #[extension]
+    pub modular: ModularExtension,
+    #[extension]
+    pub sha256: Sha256,
+    #[extension]
+    pub weierstrass: WeierstrassExtension,
+}
+
+impl Rv32WeierstrassSha256Config {
+    pub fn new(curves: Vec<CurveConfig>) -> Self {
+        let primes: Vec<BigUint> = curves
+            .iter()
+            .flat_map(|c| [c.modulus.clone(), c.scalar.clone()])
+            .collect();
+        Self {
+            system: SystemConfig::default().with_continuations(),
+            base: Default::default(),
+            mul: Default::default(),
+            io: Default::default(),
+            modular: ModularExtension::new(primes),
+            sha256: Default::default(),
+            weierstrass: WeierstrassExtension::new(curves),
+        }
+    }
+}
+
+#[derive(Clone, Debug, VmConfig, Serialize, Deserialize)]
+pub struct Rv32ModularSha256Config {
+    #[system]
+    pub system: SystemConfig,
+    #[extension]
+    pub base: Rv32I,
+    #[extension]
+    pub mul: Rv32M,
+    #[extension]
+    pub io: Rv32Io,
+    #[extension]
+    pub modular: ModularExtension,
+    #[extension]
+    pub sha256: Sha256,
+}
+
+impl Rv32ModularSha256Config {
+    pub fn new(moduli: Vec<BigUint>) -> Self {
+        let modular = ModularExtension::new(moduli);
+        let mut system = SystemConfig::default().with_continuations();
+        // The chips for RSA sized moduli need a larger range checker.
+        system.memory_config.decomp = system
+            .memory_config
+            .decomp
+            .max(modular.range_checker_bits());
+        Self {
+            system,
+            base: Default::default(),
+            mul: Default::default(),
+            io: Default::default(),
+            modular,
+            sha256: Default::default(),
+        }
+    }
+}
+
+#[test]
+fn test_rsa_runtime() -> Result<()> {
+    let elf = build_example_program_with_features("rsa", ["rsa"])?;
+    let openvm_exe = VmExe::from_elf(
+        elf,
+        Transpiler::<F>::default()
+            .with_extension(Rv32ITranspilerExtension)
+            .with_extension(Rv32MTranspilerExtension)
+            .with_extension(Rv32IoTranspilerExtension)
+            .with_extension(Sha256TranspilerExtension)
+            .with_extension(ModularTranspilerExtension),
+    )?;
+    let rsa_2048 = BigUint::from_bytes_be(&hex!(
+        "C2E44BD8155B521C0945F9C3ACA4EAFA9A3811E949AED3DE53ED5B4E37A6C80E"
+        "747B896A13D8D01FEF8DFF00D82E8376ECD5828B323E181C375185ECD05E5422"
+        "0FC6CBD0D57B912C1A2F83D47691BC44148C9E681E0029492ED09E428D571FF3"
+        "6892897258F27E2D1B1C8DB8DA7EE4B591C2CB4D4B02229CBB7192D7A1768301"
+        "E045EF71D0BB09AEB375915925F5D0477B2C4D2CA985A8D1CB2CD7AE5D95F869"
+        "9D7114075C40A6F02E87A318D27887C7D22D91E7AEDEAD9D5129776332B3352D"
+        "1F00ED4DF276AF8CFAE3E4224643C15C03DB5F46AB13C0E9F0E34B170CFFD050"
+        "38D47FCFF9B43C9A77E53A4A756ABCC774F1E01E6B8D1CFAFF489562D6B71E69"
+    ));
+    let rsa_4096 = BigUint::from_bytes_be(&hex!(
+        "97C10BE894DC9097DA0CB1B729F58A8FF534D14FB2BFBE29D67B62A8C662FCB6"
+        "E1F0DA40DEA79383A78E7052FA3D849759B59005E4E5E6CF28D08322108417CA"
+        "5AF219C536A2AC1BD50ADBB1E260BD4539A64ADCE4EF399CA493273F6B7E2550"
+        "6BC88D89325C3560DF4201A64E1FE470BE31BD96B05D9B188E55773A606F7F55"
+        "96BBA71F8222E12334560283DB47275582570C09F29667F975B873C716CF474B"
+        "71274F5A7E2897D090B528BEF331B073C2F3BB9593CAAAAC5AB0CE50CC504BAC"
+        "F310AD31CCB57CBE9233BDC8C983C0C5E50F840770834C9512A7C10017C637A1"
+        "21CD44BDD085641EA877B7447D18F335F0C1556072B3FC8384EC5B26AF5F48CC"
+        "747A841AB3E93464AF7DD58BDA9D3C9F31DFEC21D4F9486A19FA990181F7824F"
+        "4F9FDCED06CC14229A6E57EC6352295E766BEBA1BE48F13BCE24CE28FBA28AC8"
+        "63717D2DBDFA5BD7A6A0FF46609A4F7AAB012D166AD395EB24FBD51BA8BF6657"
+        "FE39BD417E7E96BD0C2FC798CFFDBEBFC250AF867ED39A5E44FCF5D540E967DC"
+        "D1A2396A27E324B735545D82F3C1BE01AE6476B8A4FB7582E08312FB00C836D6"
+        "1527FFEF31201F7643BA8849B2937797865ACD0AD7A6EB8B0519974275F61190"
+        "BAEC29916AD9AB9AC76FFF7E5A543F50B04288E4E710CC530F72C6266BBD7570"
+        "2341DF316EF0B7E92434BCC078F2448AB2FB5E3F8076E4B3694BD2CFE893E977"
+    ));
+    let config = Rv32ModularSha256Config::new(vec![rsa_2048, rsa_4096]);
+    new_air_test_with_min_segments(config, openvm_exe, vec![], 1, true);
+    Ok(())
+}
+
 #[test]
 fn test_ecdsa_runtime() -> Result<()> {
     let elf = build_example_program_with_features("ecdsa", ["k256"])?;
@@ -212,3 +368,72 @@ fn test_ecdsa_runtime() -> Result<()> {
     new_air_test_with_min_segments(config, openvm_exe, vec![], 1, true);
     Ok(())
 }
+
+#[test]
+fn test_ecdsa_p256_runtime() -> Result<()> {
+    let elf = build_example_program_with_features("ecdsa_p256", ["p256"])?;
+    let openvm_exe = VmExe::from_elf(
+        elf,
+        Transpiler::<F>::default()
+            .with_extension(Rv32ITranspilerExtension)
+            .with_extension(Rv32MTranspilerExtension)
+            .with_extension(Rv32IoTranspilerExtension)
+            .with_extension(EccTranspilerExtension)
+            .with_extension(ModularTranspilerExtension),
+    )?;
+    let config = Rv32WeierstrassConfig::new(vec![P256_CONFIG.clone()]);
+    new_air_test_with_min_segments(config, openvm_exe, vec![], 1, false);
+    Ok(())
+}
+
+#[test]
+fn test_hash_to_curve_runtime() -> Result<()> {
+    let elf = build_example_program_with_features("hash_to_curve", ["k256", "p256", "sha2"])?;
+    let openvm_exe = VmExe::from_elf(
+        elf,
+        Transpiler::<F>::default()
+            .with_extension(Rv32ITranspilerExtension)
+            .with_extension(Rv32MTranspilerExtension)
+            .with_extension(Rv32IoTranspilerExtension)
+            .with_extension(EccTranspilerExtension)
+            .with_extension(ModularTranspilerExtension),
+    )?;
+    let config = Rv32WeierstrassConfig::new(vec![SECP256K1_CONFIG.clone(), P256_CONFIG.clone()]);
+    new_air_test_with_min_segments(config, openvm_exe, vec![], 1, false);
+    Ok(())
+}
+
+#[test]
+fn test_schnorr_runtime() -> Result<()> {
+    let elf = build_example_program_with_features("schnorr", ["schnorr"])?;
+    let openvm_exe = VmExe::from_elf(
+        elf,
+        Transpiler::<F>::default()
+            .with_extension(Rv32ITranspilerExtension)
+            .with_extension(Rv32MTranspilerExtension)
+            .with_extension(Rv32IoTranspilerExtension)
+            .with_extension(Sha256TranspilerExtension)
+            .with_extension(EccTranspilerExtension)
+            .with_extension(ModularTranspilerExtension),
+    )?;
+    let config = Rv32WeierstrassSha256Config::new(vec![SECP256K1_CONFIG.clone()]);
+    new_air_test_with_min_segments(config, openvm_exe, vec![], 1, false);
+    Ok(())
+}
+
+#[test]
+fn test_ed25519_runtime() -> Result<()> {
+    let elf = build_example_program_with_features("ed25519", ["ed25519"])?;
+    let openvm_exe = VmExe::from_elf(
+        elf,
+        Transpiler::<F>::default()
+            .with_extension(Rv32ITranspilerExtension)
+            .with_extension(Rv32MTranspilerExtension)
+            .with_extension(Rv32IoTranspilerExtension)
+            .with_extension(EdwardsTranspilerExtension)
+            .with_extension(ModularTranspilerExtension),
+    )?;
+    let config = Rv32EdwardsConfig::new(vec![ED25519_CONFIG.clone()]);
+    new_air_test_with_min_segments(config, openvm_exe, vec![], 1, false);
+    Ok(())
+}
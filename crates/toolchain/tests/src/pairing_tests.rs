@@ -47,7 +47,7 @@ mod bn254 {
             modular: ModularExtension::new(primes.to_vec()),
             fp2: Fp2Extension::new(primes.to_vec()),
             weierstrass: WeierstrassExtension::new(vec![]),
-            pairing: PairingExtension::new(vec![PairingCurve::Bn254]),
+            pairing: PairingExtension::new(vec![PairingCurve::Bn254.params()]),
         }
     }
 
@@ -277,21 +277,42 @@ mod bn254 {
 }
 
 mod bls12_381 {
+    use derive_more::derive::From;
+    use openvm_algebra_circuit::{
+        Fp2ExtensionExecutor, Fp2ExtensionPeriphery, ModularExtensionExecutor,
+        ModularExtensionPeriphery,
+    };
     use openvm_algebra_transpiler::{Fp2TranspilerExtension, ModularTranspilerExtension};
+    use openvm_circuit::{
+        arch::{SystemExecutor, SystemPeriphery, VmChipComplex, VmConfig, VmInventoryError},
+        derive::{AnyEnum, InstructionExecutor, VmConfig},
+    };
+    use openvm_circuit_primitives_derive::{Chip, ChipUsageGetter};
+    use openvm_ecc_circuit::{WeierstrassExtensionExecutor, WeierstrassExtensionPeriphery};
     use openvm_ecc_guest::{
         algebra::IntMod,
         halo2curves::bls12_381::{Fq12, Fq2, Fr, G1Affine, G2Affine},
         AffinePoint,
     };
+    use openvm_ecc_transpiler::EccTranspilerExtension;
+    use openvm_pairing_circuit::{PairingExtensionExecutor, PairingExtensionPeriphery};
     use openvm_pairing_guest::{
         bls12_381::BLS12_381_MODULUS,
         halo2curves_shims::bls12_381::Bls12_381,
         pairing::{LineMulMType, MillerStep},
     };
     use openvm_pairing_transpiler::PairingTranspilerExtension;
+    use openvm_rv32im_circuit::{
+        Rv32I, Rv32IExecutor, Rv32IPeriphery, Rv32Io, Rv32IoExecutor, Rv32IoPeriphery, Rv32M,
+        Rv32MExecutor, Rv32MPeriphery,
+    };
     use openvm_rv32im_transpiler::{
         Rv32ITranspilerExtension, Rv32IoTranspilerExtension, Rv32MTranspilerExtension,
     };
+    use openvm_sha256_circuit::{Sha256, Sha256Executor, Sha256Periphery};
+    use openvm_sha256_transpiler::Sha256TranspilerExtension;
+    use openvm_stark_backend::p3_field::PrimeField32;
+    use serde::{Deserialize, Serialize};
 
     use super::*;
     use crate::utils::build_example_program_with_features;
@@ -306,7 +327,48 @@ mod bls12_381 {
             modular: ModularExtension::new(primes.to_vec()),
             fp2: Fp2Extension::new(primes.to_vec()),
             weierstrass: WeierstrassExtension::new(vec![]),
-            pairing: PairingExtension::new(vec![PairingCurve::Bls12_381]),
+            pairing: PairingExtension::new(vec![PairingCurve::Bls12_381.params()]),
+        }
+    }
+
+    /// BLS signatures also need SHA-256 to hash to the curve, and the G1 curve for the
+    /// aggregation of public keys.
+    #[derive(Clone, Debug, VmConfig, Serialize, Deserialize)]
+    pub struct Rv32PairingSha256Config {
+        #[system]
+        pub system: SystemConfig,
+        #[extension]
+        pub base: Rv32I,
+        #[extension]
+        pub mul: Rv32M,
+        #[extension]
+        pub io: Rv32Io,
+        #[extension]
+        pub modular: ModularExtension,
+        #[extension]
+        pub fp2: Fp2Extension,
+        #[extension]
+        pub weierstrass: WeierstrassExtension,
+        #[extension]
+        pub pairing: PairingExtension,
+        #[extension]
+        pub sha256: Sha256,
+    }
+
+    impl Default for Rv32PairingSha256Config {
+        fn default() -> Self {
+            let config = Rv32PairingConfig::new(vec![PairingCurve::Bls12_381.params()]);
+            Self {
+                system: config.system,
+                base: config.base,
+                mul: config.mul,
+                io: config.io,
+                modular: config.modular,
+                fp2: config.fp2,
+                weierstrass: config.weierstrass,
+                pairing: config.pairing,
+                sha256: Default::default(),
+            }
         }
     }
 
@@ -588,4 +650,119 @@ mod bls12_381 {
         new_air_test_with_min_segments(get_testing_config(), openvm_exe, vec![io], 1, false);
         Ok(())
     }
+
+    #[test]
+    fn test_bls12_381_signature() -> Result<()> {
+        let elf = build_example_program_with_features("bls_signature", ["bls12_381"])?;
+        let openvm_exe = VmExe::from_elf(
+            elf,
+            Transpiler::<F>::default()
+                .with_extension(Rv32ITranspilerExtension)
+                .with_extension(Rv32MTranspilerExtension)
+                .with_extension(Rv32IoTranspilerExtension)
+                .with_extension(PairingTranspilerExtension)
+                .with_extension(ModularTranspilerExtension)
+                .with_extension(Fp2TranspilerExtension)
+                .with_extension(EccTranspilerExtension)
+                .with_extension(Sha256TranspilerExtension),
+        )?;
+        new_air_test_with_min_segments(
+            Rv32PairingSha256Config::default(),
+            openvm_exe,
+            vec![],
+            1,
+            false,
+        );
+        Ok(())
+    }
+}
+
+mod bls12_377 {
+    use hex_literal::hex;
+    use openvm_algebra_transpiler::ModularTranspilerExtension;
+    use openvm_ecc_guest::{algebra::IntMod, weierstrass::WeierstrassPoint, CyclicGroup, Group};
+    use openvm_pairing_guest::bls12_377::{Fp, Fp2, G1Affine, BLS12_377_MODULUS};
+    use openvm_pairing_transpiler::PairingTranspilerExtension;
+    use openvm_rv32im_transpiler::{
+        Rv32ITranspilerExtension, Rv32IoTranspilerExtension, Rv32MTranspilerExtension,
+    };
+
+    use super::*;
+    use crate::utils::build_example_program_with_features;
+
+    pub fn get_testing_config() -> Rv32PairingConfig {
+        let primes = [BLS12_377_MODULUS.clone()];
+        Rv32PairingConfig {
+            system: SystemConfig::default().with_continuations(),
+            base: Default::default(),
+            mul: Default::default(),
+            io: Default::default(),
+            modular: ModularExtension::new(primes.to_vec()),
+            // The pairing chips do their own `Fp2` arithmetic with `u^2 = -5`.
+            fp2: Fp2Extension::new(vec![]),
+            weierstrass: WeierstrassExtension::new(vec![]),
+            pairing: PairingExtension::new(vec![PairingCurve::Bls12_377.params()]),
+        }
+    }
+
+    fn fp2(c0: [u8; 48], c1: [u8; 48]) -> Fp2 {
+        Fp2::new(Fp::from_be_bytes(&c0), Fp::from_be_bytes(&c1))
+    }
+
+    #[test]
+    fn test_bls12_377_pairing_check() -> Result<()> {
+        let elf = build_example_program_with_features("pairing_check", ["bls12_377"])?;
+        let openvm_exe = VmExe::from_elf(
+            elf,
+            Transpiler::<F>::default()
+                .with_extension(Rv32ITranspilerExtension)
+                .with_extension(Rv32MTranspilerExtension)
+                .with_extension(Rv32IoTranspilerExtension)
+                .with_extension(PairingTranspilerExtension)
+                .with_extension(ModularTranspilerExtension),
+        )?;
+
+        // e(2P, Q) * e(-P, 2Q) = 1
+        let s = [G1Affine::GENERATOR.double(), G1Affine::NEG_GENERATOR]
+            .map(|p| AffinePoint::new(p.x().clone(), p.y().clone()));
+        let q = [
+            AffinePoint::new(
+                fp2(
+                    hex!("0003b5cb0d1bc029813baf9e3ec5129034d3e5cec7757243d9fbd3445a6ba4f362f7f276239059f59511a93a61288fa5"),
+                    hex!("0015dd1f5871871edae8f193ae11400adb0a27781182636657380c19c3f35a6be0eeba0d46a32312963c624c6985f31b"),
+                ),
+                fp2(
+                    hex!("0088df3f27b9be56473404cb0911b2440fdaf1888a0a79ce2a746fb5a86a91a0f4973c2524a0545577ae500e80c65c49"),
+                    hex!("00d03d5b7ce77bbd56a644c2d57322a4d23e6504af2c2be2fd58031dab49a6e4c71500510910871796a32088100e6f58"),
+                ),
+            ),
+            AffinePoint::new(
+                fp2(
+                    hex!("000b408a4dd8459bd0b5f0dcc23a3775513a6694a066f626078fcc2d272744ad330d352b2e0d4553ee02a9ceaa601359"),
+                    hex!("00f9ceaece9dd9ef959ceec632c20101da26d0eb2475aa8188400c3a9ebc3284caa7b35ca0b7e1075cfc741f02f921d4"),
+                ),
+                fp2(
+                    hex!("001b37cc9c62eb317e3225985d296e6d002e0e2adc8738e5c0c9dd42878b9c480a2b02667d02e0166e1e4e8d2dac2599"),
+                    hex!("0053914cfa1ce9ac78bc16c90b90a5b715d22df5ef6ad79a9e90a2c60dc60ab67cd4d39460e75528b711c7d20daf1aaa"),
+                ),
+            ),
+        ];
+
+        let io0 = s
+            .iter()
+            .flat_map(|pt| [&pt.x, &pt.y])
+            .flat_map(|fp| fp.as_le_bytes().to_vec());
+        let io1 = q
+            .iter()
+            .flat_map(|pt| [&pt.x, &pt.y])
+            .flat_map(|fp2| [&fp2.c0, &fp2.c1])
+            .flat_map(|fp| fp.as_le_bytes().to_vec());
+        let io_all = io0
+            .chain(io1)
+            .map(AbstractField::from_canonical_u8)
+            .collect::<Vec<_>>();
+
+        new_air_test_with_min_segments(get_testing_config(), openvm_exe, vec![io_all], 1, true);
+        Ok(())
+    }
 }
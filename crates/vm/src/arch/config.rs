@@ -89,6 +89,11 @@ pub struct SystemConfig {
     /// Whether to collect metrics.
     /// **Warning**: this slows down the runtime.
     pub collect_metrics: bool,
+    /// If set, execution fails with
+    /// [CycleLimitExceeded](super::ExecutionError::CycleLimitExceeded) once this many
+    /// instructions have been executed, counted over all segments.
+    #[serde(default)]
+    pub max_cycles: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -110,6 +115,7 @@ impl SystemConfig {
             num_public_values,
             max_segment_len: DEFAULT_MAX_SEGMENT_LEN,
             collect_metrics: false,
+            max_cycles: None,
         }
     }
 
@@ -138,6 +144,11 @@ impl SystemConfig {
         self
     }
 
+    pub fn with_max_cycles(mut self, max_cycles: u64) -> Self {
+        self.max_cycles = Some(max_cycles);
+        self
+    }
+
     pub fn with_metric_collection(mut self) -> Self {
         self.collect_metrics = true;
         self
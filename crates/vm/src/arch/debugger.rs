@@ -0,0 +1,245 @@
+//! Library API to pause a running guest program and inspect the VM state, used by
+//! `cargo openvm debug`.
+
+use std::{collections::BTreeSet, ops::ControlFlow};
+
+use openvm_instructions::{
+    exe::{FnBound, FnBounds, VmExe},
+    instruction::Instruction,
+    riscv::{RV32_REGISTER_AS, RV32_REGISTER_NUM_LIMBS},
+};
+use openvm_stark_backend::p3_field::PrimeField32;
+use thiserror::Error;
+
+use super::{
+    ExecutionError, ExecutionSegment, ExecutionSummary, Streams, VmConfig, VmExecutor,
+    VmMemoryState,
+};
+
+/// Number of RISC-V general purpose registers.
+pub const RV32_NUM_REGISTERS: usize = 32;
+
+/// What the debugger does after it stopped before an instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DebugCommand {
+    /// Execute one instruction and stop again.
+    Step,
+    /// Run until the next breakpoint.
+    Continue,
+    /// Stop the execution with [ExecutionError::Interrupted].
+    Quit,
+}
+
+/// Error of [DebugState::read_memory] for a range of cells that are not addressable by field
+/// elements.
+#[derive(Clone, Copy, Debug, Error, PartialEq, Eq)]
+#[error("cannot read {len} cells at [{address_space}][{pointer:#x}]: address out of range")]
+pub struct MemoryRangeError {
+    pub address_space: u32,
+    pub pointer: u32,
+    pub len: usize,
+}
+
+/// Breakpoints of a [VmDebugger]. Symbols are resolved with the function bounds of the
+/// executable, which are only available if it was transpiled with the `function-span` feature.
+#[derive(Clone, Debug, Default)]
+pub struct Breakpoints {
+    pcs: BTreeSet<u32>,
+    fn_bounds: FnBounds,
+    single_step: bool,
+}
+
+impl Breakpoints {
+    pub fn new(fn_bounds: FnBounds) -> Self {
+        Self {
+            pcs: BTreeSet::new(),
+            fn_bounds,
+            single_step: false,
+        }
+    }
+
+    pub fn add(&mut self, pc: u32) {
+        self.pcs.insert(pc);
+    }
+
+    /// Adds a breakpoint at the start of the function `symbol` and returns its pc, or `None` if
+    /// there is no such function.
+    pub fn add_symbol(&mut self, symbol: &str) -> Option<u32> {
+        let pc = self
+            .fn_bounds
+            .values()
+            .find(|bound| bound.name == symbol)?
+            .start;
+        self.add(pc);
+        Some(pc)
+    }
+
+    /// Returns whether there was a breakpoint at `pc`.
+    pub fn remove(&mut self, pc: u32) -> bool {
+        self.pcs.remove(&pc)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        self.pcs.iter().copied()
+    }
+
+    /// If set, the debugger stops before every instruction.
+    pub fn set_single_step(&mut self, single_step: bool) {
+        self.single_step = single_step;
+    }
+
+    fn should_stop(&self, pc: u32) -> bool {
+        self.single_step || self.pcs.contains(&pc)
+    }
+}
+
+/// VM state before the instruction at [DebugState::pc] is executed.
+pub struct DebugState<'a, F, VC>
+where
+    F: PrimeField32,
+    VC: VmConfig<F>,
+{
+    pc: u32,
+    segment: &'a ExecutionSegment<F, VC>,
+}
+
+impl<F, VC> DebugState<'_, F, VC>
+where
+    F: PrimeField32,
+    VC: VmConfig<F>,
+{
+    pub fn pc(&self) -> u32 {
+        self.pc
+    }
+
+    /// Number of instructions executed so far, over all segments.
+    pub fn num_cycles(&self) -> u64 {
+        self.segment.num_cycles
+    }
+
+    pub fn timestamp(&self) -> u32 {
+        self.segment
+            .chip_complex
+            .memory_controller()
+            .borrow()
+            .timestamp()
+    }
+
+    /// The instruction which is executed next.
+    pub fn instruction(&self) -> Option<Instruction<F>> {
+        let program = &self.segment.chip_complex.program_chip().program;
+        let index = self.pc.checked_sub(program.pc_base)? / program.step;
+        program
+            .get_instruction_and_debug_info(index as usize)
+            .map(|(instruction, _)| instruction)
+    }
+
+    /// The function containing [DebugState::pc], if the executable has function bounds.
+    pub fn function(&self) -> Option<&FnBound> {
+        self.segment
+            .fn_bounds
+            .range(..=self.pc)
+            .next_back()
+            .map(|(_, bound)| bound)
+            .filter(|bound| self.pc <= bound.end)
+    }
+
+    /// Reads `len` cells starting at `pointer` in `address_space`, without affecting the
+    /// execution. Fails if the cells are not addressable by field elements.
+    pub fn read_memory(
+        &self,
+        address_space: u32,
+        pointer: u32,
+        len: usize,
+    ) -> Result<Vec<F>, MemoryRangeError> {
+        let end = u32::try_from(len)
+            .ok()
+            .and_then(|len| pointer.checked_add(len));
+        if address_space >= F::ORDER_U32 || !end.is_some_and(|end| end <= F::ORDER_U32) {
+            return Err(MemoryRangeError {
+                address_space,
+                pointer,
+                len,
+            });
+        }
+        let memory = self.segment.chip_complex.memory_controller().borrow();
+        Ok((0..len as u32)
+            .map(|i| {
+                memory.unsafe_read_cell(
+                    F::from_canonical_u32(address_space),
+                    F::from_canonical_u32(pointer + i),
+                )
+            })
+            .collect())
+    }
+
+    /// The RISC-V registers `x0..x31`.
+    pub fn registers(&self) -> [u32; RV32_NUM_REGISTERS] {
+        let limbs = self
+            .read_memory(
+                RV32_REGISTER_AS,
+                0,
+                RV32_NUM_REGISTERS * RV32_REGISTER_NUM_LIMBS,
+            )
+            .expect("registers are addressable");
+        let mut registers = [0; RV32_NUM_REGISTERS];
+        for (register, limbs) in registers
+            .iter_mut()
+            .zip(limbs.chunks_exact(RV32_REGISTER_NUM_LIMBS))
+        {
+            *register = limbs
+                .iter()
+                .rev()
+                .fold(0, |acc, limb| (acc << 8) | limb.as_canonical_u32());
+        }
+        registers
+    }
+}
+
+/// Executes a program like [VmExecutor::execute_with_summary], but stops before the
+/// instructions at [Breakpoints] and hands the VM state to a debugger front-end.
+pub struct VmDebugger<F, VC> {
+    pub executor: VmExecutor<F, VC>,
+    pub breakpoints: Breakpoints,
+    exe: VmExe<F>,
+}
+
+impl<F, VC> VmDebugger<F, VC>
+where
+    F: PrimeField32,
+    VC: VmConfig<F>,
+{
+    pub fn new(config: VC, exe: impl Into<VmExe<F>>) -> Self {
+        let exe = exe.into();
+        Self {
+            executor: VmExecutor::new(config),
+            breakpoints: Breakpoints::new(exe.fn_bounds.clone()),
+            exe,
+        }
+    }
+
+    /// Runs the program. Whenever execution stops, `on_stop` is called with the breakpoints,
+    /// which it may modify, and the current VM state, and decides how to proceed.
+    pub fn run(
+        &mut self,
+        input: impl Into<Streams<F>>,
+        mut on_stop: impl FnMut(&mut Breakpoints, &DebugState<F, VC>) -> DebugCommand,
+    ) -> Result<(Option<VmMemoryState<F>>, ExecutionSummary), ExecutionError> {
+        let breakpoints = &mut self.breakpoints;
+        let segments =
+            self.executor
+                .execute_segments_with_hook(self.exe.clone(), input, |segment, pc| {
+                    if !breakpoints.should_stop(pc) {
+                        return ControlFlow::Continue(());
+                    }
+                    let state = DebugState { pc, segment };
+                    match on_stop(breakpoints, &state) {
+                        DebugCommand::Step => breakpoints.set_single_step(true),
+                        DebugCommand::Continue => breakpoints.set_single_step(false),
+                        DebugCommand::Quit => return ControlFlow::Break(()),
+                    }
+                    ControlFlow::Continue(())
+                })?;
+        VmExecutor::summarize(segments)
+    }
+}
@@ -40,6 +40,11 @@ pub enum ExecutionError {
         num_public_values: usize,
         public_value_index: usize,
     },
+    #[error("public value index {public_value_index} in the final memory is out of bounds for num_public_values = {num_public_values}")]
+    FinalPublicValueIndexOutOfBounds {
+        num_public_values: usize,
+        public_value_index: usize,
+    },
     #[error("at pc {pc}, tried to publish {new_value} into index {public_value_index} but already had {existing_value}")]
     PublicValueNotEqual {
         pc: u32,
@@ -58,6 +63,18 @@ pub enum ExecutionError {
         discriminant: PhantomDiscriminant,
         inner: eyre::Error,
     },
+    #[error("at pc {pc}, exceeded the cycle limit of {max_cycles}")]
+    CycleLimitExceeded { pc: u32, max_cycles: u64 },
+    #[error("program exited with non-zero exit code {code}")]
+    NonZeroExitCode { code: u32 },
+    #[error("program did not terminate")]
+    DidNotTerminate,
+    #[error("execution interrupted at pc {pc}")]
+    Interrupted { pc: u32 },
+    #[error(
+        "segment checkpoint has timestamp {checkpoint} but its memory starts at timestamp {memory}"
+    )]
+    CheckpointTimestampMismatch { checkpoint: u32, memory: u32 },
 }
 
 pub trait InstructionExecutor<F> {
@@ -223,6 +223,8 @@ pub enum VmInventoryError {
     PhantomSubExecutorExists { discriminant: PhantomDiscriminant },
     #[error("Chip {name} not found")]
     ChipNotFound { name: String },
+    #[error("Range checker supports {actual} bits, but {required} bits are required")]
+    RangeCheckerTooSmall { required: usize, actual: usize },
 }
 
 impl<E, P> Default for VmInventory<E, P> {
@@ -1,4 +1,6 @@
 mod config;
+/// Breakpoints and inspection of a running guest program.
+pub mod debugger;
 /// Instruction execution traits and types.
 /// Execution bus and interface.
 mod execution;
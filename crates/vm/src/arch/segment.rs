@@ -1,3 +1,5 @@
+use std::ops::ControlFlow;
+
 use backtrace::Backtrace;
 #[cfg(feature = "function-span")]
 use openvm_instructions::exe::FnBound;
@@ -46,6 +48,8 @@ where
 
     pub air_names: Vec<String>,
     pub since_last_segment_check: usize,
+    /// Number of instructions executed so far, including the instructions of previous segments.
+    pub num_cycles: u64,
 }
 
 pub struct ExecutionSegmentState {
@@ -88,6 +92,7 @@ impl<F: PrimeField32, VC: VmConfig<F>> ExecutionSegment<F, VC> {
             fn_bounds,
             air_names,
             since_last_segment_check: 0,
+            num_cycles: 0,
         }
     }
 
@@ -103,9 +108,17 @@ impl<F: PrimeField32, VC: VmConfig<F>> ExecutionSegment<F, VC> {
     }
 
     /// Stopping is triggered by should_segment()
-    pub fn execute_from_pc(
+    pub fn execute_from_pc(&mut self, pc: u32) -> Result<ExecutionSegmentState, ExecutionError> {
+        self.execute_from_pc_with_hook(pc, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Same as [Self::execute_from_pc], but calls `hook` with the segment and the pc of the next
+    /// instruction before every instruction is executed. Execution stops with
+    /// [ExecutionError::Interrupted] if `hook` returns [ControlFlow::Break].
+    pub fn execute_from_pc_with_hook(
         &mut self,
         mut pc: u32,
+        mut hook: impl FnMut(&Self, u32) -> ControlFlow<()>,
     ) -> Result<ExecutionSegmentState, ExecutionError> {
         let mut timestamp = self.chip_complex.memory_controller().borrow().timestamp();
 
@@ -123,8 +136,19 @@ impl<F: PrimeField32, VC: VmConfig<F>> ExecutionSegment<F, VC> {
             .begin(ExecutionState::new(pc, timestamp));
 
         let mut did_terminate = false;
+        let max_cycles = self.system_config().max_cycles;
 
         loop {
+            if hook(self, pc).is_break() {
+                return Err(ExecutionError::Interrupted { pc });
+            }
+            if let Some(max_cycles) = max_cycles {
+                if self.num_cycles >= max_cycles {
+                    return Err(ExecutionError::CycleLimitExceeded { pc, max_cycles });
+                }
+            }
+            self.num_cycles += 1;
+
             let (instruction, debug_info) =
                 self.chip_complex.program_chip_mut().get_instruction(pc)?;
             tracing::trace!("pc: {pc:#x} | time: {timestamp} | {:?}", instruction);
@@ -212,9 +212,11 @@ impl VmChipTestBuilder<BabyBear> {
     }
 }
 
-impl<F: PrimeField32> Default for VmChipTestBuilder<F> {
-    fn default() -> Self {
-        let mem_config = MemoryConfig::new(2, 1, 29, 29, 17, 64);
+impl<F: PrimeField32> VmChipTestBuilder<F> {
+    /// Same as the default test builder, but the range checker supports values of up to
+    /// `range_checker_bits` bits.
+    pub fn with_range_checker_bits(range_checker_bits: usize) -> Self {
+        let mem_config = MemoryConfig::new(2, 1, 29, 29, range_checker_bits, 64);
         let range_checker = Arc::new(VariableRangeCheckerChip::new(VariableRangeCheckerBus::new(
             RANGE_CHECKER_BUS,
             mem_config.decomp,
@@ -235,6 +237,12 @@ impl<F: PrimeField32> Default for VmChipTestBuilder<F> {
     }
 }
 
+impl<F: PrimeField32> Default for VmChipTestBuilder<F> {
+    fn default() -> Self {
+        Self::with_range_checker_bits(17)
+    }
+}
+
 pub struct VmChipTester<SC: StarkGenericConfig> {
     pub memory: Option<MemoryTester<Val<SC>>>,
     pub air_proof_inputs: Vec<AirProofInput<SC>>,
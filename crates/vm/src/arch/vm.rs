@@ -1,4 +1,6 @@
-use std::{borrow::Borrow, collections::VecDeque, marker::PhantomData, mem, sync::Arc};
+use std::{
+    borrow::Borrow, collections::VecDeque, marker::PhantomData, mem, ops::ControlFlow, sync::Arc,
+};
 
 use openvm_instructions::exe::VmExe;
 use openvm_stark_backend::{
@@ -11,11 +13,13 @@ use openvm_stark_backend::{
     verifier::VerificationError,
     Chip,
 };
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use super::{ExecutionError, VmComplexTraceHeights, VmConfig, CONNECTOR_AIR_ID, MERKLE_AIR_ID};
 use crate::{
     arch::segment::ExecutionSegment,
+    metrics::cycle_tracker::CycleTracker,
     system::{
         connector::{VmConnectorPvs, DEFAULT_SUSPEND_EXIT_CODE},
         memory::{memory_image_to_equipartition, merkle::MemoryMerklePvs, Equipartition, CHUNK},
@@ -26,7 +30,7 @@ use crate::{
 /// VM memory state for continuations.
 pub type VmMemoryState<F> = Equipartition<F, CHUNK>;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct Streams<F> {
     pub input_stream: VecDeque<Vec<F>>,
     pub hint_stream: VecDeque<F>,
@@ -53,6 +57,35 @@ impl<F> From<Vec<Vec<F>>> for Streams<F> {
     }
 }
 
+/// The state of the VM at the start of a continuation segment. A segment can be re-executed and
+/// proven from its checkpoint alone, without executing the segments before it, see
+/// [VmExecutor::execute_checkpoints] and [VirtualMachine::prove_segment_from_checkpoint].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound(serialize = "F: Serialize", deserialize = "F: Deserialize<'de> + Ord"))]
+pub struct SegmentCheckpoint<F> {
+    /// Program counter of the first instruction of the segment.
+    pub pc: u32,
+    /// Memory timestamp at the start of the segment.
+    pub timestamp: u32,
+    /// Memory image at the start of the segment.
+    pub memory: VmMemoryState<F>,
+    /// Input and hint streams that have not been consumed by the previous segments.
+    pub streams: Streams<F>,
+    /// Cycle tracker spans that are still open at the start of the segment.
+    pub cycle_tracker: CycleTracker,
+    /// Number of instructions executed by the previous segments.
+    pub num_cycles: u64,
+}
+
+/// Checkpoints of all segments of an execution, returned by [VmExecutor::execute_checkpoints].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound(serialize = "F: Serialize", deserialize = "F: Deserialize<'de> + Ord"))]
+pub struct VmExecutionCheckpoints<F> {
+    pub per_segment: Vec<SegmentCheckpoint<F>>,
+    /// When VM is running on persistent mode, public values are stored in a special memory space.
+    pub final_memory: Option<VmMemoryState<F>>,
+}
+
 pub struct VmExecutor<F, VC> {
     pub config: VC,
     pub overridden_heights: Option<VmComplexTraceHeights>,
@@ -66,12 +99,30 @@ pub enum ExitCode {
     Suspended = -1, // Continuations
 }
 
+/// Summary of a successful execution returned by [VmExecutor::execute_with_summary].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExecutionSummary {
+    /// Exit code of the program, read from the end state of the last segment.
+    pub exit_code: u32,
+    /// Number of instructions executed over all segments, including the final `TERMINATE`.
+    pub num_cycles: u64,
+    /// Number of continuation segments.
+    pub num_segments: usize,
+}
+
 pub struct VmExecutorResult<SC: StarkGenericConfig> {
     pub per_segment: Vec<ProofInput<SC>>,
     /// When VM is running on persistent mode, public values are stored in a special memory space.
     pub final_memory: Option<VmMemoryState<Val<SC>>>,
 }
 
+/// Result of [VirtualMachine::prove_streaming].
+pub struct VmStreamingProof<SC: StarkGenericConfig> {
+    pub per_segment: Vec<Proof<SC>>,
+    /// When VM is running on persistent mode, public values are stored in a special memory space.
+    pub final_memory: Option<VmMemoryState<Val<SC>>>,
+}
+
 impl<F, VC> VmExecutor<F, VC>
 where
     F: PrimeField32,
@@ -108,37 +159,166 @@ where
         exe: impl Into<VmExe<F>>,
         input: impl Into<Streams<F>>,
     ) -> Result<Vec<ExecutionSegment<F, VC>>, ExecutionError> {
-        #[cfg(feature = "bench-metrics")]
-        let start = std::time::Instant::now();
+        self.execute_segments_with_hook(exe, input, |_, _| ControlFlow::Continue(()))
+    }
 
-        let exe = exe.into();
-        let streams = input.into();
+    /// Same as [Self::execute_segments], but calls `hook` before every instruction. See
+    /// [ExecutionSegment::execute_from_pc_with_hook].
+    pub fn execute_segments_with_hook(
+        &self,
+        exe: impl Into<VmExe<F>>,
+        input: impl Into<Streams<F>>,
+        hook: impl FnMut(&ExecutionSegment<F, VC>, u32) -> ControlFlow<()>,
+    ) -> Result<Vec<ExecutionSegment<F, VC>>, ExecutionError> {
         let mut segments = vec![];
+        self.execute_segments_impl(exe.into(), input.into(), hook, false, |_, segment, _| {
+            segments.push(segment)
+        })?;
+        Ok(segments)
+    }
+
+    /// Executes the program without generating any traces and returns the checkpoint of every
+    /// segment, from which each segment can be proven independently, together with the final
+    /// memory state.
+    pub fn execute_checkpoints(
+        &self,
+        exe: impl Into<VmExe<F>>,
+        input: impl Into<Streams<F>>,
+    ) -> Result<VmExecutionCheckpoints<F>, ExecutionError> {
+        let mut per_segment = vec![];
+        let mut final_memory = None;
+        self.execute_segments_impl(
+            exe.into(),
+            input.into(),
+            |_, _| ControlFlow::Continue(()),
+            true,
+            |_, mut segment, checkpoint| {
+                // Only the last segment still has its final memory set.
+                final_memory = mem::take(&mut segment.final_memory);
+                per_segment.push(checkpoint.unwrap());
+            },
+        )?;
+        Ok(VmExecutionCheckpoints {
+            per_segment,
+            final_memory,
+        })
+    }
+
+    /// Re-executes the single segment starting at `checkpoint`. The returned segment is the same
+    /// as the one at the same position in [Self::execute_segments].
+    pub fn execute_segment_from_checkpoint(
+        &self,
+        exe: impl Into<VmExe<F>>,
+        checkpoint: SegmentCheckpoint<F>,
+    ) -> Result<ExecutionSegment<F, VC>, ExecutionError> {
+        let exe = exe.into();
+        let pc = checkpoint.pc;
+        let timestamp = checkpoint.timestamp;
+        let mut segment = self.create_segment(
+            &exe,
+            checkpoint.streams,
+            Some(checkpoint.memory),
+            checkpoint.cycle_tracker,
+            checkpoint.num_cycles,
+        );
+        let memory_timestamp = segment
+            .chip_complex
+            .memory_controller()
+            .borrow()
+            .timestamp();
+        if memory_timestamp != timestamp {
+            return Err(ExecutionError::CheckpointTimestampMismatch {
+                checkpoint: timestamp,
+                memory: memory_timestamp,
+            });
+        }
+        let state = segment.execute_from_pc(pc)?;
+        if !state.is_terminated && !self.continuation_enabled() {
+            return Err(ExecutionError::DidNotTerminate);
+        }
+        Ok(segment)
+    }
+
+    /// Creates a new segment with the given initial state.
+    fn create_segment(
+        &self,
+        exe: &VmExe<F>,
+        streams: Streams<F>,
+        initial_memory: Option<VmMemoryState<F>>,
+        cycle_tracker: CycleTracker,
+        num_cycles: u64,
+    ) -> ExecutionSegment<F, VC> {
         let mut segment = ExecutionSegment::new(
             &self.config,
             exe.program.clone(),
             streams,
-            Some(memory_image_to_equipartition(exe.init_memory)),
+            initial_memory,
             exe.fn_bounds.clone(),
         );
         if let Some(overridden_heights) = self.overridden_heights.as_ref() {
             segment.set_override_trace_heights(overridden_heights.clone());
         }
+        segment.cycle_tracker = cycle_tracker;
+        segment.num_cycles = num_cycles;
+        segment
+    }
+
+    /// Executes the program one segment at a time, calling `on_segment` with the index and the
+    /// segment as soon as it has finished executing, before the next segment is started. If
+    /// `collect_checkpoints` is set, the checkpoint the segment was started from is passed too.
+    ///
+    /// The state carried over to the next segment is taken out of each segment before it is
+    /// passed on, so only the last segment still has its `final_memory` set.
+    fn execute_segments_impl(
+        &self,
+        mut exe: VmExe<F>,
+        streams: Streams<F>,
+        mut hook: impl FnMut(&ExecutionSegment<F, VC>, u32) -> ControlFlow<()>,
+        collect_checkpoints: bool,
+        mut on_segment: impl FnMut(usize, ExecutionSegment<F, VC>, Option<SegmentCheckpoint<F>>),
+    ) -> Result<(), ExecutionError> {
+        #[cfg(feature = "bench-metrics")]
+        let start = std::time::Instant::now();
+
+        let mut seg_idx = 0;
         let mut pc = exe.pc_start;
+        let mut streams = streams;
+        let mut memory = memory_image_to_equipartition(mem::take(&mut exe.init_memory));
+        let mut cycle_tracker = CycleTracker::new();
+        let mut num_cycles = 0;
 
         loop {
-            let state = tracing::info_span!("execute_segment", segment = segments.len())
-                .in_scope(|| segment.execute_from_pc(pc))?;
+            let checkpoint_state = collect_checkpoints
+                .then(|| (streams.clone(), memory.clone(), cycle_tracker.clone()));
+            let mut segment =
+                self.create_segment(&exe, streams, Some(memory), cycle_tracker, num_cycles);
+            let checkpoint =
+                checkpoint_state.map(|(streams, memory, cycle_tracker)| SegmentCheckpoint {
+                    pc,
+                    timestamp: segment
+                        .chip_complex
+                        .memory_controller()
+                        .borrow()
+                        .timestamp(),
+                    memory,
+                    streams,
+                    cycle_tracker,
+                    num_cycles,
+                });
+
+            let state = tracing::info_span!("execute_segment", segment = seg_idx)
+                .in_scope(|| segment.execute_from_pc_with_hook(pc, &mut hook))?;
             pc = state.pc;
 
             if state.is_terminated {
+                on_segment(seg_idx, segment, checkpoint);
                 break;
             }
 
-            assert!(
-                self.continuation_enabled(),
-                "multiple segments require to enable continuations"
-            );
+            // Without continuations, the program must terminate within a single segment.
+            if !self.continuation_enabled() {
+                return Err(ExecutionError::DidNotTerminate);
+            }
 
             assert_eq!(
                 pc,
@@ -147,33 +327,22 @@ where
                     .pc
             );
 
-            let cycle_tracker = mem::take(&mut segment.cycle_tracker);
-            let final_memory = mem::take(&mut segment.final_memory)
+            cycle_tracker = mem::take(&mut segment.cycle_tracker);
+            num_cycles = segment.num_cycles;
+            memory = mem::take(&mut segment.final_memory)
                 .expect("final memory should be set in continuations segment");
-            let streams = segment.chip_complex.take_streams();
+            streams = segment.chip_complex.take_streams();
 
-            segments.push(segment);
-
-            segment = ExecutionSegment::new(
-                &self.config,
-                exe.program.clone(),
-                streams,
-                Some(final_memory),
-                exe.fn_bounds.clone(),
-            );
-            if let Some(overridden_heights) = self.overridden_heights.as_ref() {
-                segment.set_override_trace_heights(overridden_heights.clone());
-            }
-            segment.cycle_tracker = cycle_tracker;
+            on_segment(seg_idx, segment, checkpoint);
+            seg_idx += 1;
         }
-        segments.push(segment);
-        tracing::debug!("Number of continuation segments: {}", segments.len());
+        tracing::debug!("Number of continuation segments: {}", seg_idx + 1);
         #[cfg(feature = "bench-metrics")]
         metrics::gauge!("execute_time_ms").set(start.elapsed().as_millis() as f64);
         #[cfg(feature = "bench-metrics")]
         tracing::info!("execute_time [all segments]: {:?}", start.elapsed());
 
-        Ok(segments)
+        Ok(())
     }
 
     pub fn execute(
@@ -181,19 +350,47 @@ where
         exe: impl Into<VmExe<F>>,
         input: impl Into<Streams<F>>,
     ) -> Result<Option<VmMemoryState<F>>, ExecutionError> {
-        let mut results = self.execute_segments(exe, input)?;
-        let last = results.last_mut().unwrap();
+        self.execute_with_summary(exe, input)
+            .map(|(final_memory, _)| final_memory)
+    }
+
+    /// Executes a program until it terminates and returns the final memory state together with
+    /// an [ExecutionSummary]. Unlike the proving paths, this never panics on a faulty guest
+    /// program: a program that exceeds `max_cycles`, does not terminate, or exits with a non-zero
+    /// exit code results in an [ExecutionError].
+    pub fn execute_with_summary(
+        &self,
+        exe: impl Into<VmExe<F>>,
+        input: impl Into<Streams<F>>,
+    ) -> Result<(Option<VmMemoryState<F>>, ExecutionSummary), ExecutionError> {
+        let segments = self.execute_segments(exe, input)?;
+        Self::summarize(segments)
+    }
+
+    /// Checks that the last of `segments` terminated successfully and returns its final memory
+    /// state together with an [ExecutionSummary].
+    pub(crate) fn summarize(
+        mut segments: Vec<ExecutionSegment<F, VC>>,
+    ) -> Result<(Option<VmMemoryState<F>>, ExecutionSummary), ExecutionError> {
+        let num_segments = segments.len();
+        let last = segments.last_mut().unwrap();
         let final_memory = mem::take(&mut last.final_memory);
         let end_state =
             last.chip_complex.connector_chip().boundary_states[1].expect("end state must be set");
-        // TODO[jpw]: add these as execution errors
-        assert_eq!(end_state.is_terminate, 1, "program must terminate");
-        assert_eq!(
-            end_state.exit_code,
-            ExitCode::Success as u32,
-            "program did not exit successfully"
-        );
-        Ok(final_memory)
+        if end_state.is_terminate != 1 {
+            return Err(ExecutionError::DidNotTerminate);
+        }
+        if end_state.exit_code != ExitCode::Success as u32 {
+            return Err(ExecutionError::NonZeroExitCode {
+                code: end_state.exit_code,
+            });
+        }
+        let summary = ExecutionSummary {
+            exit_code: end_state.exit_code,
+            num_cycles: last.num_cycles,
+            num_segments,
+        };
+        Ok((final_memory, summary))
     }
 
     pub fn execute_and_generate<SC: StarkGenericConfig>(
@@ -225,6 +422,59 @@ where
             input,
         )
     }
+    /// Same as [Self::execute_and_generate_with_cached_program], but instead of collecting the
+    /// proof inputs of all segments, generates the proof input of each segment as soon as it has
+    /// been executed and passes it to `on_proof_input` before the next segment is executed. This
+    /// bounds the memory to that of a single segment's trace.
+    ///
+    /// Returns the final memory state, as in [VmExecutorResult::final_memory].
+    pub fn execute_and_generate_streaming_with_cached_program<SC: StarkGenericConfig>(
+        &self,
+        commited_exe: Arc<VmCommittedExe<SC>>,
+        input: impl Into<Streams<F>>,
+        mut on_proof_input: impl FnMut(usize, ProofInput<SC>),
+    ) -> Result<Option<VmMemoryState<F>>, ExecutionError>
+    where
+        Domain<SC>: PolynomialSpace<Val = F>,
+        VC::Executor: Chip<SC>,
+        VC::Periphery: Chip<SC>,
+    {
+        let committed_program = commited_exe.committed_program.clone();
+        let mut final_memory = None;
+        self.execute_segments_impl(
+            commited_exe.exe.clone(),
+            input.into(),
+            |_, _| ControlFlow::Continue(()),
+            false,
+            |seg_idx, mut seg, _| {
+                // Only the last segment still has its final memory set.
+                final_memory = mem::take(&mut seg.final_memory);
+                let proof_input = tracing::info_span!("trace_gen", segment = seg_idx)
+                    .in_scope(|| seg.generate_proof_input(Some(committed_program.clone())));
+                on_proof_input(seg_idx, proof_input);
+            },
+        )?;
+        Ok(final_memory)
+    }
+
+    /// Re-executes the segment starting at `checkpoint` and generates its proof input. See
+    /// [Self::execute_segment_from_checkpoint].
+    pub fn execute_and_generate_segment_from_checkpoint<SC: StarkGenericConfig>(
+        &self,
+        commited_exe: Arc<VmCommittedExe<SC>>,
+        checkpoint: SegmentCheckpoint<F>,
+    ) -> Result<ProofInput<SC>, ExecutionError>
+    where
+        Domain<SC>: PolynomialSpace<Val = F>,
+        VC::Executor: Chip<SC>,
+        VC::Periphery: Chip<SC>,
+    {
+        let segment = self.execute_segment_from_checkpoint(commited_exe.exe.clone(), checkpoint)?;
+        Ok(tracing::info_span!("trace_gen").in_scope(|| {
+            segment.generate_proof_input(Some(commited_exe.committed_program.clone()))
+        }))
+    }
+
     fn execute_and_generate_impl<SC: StarkGenericConfig>(
         &self,
         exe: VmExe<F>,
@@ -477,6 +727,14 @@ where
             .execute_and_generate_with_cached_program(committed_exe, input)
     }
 
+    pub fn execute_checkpoints(
+        &self,
+        exe: impl Into<VmExe<F>>,
+        input: impl Into<Streams<F>>,
+    ) -> Result<VmExecutionCheckpoints<F>, ExecutionError> {
+        self.executor.execute_checkpoints(exe, input)
+    }
+
     pub fn prove_single(
         &self,
         pk: &MultiStarkProvingKey<SC>,
@@ -503,6 +761,49 @@ where
             .collect()
     }
 
+    /// Executes the program and proves it one segment at a time, so that the trace of only one
+    /// segment is held in memory at once. The proofs are the same as those of [Self::prove]
+    /// applied to [Self::execute_and_generate_with_cached_program].
+    pub fn prove_streaming(
+        &self,
+        pk: &MultiStarkProvingKey<SC>,
+        committed_exe: Arc<VmCommittedExe<SC>>,
+        input: impl Into<Streams<F>>,
+    ) -> Result<VmStreamingProof<SC>, ExecutionError> {
+        let mut per_segment = vec![];
+        let final_memory = self
+            .executor
+            .execute_and_generate_streaming_with_cached_program(
+                committed_exe,
+                input,
+                |seg_idx, proof_input| {
+                    let proof = tracing::info_span!("prove_segment", segment = seg_idx)
+                        .in_scope(|| self.engine.prove(pk, proof_input));
+                    per_segment.push(proof);
+                },
+            )?;
+        #[cfg(feature = "bench-metrics")]
+        metrics::counter!("num_segments").absolute(per_segment.len() as u64);
+        Ok(VmStreamingProof {
+            per_segment,
+            final_memory,
+        })
+    }
+
+    /// Re-executes and proves the single segment starting at `checkpoint`. Proving the
+    /// checkpoints of [Self::execute_checkpoints] in order gives the same proofs as [Self::prove].
+    pub fn prove_segment_from_checkpoint(
+        &self,
+        pk: &MultiStarkProvingKey<SC>,
+        committed_exe: Arc<VmCommittedExe<SC>>,
+        checkpoint: SegmentCheckpoint<F>,
+    ) -> Result<Proof<SC>, ExecutionError> {
+        let proof_input = self
+            .executor
+            .execute_and_generate_segment_from_checkpoint(committed_exe, checkpoint)?;
+        Ok(tracing::info_span!("prove_segment").in_scope(|| self.engine.prove(pk, proof_input)))
+    }
+
     pub fn verify_single(
         &self,
         vk: &MultiStarkVerifyingKey<SC>,
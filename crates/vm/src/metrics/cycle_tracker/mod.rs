@@ -1,4 +1,6 @@
-#[derive(Clone, Debug, Default)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct CycleTracker {
     /// Stack of span names, with most recent at the end
     stack: Vec<String>,
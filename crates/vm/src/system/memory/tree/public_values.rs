@@ -2,14 +2,32 @@ use std::sync::Arc;
 
 use openvm_stark_backend::{p3_field::PrimeField32, p3_util::log2_strict_usize};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 use crate::{
-    arch::hasher::Hasher,
+    arch::{hasher::Hasher, ExecutionError},
     system::memory::{dimensions::MemoryDimensions, tree::MemoryNode, Equipartition},
 };
 
 pub const PUBLIC_VALUES_ADDRESS_SPACE_OFFSET: usize = 2;
 
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum UserPublicValuesProofError {
+    #[error("number of public values {num_public_values} is not a power of two * {chunk}")]
+    UnexpectedLength {
+        num_public_values: usize,
+        chunk: usize,
+    },
+    #[error("incorrect proof length (expected: {expected}, actual: {actual})")]
+    IncorrectProofLength { expected: usize, actual: usize },
+    #[error("public values do not match the public values commit")]
+    UserPublicValuesCommitMismatch,
+    #[error("proof path does not lead to the public values address space at height {height}")]
+    IncorrectPath { height: usize },
+    #[error("final memory root mismatch")]
+    FinalMemoryRootMismatch,
+}
+
 /// Merkle proof for user public values in the memory state.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(bound(
@@ -33,12 +51,15 @@ impl<const CHUNK: usize, F: PrimeField32> UserPublicValuesProof<CHUNK, F> {
     /// Computes the proof of the public values from the final memory state.
     /// Assumption:
     /// - `num_public_values` is a power of two * CHUNK. It cannot be 0.
+    ///
+    /// Returns an error if the guest revealed a public value at an index of at least
+    /// `num_public_values`, which is not checked during execution in continuation mode.
     pub fn compute(
         memory_dimensions: MemoryDimensions,
         num_public_values: usize,
         hasher: &impl Hasher<CHUNK, F>,
         final_memory: &Equipartition<F, CHUNK>,
-    ) -> Self {
+    ) -> Result<Self, ExecutionError> {
         let proof = compute_merkle_proof_to_user_public_values_root(
             memory_dimensions,
             num_public_values,
@@ -46,13 +67,74 @@ impl<const CHUNK: usize, F: PrimeField32> UserPublicValuesProof<CHUNK, F> {
             final_memory,
         );
         let public_values =
-            extract_public_values(&memory_dimensions, num_public_values, final_memory);
+            extract_public_values(&memory_dimensions, num_public_values, final_memory)?;
         let public_values_commit = hasher.merkle_root(&public_values);
-        UserPublicValuesProof {
+        Ok(UserPublicValuesProof {
             proof,
             public_values,
             public_values_commit,
+        })
+    }
+}
+
+impl<const CHUNK: usize, F: PrimeField32> UserPublicValuesProof<CHUNK, F> {
+    /// Verifies that `public_values` are the public values in a memory state with Merkle root
+    /// `final_memory_root`, i.e. that they hash to `public_values_commit` and that `proof` is the
+    /// path from `public_values_commit` to `final_memory_root` through the public values address
+    /// space.
+    pub fn verify(
+        &self,
+        hasher: &impl Hasher<CHUNK, F>,
+        memory_dimensions: MemoryDimensions,
+        final_memory_root: [F; CHUNK],
+    ) -> Result<(), UserPublicValuesProofError> {
+        let num_public_values = self.public_values.len();
+        if num_public_values % CHUNK != 0 || !(num_public_values / CHUNK).is_power_of_two() {
+            return Err(UserPublicValuesProofError::UnexpectedLength {
+                num_public_values,
+                chunk: CHUNK,
+            });
+        }
+        let pv_height = log2_strict_usize(num_public_values / CHUNK);
+        if pv_height > memory_dimensions.address_height {
+            return Err(UserPublicValuesProofError::UnexpectedLength {
+                num_public_values,
+                chunk: CHUNK,
+            });
+        }
+        let address_leading_zeros = memory_dimensions.address_height - pv_height;
+        let expected_proof_len = memory_dimensions.as_height + address_leading_zeros;
+        if self.proof.len() != expected_proof_len {
+            return Err(UserPublicValuesProofError::IncorrectProofLength {
+                expected: expected_proof_len,
+                actual: self.proof.len(),
+            });
         }
+        if hasher.merkle_root(&self.public_values) != self.public_values_commit {
+            return Err(UserPublicValuesProofError::UserPublicValuesCommitMismatch);
+        }
+
+        let mut curr_root = self.public_values_commit;
+        for (height, (is_right, sibling_hash)) in self.proof.iter().enumerate() {
+            // Public values are at address 0 of the public values address space, so the path
+            // first goes through the leading zeros of the address and then through the bits of
+            // the address space from the least significant one.
+            let expected_is_right = height >= address_leading_zeros
+                && (PUBLIC_VALUES_ADDRESS_SPACE_OFFSET >> (height - address_leading_zeros)) & 1
+                    == 1;
+            if *is_right != expected_is_right {
+                return Err(UserPublicValuesProofError::IncorrectPath { height });
+            }
+            curr_root = if *is_right {
+                hasher.compress(sibling_hash, &curr_root)
+            } else {
+                hasher.compress(&curr_root, sibling_hash)
+            };
+        }
+        if curr_root != final_memory_root {
+            return Err(UserPublicValuesProofError::FinalMemoryRootMismatch);
+        }
+        Ok(())
     }
 }
 
@@ -105,11 +187,13 @@ fn compute_merkle_proof_to_user_public_values_root<const CHUNK: usize, F: PrimeF
     proof
 }
 
+/// Reads the public values from the public values address space of the final memory state.
+/// Returns an error if a public value was written at an index of at least `num_public_values`.
 pub fn extract_public_values<const CHUNK: usize, F: PrimeField32>(
     memory_dimensions: &MemoryDimensions,
     num_public_values: usize,
     final_memory: &Equipartition<F, CHUNK>,
-) -> Vec<F> {
+) -> Result<Vec<F>, ExecutionError> {
     // All (addr, value) pairs in the public value address space.
     let f_as_start =
         F::from_canonical_usize(PUBLIC_VALUES_ADDRESS_SPACE_OFFSET + memory_dimensions.as_offset);
@@ -126,17 +210,19 @@ pub fn extract_public_values<const CHUNK: usize, F: PrimeField32>(
                 .map(|(i, &v)| (*block_id * CHUNK + i, v))
         })
         .collect();
-    if let Some(last_pv) = used_pvs.last() {
-        assert!(
-            last_pv.0 < num_public_values,
-            "Last public value is out of bounds"
-        );
+    if let Some(&(public_value_index, _)) = used_pvs.last() {
+        if public_value_index >= num_public_values {
+            return Err(ExecutionError::FinalPublicValueIndexOutOfBounds {
+                num_public_values,
+                public_value_index,
+            });
+        }
     }
     let mut public_values = F::zero_vec(num_public_values);
     for (i, pv) in used_pvs {
         public_values[i] = pv;
     }
-    public_values
+    Ok(public_values)
 }
 
 #[cfg(test)]
@@ -145,11 +231,14 @@ mod tests {
     use openvm_stark_backend::p3_field::AbstractField;
     use openvm_stark_sdk::p3_baby_bear::BabyBear;
 
-    use super::{UserPublicValuesProof, PUBLIC_VALUES_ADDRESS_SPACE_OFFSET};
+    use super::{
+        extract_public_values, UserPublicValuesProof, UserPublicValuesProofError,
+        PUBLIC_VALUES_ADDRESS_SPACE_OFFSET,
+    };
     use crate::{
         arch::{
             hasher::{poseidon2::vm_poseidon2_hasher, Hasher},
-            SystemConfig,
+            ExecutionError, SystemConfig,
         },
         system::memory::{memory_image_to_equipartition, tree::MemoryNode, CHUNK},
     };
@@ -178,10 +267,11 @@ mod tests {
             num_public_values,
             &hasher,
             &final_memory,
-        );
+        )
+        .unwrap();
         assert_eq!(pv_proof.public_values, expected_pvs);
         let final_memory_root =
-            MemoryNode::tree_from_memory(memory_dimensions, &final_memory, &hasher);
+            MemoryNode::tree_from_memory(memory_dimensions, &final_memory, &hasher).hash();
         let mut curr_root = pv_proof.public_values_commit;
         for (is_right, sibling_hash) in &pv_proof.proof {
             curr_root = if *is_right {
@@ -190,6 +280,53 @@ mod tests {
                 hasher.compress(&curr_root, sibling_hash)
             }
         }
-        assert_eq!(curr_root, final_memory_root.hash());
+        assert_eq!(curr_root, final_memory_root);
+        pv_proof
+            .verify(&hasher, memory_dimensions, final_memory_root)
+            .unwrap();
+
+        let mut wrong_pv_proof = pv_proof.clone();
+        wrong_pv_proof.public_values[0] += F::ONE;
+        assert_eq!(
+            wrong_pv_proof.verify(&hasher, memory_dimensions, final_memory_root),
+            Err(UserPublicValuesProofError::UserPublicValuesCommitMismatch)
+        );
+
+        let mut wrong_pv_proof = pv_proof.clone();
+        wrong_pv_proof.proof[0].1[0] += F::ONE;
+        assert_eq!(
+            wrong_pv_proof.verify(&hasher, memory_dimensions, final_memory_root),
+            Err(UserPublicValuesProofError::FinalMemoryRootMismatch)
+        );
+
+        let mut wrong_pv_proof = pv_proof;
+        wrong_pv_proof.proof[0].0 = !wrong_pv_proof.proof[0].0;
+        assert_eq!(
+            wrong_pv_proof.verify(&hasher, memory_dimensions, final_memory_root),
+            Err(UserPublicValuesProofError::IncorrectPath { height: 0 })
+        );
+    }
+
+    #[test]
+    fn test_public_value_out_of_bounds() {
+        let mut vm_config = SystemConfig::default();
+        vm_config.memory_config.as_height = 4;
+        vm_config.memory_config.pointer_max_bits = 5;
+        let memory_dimensions = vm_config.memory_config.memory_dimensions();
+        let pv_as = F::from_canonical_usize(
+            PUBLIC_VALUES_ADDRESS_SPACE_OFFSET + memory_dimensions.as_offset,
+        );
+        let num_public_values = 16;
+        let memory: MemoryImage<F> = [((pv_as, F::from_canonical_u32(16)), F::ONE)]
+            .into_iter()
+            .collect();
+        let final_memory = memory_image_to_equipartition(memory);
+        assert!(matches!(
+            extract_public_values(&memory_dimensions, num_public_values, &final_memory),
+            Err(ExecutionError::FinalPublicValueIndexOutOfBounds {
+                num_public_values: 16,
+                public_value_index: 16,
+            })
+        ));
     }
 }
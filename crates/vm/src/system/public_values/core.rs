@@ -13,8 +13,8 @@ use openvm_stark_backend::{
 
 use crate::{
     arch::{
-        AdapterAirContext, AdapterRuntimeContext, BasicAdapterInterface, MinimalInstruction,
-        Result, VmAdapterInterface, VmCoreAir, VmCoreChip,
+        AdapterAirContext, AdapterRuntimeContext, BasicAdapterInterface, ExecutionError,
+        MinimalInstruction, Result, VmAdapterInterface, VmCoreAir, VmCoreChip,
     },
     system::public_values::columns::PublicValuesCoreColsView,
 };
@@ -132,13 +132,20 @@ impl<F: PrimeField32> VmCoreChip<F, AdapterInterface<F>> for PublicValuesCoreChi
     fn execute_instruction(
         &self,
         _instruction: &Instruction<F>,
-        _from_pc: u32,
+        from_pc: u32,
         reads: AdapterInterfaceReads<F>,
     ) -> Result<(AdapterRuntimeContext<F, AdapterInterface<F>>, Self::Record)> {
         let [[value], [index]] = reads;
         {
             let idx: usize = index.as_canonical_u32() as usize;
             let mut custom_pvs = self.custom_pvs.lock().unwrap();
+            if idx >= custom_pvs.len() {
+                return Err(ExecutionError::PublicValueIndexOutOfBounds {
+                    pc: from_pc,
+                    num_public_values: custom_pvs.len(),
+                    public_value_index: idx,
+                });
+            }
 
             if custom_pvs[idx].is_none() {
                 custom_pvs[idx] = Some(value);
@@ -3,10 +3,12 @@ use std::{collections::BTreeMap, sync::Arc};
 use derive_more::derive::From;
 use openvm_circuit::{
     arch::{
+        debugger::{DebugCommand, VmDebugger},
         hasher::{poseidon2::vm_poseidon2_hasher, Hasher},
-        ChipId, ExitCode, MemoryConfig, SingleSegmentVmExecutor, SystemConfig, SystemExecutor,
-        SystemPeriphery, SystemTraceHeights, VirtualMachine, VmChipComplex, VmComplexTraceHeights,
-        VmConfig, VmInventoryError, VmInventoryTraceHeights,
+        ChipId, ExecutionError, ExecutionSummary, ExitCode, MemoryConfig, SegmentCheckpoint,
+        SingleSegmentVmExecutor, SystemConfig, SystemExecutor, SystemPeriphery, SystemTraceHeights,
+        VirtualMachine, VmChipComplex, VmComplexTraceHeights, VmConfig, VmExecutor,
+        VmInventoryError, VmInventoryTraceHeights,
     },
     derive::{AnyEnum, InstructionExecutor, VmConfig},
     system::{
@@ -145,6 +147,129 @@ fn test_vm_1() {
     air_test(NativeConfig::default(), program);
 }
 
+#[test]
+fn test_vm_execute_with_summary() {
+    // Decrements word[0]_1 from 3 to 0, then terminates with `exit_code`.
+    let program = |exit_code: isize| {
+        Program::from_instructions(&[
+            Instruction::from_isize(VmOpcode::with_default_offset(STOREW), 3, 0, 0, 0, 1),
+            Instruction::from_isize(
+                VmOpcode::with_default_offset(NativeBranchEqualOpcode(BEQ)),
+                0,
+                0,
+                3 * DEFAULT_PC_STEP as isize,
+                1,
+                0,
+            ),
+            Instruction::large_from_isize(VmOpcode::with_default_offset(SUB), 0, 0, 1, 1, 1, 0, 0),
+            Instruction::from_isize(
+                VmOpcode::with_default_offset(JAL),
+                2,
+                -2 * DEFAULT_PC_STEP as isize,
+                0,
+                1,
+                0,
+            ),
+            Instruction::from_isize(
+                VmOpcode::with_default_offset(TERMINATE),
+                0,
+                0,
+                exit_code,
+                0,
+                0,
+            ),
+        ])
+    };
+    // STOREW, 3 loop iterations of BEQ, SUB, JAL, the final BEQ and TERMINATE.
+    let num_cycles = 1 + 3 * 3 + 1 + 1;
+
+    let mut config = NativeConfig::default();
+    let (_, summary) = VmExecutor::<BabyBear, _>::new(config.clone())
+        .execute_with_summary(program(0), vec![])
+        .unwrap();
+    assert_eq!(
+        summary,
+        ExecutionSummary {
+            exit_code: ExitCode::Success as u32,
+            num_cycles,
+            num_segments: 1,
+        }
+    );
+
+    assert!(matches!(
+        VmExecutor::<BabyBear, _>::new(config.clone()).execute_with_summary(program(1), vec![]),
+        Err(ExecutionError::NonZeroExitCode { code: 1 })
+    ));
+
+    config.system = config.system.with_max_cycles(num_cycles - 1);
+    assert!(matches!(
+        VmExecutor::<BabyBear, _>::new(config).execute_with_summary(program(0), vec![]),
+        Err(ExecutionError::CycleLimitExceeded { pc, max_cycles })
+            if pc == 4 * DEFAULT_PC_STEP && max_cycles == num_cycles - 1
+    ));
+}
+
+#[test]
+fn test_vm_debugger() {
+    // Decrements word[0]_1 from 3 to 0, then terminates.
+    let program = Program::<BabyBear>::from_instructions(&[
+        Instruction::from_isize(VmOpcode::with_default_offset(STOREW), 3, 0, 0, 0, 1),
+        Instruction::from_isize(
+            VmOpcode::with_default_offset(NativeBranchEqualOpcode(BEQ)),
+            0,
+            0,
+            3 * DEFAULT_PC_STEP as isize,
+            1,
+            0,
+        ),
+        Instruction::large_from_isize(VmOpcode::with_default_offset(SUB), 0, 0, 1, 1, 1, 0, 0),
+        Instruction::from_isize(
+            VmOpcode::with_default_offset(JAL),
+            2,
+            -2 * DEFAULT_PC_STEP as isize,
+            0,
+            1,
+            0,
+        ),
+        Instruction::from_isize(VmOpcode::with_default_offset(TERMINATE), 0, 0, 0, 0, 0),
+    ]);
+    let sub_pc = 2 * DEFAULT_PC_STEP;
+
+    let mut debugger = VmDebugger::new(NativeConfig::default(), program.clone());
+    debugger.breakpoints.add(sub_pc);
+    let mut counters = vec![];
+    let (_, summary) = debugger
+        .run(vec![], |_, state| {
+            assert_eq!(state.pc(), sub_pc);
+            assert_eq!(
+                state.instruction().unwrap().opcode,
+                VmOpcode::with_default_offset(SUB)
+            );
+            counters.push(state.read_memory(1, 0, 1).unwrap()[0].as_canonical_u32());
+            assert!(state.read_memory(1, u32::MAX, 2).is_err());
+            DebugCommand::Continue
+        })
+        .unwrap();
+    assert_eq!(counters, [3, 2, 1]);
+    assert_eq!(summary.num_cycles, 12);
+
+    // Single-step from the first breakpoint, then quit.
+    let mut pcs = vec![];
+    let result = debugger.run(vec![], |_, state| {
+        pcs.push(state.pc());
+        if pcs.len() < 3 {
+            DebugCommand::Step
+        } else {
+            DebugCommand::Quit
+        }
+    });
+    assert_eq!(pcs, [sub_pc, 3 * DEFAULT_PC_STEP, DEFAULT_PC_STEP]);
+    assert!(matches!(
+        result,
+        Err(ExecutionError::Interrupted { pc }) if pc == DEFAULT_PC_STEP
+    ));
+}
+
 #[test]
 fn test_vm_override_executor_height() {
     let fri_params = FriParameters::standard_fast();
@@ -322,6 +447,26 @@ fn test_vm_public_values() {
     }
 }
 
+#[test]
+fn test_vm_public_values_index_out_of_bounds() {
+    let num_public_values = 8;
+    let config = SystemConfig::default().with_public_values(num_public_values);
+    let instructions = vec![
+        Instruction::from_usize(VmOpcode::with_default_offset(PUBLISH), [0, 12, 8, 0, 0, 0]),
+        Instruction::from_isize(VmOpcode::with_default_offset(TERMINATE), 0, 0, 0, 0, 0),
+    ];
+    let program = Program::from_instructions(&instructions);
+    let single_vm = SingleSegmentVmExecutor::<BabyBear, _>::new(config);
+    assert!(matches!(
+        single_vm.execute(program, vec![]),
+        Err(ExecutionError::PublicValueIndexOutOfBounds {
+            pc: 0,
+            num_public_values: 8,
+            public_value_index: 8,
+        })
+    ));
+}
+
 #[test]
 fn test_vm_initial_memory() {
     // Program that fails if mem[(1, 0)] != 101.
@@ -501,11 +646,146 @@ fn test_vm_continuations() {
     let hasher = vm_poseidon2_hasher();
     let num_public_values = 8;
     let pv_proof =
-        UserPublicValuesProof::compute(memory_dimensions, num_public_values, &hasher, &final_state);
+        UserPublicValuesProof::compute(memory_dimensions, num_public_values, &hasher, &final_state)
+            .unwrap();
     assert_eq!(pv_proof.public_values.len(), num_public_values);
     assert_eq!(pv_proof.public_values[0], expected_output);
 }
 
+/// A countdown loop that runs for several segments.
+fn multi_segment_countdown() -> (Program<BabyBear>, NativeConfig) {
+    let n = 2000;
+    // [0]_1 <- n, then decrement [0]_1 until it is zero.
+    let program = Program::from_instructions(&[
+        Instruction::from_isize(VmOpcode::with_default_offset(STOREW), n, 0, 0, 0, 1),
+        Instruction::large_from_isize(VmOpcode::with_default_offset(SUB), 0, 0, 1, 1, 1, 0, 0),
+        Instruction::from_isize(
+            VmOpcode::with_default_offset(NativeBranchEqualOpcode(BNE)),
+            0,
+            0,
+            -(DEFAULT_PC_STEP as isize),
+            1,
+            0,
+        ),
+        Instruction::from_isize(VmOpcode::with_default_offset(TERMINATE), 0, 0, 0, 0, 0),
+    ]);
+    let config = NativeConfig {
+        system: SystemConfig::new(3, MemoryConfig::default(), 0).with_max_segment_len(500),
+        native: Default::default(),
+    }
+    .with_continuations();
+    (program, config)
+}
+
+#[test]
+fn test_vm_continuations_prove_streaming() {
+    let (program, config) = multi_segment_countdown();
+    let engine = BabyBearPoseidon2Engine::new(FriParameters::standard_fast());
+    let vm = VirtualMachine::new(engine, config);
+    let pk = vm.keygen();
+    let committed_exe = vm.commit_exe(program);
+
+    let proof = vm
+        .prove_streaming(&pk, committed_exe.clone(), vec![])
+        .unwrap();
+    let result = vm
+        .execute_and_generate_with_cached_program(committed_exe, vec![])
+        .unwrap();
+    assert!(proof.per_segment.len() > 1);
+    assert_eq!(proof.per_segment.len(), result.per_segment.len());
+    assert_eq!(proof.final_memory, result.final_memory);
+    vm.verify(&pk.get_vk(), proof.per_segment)
+        .expect("Verification failed");
+}
+
+#[test]
+fn test_vm_continuations_prove_from_checkpoints() {
+    let (_, config) = multi_segment_countdown();
+    let n = 2000;
+    // The countdown of `multi_segment_countdown`, but the first input is hinted before the loop
+    // and the second one after it, so the checkpoints carry both streams across segments.
+    let program = Program::from_instructions(&[
+        Instruction::from_isize(
+            VmOpcode::with_default_offset(PHANTOM),
+            0,
+            0,
+            NativePhantom::HintInput as isize,
+            0,
+            0,
+        ),
+        Instruction::from_isize(VmOpcode::with_default_offset(STOREW), n, 0, 0, 0, 1),
+        Instruction::large_from_isize(VmOpcode::with_default_offset(SUB), 0, 0, 1, 1, 1, 0, 0),
+        Instruction::from_isize(
+            VmOpcode::with_default_offset(NativeBranchEqualOpcode(BNE)),
+            0,
+            0,
+            -(DEFAULT_PC_STEP as isize),
+            1,
+            0,
+        ),
+        // [1]_1, [2]_1 <- length and value of the first input
+        Instruction::from_isize(VmOpcode::with_default_offset(SHINTW), 0, 1, 0, 1, 1),
+        Instruction::from_isize(VmOpcode::with_default_offset(SHINTW), 0, 2, 0, 1, 1),
+        Instruction::from_isize(
+            VmOpcode::with_default_offset(PHANTOM),
+            0,
+            0,
+            NativePhantom::HintInput as isize,
+            0,
+            0,
+        ),
+        // [3]_1, [4]_1 <- length and value of the second input
+        Instruction::from_isize(VmOpcode::with_default_offset(SHINTW), 0, 3, 0, 1, 1),
+        Instruction::from_isize(VmOpcode::with_default_offset(SHINTW), 0, 4, 0, 1, 1),
+        Instruction::from_isize(VmOpcode::with_default_offset(TERMINATE), 0, 0, 0, 0, 0),
+    ]);
+    let input = vec![
+        vec![BabyBear::from_canonical_u32(7)],
+        vec![BabyBear::from_canonical_u32(11)],
+    ];
+    let engine = BabyBearPoseidon2Engine::new(FriParameters::standard_fast());
+    let vm = VirtualMachine::new(engine, config);
+    let pk = vm.keygen();
+    let committed_exe = vm.commit_exe(program.clone());
+
+    let checkpoints = vm
+        .execute_checkpoints(program.clone(), input.clone())
+        .unwrap();
+    assert!(checkpoints.per_segment.len() > 1);
+    let streams = &checkpoints.per_segment[1].streams;
+    assert_eq!(streams.input_stream, input[1..].to_vec());
+    assert_eq!(
+        streams.hint_stream,
+        [BabyBear::ONE, BabyBear::from_canonical_u32(7)]
+    );
+    assert_eq!(
+        checkpoints.final_memory,
+        vm.execute(program, input).unwrap()
+    );
+
+    // A checkpoint whose timestamp does not match its memory is rejected.
+    let mut checkpoint = checkpoints.per_segment[1].clone();
+    checkpoint.timestamp += 1;
+    assert!(matches!(
+        vm.prove_segment_from_checkpoint(&pk, committed_exe.clone(), checkpoint),
+        Err(ExecutionError::CheckpointTimestampMismatch { .. })
+    ));
+
+    // Each checkpoint is proven on its own after a serialization round trip, as a worker would.
+    let proofs = checkpoints
+        .per_segment
+        .iter()
+        .map(|checkpoint| {
+            let bytes = bitcode::serialize(checkpoint).unwrap();
+            let checkpoint: SegmentCheckpoint<BabyBear> = bitcode::deserialize(&bytes).unwrap();
+            vm.prove_segment_from_checkpoint(&pk, committed_exe.clone(), checkpoint)
+                .unwrap()
+        })
+        .collect();
+    vm.verify(&pk.get_vk(), proofs)
+        .expect("Verification failed");
+}
+
 #[test]
 fn test_vm_without_field_arithmetic() {
     /*
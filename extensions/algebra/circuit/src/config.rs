@@ -27,12 +27,18 @@ pub struct Rv32ModularConfig {
 
 impl Rv32ModularConfig {
     pub fn new(moduli: Vec<BigUint>) -> Self {
+        let modular = ModularExtension::new(moduli);
+        let mut system = SystemConfig::default().with_continuations();
+        system.memory_config.decomp = system
+            .memory_config
+            .decomp
+            .max(modular.range_checker_bits());
         Self {
-            system: SystemConfig::default().with_continuations(),
+            system,
             base: Default::default(),
             mul: Default::default(),
             io: Default::default(),
-            modular: ModularExtension::new(moduli),
+            modular,
         }
     }
 }
@@ -55,12 +61,18 @@ pub struct Rv32ModularWithFp2Config {
 
 impl Rv32ModularWithFp2Config {
     pub fn new(moduli: Vec<BigUint>) -> Self {
+        let modular = ModularExtension::new(moduli.clone());
+        let mut system = SystemConfig::default().with_continuations();
+        system.memory_config.decomp = system
+            .memory_config
+            .decomp
+            .max(modular.range_checker_bits());
         Self {
-            system: SystemConfig::default().with_continuations(),
+            system,
             base: Default::default(),
             mul: Default::default(),
             io: Default::default(),
-            modular: ModularExtension::new(moduli.clone()),
+            modular,
             fp2: Fp2Extension::new(moduli),
         }
     }
@@ -2,26 +2,47 @@ use std::{cell::RefCell, rc::Rc};
 
 use openvm_mod_circuit_builder::{ExprBuilder, FieldVariable, SymbolicExpr};
 
-/// Quadratic field extension of `Fp` defined by `Fp2 = Fp[u]/(1 + u^2)`. Assumes that `-1` is not a quadratic residue in `Fp`, which is equivalent to `p` being congruent to `3 (mod 4)`.
+/// Quadratic field extension of `Fp` defined by `Fp2 = Fp[u]/(u^2 - non_residue)`, where the
+/// small integer `non_residue` must not be a quadratic residue in `Fp`.
+///
+/// [Fp2::new] and [Fp2::new_var] use `non_residue = -1`, which requires `p` to be congruent to
+/// `3 (mod 4)`.
 #[derive(Clone)]
 pub struct Fp2 {
     pub c0: FieldVariable,
     pub c1: FieldVariable,
+    pub non_residue: isize,
 }
 
 impl Fp2 {
     pub fn new(builder: Rc<RefCell<ExprBuilder>>) -> Self {
+        Self::new_with_non_residue(builder, -1)
+    }
+
+    pub fn new_with_non_residue(builder: Rc<RefCell<ExprBuilder>>, non_residue: isize) -> Self {
         let c0 = ExprBuilder::new_input(builder.clone());
         let c1 = ExprBuilder::new_input(builder.clone());
-        Fp2 { c0, c1 }
+        Fp2 {
+            c0,
+            c1,
+            non_residue,
+        }
     }
 
     pub fn new_var(builder: Rc<RefCell<ExprBuilder>>) -> ((usize, usize), Fp2) {
+        Self::new_var_with_non_residue(builder, -1)
+    }
+
+    pub fn new_var_with_non_residue(
+        builder: Rc<RefCell<ExprBuilder>>,
+        non_residue: isize,
+    ) -> ((usize, usize), Fp2) {
         let (c0_idx, c0) = builder.borrow_mut().new_var();
         let (c1_idx, c1) = builder.borrow_mut().new_var();
         let fp2 = Fp2 {
             c0: FieldVariable::from_var(builder.clone(), c0),
             c1: FieldVariable::from_var(builder.clone(), c1),
+            non_residue,
         };
         ((c0_idx, c1_idx), fp2)
     }
@@ -38,32 +59,55 @@ impl Fp2 {
     }
 
     pub fn add(&mut self, other: &mut Fp2) -> Fp2 {
+        debug_assert_eq!(self.non_residue, other.non_residue);
         Fp2 {
             c0: &mut self.c0 + &mut other.c0,
             c1: &mut self.c1 + &mut other.c1,
+            non_residue: self.non_residue,
         }
     }
 
     pub fn sub(&mut self, other: &mut Fp2) -> Fp2 {
+        debug_assert_eq!(self.non_residue, other.non_residue);
         Fp2 {
             c0: &mut self.c0 - &mut other.c0,
             c1: &mut self.c1 - &mut other.c1,
+            non_residue: self.non_residue,
         }
     }
 
     pub fn mul(&mut self, other: &mut Fp2) -> Fp2 {
-        let c0 = &mut self.c0 * &mut other.c0 - &mut self.c1 * &mut other.c1;
+        debug_assert_eq!(self.non_residue, other.non_residue);
+        let c0 = if self.non_residue == -1 {
+            &mut self.c0 * &mut other.c0 - &mut self.c1 * &mut other.c1
+        } else {
+            &mut self.c0 * &mut other.c0 + (&mut self.c1 * &mut other.c1).int_mul(self.non_residue)
+        };
         let c1 = &mut self.c0 * &mut other.c1 + &mut self.c1 * &mut other.c0;
-        Fp2 { c0, c1 }
+        Fp2 {
+            c0,
+            c1,
+            non_residue: self.non_residue,
+        }
     }
 
     pub fn square(&mut self) -> Fp2 {
-        let c0 = self.c0.square() - self.c1.square();
+        let c0 = if self.non_residue == -1 {
+            self.c0.square() - self.c1.square()
+        } else {
+            self.c0.square() + self.c1.square().int_mul(self.non_residue)
+        };
         let c1 = (&mut self.c0 * &mut self.c1).int_mul(2);
-        Fp2 { c0, c1 }
+        Fp2 {
+            c0,
+            c1,
+            non_residue: self.non_residue,
+        }
     }
 
     pub fn div(&mut self, other: &mut Fp2) -> Fp2 {
+        debug_assert_eq!(self.non_residue, other.non_residue);
+        let non_residue = self.non_residue;
         let builder = self.c0.builder.borrow();
         let prime = builder.prime.clone();
         let limb_bits = builder.limb_bits;
@@ -78,21 +122,23 @@ impl Fp2 {
         // Compute should not be affected by whether auto save is triggered.
         // So we must do compute first.
         // Compute z0
-        let compute_denom = &other.c0.expr * &other.c0.expr + &other.c1.expr * &other.c1.expr;
-        let compute_z0_nom = &self.c0.expr * &other.c0.expr + &self.c1.expr * &other.c1.expr;
+        let compute_denom = &other.c0.expr * &other.c0.expr
+            - mul_by_non_residue(&other.c1.expr * &other.c1.expr, non_residue);
+        let compute_z0_nom = &self.c0.expr * &other.c0.expr
+            - mul_by_non_residue(&self.c1.expr * &other.c1.expr, non_residue);
         let compute_z0 = &compute_z0_nom / &compute_denom;
         // Compute z1
         let compute_z1_nom = &self.c1.expr * &other.c0.expr - &self.c0.expr * &other.c1.expr;
         let compute_z1 = &compute_z1_nom / &compute_denom;
 
-        // Constraint 1: x0 = y0*z0 - y1*z1
-        let constraint1 = &self.c0.expr - &other.c0.expr * &fake_z0 + &other.c1.expr * &fake_z1;
+        // Constraint 1: x0 = y0*z0 + non_residue*y1*z1
+        let constraint1 = div_constraint_c0(self, other, &fake_z0, &fake_z1);
         let carry_bits = constraint1.constraint_carry_bits_with_pq(&prime, limb_bits, num_limbs);
         if carry_bits > self.c0.range_checker_bits {
             // TODO: should save the "bigger" one first (the one with higher limb_max_abs)
             self.save();
         }
-        let constraint1 = &self.c0.expr - &other.c0.expr * &fake_z0 + &other.c1.expr * &fake_z1;
+        let constraint1 = div_constraint_c0(self, other, &fake_z0, &fake_z1);
         let carry_bits = constraint1.constraint_carry_bits_with_pq(&prime, limb_bits, num_limbs);
         if carry_bits > self.c0.range_checker_bits {
             other.save();
@@ -114,7 +160,7 @@ impl Fp2 {
         let mut builder = self.c0.builder.borrow_mut();
         let (z0_idx, z0) = builder.new_var();
         let (z1_idx, z1) = builder.new_var();
-        let constraint1 = &self.c0.expr - &other.c0.expr * &z0 + &other.c1.expr * &z1;
+        let constraint1 = div_constraint_c0(self, other, &z0, &z1);
         let constraint2 = &self.c1.expr - &other.c1.expr * &z0 - &other.c0.expr * &z1;
         builder.set_compute(z0_idx, compute_z0);
         builder.set_compute(z1_idx, compute_z1);
@@ -127,6 +173,7 @@ impl Fp2 {
         Fp2 {
             c0: z0_var,
             c1: z1_var,
+            non_residue,
         }
     }
 
@@ -134,6 +181,7 @@ impl Fp2 {
         Fp2 {
             c0: &mut self.c0 * fp,
             c1: &mut self.c1 * fp,
+            non_residue: self.non_residue,
         }
     }
 
@@ -141,14 +189,21 @@ impl Fp2 {
         Fp2 {
             c0: self.c0.int_add(c[0]),
             c1: self.c1.int_add(c[1]),
+            non_residue: self.non_residue,
         }
     }
 
     // c is like a Fp2, but with both c0 and c1 being very small numbers.
     pub fn int_mul(&mut self, c: [isize; 2]) -> Fp2 {
+        let c0 = if self.non_residue == -1 {
+            self.c0.int_mul(c[0]) - self.c1.int_mul(c[1])
+        } else {
+            self.c0.int_mul(c[0]) + self.c1.int_mul(c[1] * self.non_residue)
+        };
         Fp2 {
-            c0: self.c0.int_mul(c[0]) - self.c1.int_mul(c[1]),
+            c0,
             c1: self.c0.int_mul(c[1]) + self.c1.int_mul(c[0]),
+            non_residue: self.non_residue,
         }
     }
 
@@ -157,13 +212,28 @@ impl Fp2 {
     }
 
     pub fn select(flag_id: usize, a: &Fp2, b: &Fp2) -> Fp2 {
+        debug_assert_eq!(a.non_residue, b.non_residue);
         Fp2 {
             c0: FieldVariable::select(flag_id, &a.c0, &b.c0),
             c1: FieldVariable::select(flag_id, &a.c1, &b.c1),
+            non_residue: a.non_residue,
         }
     }
 }
 
+fn mul_by_non_residue(expr: SymbolicExpr, non_residue: isize) -> SymbolicExpr {
+    SymbolicExpr::IntMul(Box::new(expr), non_residue)
+}
+
+/// The constraint `x0 - (y0 z0 + non_residue y1 z1)` for the constant term of `x = y z`.
+fn div_constraint_c0(x: &Fp2, y: &Fp2, z0: &SymbolicExpr, z1: &SymbolicExpr) -> SymbolicExpr {
+    if x.non_residue == -1 {
+        &x.c0.expr - &y.c0.expr * z0 + &y.c1.expr * z1
+    } else {
+        &x.c0.expr - &y.c0.expr * z0 - mul_by_non_residue(&y.c1.expr * z1, x.non_residue)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use halo2curves_axiom::bn256::Fq2;
@@ -254,6 +324,18 @@ mod tests {
         test_fp2(Fp2::div, |x, y| x * y.invert().unwrap(), false);
     }
 
+    #[test]
+    fn test_fp2_new_var_with_non_residue() {
+        let prime = BN254_MODULUS.clone();
+        let (_, builder) = setup(&prime);
+
+        let mut x_fp2 = Fp2::new_with_non_residue(builder.clone(), -5);
+        let (_, mut z_fp2) = Fp2::new_var_with_non_residue(builder.clone(), -5);
+        assert_eq!(z_fp2.non_residue, -5);
+        // Operands with different non-residues are rejected by a debug assertion.
+        assert_eq!(x_fp2.mul(&mut z_fp2).non_residue, -5);
+    }
+
     #[test]
     fn test_fp2_div2() {
         let prime = BN254_MODULUS.clone();
@@ -0,0 +1,481 @@
+use std::{
+    array::from_fn,
+    borrow::Borrow,
+    cell::{RefCell, RefMut},
+    rc::Rc,
+    sync::Arc,
+};
+
+use itertools::Itertools;
+use num_bigint_dig::BigUint;
+use num_traits::One;
+use openvm_algebra_transpiler::Rv32ModularExpOpcode;
+use openvm_circuit::{
+    arch::{
+        AdapterAirContext, AdapterRuntimeContext, ExecutionState, InstructionExecutor,
+        MinimalInstruction, Result, VecHeapAdapterInterface, VmAdapterAir, VmAdapterChip,
+    },
+    system::memory::{MemoryController, MemoryControllerRef},
+};
+use openvm_circuit_primitives::{
+    utils::next_power_of_two_or_zero,
+    var_range::{VariableRangeCheckerBus, VariableRangeCheckerChip},
+    SubAir, TraceSubRowGenerator,
+};
+use openvm_instructions::{instruction::Instruction, UsizeOpcode};
+use openvm_mod_circuit_builder::{
+    utils::{biguint_to_limbs_vec, limbs_to_biguint},
+    ExprBuilder, ExprBuilderConfig, FieldExpr, FieldExprCols, FieldVariable,
+};
+use openvm_rv32_adapters::{
+    Rv32VecHeapAdapterAir, Rv32VecHeapAdapterChip, Rv32VecHeapReadRecord, Rv32VecHeapWriteRecord,
+};
+use openvm_stark_backend::{
+    config::{StarkGenericConfig, Val},
+    interaction::InteractionBuilder,
+    p3_air::{Air, AirBuilder, BaseAir},
+    p3_field::{AbstractField, Field, PrimeField32},
+    p3_matrix::{dense::RowMajorMatrix, Matrix},
+    p3_maybe_rayon::prelude::*,
+    prover::types::AirProofInput,
+    rap::{get_air_name, AnyRap, BaseAirWithPublicValues, PartitionedBaseAir},
+    Chip, ChipUsageGetter,
+};
+
+/// Computes `base^exponent` modulo the prime, where `exponent` is a little-endian integer with as
+/// many bytes as a field element, with one square-and-multiply step per row:
+/// - the first row of an instruction holds the adapter columns, which read `base` and `exponent`
+///   and write the result,
+/// - every row squares the accumulator and multiplies it by `base` if the current exponent bit is
+///   set, starting from the most significant bit,
+/// - the exponent bytes are shifted up by one position every 8 rows, so the byte of the current
+///   bit is always the top one, and each row decomposes only that byte into bits.
+///
+/// Every instruction takes exactly `8 * NUM_LANES * LANE_SIZE` rows, and the width only grows
+/// linearly with the number of limbs.
+#[derive(Clone)]
+pub struct ModularExpAir<const NUM_LANES: usize, const LANE_SIZE: usize> {
+    pub adapter: Rv32VecHeapAdapterAir<2, NUM_LANES, NUM_LANES, LANE_SIZE, LANE_SIZE>,
+    /// One step `out = acc^2 * (bit ? base : 1)` with inputs `[acc, base]`, output `out` and
+    /// flags `[bit]`.
+    pub expr: FieldExpr,
+    pub offset: usize,
+}
+
+/// Columns of [ModularExpAir] after the adapter and the [FieldExpr] columns.
+struct ModularExpCols<'a, T> {
+    /// Whether this is the first row of an instruction.
+    is_first: &'a T,
+    /// Index of the row within the instruction.
+    row_idx: &'a T,
+    /// Little-endian bytes of the exponent, shifted up by `row_idx / 8` positions. On the first
+    /// row, this is the exponent read by the adapter.
+    exponent: &'a [T],
+    /// The result written by the adapter, copied to every row.
+    result: &'a [T],
+    /// One-hot encoding of `row_idx % 8`, the position of the current bit within the top byte of
+    /// `exponent`, counting from its most significant bit.
+    bit_sel: &'a [T],
+    /// Little-endian bits of the top byte of `exponent`.
+    bits: &'a [T],
+}
+
+impl<const NUM_LANES: usize, const LANE_SIZE: usize> ModularExpAir<NUM_LANES, LANE_SIZE> {
+    pub fn new(
+        adapter: Rv32VecHeapAdapterAir<2, NUM_LANES, NUM_LANES, LANE_SIZE, LANE_SIZE>,
+        config: ExprBuilderConfig,
+        range_bus: VariableRangeCheckerBus,
+        offset: usize,
+    ) -> Self {
+        config.check_valid();
+        assert_eq!(config.num_limbs, NUM_LANES * LANE_SIZE);
+
+        let builder = ExprBuilder::new(config, range_bus.range_max_bits);
+        let builder = Rc::new(RefCell::new(builder));
+        let mut acc = ExprBuilder::new_input(builder.clone());
+        let mut base = ExprBuilder::new_input(builder.clone());
+        let bit = builder.borrow_mut().new_flag();
+        let mut square = acc.square();
+        square.save();
+        let mut out = FieldVariable::select(bit, &square.mul(&mut base), &square);
+        out.save_output();
+
+        let builder = builder.borrow().clone();
+        // There is no setup opcode: the modulus is checked by the setup of the other chips.
+        let expr = FieldExpr::new(builder, range_bus, false);
+        Self {
+            adapter,
+            expr,
+            offset,
+        }
+    }
+
+    pub fn num_limbs(&self) -> usize {
+        NUM_LANES * LANE_SIZE
+    }
+
+    /// Number of exponent bits, which is also the number of rows per instruction.
+    pub fn num_bits(&self) -> usize {
+        self.num_limbs() * 8
+    }
+
+    fn control_width(&self) -> usize {
+        2 + 2 * self.num_limbs() + 2 * 8
+    }
+
+    fn load_cols<'a, T>(&self, core: &'a [T]) -> ModularExpCols<'a, T> {
+        let num_limbs = self.num_limbs();
+        let (is_first, rest) = core.split_first().unwrap();
+        let (row_idx, rest) = rest.split_first().unwrap();
+        let (exponent, rest) = rest.split_at(num_limbs);
+        let (result, rest) = rest.split_at(num_limbs);
+        let (bit_sel, bits) = rest.split_at(8);
+        assert_eq!(bits.len(), 8);
+        ModularExpCols {
+            is_first,
+            row_idx,
+            exponent,
+            result,
+            bit_sel,
+            bits,
+        }
+    }
+}
+
+impl<F: Field, const NUM_LANES: usize, const LANE_SIZE: usize> BaseAir<F>
+    for ModularExpAir<NUM_LANES, LANE_SIZE>
+{
+    fn width(&self) -> usize {
+        BaseAir::<F>::width(&self.adapter) + BaseAir::<F>::width(&self.expr) + self.control_width()
+    }
+}
+
+impl<F: Field, const NUM_LANES: usize, const LANE_SIZE: usize> BaseAirWithPublicValues<F>
+    for ModularExpAir<NUM_LANES, LANE_SIZE>
+{
+}
+impl<F: Field, const NUM_LANES: usize, const LANE_SIZE: usize> PartitionedBaseAir<F>
+    for ModularExpAir<NUM_LANES, LANE_SIZE>
+{
+}
+
+impl<AB: InteractionBuilder, const NUM_LANES: usize, const LANE_SIZE: usize> Air<AB>
+    for ModularExpAir<NUM_LANES, LANE_SIZE>
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &[AB::Var] = (*local).borrow();
+        let next: &[AB::Var] = (*next).borrow();
+
+        let adapter_width = BaseAir::<AB::F>::width(&self.adapter);
+        let expr_width = BaseAir::<AB::F>::width(&self.expr);
+        let (local_adapter, local) = local.split_at(adapter_width);
+        let (local_expr, local) = local.split_at(expr_width);
+        let (next_expr, next) = next[adapter_width..].split_at(expr_width);
+
+        SubAir::eval(&self.expr, builder, local_expr);
+
+        let FieldExprCols {
+            is_valid,
+            inputs,
+            vars,
+            flags,
+            ..
+        } = self.expr.load_vars(local_expr);
+        let FieldExprCols {
+            is_valid: next_is_valid,
+            inputs: next_inputs,
+            ..
+        } = self.expr.load_vars(next_expr);
+        let (acc, base) = (&inputs[0], &inputs[1]);
+        let out = &vars[self.expr.output_indices[0]];
+        let local = self.load_cols(local);
+        let next = self.load_cols(next);
+        let num_limbs = self.num_limbs();
+        let num_bits = self.num_bits();
+
+        builder.assert_bool(*local.is_first);
+        builder.when(*local.is_first).assert_one(is_valid);
+        // The current exponent bit is selected from the bits of the top byte.
+        let bit = local
+            .bit_sel
+            .iter()
+            .zip(local.bits.iter().rev())
+            .fold(AB::Expr::ZERO, |acc, (&sel, &bit)| acc + sel * bit);
+        builder.assert_eq(flags[0], bit);
+
+        // The first row of every byte decomposes the top byte into bits.
+        {
+            let mut when_byte_first = builder.when(local.bit_sel[0]);
+            for &bit in local.bits {
+                when_byte_first.assert_bool(bit);
+            }
+            let byte = local
+                .bits
+                .iter()
+                .rev()
+                .fold(AB::Expr::ZERO, |acc, &bit| acc * AB::Expr::TWO + bit.into());
+            when_byte_first.assert_eq(local.exponent[num_limbs - 1], byte);
+        }
+
+        // The first row starts from `acc = 1` and the most significant bit.
+        {
+            let mut when_first = builder.when(*local.is_first);
+            when_first.assert_zero(*local.row_idx);
+            for (i, &limb) in acc.iter().enumerate() {
+                when_first.assert_eq(limb, AB::Expr::from_bool(i == 0));
+            }
+            for (i, &sel) in local.bit_sel.iter().enumerate() {
+                when_first.assert_eq(sel, AB::Expr::from_bool(i == 0));
+            }
+        }
+
+        // Whether the next row belongs to the same instruction.
+        let is_continued: AB::Expr = next_is_valid - *next.is_first;
+        {
+            let mut when_continued = builder.when(is_continued.clone());
+            when_continued.assert_one(is_valid);
+            when_continued.assert_eq(*next.row_idx, *local.row_idx + AB::Expr::ONE);
+            for (&next_acc, &out) in next_inputs[0].iter().zip_eq(out.iter()) {
+                when_continued.assert_eq(next_acc, out);
+            }
+            for (&next_base, &base) in next_inputs[1].iter().zip_eq(base.iter()) {
+                when_continued.assert_eq(next_base, base);
+            }
+            for (&next_limb, &limb) in next.result.iter().zip_eq(local.result.iter()) {
+                when_continued.assert_eq(next_limb, limb);
+            }
+            for i in 0..8 {
+                when_continued.assert_eq(next.bit_sel[(i + 1) % 8], local.bit_sel[i]);
+            }
+        }
+        // Within a byte, the exponent and the bits of its top byte stay the same.
+        {
+            let mut when_same_byte =
+                builder.when(is_continued.clone() * (AB::Expr::ONE - local.bit_sel[7]));
+            for (&next_limb, &limb) in next.exponent.iter().zip_eq(local.exponent.iter()) {
+                when_same_byte.assert_eq(next_limb, limb);
+            }
+            for (&next_bit, &bit) in next.bits.iter().zip_eq(local.bits.iter()) {
+                when_same_byte.assert_eq(next_bit, bit);
+            }
+        }
+        // After the last bit of a byte, the exponent is shifted up by one byte.
+        {
+            let mut when_next_byte = builder.when(is_continued.clone() * local.bit_sel[7]);
+            when_next_byte.assert_zero(next.exponent[0]);
+            for (&next_limb, &limb) in next.exponent[1..]
+                .iter()
+                .zip_eq(&local.exponent[..num_limbs - 1])
+            {
+                when_next_byte.assert_eq(next_limb, limb);
+            }
+        }
+
+        // The last row has processed every bit and holds the result.
+        {
+            let mut when_last = builder.when(is_valid - is_continued);
+            when_last.assert_eq(*local.row_idx, AB::Expr::from_canonical_usize(num_bits - 1));
+            for (&limb, &out) in local.result.iter().zip_eq(out.iter()) {
+                when_last.assert_eq(limb, out);
+            }
+        }
+
+        let reads = [base.as_slice(), local.exponent]
+            .map(|x| from_fn(|i| from_fn(|j| x[i * LANE_SIZE + j].into())));
+        let writes = from_fn(|i| from_fn(|j| local.result[i * LANE_SIZE + j].into()));
+        let ctx: AdapterAirContext<
+            _,
+            VecHeapAdapterInterface<_, 2, NUM_LANES, NUM_LANES, LANE_SIZE, LANE_SIZE>,
+        > = AdapterAirContext {
+            to_pc: None,
+            reads,
+            writes,
+            instruction: MinimalInstruction {
+                is_valid: (*local.is_first).into(),
+                opcode: AB::Expr::from_canonical_usize(
+                    Rv32ModularExpOpcode::EXP as usize + self.offset,
+                ),
+            },
+        };
+        self.adapter.eval(builder, local_adapter, ctx);
+    }
+}
+
+pub struct ModularExpRecord<F: Field, const NUM_LANES: usize, const LANE_SIZE: usize> {
+    pub read_record: Rv32VecHeapReadRecord<F, 2, NUM_LANES, LANE_SIZE>,
+    pub write_record: Rv32VecHeapWriteRecord<F, NUM_LANES, LANE_SIZE>,
+    pub base: BigUint,
+    pub exponent: Vec<u32>,
+}
+
+pub struct ModularExpChip<F: PrimeField32, const NUM_LANES: usize, const LANE_SIZE: usize> {
+    pub air: ModularExpAir<NUM_LANES, LANE_SIZE>,
+    pub adapter: Rv32VecHeapAdapterChip<F, 2, NUM_LANES, NUM_LANES, LANE_SIZE, LANE_SIZE>,
+    pub range_checker: Arc<VariableRangeCheckerChip>,
+    pub records: Vec<ModularExpRecord<F, NUM_LANES, LANE_SIZE>>,
+    memory: MemoryControllerRef<F>,
+}
+
+impl<F: PrimeField32, const NUM_LANES: usize, const LANE_SIZE: usize>
+    ModularExpChip<F, NUM_LANES, LANE_SIZE>
+{
+    pub fn new(
+        adapter: Rv32VecHeapAdapterChip<F, 2, NUM_LANES, NUM_LANES, LANE_SIZE, LANE_SIZE>,
+        config: ExprBuilderConfig,
+        range_checker: Arc<VariableRangeCheckerChip>,
+        offset: usize,
+        memory: MemoryControllerRef<F>,
+    ) -> Self {
+        let air = ModularExpAir::new(adapter.air, config, range_checker.bus(), offset);
+        Self {
+            air,
+            adapter,
+            range_checker,
+            records: vec![],
+            memory,
+        }
+    }
+}
+
+impl<F: PrimeField32, const NUM_LANES: usize, const LANE_SIZE: usize> InstructionExecutor<F>
+    for ModularExpChip<F, NUM_LANES, LANE_SIZE>
+{
+    fn execute(
+        &mut self,
+        instruction: Instruction<F>,
+        from_state: ExecutionState<u32>,
+    ) -> Result<ExecutionState<u32>> {
+        let local_opcode_idx = instruction.opcode.local_opcode_idx(self.air.offset);
+        assert_eq!(
+            Rv32ModularExpOpcode::from_usize(local_opcode_idx),
+            Rv32ModularExpOpcode::EXP
+        );
+        let limb_bits = self.air.expr.canonical_limb_bits();
+        let mut memory: RefMut<MemoryController<F>> = self.memory.borrow_mut();
+        let (reads, read_record) = self.adapter.preprocess(&mut memory, &instruction)?;
+        let [base, exponent] = reads.map(|read| {
+            read.into_iter()
+                .flatten()
+                .map(|x| x.as_canonical_u32())
+                .collect_vec()
+        });
+        let base = limbs_to_biguint(&base, limb_bits);
+        let result = base.modpow(
+            &limbs_to_biguint(&exponent, limb_bits),
+            &self.air.expr.prime,
+        );
+        tracing::trace!("ModularExpOpcode | {result:?} | {base:?} | {exponent:?}");
+
+        let result = biguint_to_limbs_vec(result, limb_bits, self.air.num_limbs());
+        let writes = from_fn(|i| from_fn(|j| F::from_canonical_u32(result[i * LANE_SIZE + j])));
+        let (to_state, write_record) = self.adapter.postprocess(
+            &mut memory,
+            &instruction,
+            from_state,
+            AdapterRuntimeContext::without_pc(writes),
+            &read_record,
+        )?;
+        self.records.push(ModularExpRecord {
+            read_record,
+            write_record,
+            base,
+            exponent,
+        });
+        Ok(to_state)
+    }
+
+    fn get_opcode_name(&self, _opcode: usize) -> String {
+        "ModularExp".to_string()
+    }
+}
+
+impl<SC: StarkGenericConfig, const NUM_LANES: usize, const LANE_SIZE: usize> Chip<SC>
+    for ModularExpChip<Val<SC>, NUM_LANES, LANE_SIZE>
+where
+    Val<SC>: PrimeField32,
+{
+    fn air(&self) -> Arc<dyn AnyRap<SC>> {
+        Arc::new(self.air.clone())
+    }
+
+    fn generate_air_proof_input(self) -> AirProofInput<SC> {
+        let air = self.air();
+        let width = self.trace_width();
+        let height = next_power_of_two_or_zero(self.current_trace_height());
+        let num_bits = self.air.num_bits();
+        let adapter_width = BaseAir::<Val<SC>>::width(&self.air.adapter);
+        let expr_width = BaseAir::<Val<SC>>::width(&self.air.expr);
+        let mut values = Val::<SC>::zero_vec(height * width);
+
+        let memory_aux_cols_factory = RefCell::borrow(&self.memory).aux_cols_factory();
+        // This zip only goes through records.
+        // The padding rows after the last instruction are filled with zeros.
+        values
+            .par_chunks_mut(width * num_bits)
+            .zip(self.records.into_par_iter())
+            .for_each(|(rows, record)| {
+                let num_limbs = self.air.num_limbs();
+                let result = record
+                    .write_record
+                    .writes
+                    .iter()
+                    .flat_map(|write| write.data)
+                    .collect_vec();
+                self.adapter.generate_trace_row(
+                    &mut rows[..adapter_width],
+                    record.read_record,
+                    record.write_record,
+                    &memory_aux_cols_factory,
+                );
+
+                let mut acc = BigUint::one();
+                for (row_idx, row) in rows.chunks_exact_mut(width).enumerate() {
+                    let (shift, bit_pos) = (row_idx / 8, row_idx % 8);
+                    let (expr_row, row) = row[adapter_width..].split_at_mut(expr_width);
+                    row[0] = Val::<SC>::from_bool(row_idx == 0);
+                    row[1] = Val::<SC>::from_canonical_usize(row_idx);
+                    let (exponent_row, row) = row[2..].split_at_mut(num_limbs);
+                    let (result_row, row) = row.split_at_mut(num_limbs);
+                    let (bit_sel_row, bits_row) = row.split_at_mut(8);
+                    // The bytes shifted out are zeros.
+                    for (limb, &byte) in exponent_row[shift..].iter_mut().zip(&record.exponent) {
+                        *limb = Val::<SC>::from_canonical_u32(byte);
+                    }
+                    result_row.copy_from_slice(&result);
+                    bit_sel_row[bit_pos] = Val::<SC>::ONE;
+                    let byte = record.exponent[num_limbs - 1 - shift];
+                    for (i, bit) in bits_row.iter_mut().enumerate() {
+                        *bit = Val::<SC>::from_bool((byte >> i) & 1 == 1);
+                    }
+
+                    let bit = (byte >> (7 - bit_pos)) & 1 == 1;
+                    let inputs = vec![acc.clone(), record.base.clone()];
+                    acc = self.air.expr.execute_with_output(inputs.clone(), vec![bit])[0].clone();
+                    self.air
+                        .expr
+                        .generate_subrow((&self.range_checker, inputs, vec![bit]), expr_row);
+                }
+            });
+
+        let trace = RowMajorMatrix::new(values, width);
+        AirProofInput::simple_no_pis(air, trace)
+    }
+}
+
+impl<F: PrimeField32, const NUM_LANES: usize, const LANE_SIZE: usize> ChipUsageGetter
+    for ModularExpChip<F, NUM_LANES, LANE_SIZE>
+{
+    fn air_name(&self) -> String {
+        get_air_name(&self.air)
+    }
+
+    fn current_trace_height(&self) -> usize {
+        self.records.len() * self.air.num_bits()
+    }
+
+    fn trace_width(&self) -> usize {
+        BaseAir::<F>::width(&self.air)
+    }
+}
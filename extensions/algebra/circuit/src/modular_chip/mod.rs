@@ -1,5 +1,7 @@
 mod addsub;
 pub use addsub::*;
+mod exp;
+pub use exp::*;
 mod is_eq;
 pub use is_eq::*;
 mod muldiv;
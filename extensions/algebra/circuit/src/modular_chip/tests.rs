@@ -2,7 +2,7 @@ use std::{array::from_fn, sync::Arc};
 
 use num_bigint_dig::BigUint;
 use num_traits::Zero;
-use openvm_algebra_transpiler::Rv32ModularArithmeticOpcode;
+use openvm_algebra_transpiler::{Rv32ModularArithmeticOpcode, Rv32ModularExpOpcode};
 use openvm_circuit::arch::{
     instructions::UsizeOpcode, testing::VmChipTestBuilder, VmChipWrapper, BITWISE_OP_LOOKUP_BUS,
 };
@@ -27,7 +27,8 @@ use openvm_stark_sdk::{p3_baby_bear::BabyBear, utils::create_seeded_rng};
 use rand::Rng;
 
 use super::{
-    ModularAddSubCoreChip, ModularIsEqualChip, ModularIsEqualCoreChip, ModularMulDivCoreChip,
+    ModularAddSubCoreChip, ModularExpChip, ModularIsEqualChip, ModularIsEqualCoreChip,
+    ModularMulDivCoreChip,
 };
 
 const NUM_LIMBS: usize = 32;
@@ -377,3 +378,234 @@ fn test_modular_is_equal_1x32() {
 fn test_modular_is_equal_3x16() {
     test_is_equal::<3, 16, 48>(17, BLS12_381_MODULUS.clone(), 100);
 }
+
+#[test]
+fn test_modular_is_equal_8x32() {
+    // RSA-2048 sized modulus
+    let modulus = (BigUint::from(1u32) << 2048) - BigUint::from(159u32);
+    test_is_equal::<8, 32, 256>(17, modulus, 20);
+}
+
+fn write_lanes<const LANE_SIZE: usize>(
+    tester: &mut VmChipTestBuilder<F>,
+    address_space: usize,
+    address: usize,
+    limbs: &[u32],
+) {
+    for (i, lane) in limbs.chunks(LANE_SIZE).enumerate() {
+        let lane: [F; LANE_SIZE] = from_fn(|j| F::from_canonical_u32(lane[j]));
+        tester.write(address_space, address + i * LANE_SIZE, lane);
+    }
+}
+
+fn test_muldiv_lanes<const NUM_LANES: usize, const LANE_SIZE: usize, const TOTAL_LIMBS: usize>(
+    modulus: BigUint,
+    range_checker_bits: usize,
+    num_tests: usize,
+) {
+    let mut tester: VmChipTestBuilder<F> =
+        VmChipTestBuilder::with_range_checker_bits(range_checker_bits);
+    let config = ExprBuilderConfig {
+        modulus: modulus.clone(),
+        num_limbs: TOTAL_LIMBS,
+        limb_bits: LIMB_BITS,
+    };
+    let core = ModularMulDivCoreChip::new(
+        config,
+        tester.memory_controller().borrow().range_checker.clone(),
+        Rv32ModularArithmeticOpcode::default_offset(),
+    );
+    let bitwise_bus = BitwiseOperationLookupBus::new(BITWISE_OP_LOOKUP_BUS);
+    let bitwise_chip = Arc::new(BitwiseOperationLookupChip::<RV32_CELL_BITS>::new(
+        bitwise_bus,
+    ));
+    let adapter = Rv32VecHeapAdapterChip::<F, 2, NUM_LANES, NUM_LANES, LANE_SIZE, LANE_SIZE>::new(
+        tester.execution_bus(),
+        tester.program_bus(),
+        tester.memory_controller(),
+        bitwise_chip.clone(),
+    );
+    let mut chip = VmChipWrapper::new(adapter, core, tester.memory_controller());
+    let mut rng = create_seeded_rng();
+
+    // The first operation is the setup, with the modulus as first operand.
+    let mut all_ops = vec![MUL_LOCAL + 2];
+    let mut all_a = vec![modulus.clone()];
+    let mut all_b = vec![BigUint::zero()];
+    for _ in 0..num_tests {
+        let a = generate_field_element::<TOTAL_LIMBS, LIMB_BITS>(&modulus, &mut rng);
+        let b = generate_field_element::<TOTAL_LIMBS, LIMB_BITS>(&modulus, &mut rng);
+        all_ops.push(MUL_LOCAL);
+        all_a.push(BigUint::from_bytes_le(&a.map(|x| x as u8)));
+        all_b.push(BigUint::from_bytes_le(&b.map(|x| x as u8)));
+    }
+
+    let ptr_as = 1;
+    let addr_ptr1 = 0;
+    let addr_ptr2 = 12;
+    let addr_ptr3 = 24;
+
+    let data_as = 2;
+    let address1 = 0;
+    let address2 = TOTAL_LIMBS as u32;
+    let address3 = 2 * TOTAL_LIMBS as u32;
+    for ((op, a), b) in all_ops.into_iter().zip(all_a).zip(all_b) {
+        let expected_answer = match op - MUL_LOCAL {
+            0 => (&a * &b) % &modulus,
+            2 => a.clone() % &modulus,
+            _ => panic!(),
+        };
+
+        write_ptr_reg(&mut tester, ptr_as, addr_ptr1, address1);
+        write_ptr_reg(&mut tester, ptr_as, addr_ptr2, address2);
+        write_ptr_reg(&mut tester, ptr_as, addr_ptr3, address3);
+        write_lanes::<LANE_SIZE>(
+            &mut tester,
+            data_as,
+            address1 as usize,
+            &biguint_to_limbs::<TOTAL_LIMBS>(a, LIMB_BITS),
+        );
+        write_lanes::<LANE_SIZE>(
+            &mut tester,
+            data_as,
+            address2 as usize,
+            &biguint_to_limbs::<TOTAL_LIMBS>(b, LIMB_BITS),
+        );
+
+        let instruction = Instruction::from_isize(
+            VmOpcode::from_usize(chip.core.air.offset + op),
+            addr_ptr3 as isize,
+            addr_ptr1 as isize,
+            addr_ptr2 as isize,
+            ptr_as as isize,
+            data_as as isize,
+        );
+        tester.execute(&mut chip, instruction);
+
+        let expected_limbs = biguint_to_limbs::<TOTAL_LIMBS>(expected_answer, LIMB_BITS);
+        for (i, expected) in expected_limbs.into_iter().enumerate() {
+            let read_val = tester.read_cell(data_as, address3 as usize + i);
+            assert_eq!(BabyBear::from_canonical_u32(expected), read_val);
+        }
+    }
+    let tester = tester.build().load(chip).load(bitwise_chip).finalize();
+    tester.simple_test().expect("Verification failed");
+}
+
+#[test]
+fn test_modular_muldiv_8x32() {
+    // RSA-2048 sized modulus
+    let modulus = (BigUint::from(1u32) << 2048) - BigUint::from(159u32);
+    test_muldiv_lanes::<8, 32, 256>(modulus, 18, 5);
+}
+
+#[test]
+fn test_modular_muldiv_16x32() {
+    // RSA-4096 sized modulus
+    let modulus = (BigUint::from(1u32) << 4096) - BigUint::from(1u32);
+    test_muldiv_lanes::<16, 32, 512>(modulus, 19, 5);
+}
+
+fn test_exp<const NUM_LANES: usize, const LANE_SIZE: usize, const TOTAL_LIMBS: usize>(
+    modulus: BigUint,
+    range_checker_bits: usize,
+    exponents: Vec<BigUint>,
+) {
+    let mut tester: VmChipTestBuilder<F> =
+        VmChipTestBuilder::with_range_checker_bits(range_checker_bits);
+    let config = ExprBuilderConfig {
+        modulus: modulus.clone(),
+        num_limbs: TOTAL_LIMBS,
+        limb_bits: LIMB_BITS,
+    };
+    let bitwise_bus = BitwiseOperationLookupBus::new(BITWISE_OP_LOOKUP_BUS);
+    let bitwise_chip = Arc::new(BitwiseOperationLookupChip::<RV32_CELL_BITS>::new(
+        bitwise_bus,
+    ));
+    let adapter = Rv32VecHeapAdapterChip::<F, 2, NUM_LANES, NUM_LANES, LANE_SIZE, LANE_SIZE>::new(
+        tester.execution_bus(),
+        tester.program_bus(),
+        tester.memory_controller(),
+        bitwise_chip.clone(),
+    );
+    let mut chip = ModularExpChip::new(
+        adapter,
+        config,
+        tester.memory_controller().borrow().range_checker.clone(),
+        Rv32ModularExpOpcode::default_offset(),
+        tester.memory_controller(),
+    );
+    let mut rng = create_seeded_rng();
+
+    let ptr_as = 1;
+    let addr_ptr1 = 0;
+    let addr_ptr2 = 12;
+    let addr_ptr3 = 24;
+
+    let data_as = 2;
+    let address1 = 0;
+    let address2 = TOTAL_LIMBS as u32;
+    let address3 = 2 * TOTAL_LIMBS as u32;
+    for exponent in exponents {
+        let base = generate_field_element::<TOTAL_LIMBS, LIMB_BITS>(&modulus, &mut rng);
+        let expected_answer =
+            BigUint::from_bytes_le(&base.map(|x| x as u8)).modpow(&exponent, &modulus);
+
+        write_ptr_reg(&mut tester, ptr_as, addr_ptr1, address1);
+        write_ptr_reg(&mut tester, ptr_as, addr_ptr2, address2);
+        write_ptr_reg(&mut tester, ptr_as, addr_ptr3, address3);
+        write_lanes::<LANE_SIZE>(&mut tester, data_as, address1 as usize, &base);
+        write_lanes::<LANE_SIZE>(
+            &mut tester,
+            data_as,
+            address2 as usize,
+            &biguint_to_limbs::<TOTAL_LIMBS>(exponent, LIMB_BITS),
+        );
+
+        let instruction = Instruction::from_isize(
+            VmOpcode::from_usize(chip.air.offset + Rv32ModularExpOpcode::EXP as usize),
+            addr_ptr3 as isize,
+            addr_ptr1 as isize,
+            addr_ptr2 as isize,
+            ptr_as as isize,
+            data_as as isize,
+        );
+        tester.execute(&mut chip, instruction);
+
+        let expected_limbs = biguint_to_limbs::<TOTAL_LIMBS>(expected_answer, LIMB_BITS);
+        for (i, expected) in expected_limbs.into_iter().enumerate() {
+            let read_val = tester.read_cell(data_as, address3 as usize + i);
+            assert_eq!(BabyBear::from_canonical_u32(expected), read_val);
+        }
+    }
+    let tester = tester.build().load(chip).load(bitwise_chip).finalize();
+    tester.simple_test().expect("Verification failed");
+}
+
+#[test]
+fn test_modular_exp() {
+    let modulus = secp256k1_coord_prime();
+    let mut rng = create_seeded_rng();
+    let exponents = vec![
+        BigUint::zero(),
+        BigUint::from(1u32),
+        BigUint::from(65537u32),
+        &modulus - BigUint::from(2u32),
+        BigUint::new((0..NUM_LIMBS / 4).map(|_| rng.gen::<u32>()).collect()),
+    ];
+    test_exp::<1, 32, 32>(modulus, 17, exponents);
+}
+
+#[test]
+fn test_modular_exp_8x32() {
+    // RSA-2048 sized modulus
+    let modulus = (BigUint::from(1u32) << 2048) - BigUint::from(159u32);
+    test_exp::<8, 32, 256>(modulus, 18, vec![BigUint::from(65537u32)]);
+}
+
+#[test]
+fn test_modular_exp_16x32() {
+    // RSA-4096 sized modulus
+    let modulus = (BigUint::from(1u32) << 4096) - BigUint::from(1u32);
+    test_exp::<16, 32, 512>(modulus, 19, vec![BigUint::from(65537u32)]);
+}
@@ -2,7 +2,9 @@ use std::sync::Arc;
 
 use derive_more::derive::From;
 use num_bigint_dig::BigUint;
-use openvm_algebra_transpiler::Rv32ModularArithmeticOpcode;
+use openvm_algebra_transpiler::{
+    ModularPhantom, Rv32ModularArithmeticOpcode, Rv32ModularExpOpcode,
+};
 use openvm_circuit::{
     self,
     arch::{SystemPort, VmExtension, VmInventory, VmInventoryBuilder, VmInventoryError},
@@ -13,17 +15,17 @@ use openvm_circuit_primitives::bitwise_op_lookup::{
     BitwiseOperationLookupBus, BitwiseOperationLookupChip,
 };
 use openvm_circuit_primitives_derive::{Chip, ChipUsageGetter};
-use openvm_instructions::{UsizeOpcode, VmOpcode};
+use openvm_instructions::{PhantomDiscriminant, UsizeOpcode, VmOpcode};
 use openvm_mod_circuit_builder::ExprBuilderConfig;
 use openvm_rv32_adapters::{Rv32IsEqualModAdapterChip, Rv32VecHeapAdapterChip};
-use openvm_stark_backend::p3_field::PrimeField32;
+use openvm_stark_backend::{p3_field::PrimeField32, p3_util::log2_ceil_usize};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 use strum::EnumCount;
 
 use crate::modular_chip::{
-    ModularAddSubChip, ModularAddSubCoreChip, ModularIsEqualChip, ModularIsEqualCoreChip,
-    ModularMulDivChip, ModularMulDivCoreChip,
+    ModularAddSubChip, ModularAddSubCoreChip, ModularExpChip, ModularIsEqualChip,
+    ModularIsEqualCoreChip, ModularMulDivChip, ModularMulDivCoreChip,
 };
 
 #[serde_as]
@@ -33,16 +35,51 @@ pub struct ModularExtension {
     pub supported_modulus: Vec<BigUint>,
 }
 
+impl ModularExtension {
+    /// Number of bits the range checker must support for the carries of the chips of the
+    /// supported moduli. A product of two elements with `n` byte limbs, minus `q * p`, has limbs
+    /// of absolute value below `2 * n * 2^16`, so the carries need about `log2(n) + 10` bits. This
+    /// is more than the default 17 bits for the 256 and 512 limb chips.
+    pub fn range_checker_bits(&self) -> usize {
+        self.supported_modulus
+            .iter()
+            .map(|modulus| {
+                let num_limbs = match modulus.bits().div_ceil(8) {
+                    0..=32 => 32,
+                    33..=48 => 48,
+                    49..=256 => 256,
+                    _ => 512,
+                };
+                let limb_max_abs = 2 * num_limbs * 255 * 255 + 255;
+                log2_ceil_usize(limb_max_abs) - 8 + 1
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
+
 #[derive(ChipUsageGetter, Chip, InstructionExecutor, AnyEnum, From)]
 pub enum ModularExtensionExecutor<F: PrimeField32> {
     // 32 limbs prime
     ModularAddSubRv32_32(ModularAddSubChip<F, 1, 32>),
     ModularMulDivRv32_32(ModularMulDivChip<F, 1, 32>),
     ModularIsEqualRv32_32(ModularIsEqualChip<F, 1, 32, 32>),
+    ModularExpRv32_32(ModularExpChip<F, 1, 32>),
     // 48 limbs prime
     ModularAddSubRv32_48(ModularAddSubChip<F, 3, 16>),
     ModularMulDivRv32_48(ModularMulDivChip<F, 3, 16>),
     ModularIsEqualRv32_48(ModularIsEqualChip<F, 3, 16, 48>),
+    ModularExpRv32_48(ModularExpChip<F, 3, 16>),
+    // 256 limbs modulus, e.g. RSA-2048
+    ModularAddSubRv32_256(ModularAddSubChip<F, 8, 32>),
+    ModularMulDivRv32_256(ModularMulDivChip<F, 8, 32>),
+    ModularIsEqualRv32_256(ModularIsEqualChip<F, 8, 32, 256>),
+    ModularExpRv32_256(ModularExpChip<F, 8, 32>),
+    // 512 limbs modulus, e.g. RSA-4096
+    ModularAddSubRv32_512(ModularAddSubChip<F, 16, 32>),
+    ModularMulDivRv32_512(ModularMulDivChip<F, 16, 32>),
+    ModularIsEqualRv32_512(ModularIsEqualChip<F, 16, 32, 512>),
+    ModularExpRv32_512(ModularExpChip<F, 16, 32>),
 }
 
 #[derive(ChipUsageGetter, Chip, AnyEnum, From)]
@@ -67,6 +104,12 @@ impl<F: PrimeField32> VmExtension<F> for ModularExtension {
             memory_controller,
         } = builder.system_port();
         let range_checker = builder.system_base().range_checker_chip.clone();
+        if range_checker.range_max_bits() < self.range_checker_bits() {
+            return Err(VmInventoryError::RangeCheckerTooSmall {
+                required: self.range_checker_bits(),
+                actual: range_checker.range_max_bits(),
+            });
+        }
         let bitwise_lu_chip = if let Some(chip) = builder
             .find_chip::<Arc<BitwiseOperationLookupChip<8>>>()
             .first()
@@ -86,65 +129,59 @@ impl<F: PrimeField32> VmExtension<F> for ModularExtension {
         let iseq_opcodes = (Rv32ModularArithmeticOpcode::IS_EQ as usize)
             ..=(Rv32ModularArithmeticOpcode::SETUP_ISEQ as usize);
 
-        for (i, modulus) in self.supported_modulus.iter().enumerate() {
-            // determine the number of bytes needed to represent a prime field element
-            let bytes = modulus.bits().div_ceil(8);
-            let class_offset = Rv32ModularArithmeticOpcode::default_offset()
-                + i * Rv32ModularArithmeticOpcode::COUNT;
-
-            let config32 = ExprBuilderConfig {
-                modulus: modulus.clone(),
-                num_limbs: 32,
-                limb_bits: 8,
-            };
-            let config48 = ExprBuilderConfig {
-                modulus: modulus.clone(),
-                num_limbs: 48,
-                limb_bits: 8,
-            };
-            let adapter_chip_32 = Rv32VecHeapAdapterChip::new(
-                execution_bus,
-                program_bus,
-                memory_controller.clone(),
-                bitwise_lu_chip.clone(),
-            );
-            let adapter_chip_48 = Rv32VecHeapAdapterChip::new(
-                execution_bus,
-                program_bus,
-                memory_controller.clone(),
-                bitwise_lu_chip.clone(),
-            );
-
-            if bytes <= 32 {
+        // Adds the chips of one modulus with `$num_limbs` limbs. The lane sizes of the adapter
+        // are inferred from the executor variants.
+        macro_rules! add_modular_chips {
+            (
+                $modulus:expr,
+                $class_offset:expr,
+                $exp_class_offset:expr,
+                $num_limbs:literal,
+                $addsub:ident,
+                $muldiv:ident,
+                $iseq:ident,
+                $exp:ident
+            ) => {{
+                let config = ExprBuilderConfig {
+                    modulus: $modulus.clone(),
+                    num_limbs: $num_limbs,
+                    limb_bits: 8,
+                };
+                let adapter_chip = Rv32VecHeapAdapterChip::new(
+                    execution_bus,
+                    program_bus,
+                    memory_controller.clone(),
+                    bitwise_lu_chip.clone(),
+                );
                 let addsub_chip = ModularAddSubChip::new(
-                    adapter_chip_32.clone(),
+                    adapter_chip.clone(),
                     ModularAddSubCoreChip::new(
-                        config32.clone(),
+                        config.clone(),
                         range_checker.clone(),
-                        class_offset,
+                        $class_offset,
                     ),
                     memory_controller.clone(),
                 );
                 inventory.add_executor(
-                    ModularExtensionExecutor::ModularAddSubRv32_32(addsub_chip),
+                    ModularExtensionExecutor::$addsub(addsub_chip),
                     addsub_opcodes
                         .clone()
-                        .map(|x| VmOpcode::from_usize(x + class_offset)),
+                        .map(|x| VmOpcode::from_usize(x + $class_offset)),
                 )?;
                 let muldiv_chip = ModularMulDivChip::new(
-                    adapter_chip_32.clone(),
+                    adapter_chip.clone(),
                     ModularMulDivCoreChip::new(
-                        config32.clone(),
+                        config.clone(),
                         range_checker.clone(),
-                        class_offset,
+                        $class_offset,
                     ),
                     memory_controller.clone(),
                 );
                 inventory.add_executor(
-                    ModularExtensionExecutor::ModularMulDivRv32_32(muldiv_chip),
+                    ModularExtensionExecutor::$muldiv(muldiv_chip),
                     muldiv_opcodes
                         .clone()
-                        .map(|x| VmOpcode::from_usize(x + class_offset)),
+                        .map(|x| VmOpcode::from_usize(x + $class_offset)),
                 )?;
                 let isequal_chip = ModularIsEqualChip::new(
                     Rv32IsEqualModAdapterChip::new(
@@ -154,74 +191,181 @@ impl<F: PrimeField32> VmExtension<F> for ModularExtension {
                         bitwise_lu_chip.clone(),
                     ),
                     ModularIsEqualCoreChip::new(
-                        modulus.clone(),
+                        $modulus.clone(),
                         bitwise_lu_chip.clone(),
-                        class_offset,
+                        $class_offset,
                     ),
                     memory_controller.clone(),
                 );
                 inventory.add_executor(
-                    ModularExtensionExecutor::ModularIsEqualRv32_32(isequal_chip),
+                    ModularExtensionExecutor::$iseq(isequal_chip),
                     iseq_opcodes
                         .clone()
-                        .map(|x| VmOpcode::from_usize(x + class_offset)),
+                        .map(|x| VmOpcode::from_usize(x + $class_offset)),
                 )?;
-            } else if bytes <= 48 {
-                let addsub_chip = ModularAddSubChip::new(
-                    adapter_chip_48.clone(),
-                    ModularAddSubCoreChip::new(
-                        config48.clone(),
-                        range_checker.clone(),
-                        class_offset,
-                    ),
+                let exp_chip = ModularExpChip::new(
+                    adapter_chip,
+                    config,
+                    range_checker.clone(),
+                    $exp_class_offset,
                     memory_controller.clone(),
                 );
                 inventory.add_executor(
-                    ModularExtensionExecutor::ModularAddSubRv32_48(addsub_chip),
-                    addsub_opcodes
-                        .clone()
-                        .map(|x| VmOpcode::from_usize(x + class_offset)),
+                    ModularExtensionExecutor::$exp(exp_chip),
+                    [VmOpcode::from_usize(
+                        Rv32ModularExpOpcode::EXP as usize + $exp_class_offset,
+                    )],
                 )?;
-                let muldiv_chip = ModularMulDivChip::new(
-                    adapter_chip_48.clone(),
-                    ModularMulDivCoreChip::new(
-                        config48.clone(),
-                        range_checker.clone(),
-                        class_offset,
-                    ),
-                    memory_controller.clone(),
+            }};
+        }
+
+        for (i, modulus) in self.supported_modulus.iter().enumerate() {
+            // determine the number of bytes needed to represent a prime field element
+            let bytes = modulus.bits().div_ceil(8);
+            let class_offset = Rv32ModularArithmeticOpcode::default_offset()
+                + i * Rv32ModularArithmeticOpcode::COUNT;
+            let exp_class_offset =
+                Rv32ModularExpOpcode::default_offset() + i * Rv32ModularExpOpcode::COUNT;
+
+            if bytes <= 32 {
+                add_modular_chips!(
+                    modulus,
+                    class_offset,
+                    exp_class_offset,
+                    32,
+                    ModularAddSubRv32_32,
+                    ModularMulDivRv32_32,
+                    ModularIsEqualRv32_32,
+                    ModularExpRv32_32
                 );
-                inventory.add_executor(
-                    ModularExtensionExecutor::ModularMulDivRv32_48(muldiv_chip),
-                    muldiv_opcodes
-                        .clone()
-                        .map(|x| VmOpcode::from_usize(x + class_offset)),
-                )?;
-                let isequal_chip = ModularIsEqualChip::new(
-                    Rv32IsEqualModAdapterChip::new(
-                        execution_bus,
-                        program_bus,
-                        memory_controller.clone(),
-                        bitwise_lu_chip.clone(),
-                    ),
-                    ModularIsEqualCoreChip::new(
-                        modulus.clone(),
-                        bitwise_lu_chip.clone(),
-                        class_offset,
-                    ),
-                    memory_controller.clone(),
+            } else if bytes <= 48 {
+                add_modular_chips!(
+                    modulus,
+                    class_offset,
+                    exp_class_offset,
+                    48,
+                    ModularAddSubRv32_48,
+                    ModularMulDivRv32_48,
+                    ModularIsEqualRv32_48,
+                    ModularExpRv32_48
+                );
+            } else if bytes <= 256 {
+                add_modular_chips!(
+                    modulus,
+                    class_offset,
+                    exp_class_offset,
+                    256,
+                    ModularAddSubRv32_256,
+                    ModularMulDivRv32_256,
+                    ModularIsEqualRv32_256,
+                    ModularExpRv32_256
+                );
+            } else if bytes <= 512 {
+                add_modular_chips!(
+                    modulus,
+                    class_offset,
+                    exp_class_offset,
+                    512,
+                    ModularAddSubRv32_512,
+                    ModularMulDivRv32_512,
+                    ModularIsEqualRv32_512,
+                    ModularExpRv32_512
                 );
-                inventory.add_executor(
-                    ModularExtensionExecutor::ModularIsEqualRv32_48(isequal_chip),
-                    iseq_opcodes
-                        .clone()
-                        .map(|x| VmOpcode::from_usize(x + class_offset)),
-                )?;
             } else {
                 panic!("Modulus too large");
             }
         }
+        builder.add_phantom_sub_executor(
+            phantom::SqrtHintSubEx::new(self.supported_modulus.clone()),
+            PhantomDiscriminant(ModularPhantom::HintSqrt as u16),
+        )?;
 
         Ok(inventory)
     }
 }
+
+pub(crate) mod phantom {
+    use std::iter::{once, repeat};
+
+    use eyre::bail;
+    use num_bigint_dig::BigUint;
+    use openvm::utils::uint_mod_sqrt;
+    use openvm_circuit::{
+        arch::{PhantomSubExecutor, Streams},
+        system::memory::MemoryController,
+    };
+    use openvm_instructions::{riscv::RV32_MEMORY_AS, PhantomDiscriminant};
+    use openvm_rv32im_circuit::adapters::unsafe_read_rv32_register;
+    use openvm_stark_backend::p3_field::PrimeField32;
+
+    /// Hints whether `x` is a square modulo the `c_upper`-th modulus. The hint is one word which is
+    /// 1 if it is and 0 otherwise, followed by a square root of `x` in the first case and of
+    /// `x * non_qr` in the second, where `non_qr` is a quadratic non-residue chosen by the guest.
+    #[derive(derive_new::new)]
+    pub struct SqrtHintSubEx {
+        pub supported_modulus: Vec<BigUint>,
+    }
+
+    impl<F: PrimeField32> PhantomSubExecutor<F> for SqrtHintSubEx {
+        fn phantom_execute(
+            &mut self,
+            memory: &MemoryController<F>,
+            streams: &mut Streams<F>,
+            _: PhantomDiscriminant,
+            a: F,
+            b: F,
+            c_upper: u16,
+        ) -> eyre::Result<()> {
+            let mod_idx = c_upper as usize;
+            if mod_idx >= self.supported_modulus.len() {
+                bail!(
+                    "Modulus index {mod_idx} out of range: {} supported moduli",
+                    self.supported_modulus.len()
+                );
+            }
+            let modulus = &self.supported_modulus[mod_idx];
+            // TODO: Better support for different limb sizes
+            let num_limbs: usize = if modulus.bits().div_ceil(8) <= 32 {
+                32
+            } else if modulus.bits().div_ceil(8) <= 48 {
+                48
+            } else {
+                bail!("Modulus too large")
+            };
+            let read_biguint = |ptr: u32| {
+                let limbs: Vec<u8> = (0..num_limbs as u32)
+                    .map(|i| {
+                        memory
+                            .unsafe_read_cell(
+                                F::from_canonical_u32(RV32_MEMORY_AS),
+                                F::from_canonical_u32(ptr + i),
+                            )
+                            .as_canonical_u32() as u8
+                    })
+                    .collect();
+                BigUint::from_bytes_le(&limbs)
+            };
+            let x = read_biguint(unsafe_read_rv32_register(memory, a));
+            let non_qr = read_biguint(unsafe_read_rv32_register(memory, b));
+
+            let (is_square, root) = match uint_mod_sqrt(&x, modulus, &non_qr) {
+                Some(root) => (true, root),
+                None => match uint_mod_sqrt(&(x * &non_qr), modulus, &non_qr) {
+                    Some(root) => (false, root),
+                    None => bail!("{non_qr} is not a quadratic non-residue modulo {modulus}"),
+                },
+            };
+            streams.hint_stream = once(is_square as u8)
+                .chain(repeat(0).take(3))
+                .chain(
+                    root.to_bytes_le()
+                        .into_iter()
+                        .chain(repeat(0))
+                        .take(num_limbs),
+                )
+                .map(F::from_canonical_u8)
+                .collect();
+            Ok(())
+        }
+    }
+}
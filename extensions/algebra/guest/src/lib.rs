@@ -17,6 +17,8 @@ pub enum ModArithBaseFunct7 {
     DivMod,
     IsEqMod,
     SetupMod,
+    HintSqrt,
+    ExpMod,
 }
 
 impl ModArithBaseFunct7 {
@@ -68,6 +70,9 @@ mod halo2curves;
 mod exp_bytes;
 pub use exp_bytes::*;
 
+/// RSA signature verification
+pub mod rsa;
+
 /// Division operation that is undefined behavior when the denominator is not invertible.
 pub trait DivUnsafe<Rhs = Self>: Sized {
     /// Output type of `div_unsafe`.
@@ -83,6 +88,16 @@ pub trait DivAssignUnsafe<Rhs = Self>: Sized {
     fn div_assign_unsafe(&mut self, other: Rhs);
 }
 
+/// Square root in a prime field.
+///
+/// For types declared with `moduli_declare!`, the root is hinted by the host and checked by the
+/// guest with a single modular multiplication. If `self` is not a square, the host instead hints
+/// a root of `self` times a fixed non-residue, which proves that `self` has no root.
+pub trait Sqrt: Sized {
+    /// Returns a square root of `self`, or `None` if `self` is not a quadratic residue.
+    fn sqrt(&self) -> Option<Self>;
+}
+
 /// Trait definition for OpenVM modular integers, where each operation
 /// is done modulo MODULUS.
 ///
@@ -162,6 +177,9 @@ pub trait IntMod:
     /// Value of this IntMod as an array of bytes, big endian.
     fn to_be_bytes(&self) -> Self::Repr;
 
+    /// Raises this IntMod to the power `exp`, given as `NUM_LIMBS` bytes in little endian.
+    fn pow(&self, exp: &Self::Repr) -> Self;
+
     /// Modulus N as a BigUint.
     #[cfg(not(target_os = "zkvm"))]
     fn modulus_biguint() -> BigUint;
@@ -0,0 +1,191 @@
+//! RSA signature verification as specified in
+//! [RFC 8017](https://datatracker.ietf.org/doc/html/rfc8017), with the PKCS#1 v1.5 and PSS
+//! signature schemes.
+//!
+//! The public modulus is a type declared with `moduli_declare!`, so it is fixed at compile time.
+//! Moduli of 2048 and 4096 bits use the chips with 256 and 512 limbs. Their carries need a range
+//! checker with 18 and 19 bits, see `ModularExtension::range_checker_bits`.
+
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+use crate::IntMod;
+
+/// Hash function used to encode the message of an RSA signature.
+pub trait RsaHash {
+    /// DER encoding of the `DigestInfo` of PKCS#1 v1.5 signatures, up to the digest.
+    const DIGEST_INFO_PREFIX: &'static [u8];
+
+    /// Hashes `input`.
+    fn hash(input: &[u8]) -> Vec<u8>;
+}
+
+/// SHA-256, computed with the sha256 intrinsic.
+#[cfg(feature = "sha256")]
+#[derive(Clone, Copy, Debug)]
+pub struct Sha256;
+
+#[cfg(feature = "sha256")]
+impl RsaHash for Sha256 {
+    const DIGEST_INFO_PREFIX: &'static [u8] = &[
+        0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01,
+        0x05, 0x00, 0x04, 0x20,
+    ];
+
+    fn hash(input: &[u8]) -> Vec<u8> {
+        openvm_sha256_guest::sha256(input).to_vec()
+    }
+}
+
+/// Keccak-256, computed with the keccak256 intrinsic.
+///
+/// Keccak-256 has no registered algorithm identifier, so PKCS#1 v1.5 signatures embed the bare
+/// digest without a `DigestInfo`.
+#[cfg(feature = "keccak")]
+#[derive(Clone, Copy, Debug)]
+pub struct Keccak256;
+
+#[cfg(feature = "keccak")]
+impl RsaHash for Keccak256 {
+    const DIGEST_INFO_PREFIX: &'static [u8] = &[];
+
+    fn hash(input: &[u8]) -> Vec<u8> {
+        openvm_keccak256_guest::keccak256(input).to_vec()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RsaError {
+    /// The signature is not as long as the modulus, or it is not smaller than the modulus.
+    InvalidSignature,
+    /// The signature does not match the message.
+    VerificationFailed,
+}
+
+/// Verifies a PKCS#1 v1.5 signature (RSASSA-PKCS1-v1_5) of `message` for the public key with
+/// modulus `N` and public exponent `exponent`. The signature is in big endian.
+pub fn verify_pkcs1v15<N: IntMod, H: RsaHash>(
+    exponent: u32,
+    message: &[u8],
+    signature: &[u8],
+) -> Result<(), RsaError> {
+    let k = modulus_bits::<N>().div_ceil(8);
+    let em = rsavp1::<N>(exponent, signature)?;
+
+    let digest = H::hash(message);
+    let t_len = H::DIGEST_INFO_PREFIX.len() + digest.len();
+    if k < t_len + 11 {
+        return Err(RsaError::VerificationFailed);
+    }
+    let mut expected = Vec::with_capacity(k);
+    expected.extend([0x00, 0x01]);
+    expected.resize(k - t_len - 1, 0xff);
+    expected.push(0x00);
+    expected.extend_from_slice(H::DIGEST_INFO_PREFIX);
+    expected.extend(digest);
+
+    if em == expected {
+        Ok(())
+    } else {
+        Err(RsaError::VerificationFailed)
+    }
+}
+
+/// Verifies a PSS signature (RSASSA-PSS) of `message` for the public key with modulus `N` and
+/// public exponent `exponent`. The signature is in big endian. `H` is used both to hash the
+/// message and in the MGF1 mask generation function, and the salt must be `salt_len` bytes long.
+pub fn verify_pss<N: IntMod, H: RsaHash>(
+    exponent: u32,
+    message: &[u8],
+    signature: &[u8],
+    salt_len: usize,
+) -> Result<(), RsaError> {
+    let em_bits = modulus_bits::<N>() - 1;
+    let em_len = em_bits.div_ceil(8);
+    let m = rsavp1::<N>(exponent, signature)?;
+    // The encoded message is one byte shorter than the modulus if the modulus has 8k + 1 bits.
+    let (leading, em) = m.split_at(m.len() - em_len);
+    if leading.iter().any(|&b| b != 0) {
+        return Err(RsaError::VerificationFailed);
+    }
+
+    let m_hash = H::hash(message);
+    let h_len = m_hash.len();
+    if em_len < h_len + salt_len + 2 || em[em_len - 1] != 0xbc {
+        return Err(RsaError::VerificationFailed);
+    }
+    let (masked_db, h) = em[..em_len - 1].split_at(em_len - h_len - 1);
+    let top_mask = 0xff >> (8 * em_len - em_bits);
+    if masked_db[0] & !top_mask != 0 {
+        return Err(RsaError::VerificationFailed);
+    }
+
+    let mut db = mgf1::<H>(h, masked_db.len());
+    for (d, m) in db.iter_mut().zip(masked_db) {
+        *d ^= m;
+    }
+    db[0] &= top_mask;
+    let ps_len = em_len - h_len - salt_len - 2;
+    if db[..ps_len].iter().any(|&b| b != 0) || db[ps_len] != 0x01 {
+        return Err(RsaError::VerificationFailed);
+    }
+    let salt = &db[db.len() - salt_len..];
+
+    let mut m_prime = vec![0u8; 8];
+    m_prime.extend(m_hash);
+    m_prime.extend_from_slice(salt);
+    if H::hash(&m_prime) == h {
+        Ok(())
+    } else {
+        Err(RsaError::VerificationFailed)
+    }
+}
+
+/// Number of bits of the modulus of `N`.
+fn modulus_bits<N: IntMod>() -> usize {
+    let modulus = N::MODULUS;
+    let bytes = modulus.as_ref();
+    let top = bytes.iter().rposition(|&b| b != 0).unwrap();
+    top * 8 + (8 - bytes[top].leading_zeros() as usize)
+}
+
+/// The RSA verification primitive: returns `signature^exponent mod N` as big endian bytes, as
+/// many as the modulus has.
+fn rsavp1<N: IntMod>(exponent: u32, signature: &[u8]) -> Result<Vec<u8>, RsaError> {
+    let k = modulus_bits::<N>().div_ceil(8);
+    if signature.len() != k {
+        return Err(RsaError::InvalidSignature);
+    }
+    let modulus = N::MODULUS;
+    let modulus_be = modulus.as_ref()[..k].iter().rev();
+    if signature.iter().cmp(modulus_be) != Ordering::Less {
+        return Err(RsaError::InvalidSignature);
+    }
+
+    let s = N::from_be_bytes(signature);
+    let mut m = N::ONE;
+    for i in (0..u32::BITS - exponent.leading_zeros()).rev() {
+        m.square_assign();
+        if (exponent >> i) & 1 == 1 {
+            m *= &s;
+        }
+    }
+    // The encoding is compared byte by byte, so `m` has to be reduced.
+    m.assert_unique();
+    Ok(m.to_be_bytes().as_ref()[N::NUM_LIMBS - k..].to_vec())
+}
+
+/// The MGF1 mask generation function with hash `H`.
+fn mgf1<H: RsaHash>(seed: &[u8], len: usize) -> Vec<u8> {
+    let mut input = seed.to_vec();
+    input.extend([0; 4]);
+    let mut mask = Vec::with_capacity(len);
+    let mut counter = 0u32;
+    while mask.len() < len {
+        input[seed.len()..].copy_from_slice(&counter.to_be_bytes());
+        mask.extend(H::hash(&input));
+        counter += 1;
+    }
+    mask.truncate(len);
+    mask
+}
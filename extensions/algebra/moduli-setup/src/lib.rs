@@ -4,6 +4,8 @@ extern crate proc_macro;
 
 use std::sync::atomic::AtomicUsize;
 
+use num_bigint_dig::BigUint;
+use num_traits::{One, Zero};
 use openvm_macros_common::{string_to_bytes, MacroArgs};
 use proc_macro::TokenStream;
 use quote::format_ident;
@@ -23,6 +25,9 @@ static MOD_IDX: AtomicUsize = AtomicUsize::new(0);
 /// }
 /// ```
 /// This creates two structs, `Bls12381` and `Bn254`, each representing the modular arithmetic class (implementing `Add`, `Sub` and so on).
+/// If the modulus is an odd prime of at most 48 bytes, the struct also implements
+/// `openvm_algebra_guest::Sqrt`. Primality is checked with the Miller-Rabin test, since `sqrt`
+/// returning `None` proves nothing for a composite modulus.
 #[proc_macro]
 pub fn moduli_declare(input: TokenStream) -> TokenStream {
     let MacroArgs { items } = parse_macro_input!(input as MacroArgs);
@@ -69,9 +74,7 @@ pub fn moduli_declare(input: TokenStream) -> TokenStream {
             proc_macro::Diagnostic::new(proc_macro::Level::Warning, "`limbs` has been set to 32 because it was too small; this is going to be changed once we support more flexible reads").emit();
         }
 
-        // The largest power of two so that at most 10% of all space is wasted
-        let block_size = 1usize << ((limbs - 1) ^ (limbs + limbs / 9)).ilog2();
-        let limbs = limbs.next_multiple_of(block_size);
+        let (limbs, block_size) = limbs_and_block_size(limbs);
         let modulus_bytes = modulus_bytes
             .into_iter()
             .chain(vec![0u8; limbs])
@@ -98,6 +101,85 @@ pub fn moduli_declare(input: TokenStream) -> TokenStream {
         create_extern_func!(mul_extern_func);
         create_extern_func!(div_extern_func);
         create_extern_func!(is_eq_extern_func);
+        create_extern_func!(exp_extern_func);
+        create_extern_func!(hint_sqrt_extern_func);
+
+        let sqrt_impl = find_non_qr(&BigUint::from_bytes_le(&modulus_bytes)).map(|non_qr| {
+            quote::quote_spanned! { span.into() =>
+                extern "C" {
+                    fn #hint_sqrt_extern_func(rs1: usize, rs2: usize);
+                }
+
+                impl #struct_name {
+                    /// The smallest quadratic non-residue.
+                    const NON_QR: Self = Self::from_const_u8(#non_qr);
+
+                    /// Returns whether `self` is a square, and a square root of `self` if it is or
+                    /// of `self * Self::NON_QR` if it is not. The result is not checked.
+                    #[inline(always)]
+                    fn hint_sqrt_impl(&self) -> (bool, Self) {
+                        #[cfg(not(target_os = "zkvm"))]
+                        {
+                            let modulus = Self::modulus_biguint();
+                            let non_qr = Self::NON_QR.as_biguint();
+                            match openvm::utils::uint_mod_sqrt(&self.as_biguint(), &modulus, &non_qr) {
+                                Some(root) => (true, Self::from_biguint(root)),
+                                None => {
+                                    let root = openvm::utils::uint_mod_sqrt(
+                                        &(self.as_biguint() * &non_qr),
+                                        &modulus,
+                                        &non_qr,
+                                    )
+                                    .expect("modulus is not prime");
+                                    (false, Self::from_biguint(root))
+                                }
+                            }
+                        }
+                        #[cfg(target_os = "zkvm")]
+                        {
+                            let is_square = core::mem::MaybeUninit::<u32>::uninit();
+                            let root = core::mem::MaybeUninit::<#struct_name>::uninit();
+                            unsafe {
+                                #hint_sqrt_extern_func(
+                                    self as *const #struct_name as usize,
+                                    &Self::NON_QR as *const #struct_name as usize,
+                                );
+                                let words = core::iter::once(is_square.as_ptr() as *const u8).chain(
+                                    (0..#limbs)
+                                        .step_by(4)
+                                        .map(|i| (root.as_ptr() as *const u8).add(i)),
+                                );
+                                for ptr in words {
+                                    core::arch::asm!(
+                                        ".insn i {opcode}, {funct3}, {rd}, x0, 0",
+                                        opcode = const openvm::SYSTEM_OPCODE,
+                                        funct3 = const openvm::HINT_STORE_W_FUNCT3,
+                                        rd = in(reg) ptr,
+                                    );
+                                }
+                                (is_square.assume_init() != 0, root.assume_init())
+                            }
+                        }
+                    }
+                }
+
+                impl openvm_algebra_guest::Sqrt for #struct_name {
+                    fn sqrt(&self) -> Option<Self> {
+                        let (is_square, root) = self.hint_sqrt_impl();
+                        let square = &root * &root;
+                        if is_square {
+                            assert_eq!(square, *self, "invalid square root hint");
+                            Some(root)
+                        } else {
+                            assert_eq!(square, self * &Self::NON_QR, "invalid non-residue hint");
+                            // Zero is a square, but `0 * NON_QR = 0^2` would pass the check above.
+                            assert_ne!(root, <Self as openvm_algebra_guest::IntMod>::ZERO, "invalid non-residue hint");
+                            None
+                        }
+                    }
+                }
+            }
+        });
 
         let block_size = proc_macro::Literal::usize_unsuffixed(block_size);
         let block_size = syn::Lit::new(block_size.to_string().parse::<_>().unwrap());
@@ -115,6 +197,7 @@ pub fn moduli_declare(input: TokenStream) -> TokenStream {
                 fn #mul_extern_func(rd: usize, rs1: usize, rs2: usize);
                 fn #div_extern_func(rd: usize, rs1: usize, rs2: usize);
                 fn #is_eq_extern_func(rs1: usize, rs2: usize) -> bool;
+                fn #exp_extern_func(rd: usize, rs1: usize, rs2: usize);
             }
 
             impl #struct_name {
@@ -304,6 +387,30 @@ pub fn moduli_declare(input: TokenStream) -> TokenStream {
                     }
                 }
 
+                #[inline(always)]
+                fn pow_impl(&self, exp: &[u8; #limbs]) -> Self {
+                    #[cfg(not(target_os = "zkvm"))]
+                    {
+                        let modulus = Self::modulus_biguint();
+                        let exp = num_bigint_dig::BigUint::from_bytes_le(exp);
+                        Self::from_biguint(self.as_biguint().modpow(&exp, &modulus))
+                    }
+                    #[cfg(target_os = "zkvm")]
+                    {
+                        // Copy the exponent so that it is aligned like `Self`.
+                        let exp = Self(*exp);
+                        let mut uninit: core::mem::MaybeUninit<#struct_name> = core::mem::MaybeUninit::uninit();
+                        unsafe {
+                            #exp_extern_func(
+                                uninit.as_mut_ptr() as usize,
+                                self as *const #struct_name as usize,
+                                &exp as *const #struct_name as usize,
+                            );
+                        }
+                        unsafe { uninit.assume_init() }
+                    }
+                }
+
                 #[inline(always)]
                 fn eq_impl(&self, other: &Self) -> bool {
                     #[cfg(not(target_os = "zkvm"))]
@@ -379,6 +486,10 @@ pub fn moduli_declare(input: TokenStream) -> TokenStream {
                         core::array::from_fn(|i| self.0[#limbs - 1 - i])
                     }
 
+                    fn pow(&self, exp: &[u8; #limbs]) -> Self {
+                        self.pow_impl(exp)
+                    }
+
                     #[cfg(not(target_os = "zkvm"))]
                     fn modulus_biguint() -> num_bigint_dig::BigUint {
                         num_bigint_dig::BigUint::from_bytes_le(&Self::MODULUS)
@@ -431,6 +542,8 @@ pub fn moduli_declare(input: TokenStream) -> TokenStream {
                     }
                 }
 
+                #sqrt_impl
+
                 impl<'a> core::ops::AddAssign<&'a #struct_name> for #struct_name {
                     #[inline(always)]
                     fn add_assign(&mut self, other: &'a #struct_name) {
@@ -680,6 +793,67 @@ pub fn moduli_declare(input: TokenStream) -> TokenStream {
     TokenStream::from_iter(output)
 }
 
+/// Returns the number of limbs and the alignment of a modular integer with `num_bytes` bytes.
+fn limbs_and_block_size(num_bytes: usize) -> (usize, usize) {
+    if num_bytes > 48 {
+        // Wide moduli such as RSA moduli are supported by chips with 256 or 512 limbs, read in
+        // blocks of 32 bytes
+        (num_bytes.next_multiple_of(256), 32)
+    } else {
+        // The largest power of two so that at most 10% of all space is wasted
+        let block_size = 1usize << ((num_bytes - 1) ^ (num_bytes + num_bytes / 9)).ilog2();
+        (num_bytes.next_multiple_of(block_size), block_size)
+    }
+}
+
+/// Returns the smallest quadratic non-residue modulo `modulus` if `modulus` is an odd prime and
+/// the non-residue fits in a byte. The square root hint only supports moduli of at most 48 bytes,
+/// which also excludes composite moduli such as RSA moduli.
+fn find_non_qr(modulus: &BigUint) -> Option<u8> {
+    if modulus <= &BigUint::from(2u8)
+        || (modulus & BigUint::one()) != BigUint::one()
+        || modulus.bits() > 48 * 8
+        || !is_probable_prime(modulus)
+    {
+        return None;
+    }
+    let minus_one = modulus - BigUint::one();
+    let exponent = &minus_one >> 1;
+    (2..=u8::MAX).find(|&n| BigUint::from(n).modpow(&exponent, modulus) == minus_one)
+}
+
+/// Miller-Rabin test of the odd number `n > 2` with the prime bases below 256. A composite `n`
+/// only passes if it is a strong pseudoprime to all of them.
+fn is_probable_prime(n: &BigUint) -> bool {
+    let minus_one = n - BigUint::one();
+    // n - 1 = d * 2^s with d odd
+    let mut d = minus_one.clone();
+    let mut s = 0;
+    while (&d & BigUint::one()).is_zero() {
+        d >>= 1;
+        s += 1;
+    }
+    (2..=u8::MAX)
+        .filter(|&b| (2..b).all(|p| b % p != 0))
+        .all(|b| {
+            let b = BigUint::from(b) % n;
+            if b.is_zero() {
+                return true;
+            }
+            let mut x = b.modpow(&d, n);
+            if x.is_one() || x == minus_one {
+                return true;
+            }
+            for _ in 1..s {
+                x = &x * &x % n;
+                if x == minus_one {
+                    return true;
+                }
+            }
+            false
+        })
+}
+
 struct ModuliDefine {
     items: Vec<LitStr>,
 }
@@ -722,9 +896,7 @@ pub fn moduli_init(input: TokenStream) -> TokenStream {
             proc_macro::Diagnostic::new(proc_macro::Level::Warning, "`limbs` has been set to 32 because it was too small; this is going to be changed once we support more flexible reads").emit();
         }
 
-        // The largest power of two so that at most 10% of all space is wasted
-        let block_size = 1usize << ((limbs - 1) ^ (limbs + limbs / 9)).ilog2();
-        let limbs = limbs.next_multiple_of(block_size);
+        let (limbs, _) = limbs_and_block_size(limbs);
         let modulus_bytes = modulus_bytes
             .into_iter()
             .chain(vec![0u8; limbs])
@@ -764,7 +936,7 @@ pub fn moduli_init(input: TokenStream) -> TokenStream {
             static #serialized_name: [u8; #serialized_len] = [#(#serialized_modulus),*];
         });
 
-        for op_type in ["add", "sub", "mul", "div"] {
+        for op_type in ["add", "sub", "mul", "div", "exp"] {
             let func_name = syn::Ident::new(
                 &format!("{}_extern_func_{}", op_type, modulus_hex),
                 span.into(),
@@ -811,6 +983,26 @@ pub fn moduli_init(input: TokenStream) -> TokenStream {
             }
         });
 
+        let hint_sqrt_extern_func = syn::Ident::new(
+            &format!("hint_sqrt_extern_func_{}", modulus_hex),
+            span.into(),
+        );
+        externs.push(quote::quote_spanned! { span.into() =>
+            #[no_mangle]
+            extern "C" fn #hint_sqrt_extern_func(rs1: usize, rs2: usize) {
+                unsafe {
+                    core::arch::asm!(
+                        ".insn r {opcode}, {funct3}, {funct7}, x0, {rs1}, {rs2}",
+                        opcode = const ::openvm_algebra_guest::OPCODE,
+                        funct3 = const ::openvm_algebra_guest::MODULAR_ARITHMETIC_FUNCT3 as usize,
+                        funct7 = const ::openvm_algebra_guest::ModArithBaseFunct7::HintSqrt as usize + #mod_idx * (::openvm_algebra_guest::ModArithBaseFunct7::MODULAR_ARITHMETIC_MAX_KINDS as usize),
+                        rs1 = in(reg) rs1,
+                        rs2 = in(reg) rs2
+                    );
+                }
+            }
+        });
+
         setup_all_moduli.push(quote::quote_spanned! { span.into() =>
             #setup_function();
         });
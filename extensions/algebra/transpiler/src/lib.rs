@@ -3,7 +3,8 @@ use openvm_algebra_guest::{
     MODULAR_ARITHMETIC_FUNCT3, OPCODE,
 };
 use openvm_instructions::{
-    instruction::Instruction, riscv::RV32_REGISTER_NUM_LIMBS, UsizeOpcode, VmOpcode,
+    instruction::Instruction, riscv::RV32_REGISTER_NUM_LIMBS, PhantomDiscriminant, UsizeOpcode,
+    VmOpcode,
 };
 use openvm_instructions_derive::UsizeOpcode;
 use openvm_stark_backend::p3_field::PrimeField32;
@@ -28,6 +29,22 @@ pub enum Rv32ModularArithmeticOpcode {
     SETUP_ISEQ,
 }
 
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, EnumCount, EnumIter, FromRepr, UsizeOpcode,
+)]
+#[opcode_offset = 0x580]
+#[repr(usize)]
+#[allow(non_camel_case_types)]
+pub enum Rv32ModularExpOpcode {
+    EXP,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, FromRepr)]
+#[repr(u16)]
+pub enum ModularPhantom {
+    HintSqrt = 0x50,
+}
+
 #[derive(
     Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, EnumCount, EnumIter, FromRepr, UsizeOpcode,
 )]
@@ -73,10 +90,27 @@ impl<F: PrimeField32> TranspilerExtension<F> for ModularTranspilerExtension {
                 Rv32ModularArithmeticOpcode::COUNT
                     <= ModArithBaseFunct7::MODULAR_ARITHMETIC_MAX_KINDS as usize
             );
-            let mod_idx_shift = ((dec_insn.funct7 as u8)
+            let mod_idx = ((dec_insn.funct7 as u8)
                 / ModArithBaseFunct7::MODULAR_ARITHMETIC_MAX_KINDS)
-                as usize
-                * Rv32ModularArithmeticOpcode::COUNT;
+                as usize;
+            let mod_idx_shift = mod_idx * Rv32ModularArithmeticOpcode::COUNT;
+            if let Some(ModArithBaseFunct7::HintSqrt) = ModArithBaseFunct7::from_repr(base_funct7) {
+                assert_eq!(dec_insn.rd, 0);
+                return Some((
+                    Instruction::phantom(
+                        PhantomDiscriminant(ModularPhantom::HintSqrt as u16),
+                        F::from_canonical_usize(RV32_REGISTER_NUM_LIMBS * dec_insn.rs1),
+                        F::from_canonical_usize(RV32_REGISTER_NUM_LIMBS * dec_insn.rs2),
+                        mod_idx as u16,
+                    ),
+                    1,
+                ));
+            }
+            if let Some(ModArithBaseFunct7::ExpMod) = ModArithBaseFunct7::from_repr(base_funct7) {
+                let global_opcode = Rv32ModularExpOpcode::EXP.with_default_offset()
+                    + mod_idx * Rv32ModularExpOpcode::COUNT;
+                return Some((from_r_type(global_opcode, 2, &dec_insn), 1));
+            }
             if base_funct7 == ModArithBaseFunct7::SetupMod as u8 {
                 let local_opcode = match dec_insn.rs2 {
                     0 => Rv32ModularArithmeticOpcode::SETUP_ADDSUB,
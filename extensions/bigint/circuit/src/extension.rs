@@ -3,7 +3,8 @@ use std::sync::Arc;
 use derive_more::derive::From;
 use openvm_bigint_transpiler::{
     Rv32BaseAlu256Opcode, Rv32BranchEqual256Opcode, Rv32BranchLessThan256Opcode,
-    Rv32LessThan256Opcode, Rv32Mul256Opcode, Rv32Shift256Opcode,
+    Rv32DivRem256Opcode, Rv32LessThan256Opcode, Rv32ModArith256Opcode, Rv32Mul256Opcode,
+    Rv32Shift256Opcode,
 };
 use openvm_circuit::{
     arch::{
@@ -25,6 +26,7 @@ use openvm_rv32im_circuit::{
 };
 use openvm_stark_backend::p3_field::PrimeField32;
 use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
 
 use crate::*;
 
@@ -56,6 +58,9 @@ impl Default for Int256Rv32Config {
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct Int256 {
+    /// Sizes of the range tuple checker shared by multiplication, division and modular
+    /// arithmetic. Division and modular arithmetic need the second size to be at least
+    /// `2 * 32 * 2^8`.
     #[serde(default = "default_range_tuple_checker_sizes")]
     pub range_tuple_checker_sizes: [u32; 2],
 }
@@ -69,7 +74,11 @@ impl Default for Int256 {
 }
 
 fn default_range_tuple_checker_sizes() -> [u32; 2] {
-    [1 << 8, 32 * (1 << 8)]
+    // The carries of division and modular arithmetic are twice as large as those of
+    // multiplication, which doubles the checker from 2^21 to 2^22 rows. The checker has a constant
+    // height and is proven even when unused, so a separate checker for division would cost
+    // 2^21 + 2^22 rows instead: sharing one is cheaper, even for programs that never divide.
+    [1 << 8, 2 * 32 * (1 << 8)]
 }
 
 #[derive(ChipUsageGetter, Chip, InstructionExecutor, From, AnyEnum)]
@@ -79,13 +88,15 @@ pub enum Int256Executor<F: PrimeField32> {
     BranchEqual256(Rv32BranchEqual256Chip<F>),
     BranchLessThan256(Rv32BranchLessThan256Chip<F>),
     Multiplication256(Rv32Multiplication256Chip<F>),
+    DivRem256(Rv32DivRem256Chip<F>),
+    ModArith256(Rv32ModArith256Chip<F>),
     Shift256(Rv32Shift256Chip<F>),
 }
 
 #[derive(From, ChipUsageGetter, Chip, AnyEnum)]
 pub enum Int256Periphery<F: PrimeField32> {
     BitwiseOperationLookup(Arc<BitwiseOperationLookupChip<8>>),
-    /// Only needed for multiplication extension
+    /// Only needed for multiplication, division and modular arithmetic
     RangeTupleChecker(Arc<RangeTupleCheckerChip<2>>),
     Phantom(PhantomChip<F>),
 }
@@ -209,7 +220,10 @@ impl<F: PrimeField32> VmExtension<F> for Int256 {
                 memory_controller.clone(),
                 bitwise_lu_chip.clone(),
             ),
-            MultiplicationCoreChip::new(range_tuple_chip, Rv32Mul256Opcode::default_offset()),
+            MultiplicationCoreChip::new(
+                range_tuple_chip.clone(),
+                Rv32Mul256Opcode::default_offset(),
+            ),
             memory_controller.clone(),
         );
         inventory.add_executor(
@@ -217,6 +231,44 @@ impl<F: PrimeField32> VmExtension<F> for Int256 {
             Rv32Mul256Opcode::iter().map(VmOpcode::with_default_offset),
         )?;
 
+        let divrem_chip = Rv32DivRem256Chip::new(
+            Rv32HeapAdapterChip::new(
+                execution_bus,
+                program_bus,
+                memory_controller.clone(),
+                bitwise_lu_chip.clone(),
+            ),
+            DivRemCoreChip::new(
+                bitwise_lu_chip.clone(),
+                range_tuple_chip.clone(),
+                Rv32DivRem256Opcode::default_offset(),
+            ),
+            memory_controller.clone(),
+        );
+        inventory.add_executor(
+            divrem_chip,
+            Rv32DivRem256Opcode::iter().map(VmOpcode::with_default_offset),
+        )?;
+
+        let mod_arith_chip = Rv32ModArith256Chip::new(
+            Rv32VecHeapTwoReadsAdapterChip::new(
+                execution_bus,
+                program_bus,
+                memory_controller.clone(),
+                bitwise_lu_chip.clone(),
+            ),
+            ModArithCoreChip::new(
+                bitwise_lu_chip.clone(),
+                range_tuple_chip,
+                Rv32ModArith256Opcode::default_offset(),
+            ),
+            memory_controller.clone(),
+        );
+        inventory.add_executor(
+            mod_arith_chip,
+            Rv32ModArith256Opcode::iter().map(VmOpcode::with_default_offset),
+        )?;
+
         let shift_chip = Rv32Shift256Chip::new(
             Rv32HeapAdapterChip::new(
                 execution_bus,
@@ -1,13 +1,17 @@
 use openvm_circuit::{self, arch::VmChipWrapper};
-use openvm_rv32_adapters::{Rv32HeapAdapterChip, Rv32HeapBranchAdapterChip};
+use openvm_rv32_adapters::{
+    Rv32HeapAdapterChip, Rv32HeapBranchAdapterChip, Rv32VecHeapTwoReadsAdapterChip,
+};
 use openvm_rv32im_circuit::{
     adapters::{INT256_NUM_LIMBS, RV32_CELL_BITS},
-    BaseAluCoreChip, BranchEqualCoreChip, BranchLessThanCoreChip, LessThanCoreChip,
+    BaseAluCoreChip, BranchEqualCoreChip, BranchLessThanCoreChip, DivRemCoreChip, LessThanCoreChip,
     MultiplicationCoreChip, ShiftCoreChip,
 };
 
 mod extension;
+mod mod_arith;
 pub use extension::*;
+pub use mod_arith::*;
 
 #[cfg(test)]
 mod tests;
@@ -30,6 +34,19 @@ pub type Rv32Multiplication256Chip<F> = VmChipWrapper<
     MultiplicationCoreChip<INT256_NUM_LIMBS, RV32_CELL_BITS>,
 >;
 
+pub type Rv32DivRem256Chip<F> = VmChipWrapper<
+    F,
+    Rv32HeapAdapterChip<F, 2, INT256_NUM_LIMBS, INT256_NUM_LIMBS>,
+    DivRemCoreChip<INT256_NUM_LIMBS, RV32_CELL_BITS>,
+>;
+
+/// Reads `a` and `b` from consecutive 32-byte blocks at `rs1` and the modulus from `rs2`.
+pub type Rv32ModArith256Chip<F> = VmChipWrapper<
+    F,
+    Rv32VecHeapTwoReadsAdapterChip<F, 2, 1, 1, INT256_NUM_LIMBS, INT256_NUM_LIMBS>,
+    ModArithCoreChip<INT256_NUM_LIMBS, RV32_CELL_BITS>,
+>;
+
 pub type Rv32Shift256Chip<F> = VmChipWrapper<
     F,
     Rv32HeapAdapterChip<F, 2, INT256_NUM_LIMBS, INT256_NUM_LIMBS>,
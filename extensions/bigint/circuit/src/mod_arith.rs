@@ -0,0 +1,419 @@
+use std::{
+    array,
+    borrow::{Borrow, BorrowMut},
+    sync::Arc,
+};
+
+use num_bigint::BigUint;
+use openvm_bigint_transpiler::Rv32ModArith256Opcode;
+use openvm_circuit::arch::{
+    AdapterAirContext, AdapterRuntimeContext, MinimalInstruction, Result, VmAdapterInterface,
+    VmCoreAir, VmCoreChip,
+};
+use openvm_circuit_primitives::{
+    bitwise_op_lookup::{BitwiseOperationLookupBus, BitwiseOperationLookupChip},
+    range_tuple::{RangeTupleCheckerBus, RangeTupleCheckerChip},
+    utils::not,
+};
+use openvm_circuit_primitives_derive::AlignedBorrow;
+use openvm_instructions::{instruction::Instruction, UsizeOpcode};
+use openvm_stark_backend::{
+    interaction::InteractionBuilder,
+    p3_air::{AirBuilder, BaseAir},
+    p3_field::{AbstractField, Field, PrimeField32},
+    rap::BaseAirWithPublicValues,
+};
+use strum::IntoEnumIterator;
+
+/// Computes `(a + b) mod n` (ADDMOD) or `(a * b) mod n` (MULMOD) for a modulus `n` that is read
+/// from memory, like the other operands. As for the EVM opcodes, the result is 0 if `n = 0`.
+#[repr(C)]
+#[derive(AlignedBorrow)]
+pub struct ModArithCoreCols<T, const NUM_LIMBS: usize, const LIMB_BITS: usize> {
+    // a * y + is_addmod * b = n' * q + r for 0 <= r < n', where y = b for MULMOD and y = 1 for
+    // ADDMOD, and n' = n unless n = 0, in which case n' = 1 and thus r = 0. The quotient q is
+    // hinted and has 2 * NUM_LIMBS limbs.
+    pub a: [T; NUM_LIMBS],
+    pub b: [T; NUM_LIMBS],
+    pub n: [T; NUM_LIMBS],
+    pub y: [T; NUM_LIMBS],
+    pub q_lo: [T; NUM_LIMBS],
+    pub q_hi: [T; NUM_LIMBS],
+    pub r: [T; NUM_LIMBS],
+
+    // Flag to indicate a zero modulus.
+    pub n_zero: T,
+
+    // Auxiliary columns to constrain that r < n'.
+    pub lt_marker: [T; NUM_LIMBS],
+    pub lt_diff: T,
+
+    // Opcode flags
+    pub opcode_addmod_flag: T,
+    pub opcode_mulmod_flag: T,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct ModArithCoreAir<const NUM_LIMBS: usize, const LIMB_BITS: usize> {
+    pub bitwise_lookup_bus: BitwiseOperationLookupBus,
+    pub range_tuple_bus: RangeTupleCheckerBus<2>,
+    offset: usize,
+}
+
+impl<F: Field, const NUM_LIMBS: usize, const LIMB_BITS: usize> BaseAir<F>
+    for ModArithCoreAir<NUM_LIMBS, LIMB_BITS>
+{
+    fn width(&self) -> usize {
+        ModArithCoreCols::<F, NUM_LIMBS, LIMB_BITS>::width()
+    }
+}
+impl<F: Field, const NUM_LIMBS: usize, const LIMB_BITS: usize> BaseAirWithPublicValues<F>
+    for ModArithCoreAir<NUM_LIMBS, LIMB_BITS>
+{
+}
+
+impl<AB, I, const NUM_LIMBS: usize, const LIMB_BITS: usize> VmCoreAir<AB, I>
+    for ModArithCoreAir<NUM_LIMBS, LIMB_BITS>
+where
+    AB: InteractionBuilder,
+    I: VmAdapterInterface<AB::Expr>,
+    I::Reads: From<([[AB::Expr; NUM_LIMBS]; 2], [[AB::Expr; NUM_LIMBS]; 1])>,
+    I::Writes: From<[[AB::Expr; NUM_LIMBS]; 1]>,
+    I::ProcessedInstruction: From<MinimalInstruction<AB::Expr>>,
+{
+    fn eval(
+        &self,
+        builder: &mut AB,
+        local_core: &[AB::Var],
+        _from_pc: AB::Var,
+    ) -> AdapterAirContext<AB::Expr, I> {
+        let cols: &ModArithCoreCols<_, NUM_LIMBS, LIMB_BITS> = local_core.borrow();
+        let flags = [cols.opcode_addmod_flag, cols.opcode_mulmod_flag];
+
+        let is_valid = flags.iter().fold(AB::Expr::ZERO, |acc, &flag| {
+            builder.assert_bool(flag);
+            acc + flag.into()
+        });
+        builder.assert_bool(is_valid.clone());
+
+        let a = &cols.a;
+        let b = &cols.b;
+        let n = &cols.n;
+        let y = &cols.y;
+        let r = &cols.r;
+        let q: Vec<AB::Var> = cols.q_lo.iter().chain(cols.q_hi.iter()).copied().collect();
+
+        // Constrain that y = b for MULMOD and y = 1 for ADDMOD, so that the left hand side
+        // a * y + is_addmod * b has degree 2 for both opcodes.
+        for i in 0..NUM_LIMBS {
+            let expected_limb = if i == 0 {
+                cols.opcode_addmod_flag.into()
+            } else {
+                AB::Expr::ZERO
+            } + cols.opcode_mulmod_flag * b[i];
+            builder.assert_eq(y[i], expected_limb);
+        }
+
+        // Constrain that n_zero = 1 only if n = 0. If n = 0 and n_zero = 0, then r < n' fails.
+        builder.assert_bool(cols.n_zero);
+        for n_i in n.iter() {
+            builder.when(cols.n_zero).assert_zero(*n_i);
+        }
+        let n_prime: [AB::Expr; NUM_LIMBS] = array::from_fn(|i| {
+            if i == 0 {
+                n[0] + cols.n_zero
+            } else {
+                n[i].into()
+            }
+        });
+
+        // Constrain that a * y + is_addmod * b = n' * q + r as integers, by checking that the
+        // difference has a zero limb at every position, carrying over 3 * NUM_LIMBS limbs. The
+        // carries may be negative, so they are range checked after adding an offset of
+        // NUM_LIMBS * 2^LIMB_BITS. Each q[i] and r[i] is range checked together with a carry.
+        let carry_divide = AB::F::from_canonical_u32(1 << LIMB_BITS).inverse();
+        let mut carry: Vec<AB::Expr> = Vec::with_capacity(3 * NUM_LIMBS);
+
+        for k in 0..3 * NUM_LIMBS {
+            let mut diff = if k == 0 {
+                AB::Expr::ZERO
+            } else {
+                carry[k - 1].clone()
+            };
+            for j in k.saturating_sub(2 * NUM_LIMBS - 1)..=k.min(NUM_LIMBS - 1) {
+                diff += n_prime[j].clone() * q[k - j];
+            }
+            for i in k.saturating_sub(NUM_LIMBS - 1)..=k.min(NUM_LIMBS - 1) {
+                diff -= a[i] * y[k - i];
+            }
+            if k < NUM_LIMBS {
+                diff += r[k].into();
+                diff -= cols.opcode_addmod_flag * b[k];
+            }
+            carry.push(diff * carry_divide);
+        }
+        builder.assert_zero(carry[3 * NUM_LIMBS - 1].clone());
+
+        let carry_offset = AB::F::from_canonical_usize(NUM_LIMBS << LIMB_BITS);
+        for (limb, carry) in q.iter().chain(r.iter()).zip(carry.iter()) {
+            self.range_tuple_bus
+                .send(vec![(*limb).into(), carry.clone() + carry_offset])
+                .eval(builder, is_valid.clone());
+        }
+
+        // Constrain that r < n' by finding the most significant limb in which they differ.
+        let marker = &cols.lt_marker;
+        let mut prefix_sum = AB::Expr::ZERO;
+
+        for i in (0..NUM_LIMBS).rev() {
+            let diff = n_prime[i].clone() - r[i];
+            prefix_sum += marker[i].into();
+            builder.assert_bool(marker[i]);
+            builder.assert_zero(not::<AB::Expr>(prefix_sum.clone()) * diff.clone());
+            builder.when(marker[i]).assert_eq(cols.lt_diff, diff);
+        }
+
+        builder.when(is_valid.clone()).assert_one(prefix_sum);
+        self.bitwise_lookup_bus
+            .send_range(cols.lt_diff - AB::Expr::ONE, AB::F::ZERO)
+            .eval(builder, is_valid.clone());
+
+        let expected_opcode = flags.iter().zip(Rv32ModArith256Opcode::iter()).fold(
+            AB::Expr::ZERO,
+            |acc, (flag, local_opcode)| {
+                acc + (*flag).into() * AB::Expr::from_canonical_u8(local_opcode as u8)
+            },
+        ) + AB::Expr::from_canonical_usize(self.offset);
+
+        AdapterAirContext {
+            to_pc: None,
+            reads: (
+                [cols.a.map(Into::into), cols.b.map(Into::into)],
+                [cols.n.map(Into::into)],
+            )
+                .into(),
+            writes: [cols.r.map(Into::into)].into(),
+            instruction: MinimalInstruction {
+                is_valid,
+                opcode: expected_opcode,
+            }
+            .into(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ModArithCoreChip<const NUM_LIMBS: usize, const LIMB_BITS: usize> {
+    pub air: ModArithCoreAir<NUM_LIMBS, LIMB_BITS>,
+    pub bitwise_lookup_chip: Arc<BitwiseOperationLookupChip<LIMB_BITS>>,
+    pub range_tuple_chip: Arc<RangeTupleCheckerChip<2>>,
+}
+
+impl<const NUM_LIMBS: usize, const LIMB_BITS: usize> ModArithCoreChip<NUM_LIMBS, LIMB_BITS> {
+    pub fn new(
+        bitwise_lookup_chip: Arc<BitwiseOperationLookupChip<LIMB_BITS>>,
+        range_tuple_chip: Arc<RangeTupleCheckerChip<2>>,
+        offset: usize,
+    ) -> Self {
+        // The RangeTupleChecker is used to range check (limb, carry + offset) pairs, where the
+        // absolute value of each carry is less than NUM_LIMBS * 2^LIMB_BITS.
+        // BitwiseOperationLookup is used to check that r < n'.
+        debug_assert!(
+            range_tuple_chip.sizes()[0] == 1 << LIMB_BITS,
+            "First element of RangeTupleChecker must have size {}",
+            1 << LIMB_BITS
+        );
+        debug_assert!(
+            range_tuple_chip.sizes()[1] >= (1 << LIMB_BITS) * 2 * NUM_LIMBS as u32,
+            "Second element of RangeTupleChecker must have size of at least {}",
+            (1 << LIMB_BITS) * 2 * NUM_LIMBS as u32
+        );
+
+        Self {
+            air: ModArithCoreAir {
+                bitwise_lookup_bus: bitwise_lookup_chip.bus(),
+                range_tuple_bus: *range_tuple_chip.bus(),
+                offset,
+            },
+            bitwise_lookup_chip,
+            range_tuple_chip,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ModArithCoreRecord<T, const NUM_LIMBS: usize, const LIMB_BITS: usize> {
+    pub opcode: Rv32ModArith256Opcode,
+    pub a: [T; NUM_LIMBS],
+    pub b: [T; NUM_LIMBS],
+    pub n: [T; NUM_LIMBS],
+    pub y: [T; NUM_LIMBS],
+    pub q_lo: [T; NUM_LIMBS],
+    pub q_hi: [T; NUM_LIMBS],
+    pub r: [T; NUM_LIMBS],
+    pub n_zero: T,
+    pub lt_diff_val: T,
+    pub lt_diff_idx: usize,
+}
+
+impl<F: PrimeField32, I: VmAdapterInterface<F>, const NUM_LIMBS: usize, const LIMB_BITS: usize>
+    VmCoreChip<F, I> for ModArithCoreChip<NUM_LIMBS, LIMB_BITS>
+where
+    I::Reads: Into<([[F; NUM_LIMBS]; 2], [[F; NUM_LIMBS]; 1])>,
+    I::Writes: From<[[F; NUM_LIMBS]; 1]>,
+{
+    type Record = ModArithCoreRecord<F, NUM_LIMBS, LIMB_BITS>;
+    type Air = ModArithCoreAir<NUM_LIMBS, LIMB_BITS>;
+
+    #[allow(clippy::type_complexity)]
+    fn execute_instruction(
+        &self,
+        instruction: &Instruction<F>,
+        _from_pc: u32,
+        reads: I::Reads,
+    ) -> Result<(AdapterRuntimeContext<F, I>, Self::Record)> {
+        let Instruction { opcode, .. } = instruction;
+        let local_opcode =
+            Rv32ModArith256Opcode::from_usize(opcode.local_opcode_idx(self.air.offset));
+        let is_addmod = local_opcode == Rv32ModArith256Opcode::ADDMOD;
+
+        let ([a_data, b_data], [n_data]): ([[F; NUM_LIMBS]; 2], [[F; NUM_LIMBS]; 1]) = reads.into();
+        let a = a_data.map(|x| x.as_canonical_u32());
+        let b = b_data.map(|x| x.as_canonical_u32());
+        let n = n_data.map(|x| x.as_canonical_u32());
+
+        let n_zero = n.iter().all(|&x| x == 0);
+        let mut n_prime = n;
+        if n_zero {
+            n_prime[0] = 1;
+        }
+        let (y, q, r) = run_mod_arith::<NUM_LIMBS, LIMB_BITS>(is_addmod, &a, &b, &n_prime);
+
+        let carries =
+            run_mod_arith_carries::<NUM_LIMBS, LIMB_BITS>(is_addmod, &a, &b, &y, &n_prime, &q, &r);
+        let carry_offset = (NUM_LIMBS << LIMB_BITS) as i64;
+        for (limb, carry) in q.iter().chain(r.iter()).zip(carries.iter()) {
+            self.range_tuple_chip
+                .add_count(&[*limb, (carry + carry_offset) as u32]);
+        }
+
+        let lt_diff_idx = (0..NUM_LIMBS).rev().find(|&i| n_prime[i] != r[i]).unwrap();
+        let lt_diff_val = n_prime[lt_diff_idx] - r[lt_diff_idx];
+        self.bitwise_lookup_chip.request_range(lt_diff_val - 1, 0);
+
+        let output = AdapterRuntimeContext::without_pc([r.map(F::from_canonical_u32)]);
+        let record = ModArithCoreRecord {
+            opcode: local_opcode,
+            a: a_data,
+            b: b_data,
+            n: n_data,
+            y: y.map(F::from_canonical_u32),
+            q_lo: array::from_fn(|i| F::from_canonical_u32(q[i])),
+            q_hi: array::from_fn(|i| F::from_canonical_u32(q[NUM_LIMBS + i])),
+            r: r.map(F::from_canonical_u32),
+            n_zero: F::from_bool(n_zero),
+            lt_diff_val: F::from_canonical_u32(lt_diff_val),
+            lt_diff_idx,
+        };
+
+        Ok((output, record))
+    }
+
+    fn get_opcode_name(&self, opcode: usize) -> String {
+        format!(
+            "{:?}",
+            Rv32ModArith256Opcode::from_usize(opcode - self.air.offset)
+        )
+    }
+
+    fn generate_trace_row(&self, row_slice: &mut [F], record: Self::Record) {
+        let row_slice: &mut ModArithCoreCols<_, NUM_LIMBS, LIMB_BITS> = row_slice.borrow_mut();
+        row_slice.a = record.a;
+        row_slice.b = record.b;
+        row_slice.n = record.n;
+        row_slice.y = record.y;
+        row_slice.q_lo = record.q_lo;
+        row_slice.q_hi = record.q_hi;
+        row_slice.r = record.r;
+        row_slice.n_zero = record.n_zero;
+        row_slice.lt_marker = array::from_fn(|i| F::from_bool(i == record.lt_diff_idx));
+        row_slice.lt_diff = record.lt_diff_val;
+        row_slice.opcode_addmod_flag = F::from_bool(record.opcode == Rv32ModArith256Opcode::ADDMOD);
+        row_slice.opcode_mulmod_flag = F::from_bool(record.opcode == Rv32ModArith256Opcode::MULMOD);
+    }
+
+    fn air(&self) -> &Self::Air {
+        &self.air
+    }
+}
+
+// Returns (y, q, r) where y = 1 for ADDMOD and y = b for MULMOD, and q (2 * NUM_LIMBS limbs)
+// and r are the quotient and remainder of a * y + is_addmod * b by n, which must be non-zero.
+pub(super) fn run_mod_arith<const NUM_LIMBS: usize, const LIMB_BITS: usize>(
+    is_addmod: bool,
+    a: &[u32; NUM_LIMBS],
+    b: &[u32; NUM_LIMBS],
+    n: &[u32; NUM_LIMBS],
+) -> ([u32; NUM_LIMBS], Vec<u32>, [u32; NUM_LIMBS]) {
+    let a_big = limbs_to_biguint::<LIMB_BITS>(a);
+    let b_big = limbs_to_biguint::<LIMB_BITS>(b);
+    let n_big = limbs_to_biguint::<LIMB_BITS>(n);
+    let (y, x_big) = if is_addmod {
+        (array::from_fn(|i| (i == 0) as u32), a_big + b_big)
+    } else {
+        (*b, a_big * b_big)
+    };
+    let q = biguint_to_limbs::<LIMB_BITS>(&(&x_big / &n_big), 2 * NUM_LIMBS);
+    let r = biguint_to_limbs::<LIMB_BITS>(&(&x_big % &n_big), NUM_LIMBS);
+    (y, q, r.try_into().unwrap())
+}
+
+// Returns the 3 * NUM_LIMBS signed carries of n * q + r - a * y - is_addmod * b.
+pub(super) fn run_mod_arith_carries<const NUM_LIMBS: usize, const LIMB_BITS: usize>(
+    is_addmod: bool,
+    a: &[u32; NUM_LIMBS],
+    b: &[u32; NUM_LIMBS],
+    y: &[u32; NUM_LIMBS],
+    n: &[u32; NUM_LIMBS],
+    q: &[u32],
+    r: &[u32; NUM_LIMBS],
+) -> Vec<i64> {
+    let mut carry = vec![0i64; 3 * NUM_LIMBS];
+    for k in 0..3 * NUM_LIMBS {
+        let mut val = if k > 0 { carry[k - 1] } else { 0 };
+        for j in k.saturating_sub(2 * NUM_LIMBS - 1)..=k.min(NUM_LIMBS - 1) {
+            val += (n[j] * q[k - j]) as i64;
+        }
+        for i in k.saturating_sub(NUM_LIMBS - 1)..=k.min(NUM_LIMBS - 1) {
+            val -= (a[i] * y[k - i]) as i64;
+        }
+        if k < NUM_LIMBS {
+            val += r[k] as i64;
+            if is_addmod {
+                val -= b[k] as i64;
+            }
+        }
+        debug_assert_eq!(val % (1 << LIMB_BITS), 0);
+        carry[k] = val >> LIMB_BITS;
+    }
+    debug_assert_eq!(carry[3 * NUM_LIMBS - 1], 0);
+    carry
+}
+
+fn limbs_to_biguint<const LIMB_BITS: usize>(x: &[u32]) -> BigUint {
+    x.iter()
+        .rev()
+        .fold(BigUint::from(0u32), |acc, &limb| (acc << LIMB_BITS) + limb)
+}
+
+fn biguint_to_limbs<const LIMB_BITS: usize>(x: &BigUint, num_limbs: usize) -> Vec<u32> {
+    let mask = BigUint::from((1u32 << LIMB_BITS) - 1);
+    (0..num_limbs)
+        .map(|i| {
+            ((x >> (i * LIMB_BITS)) & &mask)
+                .iter_u32_digits()
+                .next()
+                .unwrap_or(0)
+        })
+        .collect()
+}
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use openvm_bigint_transpiler::Rv32ModArith256Opcode;
 use openvm_circuit::{
     arch::{
         testing::VmChipTestBuilder, InstructionExecutor, BITWISE_OP_LOOKUP_BUS,
@@ -14,23 +15,25 @@ use openvm_circuit_primitives::{
 use openvm_instructions::{program::PC_BITS, riscv::RV32_CELL_BITS, UsizeOpcode};
 use openvm_rv32_adapters::{
     rv32_heap_branch_default, rv32_write_heap_default, Rv32HeapAdapterChip,
-    Rv32HeapBranchAdapterChip,
+    Rv32HeapBranchAdapterChip, Rv32VecHeapTwoReadsAdapterChip,
 };
 use openvm_rv32im_circuit::{
     adapters::{INT256_NUM_LIMBS, RV_B_TYPE_IMM_BITS},
-    BaseAluCoreChip, BranchEqualCoreChip, BranchLessThanCoreChip, LessThanCoreChip,
+    BaseAluCoreChip, BranchEqualCoreChip, BranchLessThanCoreChip, DivRemCoreChip, LessThanCoreChip,
     MultiplicationCoreChip, ShiftCoreChip,
 };
 use openvm_rv32im_transpiler::{
-    BaseAluOpcode, BranchEqualOpcode, BranchLessThanOpcode, LessThanOpcode, MulOpcode, ShiftOpcode,
+    BaseAluOpcode, BranchEqualOpcode, BranchLessThanOpcode, DivRemOpcode, LessThanOpcode,
+    MulOpcode, ShiftOpcode,
 };
 use openvm_stark_backend::p3_field::{AbstractField, PrimeField32};
 use openvm_stark_sdk::{p3_baby_bear::BabyBear, utils::create_seeded_rng};
 use rand::Rng;
 
 use super::{
-    Rv32BaseAlu256Chip, Rv32BranchEqual256Chip, Rv32BranchLessThan256Chip, Rv32LessThan256Chip,
-    Rv32Multiplication256Chip, Rv32Shift256Chip,
+    ModArithCoreChip, Rv32BaseAlu256Chip, Rv32BranchEqual256Chip, Rv32BranchLessThan256Chip,
+    Rv32DivRem256Chip, Rv32LessThan256Chip, Rv32ModArith256Chip, Rv32Multiplication256Chip,
+    Rv32Shift256Chip,
 };
 
 type F = BabyBear;
@@ -212,6 +215,125 @@ fn mul_256_rand_test() {
     run_mul_256_rand_test(24);
 }
 
+fn run_divrem_256_rand_test(opcode: DivRemOpcode, num_ops: usize) {
+    let range_tuple_bus = RangeTupleCheckerBus::new(
+        RANGE_TUPLE_CHECKER_BUS,
+        [
+            1 << RV32_CELL_BITS,
+            (2 * INT256_NUM_LIMBS * (1 << RV32_CELL_BITS)) as u32,
+        ],
+    );
+    let range_tuple_checker = Arc::new(RangeTupleCheckerChip::new(range_tuple_bus));
+    let bitwise_bus = BitwiseOperationLookupBus::new(BITWISE_OP_LOOKUP_BUS);
+    let bitwise_chip = Arc::new(BitwiseOperationLookupChip::<RV32_CELL_BITS>::new(
+        bitwise_bus,
+    ));
+
+    let mut tester = VmChipTestBuilder::default();
+    let mut chip = Rv32DivRem256Chip::<F>::new(
+        Rv32HeapAdapterChip::<F, 2, INT256_NUM_LIMBS, INT256_NUM_LIMBS>::new(
+            tester.execution_bus(),
+            tester.program_bus(),
+            tester.memory_controller(),
+            bitwise_chip.clone(),
+        ),
+        DivRemCoreChip::new(bitwise_chip.clone(), range_tuple_checker.clone(), 0),
+        tester.memory_controller(),
+    );
+
+    run_int_256_rand_execute(opcode as usize, num_ops, &mut chip, &mut tester, None);
+    let tester = tester
+        .build()
+        .load(chip)
+        .load(range_tuple_checker)
+        .load(bitwise_chip)
+        .finalize();
+    tester.simple_test().expect("Verification failed");
+}
+
+#[test]
+fn divrem_256_div_rand_test() {
+    run_divrem_256_rand_test(DivRemOpcode::DIV, 24);
+}
+
+#[test]
+fn divrem_256_divu_rand_test() {
+    run_divrem_256_rand_test(DivRemOpcode::DIVU, 24);
+}
+
+#[test]
+fn divrem_256_rem_rand_test() {
+    run_divrem_256_rand_test(DivRemOpcode::REM, 24);
+}
+
+#[test]
+fn divrem_256_remu_rand_test() {
+    run_divrem_256_rand_test(DivRemOpcode::REMU, 24);
+}
+
+fn run_mod_arith_256_rand_test(opcode: Rv32ModArith256Opcode, num_ops: usize) {
+    let range_tuple_bus = RangeTupleCheckerBus::new(
+        RANGE_TUPLE_CHECKER_BUS,
+        [
+            1 << RV32_CELL_BITS,
+            (2 * INT256_NUM_LIMBS * (1 << RV32_CELL_BITS)) as u32,
+        ],
+    );
+    let range_tuple_checker = Arc::new(RangeTupleCheckerChip::new(range_tuple_bus));
+    let bitwise_bus = BitwiseOperationLookupBus::new(BITWISE_OP_LOOKUP_BUS);
+    let bitwise_chip = Arc::new(BitwiseOperationLookupChip::<RV32_CELL_BITS>::new(
+        bitwise_bus,
+    ));
+
+    let mut tester = VmChipTestBuilder::default();
+    let mut chip = Rv32ModArith256Chip::<F>::new(
+        Rv32VecHeapTwoReadsAdapterChip::<F, 2, 1, 1, INT256_NUM_LIMBS, INT256_NUM_LIMBS>::new(
+            tester.execution_bus(),
+            tester.program_bus(),
+            tester.memory_controller(),
+            bitwise_chip.clone(),
+        ),
+        ModArithCoreChip::new(bitwise_chip.clone(), range_tuple_checker.clone(), 0),
+        tester.memory_controller(),
+    );
+
+    let mut rng = create_seeded_rng();
+    for i in 0..num_ops {
+        let a = generate_long_number::<INT256_NUM_LIMBS, RV32_CELL_BITS>(&mut rng);
+        let b = generate_long_number::<INT256_NUM_LIMBS, RV32_CELL_BITS>(&mut rng);
+        // Moduli of every size, including zero.
+        let n_limbs = i % (INT256_NUM_LIMBS + 1);
+        let mut n = generate_long_number::<INT256_NUM_LIMBS, RV32_CELL_BITS>(&mut rng);
+        n[n_limbs..].fill(0);
+
+        let instruction = rv32_write_heap_default(
+            &mut tester,
+            vec![a.map(F::from_canonical_u32), b.map(F::from_canonical_u32)],
+            vec![n.map(F::from_canonical_u32)],
+            opcode as usize,
+        );
+        tester.execute(&mut chip, instruction);
+    }
+
+    let tester = tester
+        .build()
+        .load(chip)
+        .load(range_tuple_checker)
+        .load(bitwise_chip)
+        .finalize();
+    tester.simple_test().expect("Verification failed");
+}
+
+#[test]
+fn mod_arith_256_addmod_rand_test() {
+    run_mod_arith_256_rand_test(Rv32ModArith256Opcode::ADDMOD, 66);
+}
+
+#[test]
+fn mod_arith_256_mulmod_rand_test() {
+    run_mod_arith_256_rand_test(Rv32ModArith256Opcode::MULMOD, 66);
+}
+
 fn run_shift_256_rand_test(opcode: ShiftOpcode, num_ops: usize) {
     let bitwise_bus = BitwiseOperationLookupBus::new(BITWISE_OP_LOOKUP_BUS);
     let bitwise_chip = Arc::new(BitwiseOperationLookupChip::<RV32_CELL_BITS>::new(
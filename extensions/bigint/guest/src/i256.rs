@@ -1,8 +1,8 @@
 use core::{
     cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd},
     ops::{
-        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Mul,
-        MulAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
+        DivAssign, Mul, MulAssign, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
     },
 };
 
@@ -125,6 +125,46 @@ impl_bin_op!(
     |lhs: &I256, rhs: &I256| -> I256 {I256::from_bigint(&(lhs.as_bigint() * rhs.as_bigint()))}
 );
 
+// Division rounds towards zero and does not panic: as in RISC-V, dividing by zero gives a quotient
+// of -1 and the dividend as the remainder, and `I256::MIN / -1` overflows to `I256::MIN`.
+impl_bin_op!(
+    I256,
+    Div,
+    DivAssign,
+    div,
+    div_assign,
+    OPCODE,
+    INT256_FUNCT3,
+    Int256Funct7::Div as u8,
+    /=,
+    |lhs: &I256, rhs: &I256| -> I256 {
+        if *rhs == I256::ZERO {
+            I256::from_i8(-1)
+        } else {
+            I256::from_bigint(&(lhs.as_bigint() / rhs.as_bigint()))
+        }
+    }
+);
+
+impl_bin_op!(
+    I256,
+    Rem,
+    RemAssign,
+    rem,
+    rem_assign,
+    OPCODE,
+    INT256_FUNCT3,
+    Int256Funct7::Rem as u8,
+    %=,
+    |lhs: &I256, rhs: &I256| -> I256 {
+        if *rhs == I256::ZERO {
+            lhs.clone()
+        } else {
+            I256::from_bigint(&(lhs.as_bigint() % rhs.as_bigint()))
+        }
+    }
+);
+
 impl_bin_op!(
     I256,
     BitXor,
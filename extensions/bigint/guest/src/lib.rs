@@ -31,6 +31,12 @@ pub enum Int256Funct7 {
     Slt,
     Sltu,
     Mul,
+    Div,
+    Divu,
+    Rem,
+    Remu,
+    AddMod,
+    MulMod,
 }
 
 #[cfg(all(feature = "export-intrinsics", target_os = "zkvm"))]
@@ -1,8 +1,8 @@
 use core::{
     cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd},
     ops::{
-        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Mul,
-        MulAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
+        DivAssign, Mul, MulAssign, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
     },
 };
 
@@ -75,6 +75,60 @@ impl U256 {
     pub fn as_le_bytes(&self) -> &[u8; 32] {
         &self.limbs
     }
+
+    /// Returns `(self + other) % modulus` without overflowing, or zero if `modulus` is zero, as
+    /// the EVM `ADDMOD` opcode.
+    pub fn addmod(&self, other: &Self, modulus: &Self) -> Self {
+        #[cfg(target_os = "zkvm")]
+        {
+            // The operands are read from consecutive memory starting at `rs1`.
+            let operands = [Self { limbs: self.limbs }, Self { limbs: other.limbs }];
+            let mut uninit: MaybeUninit<Self> = MaybeUninit::uninit();
+            custom_insn_r!(
+                OPCODE,
+                INT256_FUNCT3,
+                Int256Funct7::AddMod as u8,
+                uninit.as_mut_ptr(),
+                operands.as_ptr(),
+                modulus as *const Self
+            );
+            unsafe { uninit.assume_init() }
+        }
+        #[cfg(not(target_os = "zkvm"))]
+        {
+            if *modulus == Self::ZERO {
+                return Self::ZERO;
+            }
+            Self::from_biguint(&((self.as_biguint() + other.as_biguint()) % modulus.as_biguint()))
+        }
+    }
+
+    /// Returns `(self * other) % modulus` without overflowing, or zero if `modulus` is zero, as
+    /// the EVM `MULMOD` opcode.
+    pub fn mulmod(&self, other: &Self, modulus: &Self) -> Self {
+        #[cfg(target_os = "zkvm")]
+        {
+            // The operands are read from consecutive memory starting at `rs1`.
+            let operands = [Self { limbs: self.limbs }, Self { limbs: other.limbs }];
+            let mut uninit: MaybeUninit<Self> = MaybeUninit::uninit();
+            custom_insn_r!(
+                OPCODE,
+                INT256_FUNCT3,
+                Int256Funct7::MulMod as u8,
+                uninit.as_mut_ptr(),
+                operands.as_ptr(),
+                modulus as *const Self
+            );
+            unsafe { uninit.assume_init() }
+        }
+        #[cfg(not(target_os = "zkvm"))]
+        {
+            if *modulus == Self::ZERO {
+                return Self::ZERO;
+            }
+            Self::from_biguint(&((self.as_biguint() * other.as_biguint()) % modulus.as_biguint()))
+        }
+    }
 }
 
 impl_bin_op!(
@@ -116,6 +170,46 @@ impl_bin_op!(
     |lhs: &U256, rhs: &U256| -> U256 {U256::from_biguint(&(lhs.as_biguint() * rhs.as_biguint()))}
 );
 
+// Division by zero does not panic: as in RISC-V, the quotient is `U256::MAX` and the remainder is
+// the dividend.
+impl_bin_op!(
+    U256,
+    Div,
+    DivAssign,
+    div,
+    div_assign,
+    OPCODE,
+    INT256_FUNCT3,
+    Int256Funct7::Divu as u8,
+    /=,
+    |lhs: &U256, rhs: &U256| -> U256 {
+        if *rhs == U256::ZERO {
+            U256::MAX
+        } else {
+            U256::from_biguint(&(lhs.as_biguint() / rhs.as_biguint()))
+        }
+    }
+);
+
+impl_bin_op!(
+    U256,
+    Rem,
+    RemAssign,
+    rem,
+    rem_assign,
+    OPCODE,
+    INT256_FUNCT3,
+    Int256Funct7::Remu as u8,
+    %=,
+    |lhs: &U256, rhs: &U256| -> U256 {
+        if *rhs == U256::ZERO {
+            lhs.clone()
+        } else {
+            U256::from_biguint(&(lhs.as_biguint() % rhs.as_biguint()))
+        }
+    }
+);
+
 impl_bin_op!(
     U256,
     BitXor,
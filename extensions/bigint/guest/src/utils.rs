@@ -106,9 +106,9 @@ macro_rules! impl_bin_op {
         impl $trait_name<$struct_name> for &$struct_name {
             type Output = $struct_name;
             #[inline(always)]
-            fn $trait_fn(self, mut rhs: $struct_name) -> Self::Output {
-                rhs $op_sym self;
-                rhs
+            fn $trait_fn(self, rhs: $struct_name) -> Self::Output {
+                // Not every operation is commutative, so `rhs` cannot be updated in place.
+                self.$trait_fn(&rhs)
             }
         }
     };
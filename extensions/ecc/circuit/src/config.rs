@@ -41,3 +41,34 @@ impl Rv32WeierstrassConfig {
         }
     }
 }
+
+#[derive(Clone, Debug, VmConfig, Serialize, Deserialize)]
+pub struct Rv32EdwardsConfig {
+    #[system]
+    pub system: SystemConfig,
+    #[extension]
+    pub base: Rv32I,
+    #[extension]
+    pub mul: Rv32M,
+    #[extension]
+    pub io: Rv32Io,
+    #[extension]
+    pub modular: ModularExtension,
+    #[extension]
+    pub edwards: TwistedEdwardsExtension,
+}
+
+impl Rv32EdwardsConfig {
+    pub fn new(curves: Vec<TeCurveConfig>) -> Self {
+        let mut primes: Vec<_> = curves.iter().map(|c| c.modulus.clone()).collect();
+        primes.extend(curves.iter().map(|c| c.scalar.clone()));
+        Self {
+            system: SystemConfig::default().with_continuations(),
+            base: Default::default(),
+            mul: Default::default(),
+            io: Default::default(),
+            modular: ModularExtension::new(primes),
+            edwards: TwistedEdwardsExtension::new(curves),
+        }
+    }
+}
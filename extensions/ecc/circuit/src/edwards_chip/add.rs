@@ -0,0 +1,36 @@
+use std::{cell::RefCell, rc::Rc};
+
+use num_bigint_dig::BigUint;
+use openvm_circuit_primitives::var_range::VariableRangeCheckerBus;
+use openvm_mod_circuit_builder::{ExprBuilder, ExprBuilderConfig, FieldExpr};
+
+pub fn te_add_expr(
+    config: ExprBuilderConfig, // The coordinate field.
+    range_bus: VariableRangeCheckerBus,
+    a_biguint: BigUint,
+    d_biguint: BigUint,
+) -> FieldExpr {
+    config.check_valid();
+    let builder = ExprBuilder::new(config, range_bus.range_max_bits);
+    let builder = Rc::new(RefCell::new(builder));
+
+    let x1 = ExprBuilder::new_input(builder.clone());
+    let y1 = ExprBuilder::new_input(builder.clone());
+    let x2 = ExprBuilder::new_input(builder.clone());
+    let y2 = ExprBuilder::new_input(builder.clone());
+    let a = ExprBuilder::new_const(builder.clone(), a_biguint);
+    let d = ExprBuilder::new_const(builder.clone(), d_biguint);
+
+    let x1x2 = x1.clone() * x2.clone();
+    let y1y2 = y1.clone() * y2.clone();
+    // d x1 x2 y1 y2 appears in both denominators, so save it once.
+    let mut dxy = d * x1x2.clone() * y1y2.clone();
+    dxy.save();
+    let mut x3 = (x1 * y2 + y1 * x2) / dxy.int_add(1);
+    x3.save_output();
+    let mut y3 = (y1y2 - a * x1x2) / (dxy.int_mul(-1).int_add(1));
+    y3.save_output();
+
+    let builder = builder.borrow().clone();
+    FieldExpr::new(builder, range_bus, true)
+}
@@ -0,0 +1,166 @@
+use std::{str::FromStr, sync::Arc};
+
+use num_bigint_dig::BigUint;
+use openvm_circuit::arch::{testing::VmChipTestBuilder, BITWISE_OP_LOOKUP_BUS};
+use openvm_circuit_primitives::bitwise_op_lookup::{
+    BitwiseOperationLookupBus, BitwiseOperationLookupChip,
+};
+use openvm_ecc_transpiler::Rv32EdwardsOpcode;
+use openvm_instructions::{riscv::RV32_CELL_BITS, UsizeOpcode};
+use openvm_mod_circuit_builder::{
+    test_utils::biguint_to_limbs, ExprBuilderConfig, FieldExpressionCoreChip,
+};
+use openvm_rv32_adapters::{rv32_write_heap_default, Rv32VecHeapAdapterChip};
+use openvm_stark_backend::p3_field::AbstractField;
+use openvm_stark_sdk::p3_baby_bear::BabyBear;
+
+use super::EdwardsAddChip;
+use crate::ED25519_CONFIG;
+
+const NUM_LIMBS: usize = 32;
+const LIMB_BITS: usize = 8;
+const BLOCK_SIZE: usize = 32;
+type F = BabyBear;
+
+lazy_static::lazy_static! {
+    // The Ed25519 base point B and its multiples 2B, 3B, computed with the
+    // reference implementation in https://datatracker.ietf.org/doc/html/rfc8032#section-6
+    pub static ref SampleEdwardsPoints: Vec<(BigUint, BigUint)> = {
+        let x1 = BigUint::from_str(
+            "15112221349535400772501151409588531511454012693041857206046113283949847762202",
+        )
+        .unwrap();
+        let y1 = BigUint::from_str(
+            "46316835694926478169428394003475163141307993866256225615783033603165251855960",
+        )
+        .unwrap();
+
+        // This is 2B.
+        let x2 = BigUint::from_str(
+            "24727413235106541002554574571675588834622768167397638456726423682521233608206",
+        )
+        .unwrap();
+        let y2 = BigUint::from_str(
+            "15549675580280190176352668710449542251549572066445060580507079593062643049417",
+        )
+        .unwrap();
+
+        // This is 3B.
+        let x3 = BigUint::from_str(
+            "46896733464454938657123544595386787789046198280132665686241321779790909858396",
+        )
+        .unwrap();
+        let y3 = BigUint::from_str(
+            "8324843778533443976490377120369201138301417226297555316741202210403726505172",
+        )
+        .unwrap();
+
+        vec![(x1, y1), (x2, y2), (x3, y3)]
+    };
+}
+
+fn prime_limbs(chip: &FieldExpressionCoreChip) -> Vec<BabyBear> {
+    chip.expr()
+        .prime_limbs
+        .iter()
+        .map(|n| BabyBear::from_canonical_usize(*n))
+        .collect::<Vec<_>>()
+}
+
+fn run_te_add(p1: (BigUint, BigUint), p2: (BigUint, BigUint), expected: (BigUint, BigUint)) {
+    let mut tester: VmChipTestBuilder<F> = VmChipTestBuilder::default();
+    let config = ExprBuilderConfig {
+        modulus: ED25519_CONFIG.modulus.clone(),
+        num_limbs: NUM_LIMBS,
+        limb_bits: LIMB_BITS,
+    };
+    let bitwise_bus = BitwiseOperationLookupBus::new(BITWISE_OP_LOOKUP_BUS);
+    let bitwise_chip = Arc::new(BitwiseOperationLookupChip::<RV32_CELL_BITS>::new(
+        bitwise_bus,
+    ));
+    let adapter = Rv32VecHeapAdapterChip::<F, 2, 2, 2, BLOCK_SIZE, BLOCK_SIZE>::new(
+        tester.execution_bus(),
+        tester.program_bus(),
+        tester.memory_controller(),
+        bitwise_chip.clone(),
+    );
+    let mut chip = EdwardsAddChip::new(
+        adapter,
+        tester.memory_controller(),
+        config,
+        Rv32EdwardsOpcode::default_offset(),
+        ED25519_CONFIG.a.clone(),
+        ED25519_CONFIG.d.clone(),
+    );
+
+    let (p1_x, p1_y) = p1;
+    let (p2_x, p2_y) = p2;
+    let p1_x_limbs =
+        biguint_to_limbs::<NUM_LIMBS>(p1_x.clone(), LIMB_BITS).map(BabyBear::from_canonical_u32);
+    let p1_y_limbs =
+        biguint_to_limbs::<NUM_LIMBS>(p1_y.clone(), LIMB_BITS).map(BabyBear::from_canonical_u32);
+    let p2_x_limbs =
+        biguint_to_limbs::<NUM_LIMBS>(p2_x.clone(), LIMB_BITS).map(BabyBear::from_canonical_u32);
+    let p2_y_limbs =
+        biguint_to_limbs::<NUM_LIMBS>(p2_y.clone(), LIMB_BITS).map(BabyBear::from_canonical_u32);
+
+    let r = chip
+        .0
+        .core
+        .expr()
+        .execute_with_output(vec![p1_x, p1_y, p2_x, p2_y], vec![]);
+    assert_eq!(r.len(), 2); // x3, y3
+    assert_eq!(r[0], expected.0);
+    assert_eq!(r[1], expected.1);
+
+    let prime_limbs: [BabyBear; NUM_LIMBS] = prime_limbs(&chip.0.core).try_into().unwrap();
+    let mut one_limbs = [BabyBear::ZERO; NUM_LIMBS];
+    one_limbs[0] = BabyBear::ONE;
+    let setup_instruction = rv32_write_heap_default(
+        &mut tester,
+        vec![prime_limbs, one_limbs], // inputs[0] = prime, others doesn't matter
+        vec![one_limbs, one_limbs],
+        chip.0.core.air.offset + Rv32EdwardsOpcode::SETUP_TE_ADD as usize,
+    );
+    tester.execute(&mut chip, setup_instruction);
+
+    let instruction = rv32_write_heap_default(
+        &mut tester,
+        vec![p1_x_limbs, p1_y_limbs],
+        vec![p2_x_limbs, p2_y_limbs],
+        chip.0.core.air.offset + Rv32EdwardsOpcode::TE_ADD as usize,
+    );
+    tester.execute(&mut chip, instruction);
+
+    let tester = tester.build().load(chip).load(bitwise_chip).finalize();
+    tester.simple_test().expect("Verification failed");
+}
+
+#[test]
+fn test_te_add() {
+    run_te_add(
+        SampleEdwardsPoints[0].clone(),
+        SampleEdwardsPoints[1].clone(),
+        SampleEdwardsPoints[2].clone(),
+    );
+}
+
+// The addition law is complete, so the same chip handles doubling.
+#[test]
+fn test_te_add_double() {
+    run_te_add(
+        SampleEdwardsPoints[0].clone(),
+        SampleEdwardsPoints[0].clone(),
+        SampleEdwardsPoints[1].clone(),
+    );
+}
+
+#[test]
+fn test_te_add_identity() {
+    let identity = (BigUint::from(0u32), BigUint::from(1u32));
+    run_te_add(
+        SampleEdwardsPoints[2].clone(),
+        identity,
+        SampleEdwardsPoints[2].clone(),
+    );
+}
@@ -0,0 +1,164 @@
+use std::sync::Arc;
+
+use derive_more::derive::From;
+use num_bigint_dig::BigUint;
+use num_traits::{Num, One};
+use once_cell::sync::Lazy;
+use openvm_circuit::{
+    arch::{SystemPort, VmExtension, VmInventory, VmInventoryBuilder, VmInventoryError},
+    system::phantom::PhantomChip,
+};
+use openvm_circuit_derive::{AnyEnum, InstructionExecutor};
+use openvm_circuit_primitives::bitwise_op_lookup::{
+    BitwiseOperationLookupBus, BitwiseOperationLookupChip,
+};
+use openvm_circuit_primitives_derive::{Chip, ChipUsageGetter};
+use openvm_ecc_guest::ed25519::{ED25519_MODULUS, ED25519_ORDER};
+use openvm_ecc_transpiler::Rv32EdwardsOpcode;
+use openvm_instructions::{UsizeOpcode, VmOpcode};
+use openvm_mod_circuit_builder::ExprBuilderConfig;
+use openvm_rv32_adapters::Rv32VecHeapAdapterChip;
+use openvm_stark_backend::p3_field::PrimeField32;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use strum::EnumCount;
+
+use super::EdwardsAddChip;
+
+#[serde_as]
+#[derive(Clone, Debug, derive_new::new, Serialize, Deserialize)]
+pub struct TeCurveConfig {
+    /// The coordinate modulus of the curve.
+    #[serde_as(as = "DisplayFromStr")]
+    pub modulus: BigUint,
+    /// The scalar field modulus of the curve.
+    #[serde_as(as = "DisplayFromStr")]
+    pub scalar: BigUint,
+    /// The coefficient a of a x^2 + y^2 = 1 + d x^2 y^2.
+    #[serde_as(as = "DisplayFromStr")]
+    pub a: BigUint,
+    /// The coefficient d of a x^2 + y^2 = 1 + d x^2 y^2.
+    #[serde_as(as = "DisplayFromStr")]
+    pub d: BigUint,
+}
+
+pub static ED25519_CONFIG: Lazy<TeCurveConfig> = Lazy::new(|| TeCurveConfig {
+    modulus: ED25519_MODULUS.clone(),
+    scalar: ED25519_ORDER.clone(),
+    a: ED25519_MODULUS.clone() - BigUint::one(),
+    d: BigUint::from_str_radix(
+        "52036cee2b6ffe738cc740797779e89800700a4d4141d8ab75eb4dca135978a3",
+        16,
+    )
+    .unwrap(),
+});
+
+#[derive(Clone, Debug, derive_new::new, Serialize, Deserialize)]
+pub struct TwistedEdwardsExtension {
+    pub supported_curves: Vec<TeCurveConfig>,
+}
+
+#[derive(Chip, ChipUsageGetter, InstructionExecutor, AnyEnum)]
+pub enum TwistedEdwardsExtensionExecutor<F: PrimeField32> {
+    // 32 limbs prime
+    EdwardsAddRv32_32(EdwardsAddChip<F, 2, 32>),
+    // 48 limbs prime
+    EdwardsAddRv32_48(EdwardsAddChip<F, 6, 16>),
+}
+
+#[derive(ChipUsageGetter, Chip, AnyEnum, From)]
+pub enum TwistedEdwardsExtensionPeriphery<F: PrimeField32> {
+    BitwiseOperationLookup(Arc<BitwiseOperationLookupChip<8>>),
+    Phantom(PhantomChip<F>),
+}
+
+impl<F: PrimeField32> VmExtension<F> for TwistedEdwardsExtension {
+    type Executor = TwistedEdwardsExtensionExecutor<F>;
+    type Periphery = TwistedEdwardsExtensionPeriphery<F>;
+
+    fn build(
+        &self,
+        builder: &mut VmInventoryBuilder<F>,
+    ) -> Result<VmInventory<Self::Executor, Self::Periphery>, VmInventoryError> {
+        let mut inventory = VmInventory::new();
+        let SystemPort {
+            execution_bus,
+            program_bus,
+            memory_controller,
+        } = builder.system_port();
+        let bitwise_lu_chip = if let Some(chip) = builder
+            .find_chip::<Arc<BitwiseOperationLookupChip<8>>>()
+            .first()
+        {
+            Arc::clone(chip)
+        } else {
+            let bitwise_lu_bus = BitwiseOperationLookupBus::new(builder.new_bus_idx());
+            let chip = Arc::new(BitwiseOperationLookupChip::new(bitwise_lu_bus));
+            inventory.add_periphery_chip(chip.clone());
+            chip
+        };
+        let te_add_opcodes =
+            (Rv32EdwardsOpcode::TE_ADD as usize)..=(Rv32EdwardsOpcode::SETUP_TE_ADD as usize);
+
+        for (i, curve) in self.supported_curves.iter().enumerate() {
+            let class_offset = Rv32EdwardsOpcode::default_offset() + i * Rv32EdwardsOpcode::COUNT;
+            let bytes = curve.modulus.bits().div_ceil(8);
+            let config32 = ExprBuilderConfig {
+                modulus: curve.modulus.clone(),
+                num_limbs: 32,
+                limb_bits: 8,
+            };
+            let config48 = ExprBuilderConfig {
+                modulus: curve.modulus.clone(),
+                num_limbs: 48,
+                limb_bits: 8,
+            };
+            // TODO: Better support for different limb sizes. Currently only 32 or 48 limbs are supported.
+            if bytes <= 32 {
+                let add_chip = EdwardsAddChip::new(
+                    Rv32VecHeapAdapterChip::<F, 2, 2, 2, 32, 32>::new(
+                        execution_bus,
+                        program_bus,
+                        memory_controller.clone(),
+                        bitwise_lu_chip.clone(),
+                    ),
+                    memory_controller.clone(),
+                    config32,
+                    class_offset,
+                    curve.a.clone(),
+                    curve.d.clone(),
+                );
+                inventory.add_executor(
+                    TwistedEdwardsExtensionExecutor::EdwardsAddRv32_32(add_chip),
+                    te_add_opcodes
+                        .clone()
+                        .map(|x| VmOpcode::from_usize(x + class_offset)),
+                )?;
+            } else if bytes <= 48 {
+                let add_chip = EdwardsAddChip::new(
+                    Rv32VecHeapAdapterChip::<F, 2, 6, 6, 16, 16>::new(
+                        execution_bus,
+                        program_bus,
+                        memory_controller.clone(),
+                        bitwise_lu_chip.clone(),
+                    ),
+                    memory_controller.clone(),
+                    config48,
+                    class_offset,
+                    curve.a.clone(),
+                    curve.d.clone(),
+                );
+                inventory.add_executor(
+                    TwistedEdwardsExtensionExecutor::EdwardsAddRv32_48(add_chip),
+                    te_add_opcodes
+                        .clone()
+                        .map(|x| VmOpcode::from_usize(x + class_offset)),
+                )?;
+            } else {
+                panic!("Modulus too large");
+            }
+        }
+
+        Ok(inventory)
+    }
+}
@@ -1,8 +1,10 @@
 mod add_ne;
 mod double;
+mod scalar_mul;
 
 pub use add_ne::*;
 pub use double::*;
+pub use scalar_mul::*;
 
 #[cfg(test)]
 mod tests;
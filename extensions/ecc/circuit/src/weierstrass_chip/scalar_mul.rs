@@ -0,0 +1,608 @@
+use std::{
+    array::from_fn,
+    borrow::Borrow,
+    cell::{RefCell, RefMut},
+    rc::Rc,
+    sync::Arc,
+};
+
+use itertools::Itertools;
+use num_bigint_dig::BigUint;
+use num_traits::{One, Zero};
+use openvm_circuit::{
+    arch::{
+        AdapterAirContext, AdapterRuntimeContext, ExecutionError, ExecutionState,
+        InstructionExecutor, MinimalInstruction, Result, VecHeapTwoReadsAdapterInterface,
+        VmAdapterAir, VmAdapterChip,
+    },
+    system::memory::{MemoryController, MemoryControllerRef},
+};
+use openvm_circuit_primitives::{
+    utils::next_power_of_two_or_zero,
+    var_range::{VariableRangeCheckerBus, VariableRangeCheckerChip},
+    SubAir, TraceSubRowGenerator,
+};
+use openvm_ecc_transpiler::Rv32WeierstrassOpcode;
+use openvm_instructions::{instruction::Instruction, UsizeOpcode};
+use openvm_mod_circuit_builder::{
+    utils::{biguint_to_limbs_vec, limbs_to_biguint},
+    ExprBuilder, ExprBuilderConfig, FieldExpr, FieldExprCols, FieldVariable,
+};
+use openvm_rv32_adapters::{
+    Rv32VecHeapTwoReadsAdapterAir, Rv32VecHeapTwoReadsAdapterChip, Rv32VecHeapTwoReadsReadRecord,
+    Rv32VecHeapTwoReadsWriteRecord,
+};
+use openvm_stark_backend::{
+    config::{StarkGenericConfig, Val},
+    interaction::InteractionBuilder,
+    p3_air::{Air, AirBuilder, BaseAir},
+    p3_field::{AbstractField, Field, PrimeField32},
+    p3_matrix::{dense::RowMajorMatrix, Matrix},
+    p3_maybe_rayon::prelude::*,
+    prover::types::AirProofInput,
+    rap::{get_air_name, AnyRap, BaseAirWithPublicValues, PartitionedBaseAir},
+    Chip, ChipUsageGetter,
+};
+
+/// One double-and-add step with inputs `[x1, y1, x2, y2]`, where `(x1, y1)` is the accumulator
+/// and `(x2, y2)` the base point, outputs `[x3, y3]` and flags `[is_inf, bit]`:
+/// - if `is_inf` is set, the accumulator is the identity and the output is the base point,
+/// - otherwise the output is `2 * acc + bit * base`.
+///
+/// The doubling always has a well defined slope: while the accumulator is the identity, the base
+/// point is doubled instead and the result is discarded. The addition is only constrained if `bit`
+/// is set, and then the doubled point must have a different x-coordinate than the base point. This
+/// is enforced with an inverse, so a row where the doubled point is plus or minus the base point
+/// cannot be proven.
+pub fn ec_scalar_mul_step_expr(
+    config: ExprBuilderConfig, // The coordinate field.
+    range_bus: VariableRangeCheckerBus,
+    a_biguint: BigUint,
+) -> FieldExpr {
+    config.check_valid();
+    let builder = ExprBuilder::new(config, range_bus.range_max_bits);
+    let builder = Rc::new(RefCell::new(builder));
+
+    let x1 = ExprBuilder::new_input(builder.clone());
+    let y1 = ExprBuilder::new_input(builder.clone());
+    let x2 = ExprBuilder::new_input(builder.clone());
+    let y2 = ExprBuilder::new_input(builder.clone());
+    let is_inf = builder.borrow_mut().new_flag();
+    let bit = builder.borrow_mut().new_flag();
+    let a = ExprBuilder::new_const(builder.clone(), a_biguint);
+    let one = ExprBuilder::new_const(builder.clone(), BigUint::one());
+
+    let mut dx = FieldVariable::select(is_inf, &x2, &x1);
+    dx.save();
+    let mut dy = FieldVariable::select(is_inf, &y2, &y1);
+    dy.save();
+    let mut lambda = (dx.square().int_mul(3) + a) / (dy.int_mul(2));
+    let mut xd = lambda.square() - dx.int_mul(2);
+    xd.save();
+    let mut yd = lambda * (dx - xd.clone()) - dy;
+    yd.save();
+
+    let mut denominator = FieldVariable::select(bit, &(x2.clone() - xd.clone()), &one);
+    // If `2 * acc = base`, both sides of `lambda * (x2 - xd) = y2 - yd` are zero and `lambda` is
+    // unconstrained. Requiring the denominator to be invertible rules this case out.
+    FieldVariable::div(&mut one.clone(), &mut denominator);
+    let mut lambda = (y2.clone() - yd.clone()) / denominator;
+    let mut xa = lambda.square() - xd.clone() - x2.clone();
+    xa.save();
+    let mut ya = lambda * (xd.clone() - xa.clone()) - yd.clone();
+    ya.save();
+
+    let mut x3 = FieldVariable::select(bit, &xa, &xd);
+    x3.save();
+    let mut y3 = FieldVariable::select(bit, &ya, &yd);
+    y3.save();
+    let mut x3 = FieldVariable::select(is_inf, &x2, &x3);
+    x3.save_output();
+    let mut y3 = FieldVariable::select(is_inf, &y2, &y3);
+    y3.save_output();
+
+    let builder = builder.borrow().clone();
+    // There is no setup opcode: the modulus is checked by the setup of the other chips.
+    FieldExpr::new(builder, range_bus, false)
+}
+
+/// Computes `scalar * P` for a point `P` of a short Weierstrass curve and a little-endian
+/// `scalar` with `SCALAR_BLOCKS * BLOCK_SIZE` bytes, with one double-and-add step per row:
+/// - the first row of an instruction holds the adapter columns, which read `P` and `scalar` and
+///   write the result,
+/// - every row doubles the accumulator and adds `P` if the current scalar bit is set, starting
+///   from the most significant bit,
+/// - the accumulator starts as the identity, which is tracked with the `is_inf` flag instead of
+///   coordinates, and the identity is written as `(0, 0)`,
+/// - the scalar bits are shifted up by one position on every row, so the current bit is always
+///   the top one.
+///
+/// `P` must not be the identity, and the scalar must be smaller than the order of `P`, which must
+/// be odd and larger than 3. Then every addition is between points with different x-coordinates:
+/// with the prefix `k` of the scalar bits, `2k + 1` is at most the scalar, so `2k * P` is neither
+/// `P` nor `-P`. For other scalars an addition may have equal x-coordinates. Such an instruction
+/// fails to execute, and the AIR does not accept any result for it.
+///
+/// Every instruction takes exactly `8 * SCALAR_BLOCKS * BLOCK_SIZE` rows.
+#[derive(Clone)]
+pub struct EcScalarMulAir<const BLOCKS: usize, const SCALAR_BLOCKS: usize, const BLOCK_SIZE: usize>
+{
+    pub adapter:
+        Rv32VecHeapTwoReadsAdapterAir<BLOCKS, SCALAR_BLOCKS, BLOCKS, BLOCK_SIZE, BLOCK_SIZE>,
+    /// See [ec_scalar_mul_step_expr].
+    pub expr: FieldExpr,
+    pub offset: usize,
+}
+
+/// Columns of [EcScalarMulAir] after the adapter and the [FieldExpr] columns.
+struct EcScalarMulCols<'a, T> {
+    /// Whether this is the first row of an instruction.
+    is_first: &'a T,
+    /// Index of the row within the instruction.
+    row_idx: &'a T,
+    /// Whether the output of this row is the identity.
+    out_inf: &'a T,
+    /// The scalar read by the adapter, copied to every row.
+    scalar: &'a [T],
+    /// The result written by the adapter, copied to every row.
+    result: &'a [T],
+    /// Little-endian bits of the scalar, shifted up by `row_idx` positions.
+    bits: &'a [T],
+}
+
+impl<const BLOCKS: usize, const SCALAR_BLOCKS: usize, const BLOCK_SIZE: usize>
+    EcScalarMulAir<BLOCKS, SCALAR_BLOCKS, BLOCK_SIZE>
+{
+    pub fn new(
+        adapter: Rv32VecHeapTwoReadsAdapterAir<
+            BLOCKS,
+            SCALAR_BLOCKS,
+            BLOCKS,
+            BLOCK_SIZE,
+            BLOCK_SIZE,
+        >,
+        config: ExprBuilderConfig,
+        range_bus: VariableRangeCheckerBus,
+        offset: usize,
+        a: BigUint,
+    ) -> Self {
+        assert_eq!(2 * config.num_limbs, BLOCKS * BLOCK_SIZE);
+        let expr = ec_scalar_mul_step_expr(config, range_bus, a);
+        Self {
+            adapter,
+            expr,
+            offset,
+        }
+    }
+
+    /// Number of limbs of a coordinate.
+    pub fn num_limbs(&self) -> usize {
+        BLOCKS * BLOCK_SIZE / 2
+    }
+
+    /// Number of bytes of the scalar.
+    pub fn scalar_bytes(&self) -> usize {
+        SCALAR_BLOCKS * BLOCK_SIZE
+    }
+
+    /// Number of scalar bits, which is also the number of rows per instruction.
+    pub fn num_bits(&self) -> usize {
+        self.scalar_bytes() * 8
+    }
+
+    fn control_width(&self) -> usize {
+        3 + self.scalar_bytes() + 2 * self.num_limbs() + self.num_bits()
+    }
+
+    fn load_cols<'a, T>(&self, core: &'a [T]) -> EcScalarMulCols<'a, T> {
+        let (is_first, rest) = core.split_first().unwrap();
+        let (row_idx, rest) = rest.split_first().unwrap();
+        let (out_inf, rest) = rest.split_first().unwrap();
+        let (scalar, rest) = rest.split_at(self.scalar_bytes());
+        let (result, bits) = rest.split_at(2 * self.num_limbs());
+        assert_eq!(bits.len(), self.num_bits());
+        EcScalarMulCols {
+            is_first,
+            row_idx,
+            out_inf,
+            scalar,
+            result,
+            bits,
+        }
+    }
+}
+
+impl<F: Field, const BLOCKS: usize, const SCALAR_BLOCKS: usize, const BLOCK_SIZE: usize> BaseAir<F>
+    for EcScalarMulAir<BLOCKS, SCALAR_BLOCKS, BLOCK_SIZE>
+{
+    fn width(&self) -> usize {
+        BaseAir::<F>::width(&self.adapter) + BaseAir::<F>::width(&self.expr) + self.control_width()
+    }
+}
+
+impl<F: Field, const BLOCKS: usize, const SCALAR_BLOCKS: usize, const BLOCK_SIZE: usize>
+    BaseAirWithPublicValues<F> for EcScalarMulAir<BLOCKS, SCALAR_BLOCKS, BLOCK_SIZE>
+{
+}
+impl<F: Field, const BLOCKS: usize, const SCALAR_BLOCKS: usize, const BLOCK_SIZE: usize>
+    PartitionedBaseAir<F> for EcScalarMulAir<BLOCKS, SCALAR_BLOCKS, BLOCK_SIZE>
+{
+}
+
+impl<
+        AB: InteractionBuilder,
+        const BLOCKS: usize,
+        const SCALAR_BLOCKS: usize,
+        const BLOCK_SIZE: usize,
+    > Air<AB> for EcScalarMulAir<BLOCKS, SCALAR_BLOCKS, BLOCK_SIZE>
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &[AB::Var] = (*local).borrow();
+        let next: &[AB::Var] = (*next).borrow();
+
+        let adapter_width = BaseAir::<AB::F>::width(&self.adapter);
+        let expr_width = BaseAir::<AB::F>::width(&self.expr);
+        let (local_adapter, local) = local.split_at(adapter_width);
+        let (local_expr, local) = local.split_at(expr_width);
+        let (next_expr, next) = next[adapter_width..].split_at(expr_width);
+
+        SubAir::eval(&self.expr, builder, local_expr);
+
+        let FieldExprCols {
+            is_valid,
+            inputs,
+            vars,
+            flags,
+            ..
+        } = self.expr.load_vars(local_expr);
+        let FieldExprCols {
+            is_valid: next_is_valid,
+            inputs: next_inputs,
+            flags: next_flags,
+            ..
+        } = self.expr.load_vars(next_expr);
+        let (is_inf, bit) = (flags[0], flags[1]);
+        let point = [&inputs[2], &inputs[3]].map(|x| x.as_slice()).concat();
+        let out = self
+            .expr
+            .output_indices
+            .iter()
+            .flat_map(|&i| vars[i].iter().copied())
+            .collect_vec();
+        let local = self.load_cols(local);
+        let next = self.load_cols(next);
+        let num_bits = self.num_bits();
+
+        builder.assert_bool(*local.is_first);
+        builder.when(*local.is_first).assert_one(is_valid);
+        // The current scalar bit is the top one.
+        builder.assert_eq(bit, local.bits[num_bits - 1]);
+        builder.assert_eq(*local.out_inf, is_inf * (AB::Expr::ONE - bit));
+
+        // The first row starts from the identity and decomposes the scalar into bits.
+        {
+            let mut when_first = builder.when(*local.is_first);
+            when_first.assert_zero(*local.row_idx);
+            when_first.assert_one(is_inf);
+            for &limb in inputs[0].iter().chain(inputs[1].iter()) {
+                when_first.assert_zero(limb);
+            }
+            for &bit in local.bits {
+                when_first.assert_bool(bit);
+            }
+            for (&limb, bits) in local.scalar.iter().zip_eq(local.bits.chunks_exact(8)) {
+                let byte = bits
+                    .iter()
+                    .rev()
+                    .fold(AB::Expr::ZERO, |acc, &bit| acc * AB::Expr::TWO + bit.into());
+                when_first.assert_eq(limb, byte);
+            }
+        }
+
+        // Whether the next row belongs to the same instruction.
+        let is_continued: AB::Expr = next_is_valid - *next.is_first;
+        {
+            let mut when_continued = builder.when(is_continued.clone());
+            when_continued.assert_one(is_valid);
+            when_continued.assert_eq(*next.row_idx, *local.row_idx + AB::Expr::ONE);
+            when_continued.assert_eq(next_flags[0], *local.out_inf);
+            let next_acc = next_inputs[0].iter().chain(next_inputs[1].iter());
+            for (&next_acc, &out) in next_acc.zip_eq(out.iter()) {
+                when_continued.assert_eq(next_acc, out);
+            }
+            let next_point = next_inputs[2].iter().chain(next_inputs[3].iter());
+            for (&next_point, &point) in next_point.zip_eq(point.iter()) {
+                when_continued.assert_eq(next_point, point);
+            }
+            for (&next_limb, &limb) in next.scalar.iter().zip_eq(local.scalar.iter()) {
+                when_continued.assert_eq(next_limb, limb);
+            }
+            for (&next_limb, &limb) in next.result.iter().zip_eq(local.result.iter()) {
+                when_continued.assert_eq(next_limb, limb);
+            }
+            when_continued.assert_zero(next.bits[0]);
+            for (&next_bit, &bit) in next.bits[1..].iter().zip_eq(&local.bits[..num_bits - 1]) {
+                when_continued.assert_eq(next_bit, bit);
+            }
+        }
+
+        // The last row has processed every bit and holds the result, which is `(0, 0)` for the
+        // identity.
+        {
+            let mut when_last = builder.when(is_valid - is_continued);
+            when_last.assert_eq(*local.row_idx, AB::Expr::from_canonical_usize(num_bits - 1));
+            for (&limb, &out) in local.result.iter().zip_eq(out.iter()) {
+                when_last.assert_eq(limb, out * (AB::Expr::ONE - *local.out_inf));
+            }
+        }
+
+        let reads = (
+            from_fn(|i| from_fn(|j| point[i * BLOCK_SIZE + j].into())),
+            from_fn(|i| from_fn(|j| local.scalar[i * BLOCK_SIZE + j].into())),
+        );
+        let writes = from_fn(|i| from_fn(|j| local.result[i * BLOCK_SIZE + j].into()));
+        let ctx: AdapterAirContext<
+            _,
+            VecHeapTwoReadsAdapterInterface<
+                _,
+                BLOCKS,
+                SCALAR_BLOCKS,
+                BLOCKS,
+                BLOCK_SIZE,
+                BLOCK_SIZE,
+            >,
+        > = AdapterAirContext {
+            to_pc: None,
+            reads,
+            writes,
+            instruction: MinimalInstruction {
+                is_valid: (*local.is_first).into(),
+                opcode: AB::Expr::from_canonical_usize(
+                    Rv32WeierstrassOpcode::EC_SCALAR_MUL as usize + self.offset,
+                ),
+            },
+        };
+        self.adapter.eval(builder, local_adapter, ctx);
+    }
+}
+
+pub struct EcScalarMulRecord<
+    F: Field,
+    const BLOCKS: usize,
+    const SCALAR_BLOCKS: usize,
+    const BLOCK_SIZE: usize,
+> {
+    pub read_record: Rv32VecHeapTwoReadsReadRecord<F, BLOCKS, SCALAR_BLOCKS, BLOCK_SIZE>,
+    pub write_record: Rv32VecHeapTwoReadsWriteRecord<F, BLOCKS, BLOCK_SIZE>,
+    pub point: [BigUint; 2],
+    pub scalar: Vec<u32>,
+}
+
+pub struct EcScalarMulChip<
+    F: PrimeField32,
+    const BLOCKS: usize,
+    const SCALAR_BLOCKS: usize,
+    const BLOCK_SIZE: usize,
+> {
+    pub air: EcScalarMulAir<BLOCKS, SCALAR_BLOCKS, BLOCK_SIZE>,
+    pub adapter:
+        Rv32VecHeapTwoReadsAdapterChip<F, BLOCKS, SCALAR_BLOCKS, BLOCKS, BLOCK_SIZE, BLOCK_SIZE>,
+    pub range_checker: Arc<VariableRangeCheckerChip>,
+    pub records: Vec<EcScalarMulRecord<F, BLOCKS, SCALAR_BLOCKS, BLOCK_SIZE>>,
+    memory: MemoryControllerRef<F>,
+}
+
+impl<F: PrimeField32, const BLOCKS: usize, const SCALAR_BLOCKS: usize, const BLOCK_SIZE: usize>
+    EcScalarMulChip<F, BLOCKS, SCALAR_BLOCKS, BLOCK_SIZE>
+{
+    pub fn new(
+        adapter: Rv32VecHeapTwoReadsAdapterChip<
+            F,
+            BLOCKS,
+            SCALAR_BLOCKS,
+            BLOCKS,
+            BLOCK_SIZE,
+            BLOCK_SIZE,
+        >,
+        memory_controller: MemoryControllerRef<F>,
+        config: ExprBuilderConfig,
+        offset: usize,
+        a: BigUint,
+    ) -> Self {
+        let range_checker = RefCell::borrow(&memory_controller).range_checker.clone();
+        let air = EcScalarMulAir::new(adapter.air, config, range_checker.bus(), offset, a);
+        Self {
+            air,
+            adapter,
+            range_checker,
+            records: vec![],
+            memory: memory_controller,
+        }
+    }
+
+    /// Scalar bits from the most significant one, which is the order of the rows.
+    fn bits(scalar: &[u32]) -> impl Iterator<Item = bool> + '_ {
+        scalar
+            .iter()
+            .rev()
+            .flat_map(|&byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+    }
+}
+
+impl<F: PrimeField32, const BLOCKS: usize, const SCALAR_BLOCKS: usize, const BLOCK_SIZE: usize>
+    InstructionExecutor<F> for EcScalarMulChip<F, BLOCKS, SCALAR_BLOCKS, BLOCK_SIZE>
+{
+    fn execute(
+        &mut self,
+        instruction: Instruction<F>,
+        from_state: ExecutionState<u32>,
+    ) -> Result<ExecutionState<u32>> {
+        let local_opcode_idx = instruction.opcode.local_opcode_idx(self.air.offset);
+        assert_eq!(
+            Rv32WeierstrassOpcode::from_usize(local_opcode_idx),
+            Rv32WeierstrassOpcode::EC_SCALAR_MUL
+        );
+        let num_limbs = self.air.num_limbs();
+        let limb_bits = self.air.expr.canonical_limb_bits();
+        let mut memory: RefMut<MemoryController<F>> = self.memory.borrow_mut();
+        let (reads, read_record) = self.adapter.preprocess(&mut memory, &instruction)?;
+        let (point, scalar): ([[F; BLOCK_SIZE]; BLOCKS], [[F; BLOCK_SIZE]; SCALAR_BLOCKS]) = reads;
+        let point = point
+            .into_iter()
+            .flatten()
+            .map(|x| x.as_canonical_u32())
+            .collect_vec();
+        let point = [&point[..num_limbs], &point[num_limbs..]]
+            .map(|coord| limbs_to_biguint(coord, limb_bits));
+        let scalar = scalar
+            .into_iter()
+            .flatten()
+            .map(|x| x.as_canonical_u32())
+            .collect_vec();
+
+        let mut acc = vec![BigUint::zero(), BigUint::zero()];
+        let mut is_inf = true;
+        for bit in Self::bits(&scalar) {
+            let inputs = [acc, point.to_vec()].concat();
+            if bit && !is_inf {
+                // The addition is undefined if the doubled accumulator has the same x-coordinate
+                // as the point, which can only happen if the scalar is not smaller than its order.
+                let doubled = self
+                    .air
+                    .expr
+                    .execute_with_output(inputs.clone(), vec![false, false]);
+                if doubled[0] == point[0] {
+                    return Err(ExecutionError::Fail { pc: from_state.pc });
+                }
+            }
+            acc = self.air.expr.execute_with_output(inputs, vec![is_inf, bit]);
+            is_inf &= !bit;
+        }
+        if is_inf {
+            acc = vec![BigUint::zero(), BigUint::zero()];
+        }
+        tracing::trace!("EcScalarMulOpcode | {acc:?} | {point:?} | {scalar:?}");
+
+        let result = acc
+            .into_iter()
+            .flat_map(|coord| biguint_to_limbs_vec(coord, limb_bits, num_limbs))
+            .collect_vec();
+        let writes = from_fn(|i| from_fn(|j| F::from_canonical_u32(result[i * BLOCK_SIZE + j])));
+        let (to_state, write_record) = self.adapter.postprocess(
+            &mut memory,
+            &instruction,
+            from_state,
+            AdapterRuntimeContext::without_pc(writes),
+            &read_record,
+        )?;
+        self.records.push(EcScalarMulRecord {
+            read_record,
+            write_record,
+            point,
+            scalar,
+        });
+        Ok(to_state)
+    }
+
+    fn get_opcode_name(&self, _opcode: usize) -> String {
+        "EcScalarMul".to_string()
+    }
+}
+
+impl<
+        SC: StarkGenericConfig,
+        const BLOCKS: usize,
+        const SCALAR_BLOCKS: usize,
+        const BLOCK_SIZE: usize,
+    > Chip<SC> for EcScalarMulChip<Val<SC>, BLOCKS, SCALAR_BLOCKS, BLOCK_SIZE>
+where
+    Val<SC>: PrimeField32,
+{
+    fn air(&self) -> Arc<dyn AnyRap<SC>> {
+        Arc::new(self.air.clone())
+    }
+
+    fn generate_air_proof_input(self) -> AirProofInput<SC> {
+        let air = self.air();
+        let width = self.trace_width();
+        let height = next_power_of_two_or_zero(self.current_trace_height());
+        let num_bits = self.air.num_bits();
+        let adapter_width = BaseAir::<Val<SC>>::width(&self.air.adapter);
+        let expr_width = BaseAir::<Val<SC>>::width(&self.air.expr);
+        let mut values = Val::<SC>::zero_vec(height * width);
+
+        let memory_aux_cols_factory = RefCell::borrow(&self.memory).aux_cols_factory();
+        // This zip only goes through records.
+        // The padding rows after the last instruction are filled with zeros.
+        values
+            .par_chunks_mut(width * num_bits)
+            .zip(self.records.into_par_iter())
+            .for_each(|(rows, record)| {
+                let bits = Self::bits(&record.scalar).collect_vec();
+                let result = record
+                    .write_record
+                    .writes
+                    .iter()
+                    .flat_map(|write| write.data)
+                    .collect_vec();
+                self.adapter.generate_trace_row(
+                    &mut rows[..adapter_width],
+                    record.read_record,
+                    record.write_record,
+                    &memory_aux_cols_factory,
+                );
+
+                let mut acc = vec![BigUint::zero(), BigUint::zero()];
+                let mut is_inf = true;
+                for (row_idx, row) in rows.chunks_exact_mut(width).enumerate() {
+                    let (expr_row, row) = row[adapter_width..].split_at_mut(expr_width);
+                    let bit = bits[row_idx];
+                    row[0] = Val::<SC>::from_bool(row_idx == 0);
+                    row[1] = Val::<SC>::from_canonical_usize(row_idx);
+                    row[2] = Val::<SC>::from_bool(is_inf && !bit);
+                    let (scalar_row, row) = row[3..].split_at_mut(self.air.scalar_bytes());
+                    let (result_row, shifted_bits) = row.split_at_mut(2 * self.air.num_limbs());
+                    for (limb, &byte) in scalar_row.iter_mut().zip_eq(record.scalar.iter()) {
+                        *limb = Val::<SC>::from_canonical_u32(byte);
+                    }
+                    result_row.copy_from_slice(&result);
+                    // The bits which are not processed yet, from the least significant one.
+                    for (i, &bit) in bits[row_idx..].iter().rev().enumerate() {
+                        shifted_bits[i + row_idx] = Val::<SC>::from_bool(bit);
+                    }
+
+                    let inputs = [acc, record.point.to_vec()].concat();
+                    let flags = vec![is_inf, bit];
+                    acc = self
+                        .air
+                        .expr
+                        .execute_with_output(inputs.clone(), flags.clone());
+                    is_inf &= !bit;
+                    self.air
+                        .expr
+                        .generate_subrow((&self.range_checker, inputs, flags), expr_row);
+                }
+            });
+
+        let trace = RowMajorMatrix::new(values, width);
+        AirProofInput::simple_no_pis(air, trace)
+    }
+}
+
+impl<F: PrimeField32, const BLOCKS: usize, const SCALAR_BLOCKS: usize, const BLOCK_SIZE: usize>
+    ChipUsageGetter for EcScalarMulChip<F, BLOCKS, SCALAR_BLOCKS, BLOCK_SIZE>
+{
+    fn air_name(&self) -> String {
+        get_air_name(&self.air)
+    }
+
+    fn current_trace_height(&self) -> usize {
+        self.records.len() * self.air.num_bits()
+    }
+
+    fn trace_width(&self) -> usize {
+        BaseAir::<F>::width(&self.air)
+    }
+}
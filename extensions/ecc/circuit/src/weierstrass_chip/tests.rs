@@ -1,10 +1,12 @@
 use std::{str::FromStr, sync::Arc};
 
 use num_bigint_dig::BigUint;
-use num_traits::{FromPrimitive, Num, Zero};
-use openvm_circuit::arch::{testing::VmChipTestBuilder, BITWISE_OP_LOOKUP_BUS};
+use num_traits::{FromPrimitive, Num, One, Zero};
+use openvm_circuit::arch::{
+    testing::VmChipTestBuilder, ExecutionState, InstructionExecutor, BITWISE_OP_LOOKUP_BUS,
+};
 use openvm_circuit_primitives::{
-    bigint::utils::{secp256k1_coord_prime, secp256r1_coord_prime},
+    bigint::utils::{secp256k1_coord_prime, secp256k1_scalar_prime, secp256r1_coord_prime},
     bitwise_op_lookup::{BitwiseOperationLookupBus, BitwiseOperationLookupChip},
 };
 use openvm_ecc_transpiler::Rv32WeierstrassOpcode;
@@ -12,11 +14,13 @@ use openvm_instructions::{riscv::RV32_CELL_BITS, UsizeOpcode};
 use openvm_mod_circuit_builder::{
     test_utils::biguint_to_limbs, ExprBuilderConfig, FieldExpressionCoreChip,
 };
-use openvm_rv32_adapters::{rv32_write_heap_default, Rv32VecHeapAdapterChip};
+use openvm_rv32_adapters::{
+    rv32_write_heap_default, Rv32VecHeapAdapterChip, Rv32VecHeapTwoReadsAdapterChip,
+};
 use openvm_stark_backend::p3_field::AbstractField;
 use openvm_stark_sdk::p3_baby_bear::BabyBear;
 
-use super::{EcAddNeChip, EcDoubleChip};
+use super::{EcAddNeChip, EcDoubleChip, EcScalarMulChip};
 
 const NUM_LIMBS: usize = 32;
 const LIMB_BITS: usize = 8;
@@ -302,3 +306,156 @@ fn test_p256_double() {
 
     tester.simple_test().expect("Verification failed");
 }
+
+/// Double-and-add over affine coordinates of a curve with `a = 0`, with `None` as the identity.
+fn scalar_mul_ref(
+    point: (BigUint, BigUint),
+    scalar: &BigUint,
+    modulus: &BigUint,
+) -> Option<(BigUint, BigUint)> {
+    let inv = |x: &BigUint| x.modpow(&(modulus - BigUint::from(2u8)), modulus);
+    let add = |p1: &(BigUint, BigUint), p2: &(BigUint, BigUint)| {
+        let lambda = if p1 == p2 {
+            BigUint::from(3u8) * &p1.0 * &p1.0 * inv(&(BigUint::from(2u8) * &p1.1))
+        } else {
+            (modulus + &p2.1 - &p1.1) * inv(&((modulus + &p2.0 - &p1.0) % modulus))
+        } % modulus;
+        let x3 = (&lambda * &lambda + modulus * BigUint::from(2u8) - &p1.0 - &p2.0) % modulus;
+        let y3 = (lambda * (modulus + &p1.0 - &x3) + modulus - &p1.1) % modulus;
+        (x3, y3)
+    };
+    let mut acc: Option<(BigUint, BigUint)> = None;
+    for i in (0..scalar.bits()).rev() {
+        acc = acc.map(|acc| add(&acc, &acc));
+        if (scalar >> i) & BigUint::one() == BigUint::one() {
+            acc = Some(acc.map_or_else(|| point.clone(), |acc| add(&acc, &point)));
+        }
+    }
+    acc
+}
+
+#[test]
+fn test_scalar_mul() {
+    let mut tester: VmChipTestBuilder<F> = VmChipTestBuilder::default();
+    let config = ExprBuilderConfig {
+        modulus: secp256k1_coord_prime(),
+        num_limbs: NUM_LIMBS,
+        limb_bits: LIMB_BITS,
+    };
+    let bitwise_bus = BitwiseOperationLookupBus::new(BITWISE_OP_LOOKUP_BUS);
+    let bitwise_chip = Arc::new(BitwiseOperationLookupChip::<RV32_CELL_BITS>::new(
+        bitwise_bus,
+    ));
+    let adapter = Rv32VecHeapTwoReadsAdapterChip::<F, 2, 1, 2, BLOCK_SIZE, BLOCK_SIZE>::new(
+        tester.execution_bus(),
+        tester.program_bus(),
+        tester.memory_controller(),
+        bitwise_chip.clone(),
+    );
+    let mut chip = EcScalarMulChip::new(
+        adapter,
+        tester.memory_controller(),
+        config,
+        Rv32WeierstrassOpcode::default_offset(),
+        BigUint::zero(),
+    );
+    assert_eq!(chip.air.num_bits(), 256);
+
+    let modulus = secp256k1_coord_prime();
+    let (p_x, p_y) = SampleEcPoints[1].clone();
+    let p_x_limbs =
+        biguint_to_limbs::<NUM_LIMBS>(p_x.clone(), LIMB_BITS).map(BabyBear::from_canonical_u32);
+    let p_y_limbs =
+        biguint_to_limbs::<NUM_LIMBS>(p_y.clone(), LIMB_BITS).map(BabyBear::from_canonical_u32);
+    let scalars = [
+        BigUint::from_u8(2).unwrap(),
+        BigUint::zero(),
+        BigUint::one(),
+        BigUint::from_str_radix(
+            "c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+            16,
+        )
+        .unwrap(),
+    ];
+    for scalar in scalars.iter() {
+        let scalar_limbs = biguint_to_limbs::<NUM_LIMBS>(scalar.clone(), LIMB_BITS)
+            .map(BabyBear::from_canonical_u32);
+        let instruction = rv32_write_heap_default(
+            &mut tester,
+            vec![p_x_limbs, p_y_limbs],
+            vec![scalar_limbs],
+            chip.air.offset + Rv32WeierstrassOpcode::EC_SCALAR_MUL as usize,
+        );
+        tester.execute(&mut chip, instruction);
+    }
+
+    // 2 * P is the double of P from the sample points, and 0 * P is written as (0, 0).
+    let expected = scalars.iter().map(|scalar| {
+        scalar_mul_ref((p_x.clone(), p_y.clone()), scalar, &modulus)
+            .unwrap_or((BigUint::zero(), BigUint::zero()))
+    });
+    assert_eq!(
+        scalar_mul_ref((p_x.clone(), p_y.clone()), &scalars[0], &modulus),
+        Some(SampleEcPoints[3].clone())
+    );
+    for (record, (x, y)) in chip.records.iter().zip(expected) {
+        let result = record
+            .write_record
+            .writes
+            .iter()
+            .flat_map(|write| write.data)
+            .collect::<Vec<_>>();
+        let expected = [x, y].map(|coord| {
+            biguint_to_limbs::<NUM_LIMBS>(coord, LIMB_BITS).map(BabyBear::from_canonical_u32)
+        });
+        assert_eq!(result, expected.concat());
+    }
+
+    let tester = tester.build().load(chip).load(bitwise_chip).finalize();
+    tester.simple_test().expect("Verification failed");
+}
+
+#[test]
+fn test_scalar_mul_not_reduced() {
+    let mut tester: VmChipTestBuilder<F> = VmChipTestBuilder::default();
+    let config = ExprBuilderConfig {
+        modulus: secp256k1_coord_prime(),
+        num_limbs: NUM_LIMBS,
+        limb_bits: LIMB_BITS,
+    };
+    let bitwise_bus = BitwiseOperationLookupBus::new(BITWISE_OP_LOOKUP_BUS);
+    let bitwise_chip = Arc::new(BitwiseOperationLookupChip::<RV32_CELL_BITS>::new(
+        bitwise_bus,
+    ));
+    let adapter = Rv32VecHeapTwoReadsAdapterChip::<F, 2, 1, 2, BLOCK_SIZE, BLOCK_SIZE>::new(
+        tester.execution_bus(),
+        tester.program_bus(),
+        tester.memory_controller(),
+        bitwise_chip.clone(),
+    );
+    let mut chip = EcScalarMulChip::new(
+        adapter,
+        tester.memory_controller(),
+        config,
+        Rv32WeierstrassOpcode::default_offset(),
+        BigUint::zero(),
+    );
+
+    // With the group order as scalar, the last step adds P to 2 * ((n - 1) / 2) * P = -P.
+    let [p_x, p_y, scalar] = [
+        SampleEcPoints[1].0.clone(),
+        SampleEcPoints[1].1.clone(),
+        secp256k1_scalar_prime(),
+    ]
+    .map(|x| biguint_to_limbs::<NUM_LIMBS>(x, LIMB_BITS).map(BabyBear::from_canonical_u32));
+    let instruction = rv32_write_heap_default(
+        &mut tester,
+        vec![p_x, p_y],
+        vec![scalar],
+        chip.air.offset + Rv32WeierstrassOpcode::EC_SCALAR_MUL as usize,
+    );
+    let timestamp = tester.memory_controller().borrow().timestamp();
+    assert!(chip
+        .execute(instruction, ExecutionState::new(0, timestamp))
+        .is_err());
+}
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use derive_more::derive::From;
 use num_bigint_dig::BigUint;
-use num_traits::{FromPrimitive, Zero};
+use num_traits::{FromPrimitive, Num, Zero};
 use once_cell::sync::Lazy;
 use openvm_circuit::{
     arch::{SystemPort, VmExtension, VmInventory, VmInventoryBuilder, VmInventoryError},
@@ -13,17 +13,20 @@ use openvm_circuit_primitives::bitwise_op_lookup::{
     BitwiseOperationLookupBus, BitwiseOperationLookupChip,
 };
 use openvm_circuit_primitives_derive::{Chip, ChipUsageGetter};
-use openvm_ecc_guest::k256::{SECP256K1_MODULUS, SECP256K1_ORDER};
+use openvm_ecc_guest::{
+    k256::{SECP256K1_MODULUS, SECP256K1_ORDER},
+    p256::{P256_MODULUS, P256_ORDER},
+};
 use openvm_ecc_transpiler::{EccPhantom, Rv32WeierstrassOpcode};
 use openvm_instructions::{PhantomDiscriminant, UsizeOpcode, VmOpcode};
 use openvm_mod_circuit_builder::ExprBuilderConfig;
-use openvm_rv32_adapters::Rv32VecHeapAdapterChip;
+use openvm_rv32_adapters::{Rv32VecHeapAdapterChip, Rv32VecHeapTwoReadsAdapterChip};
 use openvm_stark_backend::p3_field::PrimeField32;
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 use strum::EnumCount;
 
-use super::{EcAddNeChip, EcDoubleChip};
+use super::{EcAddNeChip, EcDoubleChip, EcScalarMulChip};
 
 #[serde_as]
 #[derive(Clone, Debug, derive_new::new, Serialize, Deserialize)]
@@ -49,6 +52,17 @@ pub static SECP256K1_CONFIG: Lazy<CurveConfig> = Lazy::new(|| CurveConfig {
     b: BigUint::from_u8(7u8).unwrap(),
 });
 
+pub static P256_CONFIG: Lazy<CurveConfig> = Lazy::new(|| CurveConfig {
+    modulus: P256_MODULUS.clone(),
+    scalar: P256_ORDER.clone(),
+    a: P256_MODULUS.clone() - BigUint::from_u8(3u8).unwrap(),
+    b: BigUint::from_str_radix(
+        "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+        16,
+    )
+    .unwrap(),
+});
+
 #[derive(Clone, Debug, derive_new::new, Serialize, Deserialize)]
 pub struct WeierstrassExtension {
     pub supported_curves: Vec<CurveConfig>,
@@ -59,9 +73,11 @@ pub enum WeierstrassExtensionExecutor<F: PrimeField32> {
     // 32 limbs prime
     EcAddNeRv32_32(EcAddNeChip<F, 2, 32>),
     EcDoubleRv32_32(EcDoubleChip<F, 2, 32>),
+    EcScalarMulRv32_32(EcScalarMulChip<F, 2, 1, 32>),
     // 48 limbs prime
     EcAddNeRv32_48(EcAddNeChip<F, 6, 16>),
     EcDoubleRv32_48(EcDoubleChip<F, 6, 16>),
+    EcScalarMulRv32_48(EcScalarMulChip<F, 6, 2, 16>),
 }
 
 #[derive(ChipUsageGetter, Chip, AnyEnum, From)]
@@ -99,6 +115,7 @@ impl<F: PrimeField32> VmExtension<F> for WeierstrassExtension {
             ..=(Rv32WeierstrassOpcode::SETUP_EC_ADD_NE as usize);
         let ec_double_opcodes = (Rv32WeierstrassOpcode::EC_DOUBLE as usize)
             ..=(Rv32WeierstrassOpcode::SETUP_EC_DOUBLE as usize);
+        let ec_scalar_mul_opcode = Rv32WeierstrassOpcode::EC_SCALAR_MUL as usize;
 
         for (i, curve) in self.supported_curves.iter().enumerate() {
             let class_offset =
@@ -151,6 +168,23 @@ impl<F: PrimeField32> VmExtension<F> for WeierstrassExtension {
                         .clone()
                         .map(|x| VmOpcode::from_usize(x + class_offset)),
                 )?;
+                // The scalar is read as 32 bytes regardless of the coordinate size.
+                let scalar_mul_chip = EcScalarMulChip::new(
+                    Rv32VecHeapTwoReadsAdapterChip::<F, 2, 1, 2, 32, 32>::new(
+                        execution_bus,
+                        program_bus,
+                        memory_controller.clone(),
+                        bitwise_lu_chip.clone(),
+                    ),
+                    memory_controller.clone(),
+                    config32.clone(),
+                    class_offset,
+                    curve.a.clone(),
+                );
+                inventory.add_executor(
+                    WeierstrassExtensionExecutor::EcScalarMulRv32_32(scalar_mul_chip),
+                    [VmOpcode::from_usize(ec_scalar_mul_opcode + class_offset)],
+                )?;
             } else if bytes <= 48 {
                 let add_ne_chip = EcAddNeChip::new(
                     Rv32VecHeapAdapterChip::<F, 2, 6, 6, 16, 16>::new(
@@ -187,6 +221,22 @@ impl<F: PrimeField32> VmExtension<F> for WeierstrassExtension {
                         .clone()
                         .map(|x| VmOpcode::from_usize(x + class_offset)),
                 )?;
+                let scalar_mul_chip = EcScalarMulChip::new(
+                    Rv32VecHeapTwoReadsAdapterChip::<F, 6, 2, 6, 16, 16>::new(
+                        execution_bus,
+                        program_bus,
+                        memory_controller.clone(),
+                        bitwise_lu_chip.clone(),
+                    ),
+                    memory_controller.clone(),
+                    config48.clone(),
+                    class_offset,
+                    curve.a.clone(),
+                );
+                inventory.add_executor(
+                    WeierstrassExtensionExecutor::EcScalarMulRv32_48(scalar_mul_chip),
+                    [VmOpcode::from_usize(ec_scalar_mul_opcode + class_offset)],
+                )?;
             } else {
                 panic!("Modulus too large");
             }
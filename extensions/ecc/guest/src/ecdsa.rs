@@ -1,8 +1,12 @@
+use alloc::vec::Vec;
 use core::ops::{Add, AddAssign, Mul};
 
-use ecdsa::{self, hazmat::bits2field, Error, RecoveryId, Result};
-use elliptic_curve::PrimeCurve;
-use openvm_algebra_guest::{DivUnsafe, IntMod, Reduce};
+use ecdsa::{
+    self, hazmat::bits2field, signature::hazmat::PrehashVerifier, Error, RecoveryId, Result,
+    Signature, SignatureSize,
+};
+use elliptic_curve::{generic_array::ArrayLength, PrimeCurve};
+use openvm_algebra_guest::{DivUnsafe, IntMod, Reduce, Sqrt};
 
 use crate::{
     weierstrass::{IntrinsicCurve, WeierstrassPoint},
@@ -25,15 +29,130 @@ pub struct PublicKey<C: IntrinsicCurve> {
     point: <C as IntrinsicCurve>::Point,
 }
 
+/// SEC1 tag of a compressed point with even `y` coordinate.
+const SEC1_TAG_COMPRESSED_EVEN_Y: u8 = 0x02;
+/// SEC1 tag of a compressed point with odd `y` coordinate.
+const SEC1_TAG_COMPRESSED_ODD_Y: u8 = 0x03;
+/// SEC1 tag of an uncompressed point.
+const SEC1_TAG_UNCOMPRESSED: u8 = 0x04;
+
 impl<C: IntrinsicCurve> PublicKey<C> {
     pub fn into_inner(self) -> <C as IntrinsicCurve>::Point {
         self.point
     }
+
+    pub fn as_affine(&self) -> &<C as IntrinsicCurve>::Point {
+        &self.point
+    }
+
+    /// Decodes a public key from its SEC1 encoding, either compressed or uncompressed.
+    /// Ref: <https://www.secg.org/sec1-v2.pdf#subsubsection.2.3.4>
+    ///
+    /// Returns an error if the encoding is malformed, the coordinates are not canonical,
+    /// the point is not on the curve, or the point is the identity. Unlike
+    /// [WeierstrassPoint::decompress], this does not panic on a compressed encoding whose `x` is
+    /// not the `x`-coordinate of any point, since the `y` coordinate is computed with [Sqrt],
+    /// which checks its hint in both cases.
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self>
+    where
+        Coordinate<C>: Sqrt,
+        for<'a> &'a Coordinate<C>: Mul<&'a Coordinate<C>, Output = Coordinate<C>>,
+    {
+        let num_bytes = Coordinate::<C>::NUM_LIMBS;
+        let (&tag, coords) = bytes.split_first().ok_or_else(Error::new)?;
+        let point = match tag {
+            SEC1_TAG_COMPRESSED_EVEN_Y | SEC1_TAG_COMPRESSED_ODD_Y => {
+                if coords.len() != num_bytes {
+                    return Err(Error::new());
+                }
+                let x = coord_from_be_bytes::<C>(coords)?;
+                let rhs = &(&x * &x) * &x
+                    + &(&<C::Point as WeierstrassPoint>::CURVE_A * &x)
+                    + &<C::Point as WeierstrassPoint>::CURVE_B;
+                let mut y = rhs.sqrt().ok_or_else(Error::new)?;
+                // The hinted root may not be reduced, which is needed to check the parity.
+                y.reduce();
+                if (y.as_le_bytes()[0] ^ tag) & 1 == 1 {
+                    // There is no point with `y = 0` and an odd `y` coordinate.
+                    if y == Coordinate::<C>::ZERO {
+                        return Err(Error::new());
+                    }
+                    y.neg_assign();
+                }
+                C::Point::from_xy_unchecked(x, y)
+            }
+            SEC1_TAG_UNCOMPRESSED => {
+                if coords.len() != 2 * num_bytes {
+                    return Err(Error::new());
+                }
+                let (x_be, y_be) = coords.split_at(num_bytes);
+                let x = coord_from_be_bytes::<C>(x_be)?;
+                let y = coord_from_be_bytes::<C>(y_be)?;
+                C::Point::from_xy_nonidentity(x, y).ok_or_else(Error::new)?
+            }
+            // This includes the SEC1 encoding of the identity, which is never a valid public key.
+            _ => return Err(Error::new()),
+        };
+        Ok(Self { point })
+    }
+
+    /// Encodes the public key in SEC1 format, using the compressed form if `compress` is true.
+    /// Ref: <https://www.secg.org/sec1-v2.pdf#subsubsection.2.3.3>
+    pub fn to_sec1_bytes(&self, compress: bool) -> Vec<u8> {
+        let x = self.point.x();
+        let y = self.point.y();
+        // Must assert unique so the encoding and the parity of `y` are canonical
+        x.assert_unique();
+        y.assert_unique();
+        let num_bytes = Coordinate::<C>::NUM_LIMBS;
+        if compress {
+            let mut bytes = Vec::with_capacity(1 + num_bytes);
+            bytes.push(SEC1_TAG_COMPRESSED_EVEN_Y | (y.as_le_bytes()[0] & 1));
+            bytes.extend_from_slice(x.to_be_bytes().as_ref());
+            bytes
+        } else {
+            let mut bytes = Vec::with_capacity(1 + 2 * num_bytes);
+            bytes.push(SEC1_TAG_UNCOMPRESSED);
+            bytes.extend_from_slice(x.to_be_bytes().as_ref());
+            bytes.extend_from_slice(y.to_be_bytes().as_ref());
+            bytes
+        }
+    }
 }
 
 impl<C: IntrinsicCurve> VerifyingKey<C> {
+    /// Constructs a verifying key from the affine public key point.
+    ///
+    /// Returns an error if the point is not on the curve, which includes the identity point.
+    pub fn from_affine(point: <C as IntrinsicCurve>::Point) -> Result<Self>
+    where
+        for<'a> &'a Coordinate<C>: Mul<&'a Coordinate<C>, Output = Coordinate<C>>,
+    {
+        let (x, y) = point.into_coords();
+        let point = C::Point::from_xy_nonidentity(x, y).ok_or_else(Error::new)?;
+        Ok(Self {
+            inner: PublicKey { point },
+        })
+    }
+
+    /// Decodes a verifying key from its SEC1 encoding. See [PublicKey::from_sec1_bytes].
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self>
+    where
+        Coordinate<C>: Sqrt,
+        for<'a> &'a Coordinate<C>: Mul<&'a Coordinate<C>, Output = Coordinate<C>>,
+    {
+        Ok(Self {
+            inner: PublicKey::from_sec1_bytes(bytes)?,
+        })
+    }
+
+    /// Encodes the verifying key in SEC1 format. See [PublicKey::to_sec1_bytes].
+    pub fn to_sec1_bytes(&self, compress: bool) -> Vec<u8> {
+        self.inner.to_sec1_bytes(compress)
+    }
+
     pub fn as_affine(&self) -> &<C as IntrinsicCurve>::Point {
-        &self.inner.point
+        self.inner.as_affine()
     }
 }
 
@@ -89,15 +208,21 @@ where
         let neg_u1 = z.div_unsafe(&r);
         let u2 = s.div_unsafe(&r);
         let NEG_G = C::Point::NEG_GENERATOR;
-        let point = <C as IntrinsicCurve>::msm(&[neg_u1, u2], &[NEG_G, R]);
+        // Two scalar multiplications are cheaper than a two-base MSM, since each one is a single
+        // instruction.
+        let point = &C::scalar_mul(&neg_u1, &NEG_G) + &C::scalar_mul(&u2, &R);
         let public_key = PublicKey { point };
 
         VerifyingKey { inner: public_key }
     }
 
-    // Ref: https://docs.rs/ecdsa/latest/src/ecdsa/hazmat.rs.html#270
+    /// Verifies the signature `sig`, encoded as big endian `r || s`, of the message digest
+    /// `prehash` against this public key.
+    /// Ref: <https://docs.rs/ecdsa/latest/src/ecdsa/hazmat.rs.html#270>
+    ///
+    /// Returns an error if the signature is malformed or invalid.
     #[allow(non_snake_case)]
-    pub fn verify_prehashed(self, prehash: &[u8], sig: &[u8]) -> Result<()>
+    pub fn verify_prehashed(&self, prehash: &[u8], sig: &[u8]) -> Result<()>
     where
         for<'a> &'a C::Point: Add<&'a C::Point, Output = C::Point>,
         for<'a> &'a Scalar<C>: DivUnsafe<&'a Scalar<C>, Output = Scalar<C>>,
@@ -105,7 +230,9 @@ where
         // This should get compiled out:
         assert!(Scalar::<C>::NUM_LIMBS <= Coordinate::<C>::NUM_LIMBS);
         // IntMod limbs are currently always bytes
-        assert_eq!(sig.len(), Scalar::<C>::NUM_LIMBS * 2);
+        if sig.len() != Scalar::<C>::NUM_LIMBS * 2 {
+            return Err(Error::new());
+        }
         // Signature is default encoded in big endian bytes
         let (r_be, s_be) = sig.split_at(<C as IntrinsicCurve>::Scalar::NUM_LIMBS);
         // Note: Scalar internally stores using little endian
@@ -113,21 +240,21 @@ where
         let s = Scalar::<C>::from_be_bytes(s_be);
         // The PartialEq implementation of Scalar: IntMod will constrain `r, s`
         // are in the canonical unique form (i.e., less than the modulus).
-        assert_ne!(r, Scalar::<C>::ZERO);
-        assert_ne!(s, Scalar::<C>::ZERO);
+        if r == Scalar::<C>::ZERO || s == Scalar::<C>::ZERO {
+            return Err(Error::new());
+        }
 
         // TODO: don't use bits2field from ::ecdsa
-        let z = <C as IntrinsicCurve>::Scalar::from_be_bytes(
-            bits2field::<C>(prehash).unwrap().as_ref(),
-        );
+        let z = <C as IntrinsicCurve>::Scalar::from_be_bytes(bits2field::<C>(prehash)?.as_ref());
 
         let u1 = z.div_unsafe(&s);
         let u2 = (&r).div_unsafe(&s);
 
         let G = C::Point::GENERATOR;
         // public key
-        let Q = self.inner.point;
-        let R = <C as IntrinsicCurve>::msm(&[u1, u2], &[G, Q]);
+        let Q = self.inner.point.clone();
+        // See `recover_from_prehash_noverify` for why there is no MSM.
+        let R = &C::scalar_mul(&u1, &G) + &C::scalar_mul(&u2, &Q);
         if R.is_identity() {
             return Err(Error::new());
         }
@@ -140,3 +267,33 @@ where
         }
     }
 }
+
+/// Compatibility with [PrehashVerifier] so code written against the RustCrypto `ecdsa` traits
+/// can use [VerifyingKey] unchanged.
+impl<C> PrehashVerifier<Signature<C>> for VerifyingKey<C>
+where
+    C: PrimeCurve + IntrinsicCurve,
+    SignatureSize<C>: ArrayLength<u8>,
+    for<'a> &'a C::Point: Add<&'a C::Point, Output = C::Point>,
+    for<'a> &'a Scalar<C>: DivUnsafe<&'a Scalar<C>, Output = Scalar<C>>,
+{
+    fn verify_prehash(&self, prehash: &[u8], signature: &Signature<C>) -> Result<()> {
+        self.verify_prehashed(prehash, &signature.to_bytes())
+    }
+}
+
+/// Parses a big endian coordinate, returning an error if it is not less than the modulus.
+fn coord_from_be_bytes<C: IntrinsicCurve>(bytes_be: &[u8]) -> Result<Coordinate<C>> {
+    let modulus = Coordinate::<C>::MODULUS;
+    // `MODULUS` is little endian
+    for (b, m) in bytes_be.iter().zip(modulus.as_ref().iter().rev()) {
+        if b != m {
+            return if b < m {
+                Ok(Coordinate::<C>::from_be_bytes(bytes_be))
+            } else {
+                Err(Error::new())
+            };
+        }
+    }
+    Err(Error::new())
+}
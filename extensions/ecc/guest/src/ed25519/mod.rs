@@ -0,0 +1,169 @@
+use ecdsa::{Error, Result};
+use hex_literal::hex;
+#[cfg(not(target_os = "zkvm"))]
+use lazy_static::lazy_static;
+#[cfg(not(target_os = "zkvm"))]
+use num_bigint_dig::BigUint;
+use openvm_algebra_guest::{IntMod, Reduce};
+use sha2::{Digest, Sha512};
+
+use super::group::CyclicGroup;
+use crate::msm;
+
+#[cfg(not(target_os = "zkvm"))]
+lazy_static! {
+    pub static ref ED25519_MODULUS: BigUint = BigUint::from_bytes_be(&hex!(
+        "7FFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFED"
+    ));
+    pub static ref ED25519_ORDER: BigUint = BigUint::from_bytes_be(&hex!(
+        "10000000 00000000 00000000 00000000 14DEF9DE A2F79CD6 5812631A 5CF5D3ED"
+    ));
+}
+
+pub const ED25519_NUM_LIMBS: usize = 32;
+pub const ED25519_LIMB_BITS: usize = 8;
+pub const ED25519_BLOCK_SIZE: usize = 32;
+
+openvm_algebra_moduli_setup::moduli_declare! {
+    Ed25519Coord { modulus = "0x7FFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFFF FFFFFFED" },
+    Ed25519Scalar { modulus = "0x10000000 00000000 00000000 00000000 14DEF9DE A2F79CD6 5812631A 5CF5D3ED" },
+}
+
+/// `a = -1`
+const CURVE_A: Ed25519Coord = Ed25519Coord::from_const_bytes(hex!(
+    "ECFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF7F"
+));
+/// `d = -121665 / 121666`
+const CURVE_D: Ed25519Coord = Ed25519Coord::from_const_bytes(hex!(
+    "A3785913CA4DEB75ABD841414D0A700098E879777940C78C73FE6F2BEE6C0352"
+));
+/// A square root of `-1`, i.e. `2^((p - 1) / 4)`.
+const SQRT_M1: Ed25519Coord = Ed25519Coord::from_const_bytes(hex!(
+    "B0A00E4A271BEEC478E42FAD0618432FA7D7FB3D99004D2B0BDFC14F8024832B"
+));
+/// The exponent `(p - 5) / 8` in big endian.
+const P_MINUS_5_DIV_8_BE: [u8; 32] =
+    hex!("0FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFD");
+
+openvm_ecc_te_setup::te_declare! {
+    Ed25519Point { mod_type = Ed25519Coord, a = CURVE_A, d = CURVE_D },
+}
+
+impl CyclicGroup for Ed25519Point {
+    const GENERATOR: Self = Ed25519Point {
+        x: Ed25519Coord::from_const_bytes(hex!(
+            "1AD5258F602D56C9B2A7259560C72C695CDCD6FD31E2A4C0FE536ECDD3366921"
+        )),
+        y: Ed25519Coord::from_const_bytes(hex!(
+            "5866666666666666666666666666666666666666666666666666666666666666"
+        )),
+    };
+    const NEG_GENERATOR: Self = Ed25519Point {
+        x: Ed25519Coord::from_const_bytes(hex!(
+            "D32ADA709FD2A9364D58DA6A9F38D396A3232902CE1D5B3F01AC91322CC9965E"
+        )),
+        y: Ed25519Coord::from_const_bytes(hex!(
+            "5866666666666666666666666666666666666666666666666666666666666666"
+        )),
+    };
+}
+
+impl Ed25519Point {
+    /// Decodes a point from its 32 byte encoding as defined in
+    /// [RFC 8032, Section 5.1.3](https://datatracker.ietf.org/doc/html/rfc8032#section-5.1.3).
+    ///
+    /// Returns `None` if the `y` coordinate is not canonical or the encoding does not
+    /// correspond to a point on the curve.
+    pub fn from_compressed(bytes: &[u8; 32]) -> Option<Self> {
+        let x_is_odd = bytes[31] >> 7;
+        let mut y_bytes = *bytes;
+        y_bytes[31] &= 0x7f;
+        if !is_canonical(&y_bytes, Ed25519Coord::MODULUS.as_ref()) {
+            return None;
+        }
+        let y = Ed25519Coord::from_le_bytes(&y_bytes);
+
+        // x^2 = u / v where u = y^2 - 1 and v = d y^2 + 1.
+        let y2 = &y * &y;
+        let u = &y2 - &Ed25519Coord::ONE;
+        let v = &(&y2 * &CURVE_D) + &Ed25519Coord::ONE;
+        // Candidate root x = u v^3 (u v^7)^((p - 5) / 8).
+        let v3 = &(&v * &v) * &v;
+        let uv7 = &(&(&v3 * &v3) * &v) * &u;
+        let mut x = &(&u * &v3) * &pow_be(&uv7, &P_MINUS_5_DIV_8_BE);
+
+        let vx2 = &(&x * &x) * &v;
+        if vx2 == u {
+            // x is a square root
+        } else if vx2 == -u {
+            x *= &SQRT_M1;
+        } else {
+            return None;
+        }
+
+        if x == Ed25519Coord::ZERO && x_is_odd == 1 {
+            return None;
+        }
+        // Must assert unique so we can check the parity
+        x.assert_unique();
+        if x.as_le_bytes()[0] & 1 != x_is_odd {
+            x.neg_assign();
+        }
+        Some(Ed25519Point { x, y })
+    }
+}
+
+/// Verifies an Ed25519 signature over `msg` as defined in
+/// [RFC 8032, Section 5.1.7](https://datatracker.ietf.org/doc/html/rfc8032#section-5.1.7).
+///
+/// The check `[s]B = R + [k]A` is done without multiplying by the cofactor,
+/// matching the behavior of `ed25519-dalek`'s `verify`.
+pub fn verify(public_key: &[u8; 32], msg: &[u8], signature: &[u8; 64]) -> Result<()> {
+    let a = Ed25519Point::from_compressed(public_key).ok_or_else(Error::new)?;
+    let mut r_bytes = [0u8; 32];
+    r_bytes.copy_from_slice(&signature[..32]);
+    let r = Ed25519Point::from_compressed(&r_bytes).ok_or_else(Error::new)?;
+    let s_bytes = &signature[32..];
+    if !is_canonical(s_bytes, Ed25519Scalar::MODULUS.as_ref()) {
+        return Err(Error::new());
+    }
+    let s = Ed25519Scalar::from_le_bytes(s_bytes);
+
+    let mut hasher = Sha512::new();
+    hasher.update(r_bytes);
+    hasher.update(public_key);
+    hasher.update(msg);
+    let k = Ed25519Scalar::reduce_le_bytes(&hasher.finalize());
+
+    // [s]B - [k]A = R
+    let sb_minus_ka = msm(&[s, k], &[Ed25519Point::GENERATOR, -a]);
+    if sb_minus_ka == r {
+        Ok(())
+    } else {
+        Err(Error::new())
+    }
+}
+
+/// Returns whether the little endian `bytes` are strictly less than the little endian `modulus`.
+fn is_canonical(bytes: &[u8], modulus: &[u8]) -> bool {
+    for (b, m) in bytes.iter().zip(modulus.iter()).rev() {
+        if b != m {
+            return b < m;
+        }
+    }
+    false
+}
+
+/// Square and multiply exponentiation by an exponent in big endian.
+fn pow_be(base: &Ed25519Coord, exp_be: &[u8]) -> Ed25519Coord {
+    let mut res = Ed25519Coord::ONE;
+    for byte in exp_be {
+        for bit in (0..8).rev() {
+            res.square_assign();
+            if (byte >> bit) & 1 == 1 {
+                res *= base;
+            }
+        }
+    }
+    res
+}
@@ -0,0 +1,56 @@
+use core::ops::Mul;
+
+use openvm_algebra_guest::IntMod;
+
+use super::group::Group;
+
+/// Twisted Edwards curve affine point.
+pub trait TwistedEdwardsPoint: Group {
+    /// The `a` coefficient in the twisted Edwards curve equation `a x^2 + y^2 = 1 + d x^2 y^2`.
+    const CURVE_A: Self::Coordinate;
+    /// The `d` coefficient in the twisted Edwards curve equation `a x^2 + y^2 = 1 + d x^2 y^2`.
+    const CURVE_D: Self::Coordinate;
+
+    type Coordinate: IntMod;
+
+    /// The concatenated `x, y` coordinates of the affine point, where
+    /// coordinates are in little endian.
+    ///
+    /// **Warning**: The memory layout of `Self` is expected to pack
+    /// `x` and `y` contigously with no unallocated space in between.
+    fn as_le_bytes(&self) -> &[u8];
+
+    /// Raw constructor without asserting point is on the curve.
+    fn from_xy_unchecked(x: Self::Coordinate, y: Self::Coordinate) -> Self;
+    fn into_coords(self) -> (Self::Coordinate, Self::Coordinate);
+    fn x(&self) -> &Self::Coordinate;
+    fn y(&self) -> &Self::Coordinate;
+    fn x_mut(&mut self) -> &mut Self::Coordinate;
+    fn y_mut(&mut self) -> &mut Self::Coordinate;
+
+    /// Adds two points using the unified twisted Edwards addition law.
+    ///
+    /// The addition law is complete when `a` is a square and `d` is a non-square
+    /// in the coordinate field, in which case it also handles doubling and the identity.
+    /// Hazmat: for other curves, the caller must ensure the denominators
+    /// `1 +- d x1 x2 y1 y2` are non-zero.
+    fn add_impl(p1: &Self, p2: &Self) -> Self;
+    /// Same as [TwistedEdwardsPoint::add_impl] but assigns the result to `self`.
+    fn add_assign_impl(&mut self, p2: &Self);
+
+    /// Constructs a point from its affine coordinates, returning `None` if the point
+    /// is not on the curve.
+    fn from_xy(x: Self::Coordinate, y: Self::Coordinate) -> Option<Self>
+    where
+        for<'a> &'a Self::Coordinate: Mul<&'a Self::Coordinate, Output = Self::Coordinate>,
+    {
+        let x2 = &x * &x;
+        let y2 = &y * &y;
+        let lhs = &Self::CURVE_A * &x2 + &y2;
+        let rhs = &Self::CURVE_D * &(&x2 * &y2) + &Self::Coordinate::ONE;
+        if lhs != rhs {
+            return None;
+        }
+        Some(Self::from_xy_unchecked(x, y))
+    }
+}
@@ -0,0 +1,274 @@
+//! Hashing to elliptic curves as specified in [RFC 9380](https://datatracker.ietf.org/doc/html/rfc9380).
+//!
+//! A curve opts in by implementing [HashToCurve], usually on top of [map_to_curve_sswu] and
+//! [iso_map], or [map_to_curve_svdw] for curves where the simplified SWU map does not apply.
+//! The hash function of `expand_message_xmd` is chosen with the [XmdHash] type parameter of
+//! [hash_to_curve] and [encode_to_curve].
+//!
+//! Square roots are hinted and checked, so the field arithmetic stays on the modular and complex
+//! extension intrinsics. The maps are not constant time, which does not matter in the zkVM.
+
+use alloc::vec::Vec;
+
+use openvm_algebra_guest::{DivUnsafe, Field, IntMod, Reduce, Sqrt};
+
+use crate::Group;
+
+/// Hash function with 32 byte outputs used by [expand_message_xmd].
+pub trait XmdHash {
+    /// The input block size in bytes, `s_in_bytes` in the RFC.
+    const BLOCK_SIZE: usize;
+
+    fn hash(input: &[u8]) -> [u8; 32];
+}
+
+/// Keccak-256, computed with the keccak256 intrinsic.
+#[cfg(feature = "keccak256")]
+pub struct Keccak256;
+
+#[cfg(feature = "keccak256")]
+impl XmdHash for Keccak256 {
+    const BLOCK_SIZE: usize = 136;
+
+    fn hash(input: &[u8]) -> [u8; 32] {
+        openvm_keccak256_guest::keccak256(input)
+    }
+}
+
+/// SHA-256, computed in software. This does not require the SHA-256 extension.
+#[cfg(feature = "sha2")]
+pub struct Sha256;
+
+#[cfg(feature = "sha2")]
+impl XmdHash for Sha256 {
+    const BLOCK_SIZE: usize = 64;
+
+    fn hash(input: &[u8]) -> [u8; 32] {
+        use sha2::Digest;
+        sha2::Sha256::digest(input).into()
+    }
+}
+
+/// A curve with a map from its field to its points, as required by the suites of Section 8.
+pub trait HashToCurve: Group {
+    /// The field that messages are hashed to.
+    type Field: HashToField;
+
+    /// The deterministic map to the curve. The result need not be in the prime order subgroup.
+    fn map_to_curve(u: &Self::Field) -> Self;
+
+    /// Multiplies by the effective cofactor `h_eff` of the suite.
+    fn clear_cofactor(&self) -> Self;
+}
+
+/// Hashes `msg` to a point with the random oracle (`_RO_`) suite of the curve and the domain
+/// separation tag `dst`.
+pub fn hash_to_curve<P: HashToCurve, H: XmdHash>(msg: &[u8], dst: &[u8]) -> P {
+    let u = P::Field::hash_to_field::<H>(msg, dst, 2);
+    (P::map_to_curve(&u[0]) + P::map_to_curve(&u[1])).clear_cofactor()
+}
+
+/// Encodes `msg` as a point with the nonuniform (`_NU_`) suite of the curve and the domain
+/// separation tag `dst`.
+pub fn encode_to_curve<P: HashToCurve, H: XmdHash>(msg: &[u8], dst: &[u8]) -> P {
+    let u = P::Field::hash_to_field::<H>(msg, dst, 1);
+    P::map_to_curve(&u[0]).clear_cofactor()
+}
+
+/// `expand_message_xmd` (Section 5.3.1). Returns `len` uniformly random bytes.
+///
+/// ## Panics
+/// If `len` is larger than `255 * 32` bytes.
+pub fn expand_message_xmd<H: XmdHash>(msg: &[u8], dst: &[u8], len: usize) -> Vec<u8> {
+    const B_IN_BYTES: usize = 32;
+
+    let ell = len.div_ceil(B_IN_BYTES);
+    assert!(ell <= 255, "requested too many bytes");
+    // Tags longer than 255 bytes are hashed first (Section 5.3.3).
+    let oversize_dst;
+    let dst = if dst.len() > 255 {
+        oversize_dst = H::hash(&[b"H2C-OVERSIZE-DST-", dst].concat());
+        &oversize_dst[..]
+    } else {
+        dst
+    };
+    let mut dst_prime = dst.to_vec();
+    dst_prime.push(dst.len() as u8);
+
+    let mut msg_prime = vec![0u8; H::BLOCK_SIZE];
+    msg_prime.extend_from_slice(msg);
+    msg_prime.extend((len as u16).to_be_bytes());
+    msg_prime.push(0);
+    msg_prime.extend_from_slice(&dst_prime);
+    let b_0 = H::hash(&msg_prime);
+
+    let mut b_i = H::hash(&[&b_0[..], &[1], &dst_prime].concat());
+    let mut uniform_bytes = Vec::with_capacity(ell * B_IN_BYTES);
+    uniform_bytes.extend_from_slice(&b_i);
+    for i in 2..=ell {
+        let mut input: Vec<u8> = b_0.iter().zip(&b_i).map(|(b0, bi)| b0 ^ bi).collect();
+        input.push(i as u8);
+        input.extend_from_slice(&dst_prime);
+        b_i = H::hash(&input);
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(len);
+    uniform_bytes
+}
+
+/// Field with the `hash_to_field` function of Section 5.2.
+pub trait HashToField: Sized {
+    /// Hashes `msg` to `count` field elements with [expand_message_xmd].
+    fn hash_to_field<H: XmdHash>(msg: &[u8], dst: &[u8], count: usize) -> Vec<Self>;
+}
+
+impl<F: IntMod + Reduce> HashToField for F {
+    fn hash_to_field<H: XmdHash>(msg: &[u8], dst: &[u8], count: usize) -> Vec<Self> {
+        let len = uniform_bytes_len::<F>();
+        expand_message_xmd::<H>(msg, dst, count * len)
+            .chunks(len)
+            .map(F::reduce_be_bytes)
+            .collect()
+    }
+}
+
+/// The length `L = ceil((ceil(log2(p)) + k) / 8)` of the uniform bytes per element of the prime
+/// field, for the security parameter `k = 128` used by the suites of all curves here.
+fn uniform_bytes_len<F: IntMod>() -> usize {
+    let modulus = F::MODULUS;
+    let modulus = modulus.as_ref();
+    let top = modulus.iter().rposition(|&b| b != 0).unwrap();
+    let bits = 8 * top + (8 - modulus[top].leading_zeros() as usize);
+    (bits + 128).div_ceil(8)
+}
+
+/// Field with the `sgn0` function of Section 4.1.
+pub trait Sgn0 {
+    fn sgn0(&self) -> bool;
+}
+
+impl<F: IntMod> Sgn0 for F {
+    fn sgn0(&self) -> bool {
+        // The parity is only defined for the unique representation.
+        let mut x = self.clone();
+        x.reduce();
+        x.as_le_bytes()[0] & 1 == 1
+    }
+}
+
+/// Parameters of the simplified SWU map to `y^2 = x^3 + A x + B` with `A, B != 0`, with the two
+/// quotients used in the map precomputed.
+pub struct SswuParams<F> {
+    pub a: F,
+    pub b: F,
+    pub z: F,
+    /// `-B / A`
+    pub minus_b_over_a: F,
+    /// `B / (Z A)`
+    pub b_over_za: F,
+}
+
+/// The simplified SWU map (Section 6.6.2).
+pub fn map_to_curve_sswu<F: Field + Sqrt + Sgn0>(u: &F, params: &SswuParams<F>) -> (F, F) {
+    let g = |x: &F| x.clone() * x * x + &(params.a.clone() * x) + &params.b;
+
+    let z_u2 = params.z.clone() * u * u;
+    let tv1 = z_u2.clone() * &z_u2 + &z_u2;
+    let x1 = if tv1 == F::ZERO {
+        params.b_over_za.clone()
+    } else {
+        params.minus_b_over_a.clone() * &(F::ONE + &F::ONE.div_unsafe(&tv1))
+    };
+    // If g(x1) is not a square, then g(x2) = Z^3 u^6 g(x1) is one.
+    let (x, y) = match g(&x1).sqrt() {
+        Some(y1) => (x1, y1),
+        None => {
+            let x2 = z_u2 * &x1;
+            let y2 = g(&x2).sqrt().expect("g(x2) should be a square");
+            (x2, y2)
+        }
+    };
+    fix_sign(u, x, y)
+}
+
+/// Parameters of the Shallue-van de Woestijne map to `y^2 = x^3 + A x + B`, with the constants of
+/// Section 6.6.1 precomputed.
+pub struct SvdwParams<F> {
+    pub a: F,
+    pub b: F,
+    pub z: F,
+    /// `g(Z)`
+    pub c1: F,
+    /// `-Z / 2`
+    pub c2: F,
+    /// `sqrt(-g(Z) * (3 Z^2 + 4 A))` with `sgn0(c3) = 0`
+    pub c3: F,
+    /// `-4 g(Z) / (3 Z^2 + 4 A)`
+    pub c4: F,
+}
+
+/// The Shallue-van de Woestijne map (Section 6.6.1), which applies to all curves.
+pub fn map_to_curve_svdw<F: Field + Sqrt + Sgn0>(u: &F, params: &SvdwParams<F>) -> (F, F) {
+    let g = |x: &F| x.clone() * x * x + &(params.a.clone() * x) + &params.b;
+
+    let tv1 = u.clone() * u * &params.c1;
+    let tv2 = F::ONE + &tv1;
+    let tv1 = F::ONE - &tv1;
+    // inv0(tv1 * tv2)
+    let tv3 = tv1.clone() * &tv2;
+    let tv3 = if tv3 == F::ZERO {
+        F::ZERO
+    } else {
+        F::ONE.div_unsafe(&tv3)
+    };
+    let tv4 = u.clone() * &tv1 * &tv3 * &params.c3;
+    let x1 = params.c2.clone() - &tv4;
+    if let Some(y1) = g(&x1).sqrt() {
+        return fix_sign(u, x1, y1);
+    }
+    let x2 = params.c2.clone() + &tv4;
+    if let Some(y2) = g(&x2).sqrt() {
+        return fix_sign(u, x2, y2);
+    }
+    // One of g(x1), g(x2) and g(x3) is a square.
+    let x3 = tv2.clone() * &tv2 * &tv3;
+    let x3 = x3.clone() * &x3 * &params.c4 + &params.z;
+    let y3 = g(&x3).sqrt().expect("g(x3) should be a square");
+    fix_sign(u, x3, y3)
+}
+
+/// Chooses the square root `y` with the same sign as `u`.
+fn fix_sign<F: Field + Sgn0>(u: &F, x: F, y: F) -> (F, F) {
+    if u.sgn0() != y.sgn0() {
+        (x, -y)
+    } else {
+        (x, y)
+    }
+}
+
+/// Evaluates the isogeny given by the rational maps `x_num / x_den` and `y * y_num / y_den`, with
+/// coefficients from the constant term up, and returns `None` for the identity.
+pub fn iso_map<F: Field>(
+    x: &F,
+    y: &F,
+    x_num: &[F],
+    x_den: &[F],
+    y_num: &[F],
+    y_den: &[F],
+) -> Option<(F, F)> {
+    let eval = |coeffs: &[F]| {
+        coeffs
+            .iter()
+            .rev()
+            .fold(F::ZERO, |acc, coeff| acc * x + coeff)
+    };
+    let x_den = eval(x_den);
+    let y_den = eval(y_den);
+    if x_den == F::ZERO || y_den == F::ZERO {
+        return None;
+    }
+    Some((
+        eval(x_num).div_unsafe(&x_den),
+        y.clone() * &eval(y_num).div_unsafe(&y_den),
+    ))
+}
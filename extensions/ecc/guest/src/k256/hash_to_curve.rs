@@ -0,0 +1,104 @@
+//! Hashing to secp256k1 with the suites `secp256k1_XMD:SHA-256_SSWU_RO_` and
+//! `secp256k1_XMD:SHA-256_SSWU_NU_` of RFC 9380 (Section 8.7), or their Keccak-256 analogues.
+
+use hex_literal::hex;
+
+use super::{Secp256k1Coord, Secp256k1Point};
+use crate::{
+    hash_to_curve::{iso_map, map_to_curve_sswu, HashToCurve, SswuParams},
+    weierstrass::WeierstrassPoint,
+    Group,
+};
+
+impl HashToCurve for Secp256k1Point {
+    type Field = Secp256k1Coord;
+
+    fn map_to_curve(u: &Secp256k1Coord) -> Self {
+        let (x, y) = map_to_curve_sswu(u, &SSWU);
+        match iso_map(&x, &y, &ISO_X_NUM, &ISO_X_DEN, &ISO_Y_NUM, &ISO_Y_DEN) {
+            Some((x, y)) => Self::from_xy_unchecked(x, y),
+            None => Self::IDENTITY,
+        }
+    }
+
+    /// The cofactor is 1.
+    fn clear_cofactor(&self) -> Self {
+        self.clone()
+    }
+}
+
+/// Parameters of the simplified SWU map to the curve `E'`, which is 3-isogenous to secp256k1.
+const SSWU: SswuParams<Secp256k1Coord> = SswuParams {
+    a: Secp256k1Coord::from_const_bytes(hex!(
+        "3345441AC04754405D0E6FCB63D353E972D2F5F058558AA0DC1A66DDAB31873F"
+    )),
+    b: Secp256k1Coord::from_const_bytes(hex!(
+        "EB06000000000000000000000000000000000000000000000000000000000000"
+    )),
+    z: Secp256k1Coord::from_const_bytes(hex!(
+        "24FCFFFFFEFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"
+    )),
+    minus_b_over_a: Secp256k1Coord::from_const_bytes(hex!(
+        "C345238D8ED501AAFD742DE898FFF46D0C7BE8D3F54D0CA0B2388571EE6CC50B"
+    )),
+    b_over_za: Secp256k1Coord::from_const_bytes(hex!(
+        "910349DE97CD8B6C8BAD32723C175C7E752215591607A46BCA90DD38447E40BB"
+    )),
+};
+
+/// Coefficients of the numerator of the `x`-coordinate of the 3-isogeny `E' -> E`, from the constant term up.
+const ISO_X_NUM: [Secp256k1Coord; 4] = [
+    Secp256k1Coord::from_const_bytes(hex!(
+        "C7A8AAAA8DE3388EE3388EE3388EE3388EE3388EE3388EE3388EE3388EE3388E"
+    )),
+    Secp256k1Coord::from_const_bytes(hex!(
+        "81657CF14410FFDF923BF60BFCD295D5C544FDA7CE15F3B9D521C30BC8D4D307"
+    )),
+    Secp256k1Coord::from_const_bytes(hex!(
+        "62D29D3DB5D0CB4E31407C03446150E4ECCA25CADE13A4E2E634F2238D324C53"
+    )),
+    Secp256k1Coord::from_const_bytes(hex!(
+        "8CA8AAAA8DE3388EE3388EE3388EE3388EE3388EE3388EE3388EE3388EE3388E"
+    )),
+];
+
+/// Coefficients of the denominator of the `x`-coordinate of the 3-isogeny `E' -> E`, from the constant term up.
+const ISO_X_DEN: [Secp256k1Coord; 3] = [
+    Secp256k1Coord::from_const_bytes(hex!(
+        "9BB41E7845B7E69F7D48F8429540CD86DD40B6B7CB4CA39C8A91943D197157D3"
+    )),
+    Secp256k1Coord::from_const_bytes(hex!(
+        "146D8C2A61562AC5BB415E1F646BD3065422541BD5B2C4F71DDC8343F6C6ADED"
+    )),
+    Secp256k1Coord::from_const_u8(1),
+];
+
+/// Coefficients of the numerator of the `y`-coordinate of the 3-isogeny `E' -> E`, from the constant term up.
+const ISO_Y_NUM: [Secp256k1Coord; 4] = [
+    Secp256k1Coord::from_const_bytes(hex!(
+        "3CE2388E4B682FA1BD84F612DA4B682FA1BD84F612DA4B682FA1BD84F612DA4B"
+    )),
+    Secp256k1Coord::from_const_bytes(hex!(
+        "A3711D20FC90FCDF6FDA86D646B07A64D5A6A0124BA5D0A90F7CCBD5320C5EC7"
+    )),
+    Secp256k1Coord::from_const_bytes(hex!(
+        "31E9CE9E5AE865A71820BE01A230287276E51265EF095271731AF9914619A629"
+    )),
+    Secp256k1Coord::from_const_bytes(hex!(
+        "848DE3382FA1BD84F612DA4B682FA1BD84F612DA4B682FA1BD84F612DA4B682F"
+    )),
+];
+
+/// Coefficients of the denominator of the `y`-coordinate of the 3-isogeny `E' -> E`, from the constant term up.
+const ISO_Y_DEN: [Secp256k1Coord; 4] = [
+    Secp256k1Coord::from_const_bytes(hex!(
+        "3BF9FFFFFEFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"
+    )),
+    Secp256k1Coord::from_const_bytes(hex!(
+        "73255C68D225B4DF78D9E8C8BFC1679498C2222763E6E9D59FB4BDB84B53067A"
+    )),
+    Secp256k1Coord::from_const_bytes(hex!(
+        "6FA7D2BF9281BFA799620D2F16213D0A7E33FEA83F0CA7F32CCA456571AA8464"
+    )),
+    Secp256k1Coord::from_const_u8(1),
+];
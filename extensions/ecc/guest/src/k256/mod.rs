@@ -5,10 +5,12 @@ use hex_literal::hex;
 use lazy_static::lazy_static;
 #[cfg(not(target_os = "zkvm"))]
 use num_bigint_dig::BigUint;
-use openvm_algebra_guest::IntMod;
+use openvm_algebra_guest::{Field, IntMod};
 
 use super::group::{CyclicGroup, Group};
-use crate::weierstrass::{CachedMulTable, IntrinsicCurve};
+use crate::weierstrass::{prime_order_scalar_mul, CachedMulTable, IntrinsicCurve};
+
+mod hash_to_curve;
 
 #[cfg(not(target_os = "zkvm"))]
 lazy_static! {
@@ -39,6 +41,20 @@ openvm_ecc_sw_setup::sw_declare! {
     Secp256k1Point { mod_type = Secp256k1Coord, b = CURVE_B },
 }
 
+impl Field for Secp256k1Coord {
+    type SelfRef<'a> = &'a Self;
+    const ZERO: Self = <Self as IntMod>::ZERO;
+    const ONE: Self = <Self as IntMod>::ONE;
+
+    fn double_assign(&mut self) {
+        IntMod::double_assign(self);
+    }
+
+    fn square_assign(&mut self) {
+        IntMod::square_assign(self);
+    }
+}
+
 impl CyclicGroup for Secp256k1Point {
     const GENERATOR: Self = Secp256k1Point {
         x: Secp256k1Coord::from_const_bytes(hex!(
@@ -66,6 +82,9 @@ impl IntrinsicCurve for k256::Secp256k1 {
     where
         for<'a> &'a Self::Point: Add<&'a Self::Point, Output = Self::Point>,
     {
+        if let ([coeff], [base]) = (coeffs, bases) {
+            return Self::scalar_mul(coeff, base);
+        }
         // heuristic
         if coeffs.len() < 25 {
             let table = CachedMulTable::<Self>::new_with_prime_order(bases, 4);
@@ -74,4 +93,11 @@ impl IntrinsicCurve for k256::Secp256k1 {
             crate::msm(coeffs, bases)
         }
     }
+
+    fn scalar_mul(coeff: &Self::Scalar, base: &Self::Point) -> Self::Point
+    where
+        for<'a> &'a Self::Point: Add<&'a Self::Point, Output = Self::Point>,
+    {
+        prime_order_scalar_mul::<Self>(coeff, base)
+    }
 }
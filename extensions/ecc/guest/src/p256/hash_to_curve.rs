@@ -0,0 +1,40 @@
+//! Hashing to P-256 with the suites `P256_XMD:SHA-256_SSWU_RO_` and `P256_XMD:SHA-256_SSWU_NU_`
+//! of RFC 9380 (Section 8.2), or their Keccak-256 analogues.
+
+use hex_literal::hex;
+
+use super::{P256Coord, P256Point, CURVE_A, CURVE_B};
+use crate::{
+    hash_to_curve::{map_to_curve_sswu, HashToCurve, SswuParams},
+    weierstrass::WeierstrassPoint,
+};
+
+impl HashToCurve for P256Point {
+    type Field = P256Coord;
+
+    /// The simplified SWU map applies directly since `A != 0`.
+    fn map_to_curve(u: &P256Coord) -> Self {
+        let (x, y) = map_to_curve_sswu(u, &SSWU);
+        Self::from_xy_unchecked(x, y)
+    }
+
+    /// The cofactor is 1.
+    fn clear_cofactor(&self) -> Self {
+        self.clone()
+    }
+}
+
+/// Parameters of the simplified SWU map with `Z = -10`.
+const SSWU: SswuParams<P256Coord> = SswuParams {
+    a: CURVE_A,
+    b: CURVE_B,
+    z: P256Coord::from_const_bytes(hex!(
+        "F5FFFFFFFFFFFFFFFFFFFFFF00000000000000000000000001000000FFFFFFFF"
+    )),
+    minus_b_over_a: P256Coord::from_const_bytes(hex!(
+        "6E759B62BFBE4469A7E51B443B025FCC3E82DD7C1C3FF93BF8DB68E347679773"
+    )),
+    b_over_za: P256Coord::from_const_bytes(hex!(
+        "24F275231313BABD90306920A0E66F14D35949D982B9656C99AFBD9686BD28A5"
+    )),
+};
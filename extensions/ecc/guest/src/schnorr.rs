@@ -0,0 +1,162 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use ecdsa::{Error, Result};
+use openvm_algebra_guest::{IntMod, Reduce, Sqrt};
+use openvm_sha256_guest::sha256;
+
+use crate::{
+    k256::{Secp256k1Coord, Secp256k1Point, Secp256k1Scalar},
+    weierstrass::{IntrinsicCurve, WeierstrassPoint},
+    CyclicGroup, Group,
+};
+
+/// Returns the point with `x`-coordinate given by the big endian `x` and an even `y`-coordinate,
+/// as in the `lift_x` function of
+/// [BIP-340](https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki).
+///
+/// Returns `None` if `x` is not less than the field modulus or there is no such point. Unlike
+/// [WeierstrassPoint::decompress], this does not panic, since the square root hint is checked in
+/// both cases.
+pub fn lift_x(x: &[u8; 32]) -> Option<Secp256k1Point> {
+    if !is_canonical_be(x, Secp256k1Coord::MODULUS.as_ref()) {
+        return None;
+    }
+    let x = Secp256k1Coord::from_be_bytes(x);
+    let mut y = (&(&x * &x) * &x + &<Secp256k1Point as WeierstrassPoint>::CURVE_B).sqrt()?;
+    // The hinted root may not be reduced, which is needed to check the parity.
+    y.reduce();
+    if y.as_le_bytes()[0] & 1 == 1 {
+        y.neg_assign();
+    }
+    Some(Secp256k1Point::from_xy_unchecked(x, y))
+}
+
+/// Verifies a BIP-340 Schnorr signature over `msg` with the x-only `public_key`.
+///
+/// The message is not hashed first, so it may have any length as in the current version of
+/// BIP-340.
+pub fn verify(public_key: &[u8; 32], msg: &[u8], signature: &[u8; 64]) -> Result<()> {
+    let p = lift_x(public_key).ok_or_else(Error::new)?;
+    let (r_bytes, s) = split_signature(signature)?;
+    let e = challenge(&r_bytes, public_key, msg);
+
+    // R = [s]G - [e]P
+    let r = ::k256::Secp256k1::msm(&[s, e], &[Secp256k1Point::GENERATOR, -p]);
+    if r.is_identity() || has_odd_y(&r) || r.x() != &Secp256k1Coord::from_be_bytes(&r_bytes) {
+        return Err(Error::new());
+    }
+    Ok(())
+}
+
+/// Verifies the BIP-340 Schnorr signatures `signatures[i]` of `msgs[i]` under `public_keys[i]`
+/// for all `i` at once, by checking a random linear combination of the verification equations
+/// with a single multi-scalar multiplication.
+///
+/// The random coefficients are derived from a hash of all inputs, so they are fixed before the
+/// signatures are combined. An empty batch is valid.
+pub fn batch_verify(
+    public_keys: &[[u8; 32]],
+    msgs: &[&[u8]],
+    signatures: &[[u8; 64]],
+) -> Result<()> {
+    if public_keys.len() != msgs.len() || public_keys.len() != signatures.len() {
+        return Err(Error::new());
+    }
+    if public_keys.is_empty() {
+        return Ok(());
+    }
+    let seed = batch_seed(public_keys, msgs, signatures);
+
+    // [sum a_i s_i]G - sum [a_i e_i]P_i - sum [a_i]R_i = 0, where a_0 = 1.
+    let mut s_sum = Secp256k1Scalar::ZERO;
+    let mut coeffs = vec![Secp256k1Scalar::ZERO];
+    let mut bases = vec![Secp256k1Point::GENERATOR];
+    for (i, ((public_key, msg), signature)) in
+        public_keys.iter().zip(msgs).zip(signatures).enumerate()
+    {
+        let p = lift_x(public_key).ok_or_else(Error::new)?;
+        let (r_bytes, s) = split_signature(signature)?;
+        let r = lift_x(&r_bytes).ok_or_else(Error::new)?;
+        let e = challenge(&r_bytes, public_key, msg);
+
+        let a = if i == 0 {
+            Secp256k1Scalar::ONE
+        } else {
+            let mut input = seed.to_vec();
+            input.extend((i as u32).to_le_bytes());
+            Secp256k1Scalar::reduce_be_bytes(&sha256(&input))
+        };
+        s_sum += &a * &s;
+        coeffs.push(&a * &e);
+        bases.push(-p);
+        coeffs.push(a);
+        bases.push(-r);
+    }
+    coeffs[0] = s_sum;
+
+    if ::k256::Secp256k1::msm(&coeffs, &bases).is_identity() {
+        Ok(())
+    } else {
+        Err(Error::new())
+    }
+}
+
+/// Splits a signature into the big endian `x`-coordinate of `R` and the scalar `s`, and checks
+/// that both are in range.
+fn split_signature(signature: &[u8; 64]) -> Result<([u8; 32], Secp256k1Scalar)> {
+    let (r_bytes, s_bytes) = signature.split_at(32);
+    if !is_canonical_be(r_bytes, Secp256k1Coord::MODULUS.as_ref())
+        || !is_canonical_be(s_bytes, Secp256k1Scalar::MODULUS.as_ref())
+    {
+        return Err(Error::new());
+    }
+    Ok((
+        r_bytes.try_into().unwrap(),
+        Secp256k1Scalar::from_be_bytes(s_bytes),
+    ))
+}
+
+/// The challenge `e = hash_BIP0340/challenge(r || P || m) mod n`.
+fn challenge(r_bytes: &[u8; 32], public_key: &[u8; 32], msg: &[u8]) -> Secp256k1Scalar {
+    Secp256k1Scalar::reduce_be_bytes(&tagged_hash(
+        b"BIP0340/challenge",
+        &[r_bytes, public_key, msg],
+    ))
+}
+
+/// Seeds the coefficients of [batch_verify] with a hash of all inputs.
+fn batch_seed(public_keys: &[[u8; 32]], msgs: &[&[u8]], signatures: &[[u8; 64]]) -> [u8; 32] {
+    let mut transcript = Vec::new();
+    for ((public_key, msg), signature) in public_keys.iter().zip(msgs).zip(signatures) {
+        transcript.extend_from_slice(public_key);
+        transcript.extend_from_slice(signature);
+        transcript.extend((msg.len() as u64).to_le_bytes());
+        transcript.extend_from_slice(msg);
+    }
+    tagged_hash(b"BIP0340/batch", &[&transcript])
+}
+
+/// The tagged hash `SHA256(SHA256(tag) || SHA256(tag) || x)` of BIP-340, computed with the
+/// SHA-256 intrinsic.
+fn tagged_hash(tag: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+    let tag_hash = sha256(tag);
+    let mut input = Vec::with_capacity(64 + parts.iter().map(|part| part.len()).sum::<usize>());
+    input.extend_from_slice(&tag_hash);
+    input.extend_from_slice(&tag_hash);
+    for part in parts {
+        input.extend_from_slice(part);
+    }
+    sha256(&input)
+}
+
+fn has_odd_y(p: &Secp256k1Point) -> bool {
+    let mut y = p.y().clone();
+    y.reduce();
+    y.as_le_bytes()[0] & 1 == 1
+}
+
+/// Returns whether the big endian `bytes` are strictly less than the little endian `modulus`.
+fn is_canonical_be(bytes: &[u8], modulus: &[u8]) -> bool {
+    bytes.iter().cmp(modulus.iter().rev()) == Ordering::Less
+}
@@ -7,6 +7,8 @@ use super::group::{CyclicGroup, Group};
 
 /// Short Weierstrass curve affine point.
 pub trait WeierstrassPoint: Group {
+    /// The `a` coefficient in the Weierstrass curve equation `y^2 = x^3 + a x + b`.
+    const CURVE_A: Self::Coordinate;
     /// The `b` coefficient in the Weierstrass curve equation `y^2 = x^3 + a x + b`.
     const CURVE_B: Self::Coordinate;
 
@@ -35,6 +37,11 @@ pub trait WeierstrassPoint: Group {
     fn double_nonidentity(p: &Self) -> Self;
     /// Hazmat: Assumes self != identity and 2 * self != identity.
     fn double_assign_nonidentity(&mut self);
+    /// Hazmat: Computes `scalar * p`, where `scalar` is a 32 byte little-endian integer, with a
+    /// single intrinsic instruction. Returns the identity if `scalar` is zero.
+    /// Assumes p != identity and `scalar` is less than the order of `p`. Otherwise an intermediate
+    /// addition may be between points with equal x-coordinates, and execution fails.
+    fn scalar_mul_nonidentity(p: &Self, scalar: &[u8]) -> Self;
 
     fn from_xy(x: Self::Coordinate, y: Self::Coordinate) -> Option<Self>
     where
@@ -52,7 +59,7 @@ pub trait WeierstrassPoint: Group {
         for<'a> &'a Self::Coordinate: Mul<&'a Self::Coordinate, Output = Self::Coordinate>,
     {
         let lhs = &y * &y;
-        let rhs = &x * &x * &x + &Self::CURVE_B;
+        let rhs = &x * &x * &x + &(&Self::CURVE_A * &x) + &Self::CURVE_B;
         if lhs != rhs {
             return None;
         }
@@ -106,6 +113,36 @@ pub trait IntrinsicCurve {
     {
         super::msm(coeffs, bases)
     }
+
+    /// Scalar multiplication. The default implementation is correct for any base point.
+    /// Curves of prime order may override it with [prime_order_scalar_mul], which uses a
+    /// single intrinsic instruction.
+    fn scalar_mul(coeff: &Self::Scalar, base: &Self::Point) -> Self::Point
+    where
+        for<'a> &'a Self::Point: Add<&'a Self::Point, Output = Self::Point>,
+    {
+        super::msm(core::slice::from_ref(coeff), core::slice::from_ref(base))
+    }
+}
+
+/// Scalar multiplication, computed by a single intrinsic instruction if `Scalar` is 32 bytes.
+///
+/// Assumes that the order of `base` is at least the modulus of `C::Scalar`, which holds for
+/// every point on a curve of prime order. On curves with a cofactor, a point outside the
+/// prime order subgroup can make execution fail.
+pub fn prime_order_scalar_mul<C: IntrinsicCurve>(coeff: &C::Scalar, base: &C::Point) -> C::Point
+where
+    for<'a> &'a C::Point: Add<&'a C::Point, Output = C::Point>,
+{
+    if C::Scalar::NUM_LIMBS != 32 {
+        return super::msm(core::slice::from_ref(coeff), core::slice::from_ref(base));
+    }
+    if base.is_identity() {
+        return C::Point::IDENTITY;
+    }
+    // The instruction requires the scalar to be less than the order of the base.
+    coeff.assert_unique();
+    C::Point::scalar_mul_nonidentity(base, coeff.as_le_bytes())
 }
 
 // MSM using preprocessed table (windowed method)
@@ -13,10 +13,13 @@ use syn::{
 /// Usage:
 /// ```
 /// sw_declare! {
-///     [TODO]
+///     Secp256k1Point { mod_type = Secp256k1Coord, b = CURVE_B },
+///     P256Point { mod_type = P256Coord, a = CURVE_A, b = CURVE_B },
 /// }
 /// ```
 ///
+/// The `a` coefficient is optional and defaults to zero.
+///
 /// For this macro to work, you must import the `elliptic_curve` crate and the `openvm_ecc_guest` crate..
 #[proc_macro]
 pub fn sw_declare(input: TokenStream) -> TokenStream {
@@ -30,6 +33,7 @@ pub fn sw_declare(input: TokenStream) -> TokenStream {
         let struct_name = item.name.to_string();
         let struct_name = syn::Ident::new(&struct_name, span.into());
         let mut intmod_type: Option<syn::Path> = None;
+        let mut const_a: Option<syn::Expr> = None;
         let mut const_b: Option<syn::Expr> = None;
         for param in item.params {
             match param.name.to_string().as_str() {
@@ -42,6 +46,10 @@ pub fn sw_declare(input: TokenStream) -> TokenStream {
                             .into();
                     }
                 }
+                "a" => {
+                    // We currently leave it to the compiler to check if the expression is actually a constant
+                    const_a = Some(param.value);
+                }
                 "b" => {
                     // We currently leave it to the compiler to check if the expression is actually a constant
                     const_b = Some(param.value);
@@ -53,6 +61,9 @@ pub fn sw_declare(input: TokenStream) -> TokenStream {
         }
 
         let intmod_type = intmod_type.expect("mod_type parameter is required");
+        let const_a = const_a.unwrap_or_else(|| {
+            syn::parse_quote! { <#intmod_type as openvm_algebra_guest::IntMod>::ZERO }
+        });
         let const_b = const_b.expect("constant b coefficient is required");
 
         macro_rules! create_extern_func {
@@ -74,12 +85,14 @@ pub fn sw_declare(input: TokenStream) -> TokenStream {
         }
         create_extern_func!(sw_add_ne_extern_func);
         create_extern_func!(sw_double_extern_func);
+        create_extern_func!(sw_scalar_mul_extern_func);
         create_extern_func!(hint_decompress_extern_func);
 
         let result = TokenStream::from(quote::quote_spanned! { span.into() =>
             extern "C" {
                 fn #sw_add_ne_extern_func(rd: usize, rs1: usize, rs2: usize);
                 fn #sw_double_extern_func(rd: usize, rs1: usize);
+                fn #sw_scalar_mul_extern_func(rd: usize, rs1: usize, rs2: usize);
                 fn #hint_decompress_extern_func(rs1: usize, rs2: usize);
             }
 
@@ -153,7 +166,7 @@ pub fn sw_declare(input: TokenStream) -> TokenStream {
                     {
                         use openvm_algebra_guest::DivUnsafe;
                         let two = #intmod_type::from_u8(2);
-                        let lambda = &p.x * &p.x * #intmod_type::from_u8(3).div_unsafe(&p.y * &two);
+                        let lambda = (&p.x * &p.x * #intmod_type::from_u8(3) + &#const_a).div_unsafe(&p.y * &two);
                         let x3 = &lambda * &lambda - &p.x * &two;
                         let y3 = &lambda * &(&p.x - &x3) - &p.y;
                         #struct_name { x: x3, y: y3 }
@@ -177,7 +190,7 @@ pub fn sw_declare(input: TokenStream) -> TokenStream {
                     {
                         use openvm_algebra_guest::DivUnsafe;
                         let two = #intmod_type::from_u8(2);
-                        let lambda = &self.x * &self.x * #intmod_type::from_u8(3).div_unsafe(&self.y * &two);
+                        let lambda = (&self.x * &self.x * #intmod_type::from_u8(3) + &#const_a).div_unsafe(&self.y * &two);
                         let x3 = &lambda * &lambda - &self.x * &two;
                         let y3 = &lambda * &(&self.x - &x3) - &self.y;
                         self.x = x3;
@@ -196,6 +209,7 @@ pub fn sw_declare(input: TokenStream) -> TokenStream {
             }
 
             impl ::openvm_ecc_guest::weierstrass::WeierstrassPoint for #struct_name {
+                const CURVE_A: #intmod_type = #const_a;
                 const CURVE_B: #intmod_type = #const_b;
                 type Coordinate = #intmod_type;
 
@@ -245,6 +259,35 @@ pub fn sw_declare(input: TokenStream) -> TokenStream {
                     Self::double_assign_impl(self);
                 }
 
+                fn scalar_mul_nonidentity(p: &Self, scalar: &[u8]) -> Self {
+                    assert_eq!(scalar.len(), 32);
+                    #[cfg(not(target_os = "zkvm"))]
+                    {
+                        let mut acc = Self::identity();
+                        for byte in scalar.iter().rev() {
+                            for i in (0..8).rev() {
+                                Group::double_assign(&mut acc);
+                                if (byte >> i) & 1 == 1 {
+                                    acc += p;
+                                }
+                            }
+                        }
+                        acc
+                    }
+                    #[cfg(target_os = "zkvm")]
+                    {
+                        let mut uninit: core::mem::MaybeUninit<#struct_name> = core::mem::MaybeUninit::uninit();
+                        unsafe {
+                            #sw_scalar_mul_extern_func(
+                                uninit.as_mut_ptr() as usize,
+                                p as *const #struct_name as usize,
+                                scalar.as_ptr() as usize
+                            )
+                        };
+                        unsafe { uninit.assume_init() }
+                    }
+                }
+
                 fn hint_decompress(x: &Self::Coordinate, rec_id: &u8) -> Self::Coordinate {
                     #[cfg(not(target_os = "zkvm"))]
                     {
@@ -450,6 +493,10 @@ pub fn sw_init(input: TokenStream) -> TokenStream {
             syn::Ident::new(&format!("sw_add_ne_extern_func_{}", str_path), span.into());
         let double_extern_func =
             syn::Ident::new(&format!("sw_double_extern_func_{}", str_path), span.into());
+        let scalar_mul_extern_func = syn::Ident::new(
+            &format!("sw_scalar_mul_extern_func_{}", str_path),
+            span.into(),
+        );
         let hint_decompress_extern_func = syn::Ident::new(
             &format!("hint_decompress_extern_func_{}", str_path),
             span.into(),
@@ -481,6 +528,19 @@ pub fn sw_init(input: TokenStream) -> TokenStream {
                 );
             }
 
+            #[no_mangle]
+            extern "C" fn #scalar_mul_extern_func(rd: usize, rs1: usize, rs2: usize) {
+                openvm_platform::custom_insn_r!(
+                    OPCODE,
+                    SW_FUNCT3 as usize,
+                    SwBaseFunct7::SwScalarMul as usize + #ec_idx
+                        * (SwBaseFunct7::SHORT_WEIERSTRASS_MAX_KINDS as usize),
+                    rd,
+                    rs1,
+                    rs2
+                );
+            }
+
             #[no_mangle]
             extern "C" fn #hint_decompress_extern_func(rs1: usize, rs2: usize) {
                 unsafe {
@@ -0,0 +1,401 @@
+#![feature(proc_macro_diagnostic)]
+
+extern crate proc_macro;
+
+use openvm_macros_common::MacroArgs;
+use proc_macro::TokenStream;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, Expr, ExprPath, Path, Token,
+};
+
+/// This macro generates the code to setup the twisted Edwards curve for a given modular type.
+/// Usage:
+/// ```
+/// te_declare! {
+///     Ed25519Point { mod_type = Ed25519Coord, a = CURVE_A, d = CURVE_D },
+/// }
+/// ```
+///
+/// For this macro to work, you must import the `openvm_algebra_guest` crate and the `openvm_ecc_guest` crate.
+#[proc_macro]
+pub fn te_declare(input: TokenStream) -> TokenStream {
+    let MacroArgs { items } = parse_macro_input!(input as MacroArgs);
+
+    let mut output = Vec::new();
+
+    let span = proc_macro::Span::call_site();
+
+    for item in items.into_iter() {
+        let struct_name = item.name.to_string();
+        let struct_name = syn::Ident::new(&struct_name, span.into());
+        let mut intmod_type: Option<syn::Path> = None;
+        let mut const_a: Option<syn::Expr> = None;
+        let mut const_d: Option<syn::Expr> = None;
+        for param in item.params {
+            match param.name.to_string().as_str() {
+                "mod_type" => {
+                    if let syn::Expr::Path(ExprPath { path, .. }) = param.value {
+                        intmod_type = Some(path)
+                    } else {
+                        return syn::Error::new_spanned(param.value, "Expected a type")
+                            .to_compile_error()
+                            .into();
+                    }
+                }
+                "a" => {
+                    // We currently leave it to the compiler to check if the expression is actually a constant
+                    const_a = Some(param.value);
+                }
+                "d" => {
+                    const_d = Some(param.value);
+                }
+                _ => {
+                    panic!("Unknown parameter {}", param.name);
+                }
+            }
+        }
+
+        let intmod_type = intmod_type.expect("mod_type parameter is required");
+        let const_a = const_a.expect("constant a coefficient is required");
+        let const_d = const_d.expect("constant d coefficient is required");
+
+        let te_add_extern_func = syn::Ident::new(
+            &format!(
+                "te_add_extern_func_{}",
+                intmod_type
+                    .segments
+                    .iter()
+                    .map(|x| x.ident.to_string())
+                    .collect::<Vec<_>>()
+                    .join("_")
+            ),
+            span.into(),
+        );
+
+        let result = TokenStream::from(quote::quote_spanned! { span.into() =>
+            extern "C" {
+                fn #te_add_extern_func(rd: usize, rs1: usize, rs2: usize);
+            }
+
+            #[derive(Eq, PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize)]
+            #[repr(C)]
+            pub struct #struct_name {
+                pub x: #intmod_type,
+                pub y: #intmod_type,
+            }
+
+            impl #struct_name {
+                const fn identity() -> Self {
+                    Self {
+                        x: <#intmod_type as openvm_algebra_guest::IntMod>::ZERO,
+                        y: <#intmod_type as openvm_algebra_guest::IntMod>::ONE,
+                    }
+                }
+                // Below are wrapper functions for the intrinsic instructions.
+                // Should not be called directly.
+                #[inline(always)]
+                fn add_chip(p1: &#struct_name, p2: &#struct_name) -> #struct_name {
+                    #[cfg(not(target_os = "zkvm"))]
+                    {
+                        use openvm_algebra_guest::DivUnsafe;
+                        let x1y2 = &p1.x * &p2.y;
+                        let y1x2 = &p1.y * &p2.x;
+                        let x1x2 = &p1.x * &p2.x;
+                        let y1y2 = &p1.y * &p2.y;
+                        let dxy = &(&x1x2 * &y1y2) * &#const_d;
+                        let one = <#intmod_type as openvm_algebra_guest::IntMod>::ONE;
+                        let x3 = (x1y2 + &y1x2).div_unsafe(&one + &dxy);
+                        let y3 = (y1y2 - &(&x1x2 * &#const_a)).div_unsafe(&one - &dxy);
+                        #struct_name { x: x3, y: y3 }
+                    }
+                    #[cfg(target_os = "zkvm")]
+                    {
+                        let mut uninit: core::mem::MaybeUninit<#struct_name> = core::mem::MaybeUninit::uninit();
+                        unsafe {
+                            #te_add_extern_func(
+                                uninit.as_mut_ptr() as usize,
+                                p1 as *const #struct_name as usize,
+                                p2 as *const #struct_name as usize
+                            )
+                        };
+                        unsafe { uninit.assume_init() }
+                    }
+                }
+
+                #[inline(always)]
+                fn add_assign_chip(&mut self, p2: &#struct_name) {
+                    #[cfg(not(target_os = "zkvm"))]
+                    {
+                        *self = Self::add_chip(self, p2);
+                    }
+                    #[cfg(target_os = "zkvm")]
+                    {
+                        unsafe {
+                            #te_add_extern_func(
+                                self as *mut #struct_name as usize,
+                                self as *const #struct_name as usize,
+                                p2 as *const #struct_name as usize
+                            )
+                        };
+                    }
+                }
+            }
+
+            impl ::openvm_ecc_guest::edwards::TwistedEdwardsPoint for #struct_name {
+                const CURVE_A: #intmod_type = #const_a;
+                const CURVE_D: #intmod_type = #const_d;
+                type Coordinate = #intmod_type;
+
+                /// SAFETY: assumes that #intmod_type has a memory representation
+                /// such that with repr(C), two coordinates are packed contiguously.
+                fn as_le_bytes(&self) -> &[u8] {
+                    unsafe { &*core::ptr::slice_from_raw_parts(self as *const Self as *const u8, <#intmod_type as openvm_algebra_guest::IntMod>::NUM_LIMBS * 2) }
+                }
+
+                fn from_xy_unchecked(x: Self::Coordinate, y: Self::Coordinate) -> Self {
+                    Self { x, y }
+                }
+
+                fn x(&self) -> &Self::Coordinate {
+                    &self.x
+                }
+
+                fn y(&self) -> &Self::Coordinate {
+                    &self.y
+                }
+
+                fn x_mut(&mut self) -> &mut Self::Coordinate {
+                    &mut self.x
+                }
+
+                fn y_mut(&mut self) -> &mut Self::Coordinate {
+                    &mut self.y
+                }
+
+                fn into_coords(self) -> (Self::Coordinate, Self::Coordinate) {
+                    (self.x, self.y)
+                }
+
+                fn add_impl(p1: &Self, p2: &Self) -> Self {
+                    Self::add_chip(p1, p2)
+                }
+
+                fn add_assign_impl(&mut self, p2: &Self) {
+                    Self::add_assign_chip(self, p2);
+                }
+            }
+
+            impl ::openvm_ecc_guest::Group for #struct_name {
+                type SelfRef<'a> = &'a Self;
+
+                const IDENTITY: Self = Self::identity();
+
+                fn is_identity(&self) -> bool {
+                    self.x == <#intmod_type as openvm_algebra_guest::IntMod>::ZERO && self.y == <#intmod_type as openvm_algebra_guest::IntMod>::ONE
+                }
+
+                // The addition law is unified, so doubling is addition with itself.
+                fn double(&self) -> Self {
+                    Self::add_chip(self, self)
+                }
+
+                fn double_assign(&mut self) {
+                    let p = self.clone();
+                    Self::add_assign_chip(self, &p);
+                }
+            }
+
+            impl core::ops::Add<&#struct_name> for #struct_name {
+                type Output = Self;
+
+                fn add(mut self, p2: &#struct_name) -> Self::Output {
+                    self.add_assign_chip(p2);
+                    self
+                }
+            }
+
+            impl core::ops::Add for #struct_name {
+                type Output = Self;
+
+                fn add(self, rhs: Self) -> Self::Output {
+                    self.add(&rhs)
+                }
+            }
+
+            impl core::ops::Add<&#struct_name> for &#struct_name {
+                type Output = #struct_name;
+
+                fn add(self, p2: &#struct_name) -> Self::Output {
+                    #struct_name::add_chip(self, p2)
+                }
+            }
+
+            impl core::ops::AddAssign<&#struct_name> for #struct_name {
+                fn add_assign(&mut self, p2: &#struct_name) {
+                    self.add_assign_chip(p2);
+                }
+            }
+
+            impl core::ops::AddAssign for #struct_name {
+                fn add_assign(&mut self, rhs: Self) {
+                    self.add_assign(&rhs);
+                }
+            }
+
+            impl core::ops::Neg for #struct_name {
+                type Output = Self;
+
+                fn neg(self) -> Self::Output {
+                    Self {
+                        x: -self.x,
+                        y: self.y,
+                    }
+                }
+            }
+
+            impl core::ops::Sub<&#struct_name> for #struct_name {
+                type Output = Self;
+
+                fn sub(self, rhs: &#struct_name) -> Self::Output {
+                    self.sub(rhs.clone())
+                }
+            }
+
+            impl core::ops::Sub for #struct_name {
+                type Output = #struct_name;
+
+                fn sub(self, rhs: Self) -> Self::Output {
+                    self.add(rhs.neg())
+                }
+            }
+
+            impl core::ops::Sub<&#struct_name> for &#struct_name {
+                type Output = #struct_name;
+
+                fn sub(self, p2: &#struct_name) -> Self::Output {
+                    self.add(&p2.clone().neg())
+                }
+            }
+
+            impl core::ops::SubAssign<&#struct_name> for #struct_name {
+                fn sub_assign(&mut self, p2: &#struct_name) {
+                    self.sub_assign(p2.clone());
+                }
+            }
+
+            impl core::ops::SubAssign for #struct_name {
+                fn sub_assign(&mut self, rhs: Self) {
+                    self.add_assign(rhs.neg());
+                }
+            }
+        });
+        output.push(result);
+    }
+
+    TokenStream::from_iter(output)
+}
+
+struct TeDefine {
+    items: Vec<Path>,
+}
+
+impl Parse for TeDefine {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let items = input.parse_terminated(<Expr as Parse>::parse, Token![,])?;
+        Ok(Self {
+            items: items
+                .into_iter()
+                .map(|e| {
+                    if let Expr::Path(p) = e {
+                        p.path
+                    } else {
+                        panic!("expected path");
+                    }
+                })
+                .collect(),
+        })
+    }
+}
+
+#[proc_macro]
+pub fn te_init(input: TokenStream) -> TokenStream {
+    let TeDefine { items } = parse_macro_input!(input as TeDefine);
+
+    let mut externs = Vec::new();
+    let mut setups = Vec::new();
+    let mut setup_all_te_curves = Vec::new();
+
+    let span = proc_macro::Span::call_site();
+
+    for (ec_idx, item) in items.into_iter().enumerate() {
+        let str_path = item
+            .segments
+            .iter()
+            .map(|x| x.ident.to_string())
+            .collect::<Vec<_>>()
+            .join("_");
+        let add_extern_func =
+            syn::Ident::new(&format!("te_add_extern_func_{}", str_path), span.into());
+        externs.push(quote::quote_spanned! { span.into() =>
+            #[no_mangle]
+            extern "C" fn #add_extern_func(rd: usize, rs1: usize, rs2: usize) {
+                openvm_platform::custom_insn_r!(
+                    OPCODE,
+                    TE_FUNCT3 as usize,
+                    TeBaseFunct7::TeAdd as usize + #ec_idx
+                        * (TeBaseFunct7::TWISTED_EDWARDS_MAX_KINDS as usize),
+                    rd,
+                    rs1,
+                    rs2
+                );
+            }
+        });
+
+        let setup_function = syn::Ident::new(&format!("setup_te_{}", str_path), span.into());
+        setups.push(quote::quote_spanned! { span.into() =>
+            #[allow(non_snake_case)]
+            pub fn #setup_function() {
+                #[cfg(target_os = "zkvm")]
+                {
+                    // p1 is (x1, y1), and x1 must be the modulus.
+                    // Since x1 = 0 mod the modulus, the denominators of the addition law
+                    // are 1 and there is no division by zero.
+                    let modulus_bytes = <#item as openvm_algebra_guest::IntMod>::MODULUS;
+                    let mut one = [0u8; <#item as openvm_algebra_guest::IntMod>::NUM_LIMBS];
+                    one[0] = 1;
+                    let p1 = [modulus_bytes.as_ref(), one.as_ref()].concat();
+                    let p2 = [one.as_ref(), one.as_ref()].concat();
+                    let mut uninit: core::mem::MaybeUninit<[#item; 2]> = core::mem::MaybeUninit::uninit();
+                    openvm_platform::custom_insn_r!(
+                        ::openvm_ecc_guest::OPCODE,
+                        ::openvm_ecc_guest::TE_FUNCT3 as usize,
+                        ::openvm_ecc_guest::TeBaseFunct7::TeSetup as usize
+                            + #ec_idx
+                                * (::openvm_ecc_guest::TeBaseFunct7::TWISTED_EDWARDS_MAX_KINDS as usize),
+                        uninit.as_mut_ptr(),
+                        p1.as_ptr(),
+                        p2.as_ptr()
+                    );
+                }
+            }
+        });
+
+        setup_all_te_curves.push(quote::quote_spanned! { span.into() =>
+            #setup_function();
+        });
+    }
+
+    TokenStream::from(quote::quote_spanned! { span.into() =>
+        #[cfg(target_os = "zkvm")]
+        mod openvm_intrinsics_ffi_te {
+            use ::openvm_ecc_guest::{OPCODE, TE_FUNCT3, TeBaseFunct7};
+
+            #(#externs)*
+        }
+        #(#setups)*
+        pub fn setup_all_te_curves() {
+            #(#setup_all_te_curves)*
+        }
+    })
+}
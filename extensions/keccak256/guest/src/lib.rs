@@ -6,6 +6,7 @@ use core::mem::MaybeUninit;
 /// This is custom-0 defined in RISC-V spec document
 pub const OPCODE: u8 = 0x0b;
 pub const FUNCT3: u8 = 0b100;
+pub const FUNCT7: u8 = 0x0;
 
 /// The keccak256 cryptographic hash function.
 #[inline(always)]
@@ -40,7 +41,7 @@ pub fn keccak256(input: &[u8]) -> [u8; 32] {
 #[inline(always)]
 #[no_mangle]
 extern "C" fn native_keccak256(bytes: *const u8, len: usize, output: *mut u8) {
-    openvm_platform::custom_insn_r!(OPCODE, FUNCT3, 0x0, output, bytes, len);
+    openvm_platform::custom_insn_r!(OPCODE, FUNCT3, FUNCT7, output, bytes, len);
 }
 
 /// Sets `output` to the keccak256 hash of `input`.
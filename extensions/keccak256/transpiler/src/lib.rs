@@ -1,6 +1,6 @@
 use openvm_instructions::{instruction::Instruction, UsizeOpcode};
 use openvm_instructions_derive::UsizeOpcode;
-use openvm_keccak256_guest::{FUNCT3, OPCODE};
+use openvm_keccak256_guest::{FUNCT3, FUNCT7, OPCODE};
 use openvm_stark_backend::p3_field::PrimeField32;
 use openvm_transpiler::{util::from_r_type, TranspilerExtension};
 use rrs_lib::instruction_formats::RType;
@@ -31,6 +31,9 @@ impl<F: PrimeField32> TranspilerExtension<F> for Keccak256TranspilerExtension {
             return None;
         }
         let dec_insn = RType::new(instruction_u32);
+        if dec_insn.funct7 != FUNCT7 as u32 {
+            return None;
+        }
         let instruction = from_r_type(
             Rv32KeccakOpcode::KECCAK256.with_default_offset(),
             2,
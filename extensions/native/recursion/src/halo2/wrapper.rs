@@ -1,18 +1,44 @@
 use itertools::Itertools;
 use openvm_stark_backend::p3_util::log2_ceil_usize;
+use revm::{
+    primitives::{CreateScheme, ExecutionResult, Halt, Output, TransactTo, TxEnv},
+    InMemoryDB, EVM,
+};
 use serde::{Deserialize, Serialize};
 use snark_verifier_sdk::{
-    evm::{evm_verify, gen_evm_proof_shplonk, gen_evm_verifier_shplonk},
+    evm::{gen_evm_proof_shplonk, gen_evm_verifier_shplonk},
     halo2::aggregation::{AggregationCircuit, AggregationConfigParams, VerifierUniversality},
-    snark_verifier::halo2_base::{
-        gates::circuit::{
-            CircuitBuilderStage,
-            CircuitBuilderStage::{Keygen, Prover},
+    snark_verifier::{
+        halo2_base::{
+            gates::circuit::{
+                CircuitBuilderStage,
+                CircuitBuilderStage::{Keygen, Prover},
+            },
+            halo2_proofs::{
+                halo2curves::{
+                    bn256::{Bn256, Fq, Fr, G1Affine, G2Prepared},
+                    group::Group,
+                    pairing::{MillerLoopResult, MultiMillerLoop},
+                    CurveAffine,
+                },
+                plonk::{keygen_pk2, verify_proof},
+                poly::{
+                    commitment::{Params, ParamsProver},
+                    kzg::{
+                        commitment::KZGCommitmentScheme, multiopen::VerifierSHPLONK,
+                        strategy::SingleStrategy,
+                    },
+                },
+                transcript::TranscriptReadBuffer,
+            },
         },
-        halo2_proofs::{plonk::keygen_pk2, poly::commitment::Params},
+        loader::{evm::encode_calldata, native::NativeLoader},
+        system::halo2::transcript::evm::EvmTranscript,
+        util::arithmetic::fe_from_limbs,
     },
     CircuitExt, Snark, SHPLONK,
 };
+use thiserror::Error;
 
 use crate::halo2::{
     utils::{Halo2ParamsReader, KZG_PARAMS_FOR_SVK},
@@ -34,6 +60,48 @@ impl From<EvmVerifier> for Vec<u8> {
     }
 }
 
+/// Gas limit of the verification call in [Halo2WrapperProvingKey::evm_verify]. This is the
+/// Ethereum mainnet block gas limit.
+pub const DEFAULT_EVM_VERIFY_GAS_LIMIT: u64 = 30_000_000;
+
+#[derive(Error, Debug)]
+pub enum EvmVerificationError {
+    #[error("malformed calldata: {0}")]
+    MalformedCalldata(String),
+
+    #[error("verifier deployment failed: {0}")]
+    DeploymentFailed(String),
+
+    #[error("out of gas (gas used: {gas_used}, gas limit: {gas_limit})")]
+    OutOfGas { gas_used: u64, gas_limit: u64 },
+
+    #[error("invalid proof: verifier reverted (gas used: {gas_used})")]
+    InvalidProof { gas_used: u64 },
+
+    #[error("verifier halted unexpectedly: {reason} (gas used: {gas_used})")]
+    Halted { reason: String, gas_used: u64 },
+
+    #[error("evm error: {0}")]
+    Evm(String),
+}
+
+#[derive(Error, Debug)]
+pub enum NativeVerificationError {
+    #[error("malformed proof: {0}")]
+    MalformedProof(String),
+
+    #[error("invalid proof: {0}")]
+    InvalidProof(String),
+
+    #[error("invalid accumulator")]
+    InvalidAccumulator,
+}
+
+/// Number of limbs and bits per limb of each accumulator coordinate in the public values of the
+/// wrapper circuit.
+const ACCUMULATOR_LIMBS: usize = 3;
+const ACCUMULATOR_LIMB_BITS: usize = 88;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Halo2WrapperProvingKey {
     pub pinning: Halo2ProvingPinning,
@@ -77,14 +145,153 @@ impl Halo2WrapperProvingKey {
             },
         }
     }
-    /// A helper function for testing to verify the proof of this circuit with evm verifier.
-    // FIXME: the signature is not ideal. It should return an Error instead of panicking when the verification fails.
-    pub fn evm_verify(evm_verifier: &EvmVerifier, evm_proof: &EvmProof) {
-        evm_verify(
-            evm_verifier.0.clone(),
-            evm_proof.instances.clone(),
-            evm_proof.proof.clone(),
+    /// Deploys `evm_verifier` in a local EVM and verifies `evm_proof` with it, using
+    /// [DEFAULT_EVM_VERIFY_GAS_LIMIT] for the verification call. Returns the gas used by the
+    /// verification call on success.
+    pub fn evm_verify(
+        evm_verifier: &EvmVerifier,
+        evm_proof: &EvmProof,
+    ) -> Result<u64, EvmVerificationError> {
+        Self::evm_verify_with_gas_limit(evm_verifier, evm_proof, DEFAULT_EVM_VERIFY_GAS_LIMIT)
+    }
+    /// Same as [Self::evm_verify], but the verification call is limited to `gas_limit`.
+    pub fn evm_verify_with_gas_limit(
+        evm_verifier: &EvmVerifier,
+        evm_proof: &EvmProof,
+        gas_limit: u64,
+    ) -> Result<u64, EvmVerificationError> {
+        // The wrapper circuit has a single instance column and a KZG proof is a sequence of
+        // 32-byte words.
+        if evm_proof.instances.len() != 1 {
+            return Err(EvmVerificationError::MalformedCalldata(format!(
+                "expected 1 instance column, got {}",
+                evm_proof.instances.len()
+            )));
+        }
+        if evm_proof.proof.is_empty() || evm_proof.proof.len() % 32 != 0 {
+            return Err(EvmVerificationError::MalformedCalldata(format!(
+                "proof length {} is not a positive multiple of 32",
+                evm_proof.proof.len()
+            )));
+        }
+        let calldata = encode_calldata(&evm_proof.instances, &evm_proof.proof);
+
+        let mut evm = EVM {
+            env: Default::default(),
+            db: Some(InMemoryDB::default()),
+        };
+        evm.env.tx = TxEnv {
+            gas_limit: u64::MAX,
+            transact_to: TransactTo::Create(CreateScheme::Create),
+            data: evm_verifier.0.clone().into(),
+            ..Default::default()
+        };
+        let result = evm
+            .transact_commit()
+            .map_err(|e| EvmVerificationError::Evm(format!("{e:?}")))?;
+        let contract = match result {
+            ExecutionResult::Success {
+                output: Output::Create(_, Some(contract)),
+                ..
+            } => contract,
+            ExecutionResult::Success { .. } => {
+                return Err(EvmVerificationError::DeploymentFailed(
+                    "no contract address returned".to_string(),
+                ))
+            }
+            ExecutionResult::Revert { gas_used, .. } => {
+                return Err(EvmVerificationError::DeploymentFailed(format!(
+                    "reverted with gas used {gas_used}"
+                )))
+            }
+            ExecutionResult::Halt { reason, gas_used } => {
+                return Err(EvmVerificationError::DeploymentFailed(format!(
+                    "halted with gas used {gas_used} and reason {reason:?}"
+                )))
+            }
+        };
+
+        evm.env.tx = TxEnv {
+            gas_limit,
+            transact_to: TransactTo::Call(contract),
+            data: calldata.into(),
+            ..Default::default()
+        };
+        let result = evm
+            .transact_commit()
+            .map_err(|e| EvmVerificationError::Evm(format!("{e:?}")))?;
+        match result {
+            ExecutionResult::Success { gas_used, .. } => Ok(gas_used),
+            ExecutionResult::Revert { gas_used, .. } => {
+                Err(EvmVerificationError::InvalidProof { gas_used })
+            }
+            ExecutionResult::Halt {
+                reason: Halt::OutOfGas(_),
+                gas_used,
+            } => Err(EvmVerificationError::OutOfGas {
+                gas_used,
+                gas_limit,
+            }),
+            ExecutionResult::Halt { reason, gas_used } => Err(EvmVerificationError::Halted {
+                reason: format!("{reason:?}"),
+                gas_used,
+            }),
+        }
+    }
+    /// Verifies `evm_proof` natively, without an EVM. This does the same checks as the EVM
+    /// verifier generated by [Self::generate_evm_verifier]:
+    /// - the wrapper SNARK verifies against the verifying key of this circuit with the KZG
+    ///   verifier,
+    /// - the KZG accumulator in the first 12 public values passes the pairing check, which
+    ///   verifies the SNARKs aggregated by the wrapper circuit.
+    ///
+    /// `params` must be the params used to generate `evm_proof`.
+    pub fn native_verify(
+        &self,
+        params: &Halo2Params,
+        evm_proof: &EvmProof,
+    ) -> Result<(), NativeVerificationError> {
+        assert_eq!(
+            self.pinning.metadata.config_params.k as u32,
+            params.k(),
+            "Provided params don't match circuit config"
         );
+        let num_pvs = &self.pinning.metadata.num_pvs;
+        let instance_lens = evm_proof.instances.iter().map(|x| x.len()).collect_vec();
+        if &instance_lens != num_pvs {
+            return Err(NativeVerificationError::MalformedProof(format!(
+                "expected instance lengths {num_pvs:?}, got {instance_lens:?}"
+            )));
+        }
+
+        let instances = evm_proof
+            .instances
+            .iter()
+            .map(|x| x.as_slice())
+            .collect_vec();
+        let mut transcript =
+            EvmTranscript::<G1Affine, NativeLoader, _, Vec<u8>>::init(evm_proof.proof.as_slice());
+        verify_proof::<KZGCommitmentScheme<Bn256>, VerifierSHPLONK<'_, Bn256>, _, _, _>(
+            params.verifier_params(),
+            self.pinning.pk.get_vk(),
+            SingleStrategy::new(params),
+            &[instances.as_slice()],
+            &mut transcript,
+        )
+        .map_err(|e| NativeVerificationError::InvalidProof(format!("{e:?}")))?;
+
+        let [lhs, rhs] = decode_accumulator(&evm_proof.instances[0])
+            .ok_or(NativeVerificationError::InvalidAccumulator)?;
+        let g2 = G2Prepared::from(params.g2());
+        let minus_s_g2 = G2Prepared::from(-params.s_g2());
+        let accept = Bn256::multi_miller_loop(&[(&lhs, &g2), (&rhs, &minus_s_g2)])
+            .final_exponentiation()
+            .is_identity();
+        if bool::from(accept) {
+            Ok(())
+        } else {
+            Err(NativeVerificationError::InvalidAccumulator)
+        }
     }
     /// Return deployment code for EVM verifier which can verify the snark of this circuit.
     pub fn generate_evm_verifier(&self, params: &Halo2Params) -> EvmVerifier {
@@ -169,6 +376,28 @@ impl Halo2WrapperProvingKey {
     }
 }
 
+/// Decodes the KZG accumulator `(lhs, rhs)` from the first public values of the wrapper circuit.
+/// The coordinates are laid out as `lhs.x, lhs.y, rhs.x, rhs.y`, each split into
+/// [ACCUMULATOR_LIMBS] limbs of [ACCUMULATOR_LIMB_BITS] bits. Returns `None` if a point is not on
+/// the curve.
+fn decode_accumulator(instances: &[Fr]) -> Option<[G1Affine; 2]> {
+    let coords = instances
+        .chunks_exact(ACCUMULATOR_LIMBS)
+        .take(4)
+        .map(|limbs| {
+            fe_from_limbs::<Fq, Fr, ACCUMULATOR_LIMBS, ACCUMULATOR_LIMB_BITS>(
+                limbs.try_into().unwrap(),
+            )
+        })
+        .collect_vec();
+    if coords.len() != 4 {
+        return None;
+    }
+    let lhs = Option::from(G1Affine::from_xy(coords[0], coords[1]))?;
+    let rhs = Option::from(G1Affine::from_xy(coords[2], coords[3]))?;
+    Some([lhs, rhs])
+}
+
 fn generate_wrapper_circuit_object(
     stage: CircuitBuilderStage,
     k: usize,
@@ -33,21 +33,26 @@ pub struct Rv32PairingConfig {
 }
 
 impl Rv32PairingConfig {
-    pub fn new(curves: Vec<PairingCurve>) -> Self {
-        let mut primes: Vec<_> = curves
-            .iter()
-            .map(|c| c.curve_config().modulus.clone())
+    pub fn new(curves: Vec<PairingCurveParams>) -> Self {
+        let mut primes: Vec<_> = curves.iter().map(|c| c.curve.modulus.clone()).collect();
+        primes.extend(curves.iter().map(|c| c.curve.scalar.clone()));
+        // The complex extension chips are only correct for `u^2 = -1`. Curves with another
+        // non-residue do their `Fp2` arithmetic over the modular chips in the guest.
+        let fp2_curves = curves.iter().filter(|c| c.fp2_non_residue == -1);
+        let mut fp2_primes: Vec<_> = fp2_curves
+            .clone()
+            .map(|c| c.curve.modulus.clone())
             .collect();
-        primes.extend(curves.iter().map(|c| c.curve_config().scalar.clone()));
+        fp2_primes.extend(fp2_curves.map(|c| c.curve.scalar.clone()));
         Self {
             system: SystemConfig::default().with_continuations(),
             base: Default::default(),
             mul: Default::default(),
             io: Default::default(),
             modular: ModularExtension::new(primes.to_vec()),
-            fp2: Fp2Extension::new(primes.to_vec()),
+            fp2: Fp2Extension::new(fp2_primes),
             weierstrass: WeierstrassExtension::new(
-                curves.iter().map(|c| c.curve_config()).collect(),
+                curves.iter().map(|c| c.curve.clone()).collect(),
             ),
             pairing: PairingExtension::new(curves),
         }
@@ -30,10 +30,16 @@ impl<F: PrimeField32, const BLOCKS: usize, const BLOCK_SIZE: usize>
         adapter: Rv32VecHeapAdapterChip<F, 2, BLOCKS, BLOCKS, BLOCK_SIZE, BLOCK_SIZE>,
         memory_controller: MemoryControllerRef<F>,
         config: ExprBuilderConfig,
+        fp2_non_residue: isize,
         xi: [isize; 2],
         offset: usize,
     ) -> Self {
-        let expr = fp12_mul_expr(config, memory_controller.borrow().range_checker.bus(), xi);
+        let expr = fp12_mul_expr(
+            config,
+            memory_controller.borrow().range_checker.bus(),
+            fp2_non_residue,
+            xi,
+        );
         let core = FieldExpressionCoreChip::new(
             expr,
             offset,
@@ -50,14 +56,15 @@ impl<F: PrimeField32, const BLOCKS: usize, const BLOCK_SIZE: usize>
 pub fn fp12_mul_expr(
     config: ExprBuilderConfig,
     range_bus: VariableRangeCheckerBus,
+    fp2_non_residue: isize,
     xi: [isize; 2],
 ) -> FieldExpr {
     config.check_valid();
     let builder = ExprBuilder::new(config, range_bus.range_max_bits);
     let builder = Rc::new(RefCell::new(builder));
 
-    let mut x = Fp12::new(builder.clone());
-    let mut y = Fp12::new(builder.clone());
+    let mut x = Fp12::new_with_non_residue(builder.clone(), fp2_non_residue);
+    let mut y = Fp12::new_with_non_residue(builder.clone(), fp2_non_residue);
     let mut res = x.mul(&mut y, xi);
     res.save_output();
 
@@ -81,7 +88,7 @@ mod tests {
         test_utils::{biguint_to_limbs, bn254_fq12_to_biguint_vec, bn254_fq2_to_biguint_vec},
         ExprBuilderConfig,
     };
-    use openvm_pairing_guest::bn254::{BN254_MODULUS, BN254_XI_ISIZE};
+    use openvm_pairing_guest::bn254::{BN254_FP2_NON_RESIDUE, BN254_MODULUS, BN254_XI_ISIZE};
     use openvm_rv32_adapters::rv32_write_heap_default_with_increment;
     use openvm_stark_backend::p3_field::AbstractField;
     use openvm_stark_sdk::p3_baby_bear::BabyBear;
@@ -118,6 +125,7 @@ mod tests {
             adapter,
             tester.memory_controller(),
             config,
+            BN254_FP2_NON_RESIDUE,
             BN254_XI_ISIZE,
             Fp12Opcode::default_offset(),
         );
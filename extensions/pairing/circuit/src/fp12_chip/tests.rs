@@ -14,10 +14,13 @@ use openvm_mod_circuit_builder::{
 };
 use openvm_pairing_guest::{
     bls12_381::{
-        BLS12_381_BLOCK_SIZE, BLS12_381_LIMB_BITS, BLS12_381_MODULUS, BLS12_381_NUM_LIMBS,
-        BLS12_381_XI_ISIZE,
+        BLS12_381_BLOCK_SIZE, BLS12_381_FP2_NON_RESIDUE, BLS12_381_LIMB_BITS, BLS12_381_MODULUS,
+        BLS12_381_NUM_LIMBS, BLS12_381_XI_ISIZE,
+    },
+    bn254::{
+        BN254_BLOCK_SIZE, BN254_FP2_NON_RESIDUE, BN254_LIMB_BITS, BN254_MODULUS, BN254_NUM_LIMBS,
+        BN254_XI_ISIZE,
     },
-    bn254::{BN254_BLOCK_SIZE, BN254_LIMB_BITS, BN254_MODULUS, BN254_NUM_LIMBS, BN254_XI_ISIZE},
 };
 use openvm_pairing_transpiler::{Bls12381Fp12Opcode, Bn254Fp12Opcode, Fp12Opcode};
 use openvm_rv32_adapters::{rv32_write_heap_default, Rv32VecHeapAdapterChip};
@@ -163,6 +166,7 @@ fn test_fp12_mul_bn254() {
     let expr = fp12_mul_expr(
         config,
         tester.memory_controller().borrow().range_checker.bus(),
+        BN254_FP2_NON_RESIDUE,
         xi,
     );
 
@@ -251,6 +255,7 @@ fn test_fp12_mul_bls12381() {
     let expr = fp12_mul_expr(
         config,
         tester.memory_controller().borrow().range_checker.bus(),
+        BLS12_381_FP2_NON_RESIDUE,
         xi,
     );
 
@@ -17,7 +17,9 @@ use openvm_mod_circuit_builder::{
     ExprBuilderConfig,
 };
 use openvm_pairing_guest::{
-    bn254::{BN254_LIMB_BITS, BN254_MODULUS, BN254_NUM_LIMBS, BN254_XI_ISIZE},
+    bn254::{
+        BN254_FP2_NON_RESIDUE, BN254_LIMB_BITS, BN254_MODULUS, BN254_NUM_LIMBS, BN254_XI_ISIZE,
+    },
     halo2curves_shims::bn254::{tangent_line_013, Bn254},
     pairing::{Evaluatable, LineMulDType, UnevaluatedLine},
 };
@@ -58,6 +60,7 @@ fn test_mul_013_by_013() {
             num_limbs: NUM_LIMBS,
             limb_bits: LIMB_BITS,
         },
+        BN254_FP2_NON_RESIDUE,
         BN254_XI_ISIZE,
         PairingOpcode::default_offset(),
     );
@@ -154,6 +157,7 @@ fn test_mul_by_01234() {
             num_limbs: NUM_LIMBS,
             limb_bits: LIMB_BITS,
         },
+        BN254_FP2_NON_RESIDUE,
         BN254_XI_ISIZE,
         PairingOpcode::default_offset(),
     );
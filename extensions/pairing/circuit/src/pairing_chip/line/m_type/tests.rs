@@ -12,7 +12,10 @@ use openvm_ecc_guest::AffinePoint;
 use openvm_instructions::{riscv::RV32_CELL_BITS, UsizeOpcode};
 use openvm_mod_circuit_builder::{test_utils::*, ExprBuilderConfig};
 use openvm_pairing_guest::{
-    bls12_381::{BLS12_381_LIMB_BITS, BLS12_381_MODULUS, BLS12_381_NUM_LIMBS, BLS12_381_XI_ISIZE},
+    bls12_381::{
+        BLS12_381_FP2_NON_RESIDUE, BLS12_381_LIMB_BITS, BLS12_381_MODULUS, BLS12_381_NUM_LIMBS,
+        BLS12_381_XI_ISIZE,
+    },
     halo2curves_shims::bls12_381::{tangent_line_023, Bls12_381},
     pairing::LineMulMType,
 };
@@ -52,6 +55,7 @@ fn test_mul_023_by_023() {
             num_limbs: BLS12_381_NUM_LIMBS,
             limb_bits: BLS12_381_LIMB_BITS,
         },
+        BLS12_381_FP2_NON_RESIDUE,
         BLS12_381_XI_ISIZE,
         PairingOpcode::default_offset(),
     );
@@ -151,6 +155,7 @@ fn test_mul_by_02345() {
             num_limbs: BLS12_381_NUM_LIMBS,
             limb_bits: BLS12_381_LIMB_BITS,
         },
+        BLS12_381_FP2_NON_RESIDUE,
         BLS12_381_XI_ISIZE,
         PairingOpcode::default_offset(),
     );
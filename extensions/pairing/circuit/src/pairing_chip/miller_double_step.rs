@@ -39,9 +39,14 @@ impl<
         adapter: Rv32VecHeapAdapterChip<F, 1, INPUT_BLOCKS, OUTPUT_BLOCKS, BLOCK_SIZE, BLOCK_SIZE>,
         memory_controller: MemoryControllerRef<F>,
         config: ExprBuilderConfig,
+        fp2_non_residue: isize,
         offset: usize,
     ) -> Self {
-        let expr = miller_double_step_expr(config, memory_controller.borrow().range_checker.bus());
+        let expr = miller_double_step_expr(
+            config,
+            memory_controller.borrow().range_checker.bus(),
+            fp2_non_residue,
+        );
         let core = FieldExpressionCoreChip::new(
             expr,
             offset,
@@ -59,13 +64,14 @@ impl<
 pub fn miller_double_step_expr(
     config: ExprBuilderConfig,
     range_bus: VariableRangeCheckerBus,
+    fp2_non_residue: isize,
 ) -> FieldExpr {
     config.check_valid();
     let builder = ExprBuilder::new(config, range_bus.range_max_bits);
     let builder = Rc::new(RefCell::new(builder));
 
-    let mut x_s = Fp2::new(builder.clone());
-    let mut y_s = Fp2::new(builder.clone());
+    let mut x_s = Fp2::new_with_non_residue(builder.clone(), fp2_non_residue);
+    let mut y_s = Fp2::new_with_non_residue(builder.clone(), fp2_non_residue);
 
     let mut three_x_square = x_s.square().int_mul([3, 0]);
     let mut lambda = three_x_square.div(&mut y_s.int_mul([2, 0]));
@@ -97,8 +103,10 @@ mod tests {
         biguint_to_limbs, bls12381_fq_to_biguint, bn254_fq_to_biguint,
     };
     use openvm_pairing_guest::{
-        bls12_381::{BLS12_381_LIMB_BITS, BLS12_381_MODULUS, BLS12_381_NUM_LIMBS},
-        bn254::{BN254_LIMB_BITS, BN254_MODULUS, BN254_NUM_LIMBS},
+        bls12_381::{
+            BLS12_381_FP2_NON_RESIDUE, BLS12_381_LIMB_BITS, BLS12_381_MODULUS, BLS12_381_NUM_LIMBS,
+        },
+        bn254::{BN254_FP2_NON_RESIDUE, BN254_LIMB_BITS, BN254_MODULUS, BN254_NUM_LIMBS},
         halo2curves_shims::{bls12_381::Bls12_381, bn254::Bn254},
         pairing::MillerStep,
     };
@@ -140,6 +148,7 @@ mod tests {
             adapter,
             tester.memory_controller(),
             config,
+            BN254_FP2_NON_RESIDUE,
             PairingOpcode::default_offset(),
         );
 
@@ -207,6 +216,7 @@ mod tests {
             adapter,
             tester.memory_controller(),
             config,
+            BLS12_381_FP2_NON_RESIDUE,
             PairingOpcode::default_offset(),
         );
 
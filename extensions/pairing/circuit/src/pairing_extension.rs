@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{str::FromStr, sync::Arc};
 
 use derive_more::derive::From;
 use num_bigint_dig::BigUint;
@@ -16,72 +16,170 @@ use openvm_ecc_circuit::CurveConfig;
 use openvm_instructions::{PhantomDiscriminant, UsizeOpcode, VmOpcode};
 use openvm_mod_circuit_builder::ExprBuilderConfig;
 use openvm_pairing_guest::{
-    bls12_381::{BLS12_381_MODULUS, BLS12_381_ORDER, BLS12_381_XI_ISIZE},
-    bn254::{BN254_MODULUS, BN254_ORDER, BN254_XI_ISIZE},
+    bls12_377::{
+        BLS12_377_FP2_NON_RESIDUE, BLS12_377_MODULUS, BLS12_377_ORDER, BLS12_377_XI_ISIZE,
+    },
+    bls12_381::{
+        BLS12_381_FP2_NON_RESIDUE, BLS12_381_MODULUS, BLS12_381_ORDER, BLS12_381_XI_ISIZE,
+    },
+    bn254::{BN254_FP2_NON_RESIDUE, BN254_MODULUS, BN254_ORDER, BN254_XI_ISIZE},
 };
 use openvm_pairing_transpiler::{Fp12Opcode, PairingOpcode, PairingPhantom};
 use openvm_rv32_adapters::{Rv32VecHeapAdapterChip, Rv32VecHeapTwoReadsAdapterChip};
 use openvm_stark_backend::p3_field::PrimeField32;
 use serde::{Deserialize, Serialize};
-use strum::{EnumCount, FromRepr};
+use serde_with::{serde_as, DisplayFromStr, PickFirst};
+use strum::{EnumCount, EnumString, FromRepr};
 
 use super::*;
 
-// All the supported pairing curves.
-#[derive(Clone, Copy, Debug, FromRepr, Serialize, Deserialize)]
+// Preset pairing curves. The discriminant is the pairing index of the curve.
+#[derive(Clone, Copy, Debug, FromRepr, EnumString, Serialize, Deserialize)]
 #[repr(usize)]
 pub enum PairingCurve {
     Bn254,
     Bls12_381,
+    Bls12_377,
+}
+
+/// The sextic twist that `G2` is defined over, which determines the sparse form of the line
+/// functions and therefore which line multiplication chips are used.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TwistType {
+    /// `y^2 = x^3 + b / xi`, with lines in 013-form.
+    D,
+    /// `y^2 = x^3 + b * xi`, with lines in 023-form.
+    M,
+}
+
+/// How the final exponentiation of a pairing check is verified.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FinalExpHint {
+    /// The residue witness of <https://eprint.iacr.org/2024/640.pdf> is provided by the
+    /// `HintFinalExp` phantom instruction. The hint is only implemented for BN254 and BLS12-381.
+    ResidueWitness,
+    /// No hint is provided, and the guest computes the final exponentiation itself.
+    None,
+}
+
+/// Parameters describing a pairing-friendly curve with embedding degree 12, which are all that is
+/// needed to configure the chips of the curve.
+///
+/// The field tower is
+/// `Fp2 = Fp[u]/(u^2 - fp2_non_residue)` and `Fp12 = Fp2[w]/(w^6 - xi)`. The Miller loop
+/// parameter does not appear here, since the loop itself is run by the guest program.
+///
+/// The [PairingCurve] presets give the parameters of the curves implemented by
+/// `openvm-pairing-guest`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PairingCurveParams {
+    /// The index of the curve in the pairing opcodes, i.e. `PairingIntrinsics::PAIRING_IDX` of
+    /// the guest implementation.
+    pub pairing_idx: usize,
+    pub curve: CurveConfig,
+    pub fp2_non_residue: isize,
+    pub xi: [isize; 2],
+    pub twist: TwistType,
+    pub final_exp_hint: FinalExpHint,
 }
 
 impl PairingCurve {
-    pub fn curve_config(&self) -> CurveConfig {
+    pub fn params(&self) -> PairingCurveParams {
         match self {
-            PairingCurve::Bn254 => CurveConfig::new(
-                BN254_MODULUS.clone(),
-                BN254_ORDER.clone(),
-                BigUint::zero(),
-                BigUint::from_u8(3).unwrap(),
-            ),
-            PairingCurve::Bls12_381 => CurveConfig::new(
-                BLS12_381_MODULUS.clone(),
-                BLS12_381_ORDER.clone(),
-                BigUint::zero(),
-                BigUint::from_u8(4).unwrap(),
-            ),
+            PairingCurve::Bn254 => PairingCurveParams {
+                pairing_idx: *self as usize,
+                curve: CurveConfig::new(
+                    BN254_MODULUS.clone(),
+                    BN254_ORDER.clone(),
+                    BigUint::zero(),
+                    BigUint::from_u8(3).unwrap(),
+                ),
+                fp2_non_residue: BN254_FP2_NON_RESIDUE,
+                xi: BN254_XI_ISIZE,
+                twist: TwistType::D,
+                final_exp_hint: FinalExpHint::ResidueWitness,
+            },
+            PairingCurve::Bls12_381 => PairingCurveParams {
+                pairing_idx: *self as usize,
+                curve: CurveConfig::new(
+                    BLS12_381_MODULUS.clone(),
+                    BLS12_381_ORDER.clone(),
+                    BigUint::zero(),
+                    BigUint::from_u8(4).unwrap(),
+                ),
+                fp2_non_residue: BLS12_381_FP2_NON_RESIDUE,
+                xi: BLS12_381_XI_ISIZE,
+                twist: TwistType::M,
+                final_exp_hint: FinalExpHint::ResidueWitness,
+            },
+            PairingCurve::Bls12_377 => PairingCurveParams {
+                pairing_idx: *self as usize,
+                curve: CurveConfig::new(
+                    BLS12_377_MODULUS.clone(),
+                    BLS12_377_ORDER.clone(),
+                    BigUint::zero(),
+                    BigUint::from_u8(1).unwrap(),
+                ),
+                fp2_non_residue: BLS12_377_FP2_NON_RESIDUE,
+                xi: BLS12_377_XI_ISIZE,
+                twist: TwistType::D,
+                final_exp_hint: FinalExpHint::None,
+            },
         }
     }
 
+    pub fn curve_config(&self) -> CurveConfig {
+        self.params().curve
+    }
+
     pub fn xi(&self) -> [isize; 2] {
-        match self {
-            PairingCurve::Bn254 => BN254_XI_ISIZE,
-            PairingCurve::Bls12_381 => BLS12_381_XI_ISIZE,
-        }
+        self.params().xi
+    }
+}
+
+impl From<PairingCurve> for PairingCurveParams {
+    fn from(curve: PairingCurve) -> Self {
+        curve.params()
     }
 }
 
+impl FromStr for PairingCurveParams {
+    type Err = strum::ParseError;
+
+    /// Parses the name of a [PairingCurve] preset.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PairingCurve::from_str(s).map(Self::from)
+    }
+}
+
+#[serde_as]
 #[derive(Clone, Debug, derive_new::new, Serialize, Deserialize)]
 pub struct PairingExtension {
-    pub supported_curves: Vec<PairingCurve>,
+    /// Each curve is either given by its parameters or by the name of a [PairingCurve] preset.
+    #[serde_as(as = "Vec<PickFirst<(_, DisplayFromStr)>>")]
+    pub supported_curves: Vec<PairingCurveParams>,
 }
 
 #[derive(Chip, ChipUsageGetter, InstructionExecutor, AnyEnum)]
 pub enum PairingExtensionExecutor<F: PrimeField32> {
-    // bn254 (32 limbs)
+    // 32 limbs prime
     MillerDoubleStepRv32_32(MillerDoubleStepChip<F, 4, 8, 32>),
     MillerDoubleAndAddStepRv32_32(MillerDoubleAndAddStepChip<F, 4, 12, 32>),
     EvaluateLineRv32_32(EvaluateLineChip<F, 4, 2, 4, 32>),
     Fp12MulRv32_32(Fp12MulChip<F, 12, 32>),
-    EcLineMul013By013(EcLineMul013By013Chip<F, 4, 10, 32>),
-    EcLineMulBy01234(EcLineMulBy01234Chip<F, 12, 10, 12, 32>),
-    // bls12-381 (48 limbs)
+    EcLineMul013By013Rv32_32(EcLineMul013By013Chip<F, 4, 10, 32>),
+    EcLineMulBy01234Rv32_32(EcLineMulBy01234Chip<F, 12, 10, 12, 32>),
+    EcLineMul023By023Rv32_32(EcLineMul023By023Chip<F, 4, 10, 32>),
+    EcLineMulBy02345Rv32_32(EcLineMulBy02345Chip<F, 12, 10, 12, 32>),
+    // 48 limbs prime
     MillerDoubleStepRv32_48(MillerDoubleStepChip<F, 12, 24, 16>),
     MillerDoubleAndAddStepRv32_48(MillerDoubleAndAddStepChip<F, 12, 36, 16>),
     EvaluateLineRv32_48(EvaluateLineChip<F, 12, 6, 12, 16>),
     Fp12MulRv32_48(Fp12MulChip<F, 36, 16>),
-    EcLineMul023By023(EcLineMul023By023Chip<F, 12, 30, 16>),
-    EcLineMulBy02345(EcLineMulBy02345Chip<F, 36, 30, 36, 16>),
+    EcLineMul013By013Rv32_48(EcLineMul013By013Chip<F, 12, 30, 16>),
+    EcLineMulBy01234Rv32_48(EcLineMulBy01234Chip<F, 36, 30, 36, 16>),
+    EcLineMul023By023Rv32_48(EcLineMul023By023Chip<F, 12, 30, 16>),
+    EcLineMulBy02345Rv32_48(EcLineMulBy02345Chip<F, 36, 30, 36, 16>),
 }
 
 #[derive(ChipUsageGetter, Chip, AnyEnum, From)]
@@ -115,247 +213,340 @@ impl<F: PrimeField32> VmExtension<F> for PairingExtension {
             inventory.add_periphery_chip(chip.clone());
             chip
         };
-        for curve in self.supported_curves.iter() {
-            let pairing_idx = *curve as usize;
+        for params in self.supported_curves.iter() {
             let pairing_class_offset =
-                PairingOpcode::default_offset() + pairing_idx * PairingOpcode::COUNT;
-            let fp12_class_offset = Fp12Opcode::default_offset() + pairing_idx * Fp12Opcode::COUNT;
-            match curve {
-                PairingCurve::Bn254 => {
-                    let bn_config = ExprBuilderConfig {
-                        modulus: curve.curve_config().modulus.clone(),
-                        num_limbs: 32,
-                        limb_bits: 8,
-                    };
-                    let miller_double = MillerDoubleStepChip::new(
-                        Rv32VecHeapAdapterChip::<F, 1, 4, 8, 32, 32>::new(
-                            execution_bus,
-                            program_bus,
-                            memory_controller.clone(),
-                            bitwise_lu_chip.clone(),
-                        ),
+                PairingOpcode::default_offset() + params.pairing_idx * PairingOpcode::COUNT;
+            let fp12_class_offset =
+                Fp12Opcode::default_offset() + params.pairing_idx * Fp12Opcode::COUNT;
+            let bytes = params.curve.modulus.bits().div_ceil(8);
+
+            if bytes <= 32 {
+                let config = ExprBuilderConfig {
+                    modulus: params.curve.modulus.clone(),
+                    num_limbs: 32,
+                    limb_bits: 8,
+                };
+                let miller_double = MillerDoubleStepChip::new(
+                    Rv32VecHeapAdapterChip::<F, 1, 4, 8, 32, 32>::new(
+                        execution_bus,
+                        program_bus,
                         memory_controller.clone(),
-                        bn_config.clone(),
-                        pairing_class_offset,
-                    );
-                    inventory.add_executor(
-                        PairingExtensionExecutor::MillerDoubleStepRv32_32(miller_double),
-                        [VmOpcode::from_usize(
-                            pairing_class_offset + PairingOpcode::MILLER_DOUBLE_STEP as usize,
-                        )],
-                    )?;
-                    let miller_double_and_add = MillerDoubleAndAddStepChip::new(
-                        Rv32VecHeapAdapterChip::<F, 2, 4, 12, 32, 32>::new(
-                            execution_bus,
-                            program_bus,
-                            memory_controller.clone(),
-                            bitwise_lu_chip.clone(),
-                        ),
+                        bitwise_lu_chip.clone(),
+                    ),
+                    memory_controller.clone(),
+                    config.clone(),
+                    params.fp2_non_residue,
+                    pairing_class_offset,
+                );
+                inventory.add_executor(
+                    PairingExtensionExecutor::MillerDoubleStepRv32_32(miller_double),
+                    [VmOpcode::from_usize(
+                        pairing_class_offset + PairingOpcode::MILLER_DOUBLE_STEP as usize,
+                    )],
+                )?;
+                let miller_double_and_add = MillerDoubleAndAddStepChip::new(
+                    Rv32VecHeapAdapterChip::<F, 2, 4, 12, 32, 32>::new(
+                        execution_bus,
+                        program_bus,
                         memory_controller.clone(),
-                        bn_config.clone(),
-                        pairing_class_offset,
-                    );
-                    inventory.add_executor(
-                        PairingExtensionExecutor::MillerDoubleAndAddStepRv32_32(
-                            miller_double_and_add,
-                        ),
-                        [VmOpcode::from_usize(
-                            pairing_class_offset
-                                + PairingOpcode::MILLER_DOUBLE_AND_ADD_STEP as usize,
-                        )],
-                    )?;
-                    let eval_line = EvaluateLineChip::new(
-                        Rv32VecHeapTwoReadsAdapterChip::<F, 4, 2, 4, 32, 32>::new(
-                            execution_bus,
-                            program_bus,
-                            memory_controller.clone(),
-                            bitwise_lu_chip.clone(),
-                        ),
+                        bitwise_lu_chip.clone(),
+                    ),
+                    memory_controller.clone(),
+                    config.clone(),
+                    params.fp2_non_residue,
+                    pairing_class_offset,
+                );
+                inventory.add_executor(
+                    PairingExtensionExecutor::MillerDoubleAndAddStepRv32_32(miller_double_and_add),
+                    [VmOpcode::from_usize(
+                        pairing_class_offset + PairingOpcode::MILLER_DOUBLE_AND_ADD_STEP as usize,
+                    )],
+                )?;
+                let eval_line = EvaluateLineChip::new(
+                    Rv32VecHeapTwoReadsAdapterChip::<F, 4, 2, 4, 32, 32>::new(
+                        execution_bus,
+                        program_bus,
                         memory_controller.clone(),
-                        bn_config.clone(),
-                        pairing_class_offset,
-                    );
-                    inventory.add_executor(
-                        PairingExtensionExecutor::EvaluateLineRv32_32(eval_line),
-                        [VmOpcode::from_usize(
-                            pairing_class_offset + PairingOpcode::EVALUATE_LINE as usize,
-                        )],
-                    )?;
-                    let mul013 = EcLineMul013By013Chip::new(
-                        Rv32VecHeapAdapterChip::<F, 2, 4, 10, 32, 32>::new(
-                            execution_bus,
-                            program_bus,
+                        bitwise_lu_chip.clone(),
+                    ),
+                    memory_controller.clone(),
+                    config.clone(),
+                    pairing_class_offset,
+                );
+                inventory.add_executor(
+                    PairingExtensionExecutor::EvaluateLineRv32_32(eval_line),
+                    [VmOpcode::from_usize(
+                        pairing_class_offset + PairingOpcode::EVALUATE_LINE as usize,
+                    )],
+                )?;
+                match params.twist {
+                    TwistType::D => {
+                        let mul013 = EcLineMul013By013Chip::new(
+                            Rv32VecHeapAdapterChip::<F, 2, 4, 10, 32, 32>::new(
+                                execution_bus,
+                                program_bus,
+                                memory_controller.clone(),
+                                bitwise_lu_chip.clone(),
+                            ),
                             memory_controller.clone(),
-                            bitwise_lu_chip.clone(),
-                        ),
-                        memory_controller.clone(),
-                        bn_config.clone(),
-                        curve.xi(),
-                        pairing_class_offset,
-                    );
-                    inventory.add_executor(
-                        PairingExtensionExecutor::EcLineMul013By013(mul013),
-                        [VmOpcode::from_usize(
-                            pairing_class_offset + PairingOpcode::MUL_013_BY_013 as usize,
-                        )],
-                    )?;
-                    let mul01234 = EcLineMulBy01234Chip::new(
-                        Rv32VecHeapTwoReadsAdapterChip::<F, 12, 10, 12, 32, 32>::new(
-                            execution_bus,
-                            program_bus,
+                            config.clone(),
+                            params.fp2_non_residue,
+                            params.xi,
+                            pairing_class_offset,
+                        );
+                        inventory.add_executor(
+                            PairingExtensionExecutor::EcLineMul013By013Rv32_32(mul013),
+                            [VmOpcode::from_usize(
+                                pairing_class_offset + PairingOpcode::MUL_013_BY_013 as usize,
+                            )],
+                        )?;
+                        let mul01234 = EcLineMulBy01234Chip::new(
+                            Rv32VecHeapTwoReadsAdapterChip::<F, 12, 10, 12, 32, 32>::new(
+                                execution_bus,
+                                program_bus,
+                                memory_controller.clone(),
+                                bitwise_lu_chip.clone(),
+                            ),
                             memory_controller.clone(),
-                            bitwise_lu_chip.clone(),
-                        ),
-                        memory_controller.clone(),
-                        bn_config.clone(),
-                        curve.xi(),
-                        pairing_class_offset,
-                    );
-                    inventory.add_executor(
-                        PairingExtensionExecutor::EcLineMulBy01234(mul01234),
-                        [VmOpcode::from_usize(
-                            pairing_class_offset + PairingOpcode::MUL_BY_01234 as usize,
-                        )],
-                    )?;
-                    let fp12_mul = Fp12MulChip::new(
-                        Rv32VecHeapAdapterChip::<F, 2, 12, 12, 32, 32>::new(
-                            execution_bus,
-                            program_bus,
+                            config.clone(),
+                            params.fp2_non_residue,
+                            params.xi,
+                            pairing_class_offset,
+                        );
+                        inventory.add_executor(
+                            PairingExtensionExecutor::EcLineMulBy01234Rv32_32(mul01234),
+                            [VmOpcode::from_usize(
+                                pairing_class_offset + PairingOpcode::MUL_BY_01234 as usize,
+                            )],
+                        )?;
+                    }
+                    TwistType::M => {
+                        let mul023 = EcLineMul023By023Chip::new(
+                            Rv32VecHeapAdapterChip::<F, 2, 4, 10, 32, 32>::new(
+                                execution_bus,
+                                program_bus,
+                                memory_controller.clone(),
+                                bitwise_lu_chip.clone(),
+                            ),
                             memory_controller.clone(),
-                            bitwise_lu_chip.clone(),
-                        ),
-                        memory_controller.clone(),
-                        bn_config.clone(),
-                        curve.xi(),
-                        fp12_class_offset,
-                    );
-                    inventory.add_executor(
-                        PairingExtensionExecutor::Fp12MulRv32_32(fp12_mul),
-                        [VmOpcode::from_usize(
-                            fp12_class_offset + Fp12Opcode::MUL as usize,
-                        )],
-                    )?;
-                }
-                PairingCurve::Bls12_381 => {
-                    let bls_config = ExprBuilderConfig {
-                        modulus: curve.curve_config().modulus.clone(),
-                        num_limbs: 48,
-                        limb_bits: 8,
-                    };
-                    let miller_double = MillerDoubleStepChip::new(
-                        Rv32VecHeapAdapterChip::<F, 1, 12, 24, 16, 16>::new(
-                            execution_bus,
-                            program_bus,
+                            config.clone(),
+                            params.fp2_non_residue,
+                            params.xi,
+                            pairing_class_offset,
+                        );
+                        inventory.add_executor(
+                            PairingExtensionExecutor::EcLineMul023By023Rv32_32(mul023),
+                            [VmOpcode::from_usize(
+                                pairing_class_offset + PairingOpcode::MUL_023_BY_023 as usize,
+                            )],
+                        )?;
+                        let mul02345 = EcLineMulBy02345Chip::new(
+                            Rv32VecHeapTwoReadsAdapterChip::<F, 12, 10, 12, 32, 32>::new(
+                                execution_bus,
+                                program_bus,
+                                memory_controller.clone(),
+                                bitwise_lu_chip.clone(),
+                            ),
                             memory_controller.clone(),
-                            bitwise_lu_chip.clone(),
-                        ),
+                            config.clone(),
+                            params.fp2_non_residue,
+                            params.xi,
+                            pairing_class_offset,
+                        );
+                        inventory.add_executor(
+                            PairingExtensionExecutor::EcLineMulBy02345Rv32_32(mul02345),
+                            [VmOpcode::from_usize(
+                                pairing_class_offset + PairingOpcode::MUL_BY_02345 as usize,
+                            )],
+                        )?;
+                    }
+                }
+                let fp12_mul = Fp12MulChip::new(
+                    Rv32VecHeapAdapterChip::<F, 2, 12, 12, 32, 32>::new(
+                        execution_bus,
+                        program_bus,
                         memory_controller.clone(),
-                        bls_config.clone(),
-                        pairing_class_offset,
-                    );
-                    inventory.add_executor(
-                        PairingExtensionExecutor::MillerDoubleStepRv32_48(miller_double),
-                        [VmOpcode::from_usize(
-                            pairing_class_offset + PairingOpcode::MILLER_DOUBLE_STEP as usize,
-                        )],
-                    )?;
-                    let miller_double_and_add = MillerDoubleAndAddStepChip::new(
-                        Rv32VecHeapAdapterChip::<F, 2, 12, 36, 16, 16>::new(
-                            execution_bus,
-                            program_bus,
-                            memory_controller.clone(),
-                            bitwise_lu_chip.clone(),
-                        ),
+                        bitwise_lu_chip.clone(),
+                    ),
+                    memory_controller.clone(),
+                    config.clone(),
+                    params.fp2_non_residue,
+                    params.xi,
+                    fp12_class_offset,
+                );
+                inventory.add_executor(
+                    PairingExtensionExecutor::Fp12MulRv32_32(fp12_mul),
+                    [VmOpcode::from_usize(
+                        fp12_class_offset + Fp12Opcode::MUL as usize,
+                    )],
+                )?;
+            } else if bytes <= 48 {
+                let config = ExprBuilderConfig {
+                    modulus: params.curve.modulus.clone(),
+                    num_limbs: 48,
+                    limb_bits: 8,
+                };
+                let miller_double = MillerDoubleStepChip::new(
+                    Rv32VecHeapAdapterChip::<F, 1, 12, 24, 16, 16>::new(
+                        execution_bus,
+                        program_bus,
                         memory_controller.clone(),
-                        bls_config.clone(),
-                        pairing_class_offset,
-                    );
-                    inventory.add_executor(
-                        PairingExtensionExecutor::MillerDoubleAndAddStepRv32_48(
-                            miller_double_and_add,
-                        ),
-                        [VmOpcode::from_usize(
-                            pairing_class_offset
-                                + PairingOpcode::MILLER_DOUBLE_AND_ADD_STEP as usize,
-                        )],
-                    )?;
-                    let eval_line = EvaluateLineChip::new(
-                        Rv32VecHeapTwoReadsAdapterChip::<F, 12, 6, 12, 16, 16>::new(
-                            execution_bus,
-                            program_bus,
-                            memory_controller.clone(),
-                            bitwise_lu_chip.clone(),
-                        ),
+                        bitwise_lu_chip.clone(),
+                    ),
+                    memory_controller.clone(),
+                    config.clone(),
+                    params.fp2_non_residue,
+                    pairing_class_offset,
+                );
+                inventory.add_executor(
+                    PairingExtensionExecutor::MillerDoubleStepRv32_48(miller_double),
+                    [VmOpcode::from_usize(
+                        pairing_class_offset + PairingOpcode::MILLER_DOUBLE_STEP as usize,
+                    )],
+                )?;
+                let miller_double_and_add = MillerDoubleAndAddStepChip::new(
+                    Rv32VecHeapAdapterChip::<F, 2, 12, 36, 16, 16>::new(
+                        execution_bus,
+                        program_bus,
                         memory_controller.clone(),
-                        bls_config.clone(),
-                        pairing_class_offset,
-                    );
-                    inventory.add_executor(
-                        PairingExtensionExecutor::EvaluateLineRv32_48(eval_line),
-                        [VmOpcode::from_usize(
-                            pairing_class_offset + PairingOpcode::EVALUATE_LINE as usize,
-                        )],
-                    )?;
-                    let mul023 = EcLineMul023By023Chip::new(
-                        Rv32VecHeapAdapterChip::<F, 2, 12, 30, 16, 16>::new(
-                            execution_bus,
-                            program_bus,
-                            memory_controller.clone(),
-                            bitwise_lu_chip.clone(),
-                        ),
+                        bitwise_lu_chip.clone(),
+                    ),
+                    memory_controller.clone(),
+                    config.clone(),
+                    params.fp2_non_residue,
+                    pairing_class_offset,
+                );
+                inventory.add_executor(
+                    PairingExtensionExecutor::MillerDoubleAndAddStepRv32_48(miller_double_and_add),
+                    [VmOpcode::from_usize(
+                        pairing_class_offset + PairingOpcode::MILLER_DOUBLE_AND_ADD_STEP as usize,
+                    )],
+                )?;
+                let eval_line = EvaluateLineChip::new(
+                    Rv32VecHeapTwoReadsAdapterChip::<F, 12, 6, 12, 16, 16>::new(
+                        execution_bus,
+                        program_bus,
                         memory_controller.clone(),
-                        bls_config.clone(),
-                        curve.xi(),
-                        pairing_class_offset,
-                    );
-                    inventory.add_executor(
-                        PairingExtensionExecutor::EcLineMul023By023(mul023),
-                        [VmOpcode::from_usize(
-                            pairing_class_offset + PairingOpcode::MUL_023_BY_023 as usize,
-                        )],
-                    )?;
-                    let mul02345 = EcLineMulBy02345Chip::new(
-                        Rv32VecHeapTwoReadsAdapterChip::<F, 36, 30, 36, 16, 16>::new(
-                            execution_bus,
-                            program_bus,
+                        bitwise_lu_chip.clone(),
+                    ),
+                    memory_controller.clone(),
+                    config.clone(),
+                    pairing_class_offset,
+                );
+                inventory.add_executor(
+                    PairingExtensionExecutor::EvaluateLineRv32_48(eval_line),
+                    [VmOpcode::from_usize(
+                        pairing_class_offset + PairingOpcode::EVALUATE_LINE as usize,
+                    )],
+                )?;
+                match params.twist {
+                    TwistType::D => {
+                        let mul013 = EcLineMul013By013Chip::new(
+                            Rv32VecHeapAdapterChip::<F, 2, 12, 30, 16, 16>::new(
+                                execution_bus,
+                                program_bus,
+                                memory_controller.clone(),
+                                bitwise_lu_chip.clone(),
+                            ),
                             memory_controller.clone(),
-                            bitwise_lu_chip.clone(),
-                        ),
-                        memory_controller.clone(),
-                        bls_config.clone(),
-                        curve.xi(),
-                        pairing_class_offset,
-                    );
-                    inventory.add_executor(
-                        PairingExtensionExecutor::EcLineMulBy02345(mul02345),
-                        [VmOpcode::from_usize(
-                            pairing_class_offset + PairingOpcode::MUL_BY_02345 as usize,
-                        )],
-                    )?;
-                    let fp12_mul = Fp12MulChip::new(
-                        Rv32VecHeapAdapterChip::<F, 2, 36, 36, 16, 16>::new(
-                            execution_bus,
-                            program_bus,
+                            config.clone(),
+                            params.fp2_non_residue,
+                            params.xi,
+                            pairing_class_offset,
+                        );
+                        inventory.add_executor(
+                            PairingExtensionExecutor::EcLineMul013By013Rv32_48(mul013),
+                            [VmOpcode::from_usize(
+                                pairing_class_offset + PairingOpcode::MUL_013_BY_013 as usize,
+                            )],
+                        )?;
+                        let mul01234 = EcLineMulBy01234Chip::new(
+                            Rv32VecHeapTwoReadsAdapterChip::<F, 36, 30, 36, 16, 16>::new(
+                                execution_bus,
+                                program_bus,
+                                memory_controller.clone(),
+                                bitwise_lu_chip.clone(),
+                            ),
                             memory_controller.clone(),
-                            bitwise_lu_chip.clone(),
-                        ),
-                        memory_controller.clone(),
-                        bls_config.clone(),
-                        curve.xi(),
-                        fp12_class_offset,
-                    );
-                    inventory.add_executor(
-                        PairingExtensionExecutor::Fp12MulRv32_48(fp12_mul),
-                        [VmOpcode::from_usize(
-                            fp12_class_offset + Fp12Opcode::MUL as usize,
-                        )],
-                    )?;
+                            config.clone(),
+                            params.fp2_non_residue,
+                            params.xi,
+                            pairing_class_offset,
+                        );
+                        inventory.add_executor(
+                            PairingExtensionExecutor::EcLineMulBy01234Rv32_48(mul01234),
+                            [VmOpcode::from_usize(
+                                pairing_class_offset + PairingOpcode::MUL_BY_01234 as usize,
+                            )],
+                        )?;
+                    }
+                    TwistType::M => {
+                        let mul023 = EcLineMul023By023Chip::new(
+                            Rv32VecHeapAdapterChip::<F, 2, 12, 30, 16, 16>::new(
+                                execution_bus,
+                                program_bus,
+                                memory_controller.clone(),
+                                bitwise_lu_chip.clone(),
+                            ),
+                            memory_controller.clone(),
+                            config.clone(),
+                            params.fp2_non_residue,
+                            params.xi,
+                            pairing_class_offset,
+                        );
+                        inventory.add_executor(
+                            PairingExtensionExecutor::EcLineMul023By023Rv32_48(mul023),
+                            [VmOpcode::from_usize(
+                                pairing_class_offset + PairingOpcode::MUL_023_BY_023 as usize,
+                            )],
+                        )?;
+                        let mul02345 = EcLineMulBy02345Chip::new(
+                            Rv32VecHeapTwoReadsAdapterChip::<F, 36, 30, 36, 16, 16>::new(
+                                execution_bus,
+                                program_bus,
+                                memory_controller.clone(),
+                                bitwise_lu_chip.clone(),
+                            ),
+                            memory_controller.clone(),
+                            config.clone(),
+                            params.fp2_non_residue,
+                            params.xi,
+                            pairing_class_offset,
+                        );
+                        inventory.add_executor(
+                            PairingExtensionExecutor::EcLineMulBy02345Rv32_48(mul02345),
+                            [VmOpcode::from_usize(
+                                pairing_class_offset + PairingOpcode::MUL_BY_02345 as usize,
+                            )],
+                        )?;
+                    }
                 }
+                let fp12_mul = Fp12MulChip::new(
+                    Rv32VecHeapAdapterChip::<F, 2, 36, 36, 16, 16>::new(
+                        execution_bus,
+                        program_bus,
+                        memory_controller.clone(),
+                        bitwise_lu_chip.clone(),
+                    ),
+                    memory_controller.clone(),
+                    config.clone(),
+                    params.fp2_non_residue,
+                    params.xi,
+                    fp12_class_offset,
+                );
+                inventory.add_executor(
+                    PairingExtensionExecutor::Fp12MulRv32_48(fp12_mul),
+                    [VmOpcode::from_usize(
+                        fp12_class_offset + Fp12Opcode::MUL as usize,
+                    )],
+                )?;
+            } else {
+                panic!("Modulus too large");
             }
         }
 
         builder.add_phantom_sub_executor(
-            phantom::PairingHintSubEx,
+            phantom::PairingHintSubEx::new(self.supported_curves.clone()),
             PhantomDiscriminant(PairingPhantom::HintFinalExp as u16),
         )?;
 
@@ -377,16 +568,19 @@ pub(crate) mod phantom {
         PhantomDiscriminant,
     };
     use openvm_pairing_guest::{
-        bls12_381::BLS12_381_NUM_LIMBS,
-        bn254::BN254_NUM_LIMBS,
+        bls12_381::{BLS12_381_MODULUS, BLS12_381_NUM_LIMBS},
+        bn254::{BN254_MODULUS, BN254_NUM_LIMBS},
         pairing::{FinalExp, MultiMillerLoop},
     };
     use openvm_rv32im_circuit::adapters::{compose, unsafe_read_rv32_register};
     use openvm_stark_backend::p3_field::PrimeField32;
 
-    use super::PairingCurve;
+    use super::{FinalExpHint, PairingCurveParams};
 
-    pub struct PairingHintSubEx;
+    #[derive(derive_new::new)]
+    pub struct PairingHintSubEx {
+        pub supported_curves: Vec<PairingCurveParams>,
+    }
 
     impl<F: PrimeField32> PhantomSubExecutor<F> for PairingHintSubEx {
         fn phantom_execute(
@@ -398,9 +592,16 @@ pub(crate) mod phantom {
             b: F,
             c_upper: u16,
         ) -> eyre::Result<()> {
+            let Some(params) = self
+                .supported_curves
+                .iter()
+                .find(|params| params.pairing_idx == c_upper as usize)
+            else {
+                bail!("hint_pairing: invalid pairing index {c_upper}");
+            };
             let rs1 = unsafe_read_rv32_register(memory, a);
             let rs2 = unsafe_read_rv32_register(memory, b);
-            hint_pairing(memory, &mut streams.hint_stream, rs1, rs2, c_upper)
+            hint_pairing(memory, &mut streams.hint_stream, rs1, rs2, params)
         }
     }
 
@@ -409,7 +610,7 @@ pub(crate) mod phantom {
         hint_stream: &mut VecDeque<F>,
         rs1: u32,
         rs2: u32,
-        c_upper: u16,
+        params: &PairingCurveParams,
     ) -> eyre::Result<()> {
         let p_ptr = compose(memory.unsafe_read(
             F::from_canonical_u32(RV32_MEMORY_AS),
@@ -430,96 +631,99 @@ pub(crate) mod phantom {
             F::from_canonical_u32(rs2 + RV32_REGISTER_NUM_LIMBS as u32),
         ));
 
-        match PairingCurve::from_repr(c_upper as usize) {
-            Some(PairingCurve::Bn254) => {
-                use openvm_ecc_guest::halo2curves::bn256::{Fq, Fq12, Fq2};
-                use openvm_pairing_guest::halo2curves_shims::bn254::Bn254;
-                const N: usize = 32;
-                debug_assert_eq!(BN254_NUM_LIMBS, N); // TODO: make this const instead of static
-                if p_len != q_len {
-                    bail!("hint_pairing: p_len={p_len} != q_len={q_len}");
-                }
-                let p = (0..p_len)
-                    .map(|i| -> eyre::Result<_> {
-                        let ptr = p_ptr + i * 2 * (N as u32);
-                        let x = read_fp::<N, F, Fq>(memory, ptr)?;
-                        let y = read_fp::<N, F, Fq>(memory, ptr + N as u32)?;
-                        Ok(AffinePoint::new(x, y))
-                    })
-                    .collect::<eyre::Result<Vec<_>>>()?;
-                let q = (0..q_len)
-                    .map(|i| -> eyre::Result<_> {
-                        let mut ptr = q_ptr + i * 4 * (N as u32);
-                        let mut read_fp2 = || -> eyre::Result<_> {
-                            let c0 = read_fp::<N, F, Fq>(memory, ptr)?;
-                            let c1 = read_fp::<N, F, Fq>(memory, ptr + N as u32)?;
-                            ptr += 2 * N as u32;
-                            Ok(Fq2::new(c0, c1))
-                        };
-                        let x = read_fp2()?;
-                        let y = read_fp2()?;
-                        Ok(AffinePoint::new(x, y))
-                    })
-                    .collect::<eyre::Result<Vec<_>>>()?;
-
-                let f: Fq12 = Bn254::multi_miller_loop(&p, &q);
-                let (c, u) = Bn254::final_exp_hint(&f);
-                hint_stream.clear();
-                hint_stream.extend(
-                    c.to_coeffs()
-                        .into_iter()
-                        .chain(u.to_coeffs())
-                        .flat_map(|fp2| fp2.to_coeffs())
-                        .flat_map(|fp| fp.to_bytes())
-                        .map(F::from_canonical_u8),
-                );
+        if params.final_exp_hint == FinalExpHint::None {
+            bail!(
+                "hint_pairing: no final exponentiation hint for pairing index {}",
+                params.pairing_idx
+            );
+        }
+        let modulus = &params.curve.modulus;
+        if *modulus == *BN254_MODULUS {
+            use openvm_ecc_guest::halo2curves::bn256::{Fq, Fq12, Fq2};
+            use openvm_pairing_guest::halo2curves_shims::bn254::Bn254;
+            const N: usize = 32;
+            debug_assert_eq!(BN254_NUM_LIMBS, N); // TODO: make this const instead of static
+            if p_len != q_len {
+                bail!("hint_pairing: p_len={p_len} != q_len={q_len}");
             }
-            Some(PairingCurve::Bls12_381) => {
-                use openvm_ecc_guest::halo2curves::bls12_381::{Fq, Fq12, Fq2};
-                use openvm_pairing_guest::halo2curves_shims::bls12_381::Bls12_381;
-                const N: usize = 48;
-                debug_assert_eq!(BLS12_381_NUM_LIMBS, N); // TODO: make this const instead of static
-                if p_len != q_len {
-                    bail!("hint_pairing: p_len={p_len} != q_len={q_len}");
-                }
-                let p = (0..p_len)
-                    .map(|i| -> eyre::Result<_> {
-                        let ptr = p_ptr + i * 2 * (N as u32);
-                        let x = read_fp::<N, F, Fq>(memory, ptr)?;
-                        let y = read_fp::<N, F, Fq>(memory, ptr + N as u32)?;
-                        Ok(AffinePoint::new(x, y))
-                    })
-                    .collect::<eyre::Result<Vec<_>>>()?;
-                let q = (0..q_len)
-                    .map(|i| -> eyre::Result<_> {
-                        let mut ptr = q_ptr + i * 4 * (N as u32);
-                        let mut read_fp2 = || -> eyre::Result<_> {
-                            let c0 = read_fp::<N, F, Fq>(memory, ptr)?;
-                            let c1 = read_fp::<N, F, Fq>(memory, ptr + N as u32)?;
-                            ptr += 2 * N as u32;
-                            Ok(Fq2 { c0, c1 })
-                        };
-                        let x = read_fp2()?;
-                        let y = read_fp2()?;
-                        Ok(AffinePoint::new(x, y))
-                    })
-                    .collect::<eyre::Result<Vec<_>>>()?;
+            let p = (0..p_len)
+                .map(|i| -> eyre::Result<_> {
+                    let ptr = p_ptr + i * 2 * (N as u32);
+                    let x = read_fp::<N, F, Fq>(memory, ptr)?;
+                    let y = read_fp::<N, F, Fq>(memory, ptr + N as u32)?;
+                    Ok(AffinePoint::new(x, y))
+                })
+                .collect::<eyre::Result<Vec<_>>>()?;
+            let q = (0..q_len)
+                .map(|i| -> eyre::Result<_> {
+                    let mut ptr = q_ptr + i * 4 * (N as u32);
+                    let mut read_fp2 = || -> eyre::Result<_> {
+                        let c0 = read_fp::<N, F, Fq>(memory, ptr)?;
+                        let c1 = read_fp::<N, F, Fq>(memory, ptr + N as u32)?;
+                        ptr += 2 * N as u32;
+                        Ok(Fq2::new(c0, c1))
+                    };
+                    let x = read_fp2()?;
+                    let y = read_fp2()?;
+                    Ok(AffinePoint::new(x, y))
+                })
+                .collect::<eyre::Result<Vec<_>>>()?;
 
-                let f: Fq12 = Bls12_381::multi_miller_loop(&p, &q);
-                let (c, u) = Bls12_381::final_exp_hint(&f);
-                hint_stream.clear();
-                hint_stream.extend(
-                    c.to_coeffs()
-                        .into_iter()
-                        .chain(u.to_coeffs())
-                        .flat_map(|fp2| fp2.to_coeffs())
-                        .flat_map(|fp| fp.to_bytes())
-                        .map(F::from_canonical_u8),
-                );
-            }
-            _ => {
-                bail!("hint_pairing: invalid PairingCurve={c_upper}");
+            let f: Fq12 = Bn254::multi_miller_loop(&p, &q);
+            let (c, u) = Bn254::final_exp_hint(&f);
+            hint_stream.clear();
+            hint_stream.extend(
+                c.to_coeffs()
+                    .into_iter()
+                    .chain(u.to_coeffs())
+                    .flat_map(|fp2| fp2.to_coeffs())
+                    .flat_map(|fp| fp.to_bytes())
+                    .map(F::from_canonical_u8),
+            );
+        } else if *modulus == *BLS12_381_MODULUS {
+            use openvm_ecc_guest::halo2curves::bls12_381::{Fq, Fq12, Fq2};
+            use openvm_pairing_guest::halo2curves_shims::bls12_381::Bls12_381;
+            const N: usize = 48;
+            debug_assert_eq!(BLS12_381_NUM_LIMBS, N); // TODO: make this const instead of static
+            if p_len != q_len {
+                bail!("hint_pairing: p_len={p_len} != q_len={q_len}");
             }
+            let p = (0..p_len)
+                .map(|i| -> eyre::Result<_> {
+                    let ptr = p_ptr + i * 2 * (N as u32);
+                    let x = read_fp::<N, F, Fq>(memory, ptr)?;
+                    let y = read_fp::<N, F, Fq>(memory, ptr + N as u32)?;
+                    Ok(AffinePoint::new(x, y))
+                })
+                .collect::<eyre::Result<Vec<_>>>()?;
+            let q = (0..q_len)
+                .map(|i| -> eyre::Result<_> {
+                    let mut ptr = q_ptr + i * 4 * (N as u32);
+                    let mut read_fp2 = || -> eyre::Result<_> {
+                        let c0 = read_fp::<N, F, Fq>(memory, ptr)?;
+                        let c1 = read_fp::<N, F, Fq>(memory, ptr + N as u32)?;
+                        ptr += 2 * N as u32;
+                        Ok(Fq2 { c0, c1 })
+                    };
+                    let x = read_fp2()?;
+                    let y = read_fp2()?;
+                    Ok(AffinePoint::new(x, y))
+                })
+                .collect::<eyre::Result<Vec<_>>>()?;
+
+            let f: Fq12 = Bls12_381::multi_miller_loop(&p, &q);
+            let (c, u) = Bls12_381::final_exp_hint(&f);
+            hint_stream.clear();
+            hint_stream.extend(
+                c.to_coeffs()
+                    .into_iter()
+                    .chain(u.to_coeffs())
+                    .flat_map(|fp2| fp2.to_coeffs())
+                    .flat_map(|fp| fp.to_bytes())
+                    .map(F::from_canonical_u8),
+            );
+        } else {
+            bail!("hint_pairing: the final exponentiation hint is not implemented for {modulus}");
         }
         Ok(())
     }
@@ -0,0 +1,338 @@
+use alloc::vec::Vec;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use openvm_algebra_guest::{
+    field::{ComplexConjugate, FieldExtension},
+    DivAssignUnsafe, DivUnsafe, Field, IntMod,
+};
+use serde::{Deserialize, Serialize};
+
+use super::Fp;
+
+/// Quadratic extension `Fp2 = Fp[u]/(u^2 + 5)` of the BLS12-377 base field.
+///
+/// Since `-1` is a square in `Fp`, this cannot be declared with `complex_declare!`, whose
+/// intrinsics assume `u^2 = -1`. The arithmetic is instead done over the `Fp` intrinsics. The
+/// memory layout is the same as for the complex extensions, which the pairing intrinsics rely on.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(C)]
+pub struct Fp2 {
+    pub c0: Fp,
+    pub c1: Fp,
+}
+
+impl Fp2 {
+    pub const fn new(c0: Fp, c1: Fp) -> Self {
+        Self { c0, c1 }
+    }
+
+    pub fn neg_assign(&mut self) {
+        self.c0.neg_assign();
+        self.c1.neg_assign();
+    }
+
+    /// Returns `-5 * x`.
+    fn mul_by_non_residue(x: &Fp) -> Fp {
+        let mut four_x = x.clone();
+        IntMod::double_assign(&mut four_x);
+        IntMod::double_assign(&mut four_x);
+        -(four_x + x)
+    }
+
+    fn add_refs_impl(&self, other: &Self) -> Self {
+        Self::new(&self.c0 + &other.c0, &self.c1 + &other.c1)
+    }
+
+    fn sub_refs_impl(&self, other: &Self) -> Self {
+        Self::new(&self.c0 - &other.c0, &self.c1 - &other.c1)
+    }
+
+    fn mul_refs_impl(&self, other: &Self) -> Self {
+        let v0 = &self.c0 * &other.c0;
+        let v1 = &self.c1 * &other.c1;
+        // (c0 + c1)(d0 + d1) - c0 d0 - c1 d1 = c0 d1 + c1 d0
+        let c1 = (&self.c0 + &self.c1) * &(&other.c0 + &other.c1) - &v0 - &v1;
+        Self::new(v0 + Self::mul_by_non_residue(&v1), c1)
+    }
+
+    fn square_impl(&self) -> Self {
+        let c0 = self.c0.square() + Self::mul_by_non_residue(&self.c1.square());
+        let mut c1 = &self.c0 * &self.c1;
+        IntMod::double_assign(&mut c1);
+        Self::new(c0, c1)
+    }
+
+    /// Returns `1 / self`, using that `(c0 + c1 u)(c0 - c1 u) = c0^2 + 5 c1^2` is in `Fp`.
+    fn invert_impl(&self) -> Self {
+        let norm = self.c0.square() - Self::mul_by_non_residue(&self.c1.square());
+        let norm_inv = <Fp as IntMod>::ONE.div_unsafe(&norm);
+        Self::new(&self.c0 * &norm_inv, -(&self.c1 * &norm_inv))
+    }
+}
+
+impl Field for Fp2 {
+    type SelfRef<'a> = &'a Self;
+    const ZERO: Self = Self::new(<Fp as IntMod>::ZERO, <Fp as IntMod>::ZERO);
+    const ONE: Self = Self::new(<Fp as IntMod>::ONE, <Fp as IntMod>::ZERO);
+
+    fn double_assign(&mut self) {
+        IntMod::double_assign(&mut self.c0);
+        IntMod::double_assign(&mut self.c1);
+    }
+
+    fn square_assign(&mut self) {
+        *self = self.square_impl();
+    }
+}
+
+impl FieldExtension<Fp> for Fp2 {
+    const D: usize = 2;
+    type Coeffs = [Fp; 2];
+
+    fn from_coeffs([c0, c1]: Self::Coeffs) -> Self {
+        Self { c0, c1 }
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), 96);
+        Self::from_coeffs([
+            Fp::from_const_bytes(bytes[0..48].try_into().unwrap()),
+            Fp::from_const_bytes(bytes[48..96].try_into().unwrap()),
+        ])
+    }
+
+    fn to_coeffs(self) -> Self::Coeffs {
+        [self.c0, self.c1]
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(96);
+        bytes.extend_from_slice(self.c0.as_le_bytes());
+        bytes.extend_from_slice(self.c1.as_le_bytes());
+        bytes
+    }
+
+    fn embed(base_elem: Fp) -> Self {
+        Self {
+            c0: base_elem,
+            c1: <Fp as IntMod>::ZERO,
+        }
+    }
+
+    /// `u^p = -u`, since `-5` is not a square in `Fp`.
+    fn frobenius_map(&self, power: usize) -> Self {
+        if power % 2 == 0 {
+            self.clone()
+        } else {
+            self.clone().conjugate()
+        }
+    }
+
+    fn mul_base(&self, rhs: &Fp) -> Self {
+        Self {
+            c0: &self.c0 * rhs,
+            c1: &self.c1 * rhs,
+        }
+    }
+}
+
+impl ComplexConjugate for Fp2 {
+    fn conjugate(self) -> Self {
+        Self {
+            c0: self.c0,
+            c1: -self.c1,
+        }
+    }
+
+    fn conjugate_assign(&mut self) {
+        self.c1.neg_assign();
+    }
+}
+
+impl<'a> AddAssign<&'a Fp2> for Fp2 {
+    #[inline(always)]
+    fn add_assign(&mut self, other: &'a Fp2) {
+        self.c0 += &other.c0;
+        self.c1 += &other.c1;
+    }
+}
+
+impl AddAssign for Fp2 {
+    #[inline(always)]
+    fn add_assign(&mut self, other: Self) {
+        *self += &other;
+    }
+}
+
+impl Add for Fp2 {
+    type Output = Self;
+    #[inline(always)]
+    fn add(mut self, other: Self) -> Self::Output {
+        self += other;
+        self
+    }
+}
+
+impl<'a> Add<&'a Fp2> for Fp2 {
+    type Output = Self;
+    #[inline(always)]
+    fn add(mut self, other: &'a Fp2) -> Self::Output {
+        self += other;
+        self
+    }
+}
+
+impl<'a> Add<&'a Fp2> for &Fp2 {
+    type Output = Fp2;
+    #[inline(always)]
+    fn add(self, other: &'a Fp2) -> Self::Output {
+        self.add_refs_impl(other)
+    }
+}
+
+impl<'a> SubAssign<&'a Fp2> for Fp2 {
+    #[inline(always)]
+    fn sub_assign(&mut self, other: &'a Fp2) {
+        self.c0 -= &other.c0;
+        self.c1 -= &other.c1;
+    }
+}
+
+impl SubAssign for Fp2 {
+    #[inline(always)]
+    fn sub_assign(&mut self, other: Self) {
+        *self -= &other;
+    }
+}
+
+impl Sub for Fp2 {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(mut self, other: Self) -> Self::Output {
+        self -= other;
+        self
+    }
+}
+
+impl<'a> Sub<&'a Fp2> for Fp2 {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(mut self, other: &'a Fp2) -> Self::Output {
+        self -= other;
+        self
+    }
+}
+
+impl<'a> Sub<&'a Fp2> for &Fp2 {
+    type Output = Fp2;
+    #[inline(always)]
+    fn sub(self, other: &'a Fp2) -> Self::Output {
+        self.sub_refs_impl(other)
+    }
+}
+
+impl<'a> MulAssign<&'a Fp2> for Fp2 {
+    #[inline(always)]
+    fn mul_assign(&mut self, other: &'a Fp2) {
+        *self = self.mul_refs_impl(other);
+    }
+}
+
+impl MulAssign for Fp2 {
+    #[inline(always)]
+    fn mul_assign(&mut self, other: Self) {
+        *self *= &other;
+    }
+}
+
+impl Mul for Fp2 {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, other: Self) -> Self::Output {
+        self.mul_refs_impl(&other)
+    }
+}
+
+impl<'a> Mul<&'a Fp2> for Fp2 {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, other: &'a Fp2) -> Self::Output {
+        self.mul_refs_impl(other)
+    }
+}
+
+impl<'a> Mul<&'a Fp2> for &'a Fp2 {
+    type Output = Fp2;
+    #[inline(always)]
+    fn mul(self, other: &'a Fp2) -> Self::Output {
+        self.mul_refs_impl(other)
+    }
+}
+
+impl<'a> DivAssignUnsafe<&'a Fp2> for Fp2 {
+    /// Undefined behaviour when denominator is not coprime to N
+    #[inline(always)]
+    fn div_assign_unsafe(&mut self, other: &'a Fp2) {
+        *self = self.mul_refs_impl(&other.invert_impl());
+    }
+}
+
+impl DivAssignUnsafe for Fp2 {
+    /// Undefined behaviour when denominator is not coprime to N
+    #[inline(always)]
+    fn div_assign_unsafe(&mut self, other: Self) {
+        self.div_assign_unsafe(&other);
+    }
+}
+
+impl DivUnsafe for Fp2 {
+    type Output = Self;
+    /// Undefined behaviour when denominator is not coprime to N
+    #[inline(always)]
+    fn div_unsafe(mut self, other: Self) -> Self::Output {
+        self.div_assign_unsafe(&other);
+        self
+    }
+}
+
+impl<'a> DivUnsafe<&'a Fp2> for Fp2 {
+    type Output = Self;
+    /// Undefined behaviour when denominator is not coprime to N
+    #[inline(always)]
+    fn div_unsafe(mut self, other: &'a Fp2) -> Self::Output {
+        self.div_assign_unsafe(other);
+        self
+    }
+}
+
+impl<'a> DivUnsafe<&'a Fp2> for &Fp2 {
+    type Output = Fp2;
+    /// Undefined behaviour when denominator is not coprime to N
+    #[inline(always)]
+    fn div_unsafe(self, other: &'a Fp2) -> Self::Output {
+        self.mul_refs_impl(&other.invert_impl())
+    }
+}
+
+impl<'a> Neg for &'a Fp2 {
+    type Output = Fp2;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Fp2::new(-&self.c0, -&self.c1)
+    }
+}
+
+impl Neg for Fp2 {
+    type Output = Fp2;
+    #[inline(always)]
+    fn neg(mut self) -> Self::Output {
+        self.neg_assign();
+        self
+    }
+}
+
+impl core::fmt::Debug for Fp2 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?} + {:?} * u", self.c0, self.c1)
+    }
+}
@@ -0,0 +1,634 @@
+use core::ops::{Add, AddAssign, Neg};
+
+use openvm_algebra_guest::{Field, IntMod};
+use openvm_algebra_moduli_setup::moduli_declare;
+use openvm_ecc_guest::{weierstrass::IntrinsicCurve, CyclicGroup, Group};
+
+mod fp12;
+mod fp2;
+mod pairing;
+
+pub use fp12::*;
+pub use fp2::*;
+use hex_literal::hex;
+#[cfg(not(target_os = "zkvm"))]
+use lazy_static::lazy_static;
+#[cfg(not(target_os = "zkvm"))]
+use num_bigint_dig::BigUint;
+
+use crate::pairing::PairingIntrinsics;
+
+pub struct Bls12_377;
+
+#[cfg(all(test, not(target_os = "zkvm")))]
+mod tests;
+
+#[cfg(not(target_os = "zkvm"))]
+lazy_static! {
+    pub static ref BLS12_377_MODULUS: BigUint = BigUint::from_bytes_be(&hex!(
+        "01ae3a4617c510eac63b05c06ca1493b1a22d9f300f5138f1ef3622fba094800170b5d44300000008508c00000000001"
+    ));
+    pub static ref BLS12_377_ORDER: BigUint = BigUint::from_bytes_be(&hex!(
+        "12ab655e9a2ca55660b44d1e5c37b00159aa76fed00000010a11800000000001"
+    ));
+}
+
+/// `Fp2 = Fp[u]/(u^2 - BLS12_377_FP2_NON_RESIDUE)`
+pub const BLS12_377_FP2_NON_RESIDUE: isize = -5;
+pub const BLS12_377_XI_ISIZE: [isize; 2] = [0, 1];
+pub const BLS12_377_NUM_LIMBS: usize = 48;
+pub const BLS12_377_LIMB_BITS: usize = 8;
+pub const BLS12_377_BLOCK_SIZE: usize = 16;
+
+pub const BLS12_377_SEED: u64 = 0x8508c00000000001;
+pub const BLS12_377_PSEUDO_BINARY_ENCODING: [i8; 64] = [
+    1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 1,
+];
+
+moduli_declare! {
+    Bls12_377Fp { modulus = "0x01ae3a4617c510eac63b05c06ca1493b1a22d9f300f5138f1ef3622fba094800170b5d44300000008508c00000000001" },
+    Bls12_377Scalar { modulus = "0x12ab655e9a2ca55660b44d1e5c37b00159aa76fed00000010a11800000000001" },
+}
+
+const CURVE_B: Bls12_377Fp = Bls12_377Fp::from_const_u8(1);
+
+openvm_ecc_sw_setup::sw_declare! {
+    Bls12_377G1Affine { mod_type = Bls12_377Fp, b = CURVE_B },
+}
+
+pub type Fp = Bls12_377Fp;
+pub type Scalar = Bls12_377Scalar;
+/// Affine point representation of `Fp` points of BLS12-377.
+/// **Note**: an instance of this type may be constructed that lies
+/// on the curve but not necessarily in the prime order subgroup
+/// because the group has cofactors.
+pub type G1Affine = Bls12_377G1Affine;
+
+impl Field for Fp {
+    type SelfRef<'a> = &'a Self;
+    const ZERO: Self = <Self as IntMod>::ZERO;
+    const ONE: Self = <Self as IntMod>::ONE;
+
+    fn double_assign(&mut self) {
+        IntMod::double_assign(self);
+    }
+
+    fn square_assign(&mut self) {
+        IntMod::square_assign(self);
+    }
+}
+
+impl Field for Scalar {
+    type SelfRef<'a> = &'a Self;
+    const ZERO: Self = <Self as IntMod>::ZERO;
+    const ONE: Self = <Self as IntMod>::ONE;
+
+    fn double_assign(&mut self) {
+        IntMod::double_assign(self);
+    }
+
+    fn square_assign(&mut self) {
+        IntMod::square_assign(self);
+    }
+}
+
+impl CyclicGroup for G1Affine {
+    // https://github.com/arkworks-rs/algebra/blob/master/curves/bls12_377/src/curves/g1.rs
+    const GENERATOR: Self = G1Affine {
+        x: Bls12_377Fp::from_const_bytes(hex!(
+            "EFE91BB26EB1B9EA4E39CDFF121548D55CCB37BDC8828218BB419DAA2C1E958554FF87BF2562FCC8670A74FEDE488800"
+        )),
+        y: Bls12_377Fp::from_const_bytes(hex!(
+            "A68E9C5555DE82FD1A59A934363DFEC20523B84FD42A186DD9523ECA48B37FBDC4EEAF305D4F671FFF2E10C5694A9101"
+        )),
+    };
+    const NEG_GENERATOR: Self = G1Affine {
+        x: Bls12_377Fp::from_const_bytes(hex!(
+            "EFE91BB26EB1B9EA4E39CDFF121548D55CCB37BDC8828218BB419DAA2C1E958554FF87BF2562FCC8670A74FEDE488800"
+        )),
+        y: Bls12_377Fp::from_const_bytes(hex!(
+            "5B7163AAAAE18587E5A656FB0D200D54FA24516A5B37DBB1B5C0B636AA26A35C765AF13B63B6D3A6EBE1B452DCEF1C00"
+        )),
+    };
+}
+
+impl IntrinsicCurve for Bls12_377 {
+    type Scalar = Scalar;
+    type Point = G1Affine;
+}
+
+impl PairingIntrinsics for Bls12_377 {
+    type Fp = Fp;
+    type Fp2 = Fp2;
+    type Fp12 = Fp12;
+
+    const PAIRING_IDX: usize = 2;
+    const XI: Fp2 = Fp2::new(<Fp as IntMod>::ZERO, Fp::from_const_u8(1));
+    const FROBENIUS_COEFFS: [[Self::Fp2; 5]; 12] = [
+        [
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+        ],
+        [
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "31204f10d1a938e98801eb58e56876b59d55aaa334bf81c68ebc4ef9e0458a5c917f568206e3c13396019c3975999a00"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0200000000c0088500000090cc17224500ec0d974713edc5ab4a59347daf9a61ecf614dd05afb3090000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "631c39f1ecd5766e8e7df2bf59845899620f6b43cf49e6bcc1ded10af5980340b1be791adb34c5c0ca376579400a6801"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0100000000c0088500000090cc17224500ec0d974713edc5ab4a59347daf9a61ecf614dd05afb3090000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "32fce9e01b2c3e85057c0767741be2e3c4b9c09f9a8a64f632228311145379e31f3f2398d451038d3436c93fcb70cd00"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+        ],
+        [
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0200000000c0088500000090cc17224500ec0d974713edc5ab4a59347daf9a61ecf614dd05afb3090000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0100000000c0088500000090cc17224500ec0d974713edc5ab4a59347daf9a61ecf614dd05afb3090000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0000000000c0088500000030445d0b17004809ba2f62f31e8f13f500f3d9221a3b49a16cc0053bc6ea10c517463aae01"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "ffffffffffffffffffffff9f7745e9d1ff5bfb22e84e0659e3c89bcc752a88b84e528c8fba5687bcea10c517463aae01"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0000000000000000000000a07745e9d1ff5bfb22e84e0659e3c89bcc752a88b84e528c8fba5687bcea10c517463aae01"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+        ],
+        [
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "631c39f1ecd5766e8e7df2bf59845899620f6b43cf49e6bcc1ded10af5980340b1be791adb34c5c0ca376579400a6801"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0000000000c0088500000030445d0b17004809ba2f62f31e8f13f500f3d9221a3b49a16cc0053bc6ea10c517463aae01"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "9ee3c60e13ea911672820d70ead8b27d9d389e7660180d62cd3423f6fd401fda898a2752e5d0750520d95f9e05304600"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "631c39f1ecd5766e8e7df2bf59845899620f6b43cf49e6bcc1ded10af5980340b1be791adb34c5c0ca376579400a6801"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+        ],
+        [
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0100000000c0088500000090cc17224500ec0d974713edc5ab4a59347daf9a61ecf614dd05afb3090000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "ffffffffffffffffffffff9f7745e9d1ff5bfb22e84e0659e3c89bcc752a88b84e528c8fba5687bcea10c517463aae01"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0100000000c0088500000090cc17224500ec0d974713edc5ab4a59347daf9a61ecf614dd05afb3090000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "ffffffffffffffffffffff9f7745e9d1ff5bfb22e84e0659e3c89bcc752a88b84e528c8fba5687bcea10c517463aae01"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+        ],
+        [
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "32fce9e01b2c3e85057c0767741be2e3c4b9c09f9a8a64f632228311145379e31f3f2398d451038d3436c93fcb70cd00"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0000000000000000000000a07745e9d1ff5bfb22e84e0659e3c89bcc752a88b84e528c8fba5687bcea10c517463aae01"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "631c39f1ecd5766e8e7df2bf59845899620f6b43cf49e6bcc1ded10af5980340b1be791adb34c5c0ca376579400a6801"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "ffffffffffffffffffffff9f7745e9d1ff5bfb22e84e0659e3c89bcc752a88b84e528c8fba5687bcea10c517463aae01"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "31204f10d1a938e98801eb58e56876b59d55aaa334bf81c68ebc4ef9e0458a5c917f568206e3c13396019c3975999a00"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+        ],
+        [
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0000000000c0088500000030445d0b17004809ba2f62f31e8f13f500f3d9221a3b49a16cc0053bc6ea10c517463aae01"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0000000000c0088500000030445d0b17004809ba2f62f31e8f13f500f3d9221a3b49a16cc0053bc6ea10c517463aae01"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0000000000c0088500000030445d0b17004809ba2f62f31e8f13f500f3d9221a3b49a16cc0053bc6ea10c517463aae01"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+        ],
+        [
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "d0dfb0ef2e16d09b77fe14d75ef4946162f25e16fba271580057a607129498bda9c94aeab9227992540f29ded0a01301"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0200000000c0088500000090cc17224500ec0d974713edc5ab4a59347daf9a61ecf614dd05afb3090000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "9ee3c60e13ea911672820d70ead8b27d9d389e7660180d62cd3423f6fd401fda898a2752e5d0750520d95f9e05304600"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0100000000c0088500000090cc17224500ec0d974713edc5ab4a59347daf9a61ecf614dd05afb3090000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "cf03161fe493cafffa83f8c8cf4129333b8e481a95d78e285cf171efde86a9361b0a7ed4ebb33739b6dafbd77ac9e000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+        ],
+        [
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "ffffffffffffffffffffff9f7745e9d1ff5bfb22e84e0659e3c89bcc752a88b84e528c8fba5687bcea10c517463aae01"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0100000000c0088500000090cc17224500ec0d974713edc5ab4a59347daf9a61ecf614dd05afb3090000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "ffffffffffffffffffffff9f7745e9d1ff5bfb22e84e0659e3c89bcc752a88b84e528c8fba5687bcea10c517463aae01"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0100000000c0088500000090cc17224500ec0d974713edc5ab4a59347daf9a61ecf614dd05afb3090000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+        ],
+        [
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "9ee3c60e13ea911672820d70ead8b27d9d389e7660180d62cd3423f6fd401fda898a2752e5d0750520d95f9e05304600"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0000000000c0088500000030445d0b17004809ba2f62f31e8f13f500f3d9221a3b49a16cc0053bc6ea10c517463aae01"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "631c39f1ecd5766e8e7df2bf59845899620f6b43cf49e6bcc1ded10af5980340b1be791adb34c5c0ca376579400a6801"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "9ee3c60e13ea911672820d70ead8b27d9d389e7660180d62cd3423f6fd401fda898a2752e5d0750520d95f9e05304600"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+        ],
+        [
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0000000000000000000000a07745e9d1ff5bfb22e84e0659e3c89bcc752a88b84e528c8fba5687bcea10c517463aae01"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "ffffffffffffffffffffff9f7745e9d1ff5bfb22e84e0659e3c89bcc752a88b84e528c8fba5687bcea10c517463aae01"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0000000000c0088500000030445d0b17004809ba2f62f31e8f13f500f3d9221a3b49a16cc0053bc6ea10c517463aae01"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0100000000c0088500000090cc17224500ec0d974713edc5ab4a59347daf9a61ecf614dd05afb3090000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0200000000c0088500000090cc17224500ec0d974713edc5ab4a59347daf9a61ecf614dd05afb3090000000000000000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+        ],
+        [
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "cf03161fe493cafffa83f8c8cf4129333b8e481a95d78e285cf171efde86a9361b0a7ed4ebb33739b6dafbd77ac9e000"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "0000000000000000000000a07745e9d1ff5bfb22e84e0659e3c89bcc752a88b84e528c8fba5687bcea10c517463aae01"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "9ee3c60e13ea911672820d70ead8b27d9d389e7660180d62cd3423f6fd401fda898a2752e5d0750520d95f9e05304600"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "ffffffffffffffffffffff9f7745e9d1ff5bfb22e84e0659e3c89bcc752a88b84e528c8fba5687bcea10c517463aae01"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+            Fp2 {
+                c0: Bls12_377Fp(hex!(
+                    "d0dfb0ef2e16d09b77fe14d75ef4946162f25e16fba271580057a607129498bda9c94aeab9227992540f29ded0a01301"
+                )),
+                c1: Bls12_377Fp(hex!(
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+                )),
+            },
+        ],
+    ];
+}
@@ -0,0 +1,120 @@
+use alloc::vec::Vec;
+
+use hex_literal::hex;
+use openvm_algebra_guest::{field::FieldExtension, DivUnsafe, Field, IntMod};
+use openvm_ecc_guest::{weierstrass::WeierstrassPoint, AffinePoint, CyclicGroup, Group};
+
+use super::{Bls12_377, Fp, Fp12, Fp2, G1Affine, BLS12_377_MODULUS};
+use crate::pairing::{MultiMillerLoop, PairingCheck};
+
+fn fp2(c0: [u8; 48], c1: [u8; 48]) -> Fp2 {
+    Fp2::new(Fp::from_be_bytes(&c0), Fp::from_be_bytes(&c1))
+}
+
+/// A point of the order `r` subgroup of the twist.
+fn g2_point() -> AffinePoint<Fp2> {
+    AffinePoint::new(
+        fp2(
+            hex!("0003b5cb0d1bc029813baf9e3ec5129034d3e5cec7757243d9fbd3445a6ba4f362f7f276239059f59511a93a61288fa5"),
+            hex!("0015dd1f5871871edae8f193ae11400adb0a27781182636657380c19c3f35a6be0eeba0d46a32312963c624c6985f31b"),
+        ),
+        fp2(
+            hex!("0088df3f27b9be56473404cb0911b2440fdaf1888a0a79ce2a746fb5a86a91a0f4973c2524a0545577ae500e80c65c49"),
+            hex!("00d03d5b7ce77bbd56a644c2d57322a4d23e6504af2c2be2fd58031dab49a6e4c71500510910871796a32088100e6f58"),
+        ),
+    )
+}
+
+/// Twice [g2_point].
+fn g2_point_double() -> AffinePoint<Fp2> {
+    AffinePoint::new(
+        fp2(
+            hex!("000b408a4dd8459bd0b5f0dcc23a3775513a6694a066f626078fcc2d272744ad330d352b2e0d4553ee02a9ceaa601359"),
+            hex!("00f9ceaece9dd9ef959ceec632c20101da26d0eb2475aa8188400c3a9ebc3284caa7b35ca0b7e1075cfc741f02f921d4"),
+        ),
+        fp2(
+            hex!("001b37cc9c62eb317e3225985d296e6d002e0e2adc8738e5c0c9dd42878b9c480a2b02667d02e0166e1e4e8d2dac2599"),
+            hex!("0053914cfa1ce9ac78bc16c90b90a5b715d22df5ef6ad79a9e90a2c60dc60ab67cd4d39460e75528b711c7d20daf1aaa"),
+        ),
+    )
+}
+
+fn to_affine(p: &G1Affine) -> AffinePoint<Fp> {
+    AffinePoint::new(p.x().clone(), p.y().clone())
+}
+
+fn sample_fp12() -> Fp12 {
+    Fp12::from_coeffs(core::array::from_fn(|i| {
+        Fp2::new(
+            Fp::from_u32(2 * i as u32 + 3),
+            Fp::from_u32(5 * i as u32 + 7),
+        )
+    }))
+}
+
+#[test]
+fn test_bls12377_fp2_non_residue() {
+    let u = Fp2::new(<Fp as IntMod>::ZERO, <Fp as IntMod>::ONE);
+    assert_eq!(&u * &u, Fp2::new(-Fp::from_u8(5), <Fp as IntMod>::ZERO));
+
+    let a = Fp2::new(Fp::from_u32(12), Fp::from_u32(34));
+    let b = Fp2::new(Fp::from_u32(56), Fp::from_u32(78));
+    assert_eq!((&a * &b).div_unsafe(&b), a);
+    let mut a_sq = a.clone();
+    a_sq.square_assign();
+    assert_eq!(a_sq, &a * &a);
+}
+
+#[test]
+fn test_bls12377_fp12_invert() {
+    let f = sample_fp12();
+    assert_eq!(&f * &f.invert(), Fp12::ONE);
+}
+
+#[test]
+fn test_bls12377_frobenius() {
+    let f = sample_fp12();
+    let mut f_p = Fp12::ONE;
+    let modulus = BLS12_377_MODULUS.to_bytes_le();
+    for byte in modulus.iter().rev() {
+        for i in (0..8).rev() {
+            f_p.square_assign();
+            if (byte >> i) & 1 == 1 {
+                f_p *= &f;
+            }
+        }
+    }
+    assert_eq!(f.frobenius_map(1), f_p);
+
+    for pow in 0..12 {
+        assert_eq!(
+            f.frobenius_map(pow).frobenius_map(1),
+            f.frobenius_map(pow + 1)
+        );
+    }
+    assert_eq!(f.frobenius_map(12), f);
+}
+
+#[test]
+fn test_bls12377_bilinearity() {
+    let p = G1Affine::GENERATOR;
+    let p2 = p.double();
+    let lhs = Bls12_377::multi_miller_loop(&[to_affine(&p2)], &[g2_point()]);
+    let rhs = Bls12_377::multi_miller_loop(&[to_affine(&p)], &[g2_point_double()]);
+    let lhs = Bls12_377::final_exp(&lhs);
+    assert_ne!(lhs, Fp12::ONE);
+    assert_eq!(lhs, Bls12_377::final_exp(&rhs));
+}
+
+#[test]
+fn test_bls12377_pairing_check() {
+    let p = G1Affine::GENERATOR;
+    let ps: Vec<_> = [p.double(), G1Affine::NEG_GENERATOR]
+        .iter()
+        .map(to_affine)
+        .collect();
+    let qs = [g2_point(), g2_point_double()];
+    assert!(Bls12_377::pairing_check(&ps, &qs).is_ok());
+
+    assert!(Bls12_377::pairing_check(&[to_affine(&p)], &[g2_point()]).is_err());
+}
@@ -2,7 +2,7 @@ use alloc::vec::Vec;
 use core::ops::Neg;
 
 use openvm_algebra_complex_macros::{complex_declare, complex_impl_field};
-use openvm_algebra_guest::{field::FieldExtension, DivUnsafe, Field, IntMod};
+use openvm_algebra_guest::{field::FieldExtension, DivUnsafe, Field, IntMod, Sqrt};
 
 use super::Fp;
 
@@ -70,3 +70,30 @@ impl FieldExtension<Fp> for Fp2 {
         }
     }
 }
+
+impl Sqrt for Fp2 {
+    /// `c0 + c1 u` is a square if and only if its norm `c0^2 + c1^2` is a square in `Fp`. The
+    /// square roots in `Fp` are hinted, so this costs a few intrinsic calls.
+    fn sqrt(&self) -> Option<Self> {
+        if self.c1 == <Fp as IntMod>::ZERO {
+            // Every element of `Fp` is a square in `Fp2`, and `-1` is not a square in `Fp`.
+            if self.c0 == <Fp as IntMod>::ZERO {
+                return Some(<Self as Field>::ZERO);
+            }
+            return Some(match self.c0.sqrt() {
+                Some(c0) => Self::new(c0, <Fp as IntMod>::ZERO),
+                None => Self::new(<Fp as IntMod>::ZERO, (-self.c0.clone()).sqrt()?),
+            });
+        }
+        let norm_sqrt = (&self.c0 * &self.c0 + &self.c1 * &self.c1).sqrt()?;
+        let two = Fp::from_const_u8(2);
+        // The product of `(c0 + norm_sqrt) / 2` and `(c0 - norm_sqrt) / 2` is `-c1^2 / 4`, which
+        // is not a square, so exactly one of them is a nonzero square.
+        let c0 = match (&self.c0 + &norm_sqrt).div_unsafe(&two).sqrt() {
+            Some(c0) => c0,
+            None => (&self.c0 - &norm_sqrt).div_unsafe(&two).sqrt()?,
+        };
+        let c1 = (&self.c1).div_unsafe(&(&c0 + &c0));
+        Some(Self::new(c0, c1))
+    }
+}
@@ -0,0 +1,277 @@
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+use hex_literal::hex;
+use openvm_algebra_guest::{field::ComplexConjugate, DivUnsafe, Field};
+use openvm_ecc_guest::{AffinePoint, CyclicGroup, Group};
+
+use super::{mul_by_seed_abs, Fp, Fp2};
+
+/// Affine point representation of `Fp2` points of BLS12-381 on the sextic twist
+/// `y^2 = x^3 + 4(1 + u)`, where `(0, 0)` is the identity. The group law is computed in software
+/// on top of the `Fp2` intrinsics.
+/// **Note**: as for [G1Affine](super::G1Affine), an instance of this type may lie on the curve
+/// but not in the prime order subgroup. Use [G2Affine::is_torsion_free] to check.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct G2Affine {
+    x: Fp2,
+    y: Fp2,
+}
+
+impl G2Affine {
+    pub const CURVE_B: Fp2 = Fp2::new(Fp::from_const_u8(4), Fp::from_const_u8(4));
+
+    /// Coefficients of the endomorphism `psi`, which maps `(x, y)` to
+    /// `(conj(x) * PSI_COEFF_X, conj(y) * PSI_COEFF_Y)`. Here
+    /// `PSI_COEFF_X = 1 / (1 + u)^((p - 1) / 3)` and `PSI_COEFF_Y = 1 / (1 + u)^((p - 1) / 2)`.
+    const PSI_COEFF_X: Fp2 = Fp2::new(
+        Fp::from_const_u8(0),
+        Fp::from_const_bytes(hex!(
+            "adaa00000000fd8bfdff494feb2794409b5fb80f65297d89d49a75897d850daa85ded463864002ec99e67f39ea11011a"
+        )),
+    );
+    const PSI_COEFF_Y: Fp2 = Fp2::new(
+        Fp::from_const_bytes(hex!(
+            "a2de1b12047beef10afa673ecf6644305eb41ef6896439ef60cfb130d9ed3d1cd92c7ad748c4e9e28ea68001e6035213"
+        )),
+        Fp::from_const_bytes(hex!(
+            "09cce3edfb8410c8f405ec722f9967eec5419200176ef7775e43d3c2ab5d3948fe7fd16b6de331680b40ff37040eaf06"
+        )),
+    );
+
+    pub const fn from_xy_unchecked(x: Fp2, y: Fp2) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns `None` if `(x, y)` is neither on the curve nor the identity `(0, 0)`.
+    pub fn from_xy(x: Fp2, y: Fp2) -> Option<Self> {
+        let p = Self { x, y };
+        (p.is_identity() || p.is_on_curve()).then_some(p)
+    }
+
+    pub fn x(&self) -> &Fp2 {
+        &self.x
+    }
+
+    pub fn y(&self) -> &Fp2 {
+        &self.y
+    }
+
+    pub fn into_coords(self) -> (Fp2, Fp2) {
+        (self.x, self.y)
+    }
+
+    fn is_on_curve(&self) -> bool {
+        &self.y * &self.y == &(&self.x * &self.x) * &self.x + &Self::CURVE_B
+    }
+
+    /// The untwist-Frobenius-twist endomorphism `psi`.
+    pub fn psi(&self) -> Self {
+        if self.is_identity() {
+            return self.clone();
+        }
+        Self {
+            x: self.x.clone().conjugate() * &Self::PSI_COEFF_X,
+            y: self.y.clone().conjugate() * &Self::PSI_COEFF_Y,
+        }
+    }
+
+    /// Returns whether `self` is in the prime order subgroup, using that this is the case if and
+    /// only if `psi(P) = [x]P` for the curve seed `x`.
+    ///
+    /// Reference: <https://eprint.iacr.org/2021/1130.pdf>
+    pub fn is_torsion_free(&self) -> bool {
+        // The seed is negative.
+        self.psi() == -mul_by_seed_abs(self)
+    }
+
+    /// Assumes that `p1` and `p2` are not the identity and `p1 != ±p2`.
+    fn add_ne(p1: &Self, p2: &Self) -> Self {
+        // λ = (y2 - y1) / (x2 - x1)
+        let lambda = (&p2.y - &p1.y).div_unsafe(&(&p2.x - &p1.x));
+        // x3 = λ^2 - x1 - x2
+        let x3 = &(&lambda * &lambda) - &p1.x - &p2.x;
+        // y3 = λ(x1 - x3) - y1
+        let y3 = &lambda * &(&p1.x - &x3) - &p1.y;
+        Self { x: x3, y: y3 }
+    }
+
+    /// Assumes that `p` is not the identity. The twist has no points with `y = 0`.
+    fn double_impl(p: &Self) -> Self {
+        let x_sq = &p.x * &p.x;
+        // λ = 3x^2 / 2y
+        let lambda = (&(&x_sq + &x_sq) + &x_sq).div_unsafe(&(&p.y + &p.y));
+        // x3 = λ^2 - 2x
+        let x3 = &(&lambda * &lambda) - &(&p.x + &p.x);
+        // y3 = λ(x - x3) - y
+        let y3 = &lambda * &(&p.x - &x3) - &p.y;
+        Self { x: x3, y: y3 }
+    }
+}
+
+impl From<G2Affine> for AffinePoint<Fp2> {
+    fn from(p: G2Affine) -> Self {
+        AffinePoint::new(p.x, p.y)
+    }
+}
+
+impl Group for G2Affine {
+    type SelfRef<'a> = &'a Self;
+
+    const IDENTITY: Self = Self {
+        x: <Fp2 as Field>::ZERO,
+        y: <Fp2 as Field>::ZERO,
+    };
+
+    fn is_identity(&self) -> bool {
+        self.x == <Fp2 as Field>::ZERO && self.y == <Fp2 as Field>::ZERO
+    }
+
+    fn double(&self) -> Self {
+        if self.is_identity() {
+            self.clone()
+        } else {
+            Self::double_impl(self)
+        }
+    }
+
+    fn double_assign(&mut self) {
+        if !self.is_identity() {
+            *self = Self::double_impl(self);
+        }
+    }
+}
+
+// https://github.com/zcash/librustzcash/blob/6e0364cd42a2b3d2b958a54771ef51a8db79dd29/pairing/src/bls12_381/README.md#generators
+impl CyclicGroup for G2Affine {
+    const GENERATOR: Self = G2Affine {
+        x: Fp2::new(
+            Fp::from_const_bytes(hex!(
+                "b8bd21c1c85680d4efbb05a82603ac0b77d1e37a640b51b4023b40fad47ae4c65110c52d27050826910a8ff0b2a24a02"
+            )),
+            Fp::from_const_bytes(hex!(
+                "7e2b045d057dace5575d941312f14c3349507fdcbb61dab51ab62099d0d06b59654f2788a0d3ac7d609f7152602be013"
+            )),
+        ),
+        y: Fp2::new(
+            Fp::from_const_bytes(hex!(
+                "0128b808865493e189a2ac3bccc93a922cd16051699a426da7d3bd8caa9bfdad1a352edac6cdc98c116e7d7227d5e50c"
+            )),
+            Fp::from_const_bytes(hex!(
+                "be795ff05f07a9aaa11dec5c270d373fab992e57ab927426af63a7857e283ecb998bc22bb0d2ac32cc34a72ea0c40606"
+            )),
+        ),
+    };
+    const NEG_GENERATOR: Self = G2Affine {
+        x: Fp2::new(
+            Fp::from_const_bytes(hex!(
+                "b8bd21c1c85680d4efbb05a82603ac0b77d1e37a640b51b4023b40fad47ae4c65110c52d27050826910a8ff0b2a24a02"
+            )),
+            Fp::from_const_bytes(hex!(
+                "7e2b045d057dace5575d941312f14c3349507fdcbb61dab51ab62099d0d06b59654f2788a0d3ac7d609f7152602be013"
+            )),
+        ),
+        y: Fp2::new(
+            Fp::from_const_bytes(hex!(
+                "aa8247f779ab6bd8755da7753236718cf72450a53738eef9173fc766daaf79b6bc771d69efd951be887802c7c23c1b0d"
+            )),
+            Fp::from_const_bytes(hex!(
+                "ed30a00fa0f8550f5ee26754d7f274df785c829ff53fbc4010afdd6d062339993d21891706d56e18ceb1d80a4a4dfa13"
+            )),
+        ),
+    };
+}
+
+impl Add<&G2Affine> for &G2Affine {
+    type Output = G2Affine;
+
+    fn add(self, p2: &G2Affine) -> Self::Output {
+        if self.is_identity() {
+            p2.clone()
+        } else if p2.is_identity() {
+            self.clone()
+        } else if self.x == p2.x {
+            if &self.y + &p2.y == <Fp2 as Field>::ZERO {
+                G2Affine::IDENTITY
+            } else {
+                G2Affine::double_impl(self)
+            }
+        } else {
+            G2Affine::add_ne(self, p2)
+        }
+    }
+}
+
+impl Add<&G2Affine> for G2Affine {
+    type Output = Self;
+
+    fn add(self, p2: &G2Affine) -> Self::Output {
+        &self + p2
+    }
+}
+
+impl Add for G2Affine {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl AddAssign<&G2Affine> for G2Affine {
+    fn add_assign(&mut self, p2: &G2Affine) {
+        *self = &*self + p2;
+    }
+}
+
+impl AddAssign for G2Affine {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = &*self + &rhs;
+    }
+}
+
+impl Neg for G2Affine {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl Sub<&G2Affine> for &G2Affine {
+    type Output = G2Affine;
+
+    fn sub(self, p2: &G2Affine) -> Self::Output {
+        self + &p2.clone().neg()
+    }
+}
+
+impl Sub<&G2Affine> for G2Affine {
+    type Output = Self;
+
+    fn sub(self, p2: &G2Affine) -> Self::Output {
+        &self - p2
+    }
+}
+
+impl Sub for G2Affine {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl SubAssign<&G2Affine> for G2Affine {
+    fn sub_assign(&mut self, p2: &G2Affine) {
+        *self = &*self - p2;
+    }
+}
+
+impl SubAssign for G2Affine {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = &*self - &rhs;
+    }
+}
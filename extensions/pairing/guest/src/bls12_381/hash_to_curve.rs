@@ -0,0 +1,472 @@
+//! Hashing to the BLS12-381 groups as specified in
+//! [RFC 9380](https://datatracker.ietf.org/doc/html/rfc9380), with the suites
+//! `BLS12381G1_XMD:SHA-256_SSWU_RO_` and `BLS12381G2_XMD:SHA-256_SSWU_RO_` and their
+//! nonuniform `_NU_` counterparts.
+//!
+//! [G1Affine] and [G2Affine] implement [HashToCurve], so they can also be used with other hash
+//! functions through [hash_to_curve] and [encode_to_curve].
+
+use alloc::vec::Vec;
+
+use hex_literal::hex;
+use openvm_algebra_guest::IntMod;
+use openvm_ecc_guest::{
+    hash_to_curve::{
+        encode_to_curve, hash_to_curve, iso_map, map_to_curve_sswu, HashToCurve, HashToField, Sgn0,
+        SswuParams, XmdHash,
+    },
+    weierstrass::WeierstrassPoint,
+    Group,
+};
+
+use super::{mul_by_seed_abs, Fp, Fp2, G1Affine, G2Affine};
+
+/// SHA-256, computed with the SHA-256 intrinsic.
+pub(crate) struct Sha256;
+
+impl XmdHash for Sha256 {
+    const BLOCK_SIZE: usize = 64;
+
+    fn hash(input: &[u8]) -> [u8; 32] {
+        openvm_sha256_guest::sha256(input)
+    }
+}
+
+/// Hashes `msg` to a point in G1 with the random oracle suite `BLS12381G1_XMD:SHA-256_SSWU_RO_`
+/// and the domain separation tag `dst`.
+pub fn hash_to_g1(msg: &[u8], dst: &[u8]) -> G1Affine {
+    hash_to_curve::<G1Affine, Sha256>(msg, dst)
+}
+
+/// Encodes `msg` as a point in G1 with the nonuniform suite `BLS12381G1_XMD:SHA-256_SSWU_NU_`
+/// and the domain separation tag `dst`.
+pub fn encode_to_g1(msg: &[u8], dst: &[u8]) -> G1Affine {
+    encode_to_curve::<G1Affine, Sha256>(msg, dst)
+}
+
+/// Hashes `msg` to a point in G2 with the random oracle suite `BLS12381G2_XMD:SHA-256_SSWU_RO_`
+/// and the domain separation tag `dst`.
+pub fn hash_to_g2(msg: &[u8], dst: &[u8]) -> G2Affine {
+    hash_to_curve::<G2Affine, Sha256>(msg, dst)
+}
+
+/// Encodes `msg` as a point in G2 with the nonuniform suite `BLS12381G2_XMD:SHA-256_SSWU_NU_`
+/// and the domain separation tag `dst`.
+pub fn encode_to_g2(msg: &[u8], dst: &[u8]) -> G2Affine {
+    encode_to_curve::<G2Affine, Sha256>(msg, dst)
+}
+
+impl HashToCurve for G1Affine {
+    type Field = Fp;
+
+    fn map_to_curve(u: &Fp) -> Self {
+        let (x, y) = map_to_curve_sswu(u, &G1_SSWU);
+        match iso_map(
+            &x,
+            &y,
+            &G1_ISO_X_NUM,
+            &G1_ISO_X_DEN,
+            &G1_ISO_Y_NUM,
+            &G1_ISO_Y_DEN,
+        ) {
+            Some((x, y)) => Self::from_xy_unchecked(x, y),
+            None => Self::IDENTITY,
+        }
+    }
+
+    /// Multiplies by `h_eff = 1 - x` for the curve seed `x` (Section 8.8.1).
+    fn clear_cofactor(&self) -> Self {
+        // The seed is negative.
+        mul_by_seed_abs(self) + self
+    }
+}
+
+impl HashToCurve for G2Affine {
+    type Field = Fp2;
+
+    fn map_to_curve(u: &Fp2) -> Self {
+        let (x, y) = map_to_curve_sswu(u, &G2_SSWU);
+        match iso_map(
+            &x,
+            &y,
+            &G2_ISO_X_NUM,
+            &G2_ISO_X_DEN,
+            &G2_ISO_Y_NUM,
+            &G2_ISO_Y_DEN,
+        ) {
+            Some((x, y)) => Self::from_xy_unchecked(x, y),
+            None => Self::IDENTITY,
+        }
+    }
+
+    /// Multiplies by `h_eff` with the endomorphism `psi` (Appendix G.3).
+    fn clear_cofactor(&self) -> Self {
+        // The seed is negative, so `[x]P = -[|x|]P`.
+        let t1 = -mul_by_seed_abs(self);
+        let t2 = self.psi();
+        let t3 = self.double().psi().psi() - &t2;
+        let t2 = -mul_by_seed_abs(&(&t1 + &t2));
+        t3 + &t2 - &t1 - self
+    }
+}
+
+impl HashToField for Fp2 {
+    fn hash_to_field<H: XmdHash>(msg: &[u8], dst: &[u8], count: usize) -> Vec<Self> {
+        let mut coeffs = Fp::hash_to_field::<H>(msg, dst, 2 * count).into_iter();
+        (0..count)
+            .map(|_| Fp2::new(coeffs.next().unwrap(), coeffs.next().unwrap()))
+            .collect()
+    }
+}
+
+impl Sgn0 for Fp2 {
+    fn sgn0(&self) -> bool {
+        self.c0.sgn0() || (self.c0 == <Fp as IntMod>::ZERO && self.c1.sgn0())
+    }
+}
+
+/// Parameters of the simplified SWU map to the curve `E'`, which is 11-isogenous to `E`.
+const G1_SSWU: SswuParams<Fp> = SswuParams {
+    a: Fp::from_const_bytes(hex!(
+        "1d4c582d0828f45c7ff9e0a08d6f9398ac81d8ef1a98e8d8a866ee835398eab082496dc9023a693d43e9b8a398461400"
+    )),
+    b: Fp::from_const_bytes(hex!(
+        "e02b178ee948ccd1a5ea6c315a21235af55ef3ce4fc1b9a070404ff2f0c116203bee3e75e8128b01308068118d90e212"
+    )),
+    z: Fp::from_const_u8(11),
+    minus_b_over_a: Fp::from_const_bytes(hex!(
+        "7c9c4c5e6770d6298e18845af9fcbd51a78b27aa5397f31dc2fadb5b9fad28a9f6780c467024ef66d93156d84f159307"
+    )),
+    b_over_za: Fp::from_const_bytes(hex!(
+        "14286df76a81d4f74f2c8ebe5c5d9af7ad1a7dd2e15c0d3105b1ef620cca3b6840a11e597abc72e72da32616a3393912"
+    )),
+};
+
+/// Coefficients of the numerator of the `x`-coordinate of the 11-isogeny `E' -> E`, from the constant term up.
+const G1_ISO_X_NUM: [Fp; 12] = [
+    Fp::from_const_bytes(hex!(
+        "b74946736216acae6e2de6f2d5c21056c8e2b4cd567b62f25f00d7a2883e306b851598d91d1009b84033831e2b5fa011"
+    )),
+    Fp::from_const_bytes(hex!(
+        "bb83cbb3f1ee34e856c318f3a6f238486be8d1703ce365f517846a2f5be7177c1ca84721b2ba88052fab43e9d34e2917"
+    )),
+    Fp::from_const_bytes(hex!(
+        "b0dc9eac9d9f17e09f72092a3d3e8c955ce268ec01e57868e5835929732403ce1b9a0ad1c548101dec7876b95d00540d"
+    )),
+    Fp::from_const_bytes(hex!(
+        "61689b1d6488b3c5937110e35ce236538330b3f18932b3f125bf8d6a65e4f5d71254e507d309064eb76dcc6f16e77817"
+    )),
+    Fp::from_const_bytes(hex!(
+        "d99588ace94c1551c4771e306f285a9818ac2f9865eb6e083ffb57125a99db99548e11e4b3b4426643f499316a72990e"
+    )),
+    Fp::from_const_bytes(hex!(
+        "8329656fc6c113cd193de7ca2d0d87a0908fe69790abd39e9b7952d97db13cdbb54aa7f71b20d101ff13730d25c33016"
+    )),
+    Fp::from_const_bytes(hex!(
+        "84ed39a125f2d7ddca2e05c12851a28d2a6bc8f918e20890e18c0f4f268615b1bf52e68ac326376a294de43f55d66e0d"
+    )),
+    Fp::from_const_bytes(hex!(
+        "8ec8f0e31856cb9caf75f4c8f8c8b7393375f2f02ec682a6b14d5b27abe56d35537e11d1843874e8e2dbab01771eb817"
+    )),
+    Fp::from_const_bytes(hex!(
+        "17e397846a98716d954eb796f295a24fded1c528c996c5a274559fe76c753bc40d99be6335b3907bc48fa7f9f13c0d08"
+    )),
+    Fp::from_const_bytes(hex!(
+        "9e0c39be6710247f792703dc2e0b19a37f1bbbf4ba146367995c0c3a80cb2edd228f135d51370c2ec4a7cf1b8e1f9b16"
+    )),
+    Fp::from_const_bytes(hex!(
+        "7bfb05163fdf67caec5d288c1f779bf66648b10360f30ad5967f78e6ddcc7dfa0d5b56d209ecd872e207ce79a01d3210"
+    )),
+    Fp::from_const_bytes(hex!(
+        "29d2a28b2ebac8a91f394db6801b4bc2686b4cc21bbfc023bc37c8227e9dd7313deeed8123961ebd700e268e248ce006"
+    )),
+];
+
+/// Coefficients of the denominator of the `x`-coordinate of the 11-isogeny `E' -> E`, from the constant term up.
+const G1_ISO_X_DEN: [Fp; 11] = [
+    Fp::from_const_bytes(hex!(
+        "1c1bd240faf93c99f83d34be81d658b562acc87f6188959cba485ba34befd5016ffad34b2fe6b218ae19ff8c548dca08"
+    )),
+    Fp::from_const_bytes(hex!(
+        "ff3b2bc86e27c8e5e926b06c84a8da1357bf488c58c226010c80f00ccae841703636e5981271b448439c55eb5d1a5612"
+    )),
+    Fp::from_const_bytes(hex!(
+        "193eb85cba39c2fcdcfe947c96d0a3d6acce1ab1004ea6fcd18c715a6f41896f1f99f2bf29e637815e22a357fe62290b"
+    )),
+    Fp::from_const_bytes(hex!(
+        "d82cc68d93e80d13c4f5ec3e24d57649d06f8dc2aba8cc545165b1163f24085b54eb407cefaf3ac8fee28aa581554203"
+    )),
+    Fp::from_const_bytes(hex!(
+        "1ea232355b399d531d78351fa89bd29bda06b333e8446b8dbb6220a159c7dfff7da0e7435f1d6f0aa814290262e1a813"
+    )),
+    Fp::from_const_bytes(hex!(
+        "a504639fa2f92dc0118f22c44bd200742f98b824efbc430a4dad9ccee9355739e9c606057f0f3955b967e6e4f855730e"
+    )),
+    Fp::from_const_bytes(hex!(
+        "3a4ae86e497425ec6ce00d8f53b373ea9cdeae6230072e4e89a153af99570f57216759e0630c3e0f193669f1acca7207"
+    )),
+    Fp::from_const_bytes(hex!(
+        "5e5accbd9bd9f711d3e2119d48b9a50f582c82f9cde19619a868ca1bc2637f6e9901cf74b0f5b330b2a8649d2aaca714"
+    )),
+    Fp::from_const_bytes(hex!(
+        "41d6a1793aec76477043baab926682033588397fa60d10746b3661711d8d7fe0cca3c7af3d0b925e824fa5adf6ec100a"
+    )),
+    Fp::from_const_bytes(hex!(
+        "0addec68d184632d0065784b4b4e1793151cf3783953df7637d0f796eeb482f68066a5b33e6e6d47d42ae9b93ac15f09"
+    )),
+    Fp::from_const_u8(1),
+];
+
+/// Coefficients of the numerator of the `y`-coordinate of the 11-isogeny `E' -> E`, from the constant term up.
+const G1_ISO_Y_NUM: [Fp; 16] = [
+    Fp::from_const_bytes(hex!(
+        "33bb0797714598bec2a33b9bee7a0ccdd34365956caf522b5269a8488e13ad11fadc8049091f9d25e04ea21bc8970d09"
+    )),
+    Fp::from_const_bytes(hex!(
+        "96c6412e5ae797e0bfa82f961167c5d634ad67eb4363900f3d384f256ce923128346fb76d73610d51158ee04a1964913"
+    )),
+    Fp::from_const_bytes(hex!(
+        "f6e12dc740e2dfb82305b02816526dd2a70d40914bbe44c3e21ca358d6e2522594493b6ac884a3f4666e96aa6b78cc00"
+    )),
+    Fp::from_const_bytes(hex!(
+        "cbf4e5b0775c35a6ec1dc69eba5a40def91c2503eca3e4091c79eb0eb9a72ad4576774d81a759878211c98e87663f801"
+    )),
+    Fp::from_const_bytes(hex!(
+        "dbfee8f2ecdab641a8409009dcf8e72edc1a3521d23f8379b850cee3fb365519c42915a2e24faf5c13ffe0effd03cc08"
+    )),
+    Fp::from_const_bytes(hex!(
+        "f0a53336b13ab29972805ac926633f204e54d53a3d5c5076bdb7adafd4d0a774a0a6f0b81de111226a4b6340ca3f6016"
+    )),
+    Fp::from_const_bytes(hex!(
+        "f2d6e95f85f861c95e410f46c27aa847374f634d3c413152cb84e12bcab85be73c6b7927d077c9b2bc1baccf9b0bab04"
+    )),
+    Fp::from_const_bytes(hex!(
+        "29fb7018a34c5ea135e9dffe5045f64226846cc2a68d03fd1fd8bde3bf050a1774c6a62cbd2699de6fb83a33d5c88709"
+    )),
+    Fp::from_const_bytes(hex!(
+        "87a5db7b570e3760a30786c70152d669beca201f6a6e8b1e6cc29d6716bd3a8fbba14d1e229e8ce84b6896bd1840fc09"
+    )),
+    Fp::from_const_bytes(hex!(
+        "301c73caebaaaf2b6fba4edd55703f9b3bd4e4d17e5e9806fef65a91a70c2ac4234aa1ade7bd3a22b5bd86117aba1b0e"
+    )),
+    Fp::from_const_bytes(hex!(
+        "32c111d01a7113e8fcba3fce5c3abf3110e68963413e18d13f49af6ccbcb2fcd93fb431d8f0bfd0dbed17c93473e7119"
+    )),
+    Fp::from_const_bytes(hex!(
+        "8e4d07d0a4c807ce06d6441bf4cdd94932641f917ffe6b2e46c2f0aab8593552ccedd2fe43c118b9def6368f906ab418"
+    )),
+    Fp::from_const_bytes(hex!(
+        "f81e970bf0044c0df2119291c151c8063f63b407e81027c08eb026342ab1a77a443ff504600955d199931b10ac2c180b"
+    )),
+    Fp::from_const_bytes(hex!(
+        "330198dbf5d3d942642a231cad90cfc69c65403c2d63e61380154c0d083b7b75dc15e37bae00fc729bca1ead94a34502"
+    )),
+    Fp::from_const_bytes(hex!(
+        "4b227554711e6b86b7af79659b04a16be47c6a395d0fabd96865e8d7813d675efca3c448f759a10211cf445e6429c105"
+    )),
+    Fp::from_const_bytes(hex!(
+        "04b6c869be56b404771cc0fe7e0265b6b201af95fad4ad57395a96848f1d18cbb52edf423b0ba54ece030f994ebee615"
+    )),
+];
+
+/// Coefficients of the denominator of the `y`-coordinate of the 11-isogeny `E' -> E`, from the constant term up.
+const G1_ISO_Y_DEN: [Fp; 16] = [
+    Fp::from_const_bytes(hex!(
+        "c16336b0539247016d200cf68e68f3077a2ee75b2b23c3eee60b9878e56d1a60e9ead0fa40111852b2989c3a4c2c1116"
+    )),
+    Fp::from_const_bytes(hex!(
+        "3da0492e2c10f632359e52630726a4781fe053f45603a1a49cd5c431f74fc885b848c3436cbd0c1a1e2081235cd76219"
+    )),
+    Fp::from_const_bytes(hex!(
+        "f267bf3db538251e896ff936d67c75a6ecd29c27dda5350c317fae515585c47857b18e6e7daeaa6f27da406630f38d05"
+    )),
+    Fp::from_const_bytes(hex!(
+        "16545f44986dd2a8ad9782c2f264737241af26e789a43d120ed3bcdddbc515d1d1b4ed89bf230df295538e7988d2b716"
+    )),
+    Fp::from_const_bytes(hex!(
+        "1d00a511231439daededd20fdc150ba216c9dec90fda2e54b0bb690f9f9cd1c6dc8d22f812c90cb0e4435f5479e0e00b"
+    )),
+    Fp::from_const_bytes(hex!(
+        "acb7f9aa7f47c602e2cca9dfb98df349645a0f6f7bd8ecc581d85055c65201b7823178acea66b29f2ddb867129e5d908"
+    )),
+    Fp::from_const_bytes(hex!(
+        "5c776a1299131a3db91f8ba5019cfad551000a40bc65d35deff80c8dfafdec5e4b82e9ac3487bac32fdb998ac0076016"
+    )),
+    Fp::from_const_bytes(hex!(
+        "d92e81155a41ee60e4de0108b0f520b932713506d24fb3fed74f1ffa5e37a4e56effa6bbfaddbc03eaa73ebe08efa316"
+    )),
+    Fp::from_const_bytes(hex!(
+        "4a5d53559d3d236b834892bbf7e2cf52489bf34b0c75c5ab2059afc6e40cfbf9c44cd7d14fe51b1a23616c33edc86618"
+    )),
+    Fp::from_const_bytes(hex!(
+        "553f91b88bf46e345eb329d5a35e38c7fbd4a77e2e590853bb873de163f7163290844ad9970582ea1c6e0aa7cd557a16"
+    )),
+    Fp::from_const_bytes(hex!(
+        "a86a8fba9cb4f8000406834f9fc2a571c8a536e6361b590e29a661bb39d06d9c1d91d21aa010f048bd05a4ee59f2d204"
+    )),
+    Fp::from_const_bytes(hex!(
+        "921056e229b58496eabb7e6f9868f9163591a7ce889a0f8cd242ceef8aff947f470cc5481e2c85f53f031d4867bbcc0a"
+    )),
+    Fp::from_const_bytes(hex!(
+        "cc45335738b1991ec10ab9e363070093869b0da0f9ee5c7d2602df8ad946335496145fb4443161c3e37f764c51b9d60a"
+    )),
+    Fp::from_const_bytes(hex!(
+        "f706ed2613dcfad180cc1487bd1705420e484279f28d74cbc12729e7945b56bff276cd530ddd8b623b4f2eeb00046602"
+    )),
+    Fp::from_const_bytes(hex!(
+        "8f4b631d3a471544809734f170f5a25c20aa6c35d6fc4e3253b873e2650fc471c119780d5e25246b3ec0dd16d8a10f0e"
+    )),
+    Fp::from_const_u8(1),
+];
+
+/// Parameters of the simplified SWU map to the curve `E2'`, which is 3-isogenous to `E2`.
+const G2_SSWU: SswuParams<Fp2> = SswuParams {
+    a: Fp2::new(
+        Fp::from_const_u8(0),
+        Fp::from_const_u8(240),
+    ),
+    b: Fp2::new(
+        Fp::from_const_bytes(hex!(
+            "f40300000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+        )),
+        Fp::from_const_bytes(hex!(
+            "f40300000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+        )),
+    ),
+    z: Fp2::new(
+        Fp::from_const_bytes(hex!(
+            "a9aafffffffffeb9ffff53b1feffab1e24f6b0f6a0d23067bf1285f3844b7764d7ac4b43b6a71b4b9ae67f39ea11011a"
+        )),
+        Fp::from_const_bytes(hex!(
+            "aaaafffffffffeb9ffff53b1feffab1e24f6b0f6a0d23067bf1285f3844b7764d7ac4b43b6a71b4b9ae67f39ea11011a"
+        )),
+    ),
+    minus_b_over_a: Fp2::new(
+        Fp::from_const_bytes(hex!(
+            "c3b1cccccc8c5d72888849da434483d6f14dfc2be675cf0223793f6f3f2d8c9baae5c60c4f282ffed2d5bd1a79123c08"
+        )),
+        Fp::from_const_bytes(hex!(
+            "e8f832333373a14777770ad7babb284832a8b4caba5c61649c994584451eebc82cc78436677fec4cc710c21e71ffc411"
+        )),
+    ),
+    b_over_za: Fp2::new(
+        Fp::from_const_bytes(hex!(
+            "27bdf5285c4face3b481db5eda401a5e30a965a2c74af6666d4ba67cd9d5e8ebee2d8e02433bd6322a91bf6b4b9da501"
+        )),
+        Fp::from_const_bytes(hex!(
+            "36731e85eb11fa0ee17ac1946f3d5d0493fa7f0f4af24d327730927df8c9bca00a23a13bedf598b21b3341f6073a1015"
+        )),
+    ),
+};
+
+/// Coefficients of the numerator of the `x`-coordinate of the 3-isogeny `E2' -> E2`, from the constant term up.
+const G2_ISO_X_NUM: [Fp2; 4] = [
+    Fp2::new(
+        Fp::from_const_bytes(hex!(
+            "d697aaaaaaaa38621cc7d943e338265c5de10ac52384b5882a043afd392dc53285edd7479a7a5bbb3e338e7e5059c705"
+        )),
+        Fp::from_const_bytes(hex!(
+            "d697aaaaaaaa38621cc7d943e338265c5de10ac52384b5882a043afd392dc53285edd7479a7a5bbb3e338e7e5059c705"
+        )),
+    ),
+    Fp2::new(
+        Fp::from_const_u8(0),
+        Fp::from_const_bytes(hex!(
+            "1ac7ffffffffa92655558dcba9aa721418a4204f6b8c209a7f0caef7ad874f988fc887d7ce6f1232bc99aa7bf10b5611"
+        )),
+    ),
+    Fp2::new(
+        Fp::from_const_bytes(hex!(
+            "1ec7ffffffffa92655558dcba9aa721418a4204f6b8c209a7f0caef7ad874f988fc887d7ce6f1232bc99aa7bf10b5611"
+        )),
+        Fp::from_const_bytes(hex!(
+            "8de3ffffffff5493aaaac6e55455390a0c5290a7354610cd3f06d7fbd6c327cc47e4c36be7370919de4cd5bdf805ab08"
+        )),
+    ),
+    Fp2::new(
+        Fp::from_const_bytes(hex!(
+            "d15eaaaaaaaae288711c670f8de3987075852b148f10d622aa10e8f4e7b414cb14b65f1f69ea6dedfacc38fa41651d17"
+        )),
+        Fp::from_const_u8(0),
+    ),
+];
+
+/// Coefficients of the denominator of the `x`-coordinate of the 3-isogeny `E2' -> E2`, from the constant term up.
+const G2_ISO_X_DEN: [Fp2; 3] = [
+    Fp2::new(
+        Fp::from_const_u8(0),
+        Fp::from_const_bytes(hex!(
+            "63aafffffffffeb9ffff53b1feffab1e24f6b0f6a0d23067bf1285f3844b7764d7ac4b43b6a71b4b9ae67f39ea11011a"
+        )),
+    ),
+    Fp2::new(
+        Fp::from_const_u8(12),
+        Fp::from_const_bytes(hex!(
+            "9faafffffffffeb9ffff53b1feffab1e24f6b0f6a0d23067bf1285f3844b7764d7ac4b43b6a71b4b9ae67f39ea11011a"
+        )),
+    ),
+    Fp2::new(
+        Fp::from_const_u8(1),
+        Fp::from_const_u8(0),
+    ),
+];
+
+/// Coefficients of the numerator of the `y`-coordinate of the 3-isogeny `E2' -> E2`, from the constant term up.
+const G2_ISO_Y_NUM: [Fp2; 4] = [
+    Fp2::new(
+        Fp::from_const_bytes(hex!(
+            "06d7c6711cc7cf12682fc9f8eb258cfc00e5277dd83944f59b647fa0d4a57d0f93116d078bc1a4593b11b47a7c473015"
+        )),
+        Fp::from_const_bytes(hex!(
+            "06d7c6711cc7cf12682fc9f8eb258cfc00e5277dd83944f59b647fa0d4a57d0f93116d078bc1a4593b11b47a7c473015"
+        )),
+    ),
+    Fp2::new(
+        Fp::from_const_u8(0),
+        Fp::from_const_bytes(hex!(
+            "be97aaaaaaaa38621cc7d943e338265c5de10ac52384b5882a043afd392dc53285edd7479a7a5bbb3e338e7e5059c705"
+        )),
+    ),
+    Fp2::new(
+        Fp::from_const_bytes(hex!(
+            "1cc7ffffffffa92655558dcba9aa721418a4204f6b8c209a7f0caef7ad874f988fc887d7ce6f1232bc99aa7bf10b5611"
+        )),
+        Fp::from_const_bytes(hex!(
+            "8fe3ffffffff5493aaaac6e55455390a0c5290a7354610cd3f06d7fbd6c327cc47e4c36be7370919de4cd5bdf805ab08"
+        )),
+    ),
+    Fp2::new(
+        Fp::from_const_bytes(hex!(
+            "108b711cc771b3e1d94bdc567a09794e5274a29ac677e9b08662e2a1370f1b76d01a81e33d04f7fb9bf76c3bd49a4c12"
+        )),
+        Fp::from_const_u8(0),
+    ),
+];
+
+/// Coefficients of the denominator of the `y`-coordinate of the 3-isogeny `E2' -> E2`, from the constant term up.
+const G2_ISO_Y_DEN: [Fp2; 4] = [
+    Fp2::new(
+        Fp::from_const_bytes(hex!(
+            "fba8fffffffffeb9ffff53b1feffab1e24f6b0f6a0d23067bf1285f3844b7764d7ac4b43b6a71b4b9ae67f39ea11011a"
+        )),
+        Fp::from_const_bytes(hex!(
+            "fba8fffffffffeb9ffff53b1feffab1e24f6b0f6a0d23067bf1285f3844b7764d7ac4b43b6a71b4b9ae67f39ea11011a"
+        )),
+    ),
+    Fp2::new(
+        Fp::from_const_u8(0),
+        Fp::from_const_bytes(hex!(
+            "d3a9fffffffffeb9ffff53b1feffab1e24f6b0f6a0d23067bf1285f3844b7764d7ac4b43b6a71b4b9ae67f39ea11011a"
+        )),
+    ),
+    Fp2::new(
+        Fp::from_const_u8(18),
+        Fp::from_const_bytes(hex!(
+            "99aafffffffffeb9ffff53b1feffab1e24f6b0f6a0d23067bf1285f3844b7764d7ac4b43b6a71b4b9ae67f39ea11011a"
+        )),
+    ),
+    Fp2::new(
+        Fp::from_const_u8(1),
+        Fp::from_const_u8(0),
+    ),
+];
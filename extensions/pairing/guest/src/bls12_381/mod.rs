@@ -2,14 +2,22 @@ use core::ops::{Add, AddAssign, Neg};
 
 use openvm_algebra_guest::{Field, IntMod};
 use openvm_algebra_moduli_setup::moduli_declare;
-use openvm_ecc_guest::{weierstrass::IntrinsicCurve, CyclicGroup, Group};
+use openvm_ecc_guest::{
+    weierstrass::{IntrinsicCurve, WeierstrassPoint},
+    CyclicGroup, Group,
+};
 
 mod fp12;
 mod fp2;
+mod g2;
+mod hash_to_curve;
 mod pairing;
+pub mod signature;
 
 pub use fp12::*;
 pub use fp2::*;
+pub use g2::*;
+pub use hash_to_curve::*;
 use hex_literal::hex;
 #[cfg(not(target_os = "zkvm"))]
 use lazy_static::lazy_static;
@@ -33,6 +41,8 @@ lazy_static! {
     ));
 }
 
+/// `Fp2 = Fp[u]/(u^2 - BLS12_381_FP2_NON_RESIDUE)`
+pub const BLS12_381_FP2_NON_RESIDUE: isize = -1;
 pub const BLS12_381_XI_ISIZE: [isize; 2] = [1, 1];
 pub const BLS12_381_NUM_LIMBS: usize = 48;
 pub const BLS12_381_LIMB_BITS: usize = 8;
@@ -116,6 +126,38 @@ impl CyclicGroup for G1Affine {
     };
 }
 
+impl G1Affine {
+    /// A primitive cube root of unity, for the endomorphism `phi` that maps `(x, y)` to
+    /// `(BETA * x, y)`.
+    const BETA: Fp = Bls12_381Fp::from_const_bytes(hex!(
+        "fefffeffffff012e02000a6213d817de8896f8e63ba9b3ddea770f6a07c669ba51ce76df2f67195f0000000000000000"
+    ));
+
+    /// Returns whether `self` is in the prime order subgroup, using that this is the case if and
+    /// only if `phi(P) = -[x^2]P` for the curve seed `x`.
+    ///
+    /// Reference: <https://eprint.iacr.org/2021/1130.pdf>
+    pub fn is_torsion_free(&self) -> bool {
+        if self.is_identity() {
+            return true;
+        }
+        let phi = G1Affine::from_xy_unchecked(&Self::BETA * self.x(), self.y().clone());
+        phi == -mul_by_seed_abs(&mul_by_seed_abs(self))
+    }
+}
+
+/// Returns `[|x|]P` for the curve seed `x`, by double-and-add.
+pub(crate) fn mul_by_seed_abs<G: Group>(p: &G) -> G {
+    let mut acc = p.clone();
+    for i in (0..BLS12_381_SEED_ABS.ilog2()).rev() {
+        acc.double_assign();
+        if (BLS12_381_SEED_ABS >> i) & 1 == 1 {
+            acc += p;
+        }
+    }
+    acc
+}
+
 impl IntrinsicCurve for Bls12_381 {
     type Scalar = Scalar;
     type Point = G1Affine;
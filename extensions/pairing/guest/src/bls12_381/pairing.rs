@@ -18,7 +18,7 @@ use {
 use super::{Bls12_381, Fp, Fp12, Fp2};
 use crate::pairing::{
     Evaluatable, EvaluatedLine, FromLineMType, LineMulMType, MillerStep, MultiMillerLoop,
-    PairingCheck, PairingCheckError, PairingIntrinsics, UnevaluatedLine,
+    PairingCheck, PairingCheckError, PairingCheckHint, PairingIntrinsics, UnevaluatedLine,
 };
 
 // TODO[jpw]: make macro
@@ -268,6 +268,39 @@ impl PairingCheck for Bls12_381 {
     type Fp2 = Fp2;
     type Fp12 = Fp12;
 
+    fn pairing_check(
+        P: &[AffinePoint<Self::Fp>],
+        Q: &[AffinePoint<Self::Fp2>],
+    ) -> Result<(), PairingCheckError> {
+        let (c, s) = Self::pairing_check_hint(P, Q);
+
+        // f * s = c^{q - x}
+        // f * s = c^q * c^-x
+        // f * c^x * c^-q * s = 1,
+        //   where fc = f * c'^x (embedded Miller loop with c conjugate inverse),
+        //   and the curve seed x = -0xd201000000010000
+        //   the miller loop computation includes a conjugation at the end because the value of the
+        //   seed is negative, so we need to conjugate the miller loop input c as c'. We then substitute
+        //   y = -x to get c^-y and finally compute c'^-y as input to the miller loop:
+        // f * c'^-y * c^-q * s = 1
+        let c_q = FieldExtension::frobenius_map(&c, 1);
+        let c_conj_inv = Fp12::ONE.div_unsafe(&c.conjugate());
+
+        // fc = f_{Miller,x,Q}(P) * c^{x}
+        // where
+        //   fc = conjugate( f_{Miller,-x,Q}(P) * c'^{-x} ), with c' denoting the conjugate of c
+        let fc = Self::multi_miller_loop_embedded_exp(P, Q, Some(c_conj_inv));
+
+        if fc * s == c_q {
+            Ok(())
+        } else {
+            Err(PairingCheckError)
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+impl PairingCheckHint for Bls12_381 {
     #[allow(unused_variables)]
     fn pairing_check_hint(
         P: &[AffinePoint<Self::Fp>],
@@ -302,34 +335,4 @@ impl PairingCheck for Bls12_381 {
             }
         }
     }
-
-    fn pairing_check(
-        P: &[AffinePoint<Self::Fp>],
-        Q: &[AffinePoint<Self::Fp2>],
-    ) -> Result<(), PairingCheckError> {
-        let (c, s) = Self::pairing_check_hint(P, Q);
-
-        // f * s = c^{q - x}
-        // f * s = c^q * c^-x
-        // f * c^x * c^-q * s = 1,
-        //   where fc = f * c'^x (embedded Miller loop with c conjugate inverse),
-        //   and the curve seed x = -0xd201000000010000
-        //   the miller loop computation includes a conjugation at the end because the value of the
-        //   seed is negative, so we need to conjugate the miller loop input c as c'. We then substitute
-        //   y = -x to get c^-y and finally compute c'^-y as input to the miller loop:
-        // f * c'^-y * c^-q * s = 1
-        let c_q = FieldExtension::frobenius_map(&c, 1);
-        let c_conj_inv = Fp12::ONE.div_unsafe(&c.conjugate());
-
-        // fc = f_{Miller,x,Q}(P) * c^{x}
-        // where
-        //   fc = conjugate( f_{Miller,-x,Q}(P) * c'^{-x} ), with c' denoting the conjugate of c
-        let fc = Self::multi_miller_loop_embedded_exp(P, Q, Some(c_conj_inv));
-
-        if fc * s == c_q {
-            Ok(())
-        } else {
-            Err(PairingCheckError)
-        }
-    }
 }
@@ -0,0 +1,210 @@
+//! The variant with public keys in G1 and signatures in G2.
+
+use alloc::vec::Vec;
+
+use openvm_ecc_guest::{msm, weierstrass::IntrinsicCurve, AffinePoint, CyclicGroup, Group};
+
+use super::{batch_scalars, g1_affine_point, BlsError, PointEncoding};
+use crate::{
+    bls12_381::{hash_to_g2, Bls12_381, Fp, Fp2, G1Affine, G2Affine},
+    pairing::PairingCheck,
+};
+
+/// Domain separation tag of the basic scheme, which requires distinct messages in
+/// [aggregate_verify].
+pub const DST_BASIC: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
+/// Domain separation tag of the proof of possession scheme, which is used by Ethereum.
+pub const DST_POP: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+/// Domain separation tag of proofs of possession.
+pub const DST_POP_PROOF: &[u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// A public key that passed `KeyValidate`: it is in the prime order subgroup and not the
+/// identity.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey(G1Affine);
+
+impl PublicKey {
+    pub const SIZE: usize = 48;
+
+    /// Deserializes and validates a compressed public key.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BlsError> {
+        Self::from_point(G1Affine::decode(bytes)?)
+    }
+
+    pub fn from_point(point: G1Affine) -> Result<Self, BlsError> {
+        if point.is_identity() {
+            return Err(BlsError::IdentityPublicKey);
+        }
+        if !point.is_torsion_free() {
+            return Err(BlsError::NotInSubgroup);
+        }
+        Ok(Self(point))
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.encode()
+    }
+
+    pub fn as_point(&self) -> &G1Affine {
+        &self.0
+    }
+}
+
+/// A signature in the prime order subgroup.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature(G2Affine);
+
+impl Signature {
+    pub const SIZE: usize = 96;
+
+    /// Deserializes a compressed signature and checks that it is in the subgroup.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BlsError> {
+        Ok(Self(G2Affine::decode_checked(bytes)?))
+    }
+
+    pub fn from_point(point: G2Affine) -> Result<Self, BlsError> {
+        if !point.is_torsion_free() {
+            return Err(BlsError::NotInSubgroup);
+        }
+        Ok(Self(point))
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.encode()
+    }
+
+    pub fn as_point(&self) -> &G2Affine {
+        &self.0
+    }
+}
+
+/// Verifies `signature` of `msg` under `public_key`, i.e., checks that
+/// `e(public_key, H(msg)) = e(G1, signature)`.
+pub fn verify(
+    public_key: &PublicKey,
+    msg: &[u8],
+    signature: &Signature,
+    dst: &[u8],
+) -> Result<(), BlsError> {
+    pairing_check(
+        [(&public_key.0, hash_to_g2(msg, dst))].into_iter(),
+        &signature.0,
+    )
+}
+
+/// Verifies an aggregate `signature` of `msgs[i]` under `public_keys[i]` for all `i`. With
+/// [DST_BASIC], the caller must also check that the messages are distinct.
+pub fn aggregate_verify(
+    public_keys: &[PublicKey],
+    msgs: &[&[u8]],
+    signature: &Signature,
+    dst: &[u8],
+) -> Result<(), BlsError> {
+    if public_keys.is_empty() || public_keys.len() != msgs.len() {
+        return Err(BlsError::InvalidInputLength);
+    }
+    pairing_check(
+        public_keys
+            .iter()
+            .zip(msgs)
+            .map(|(public_key, msg)| (&public_key.0, hash_to_g2(msg, dst))),
+        &signature.0,
+    )
+}
+
+/// Verifies an aggregate `signature` of the same `msg` under all `public_keys`, as for Ethereum
+/// sync committees. This is only secure with proofs of possession of the keys, i.e. with
+/// [DST_POP].
+pub fn fast_aggregate_verify(
+    public_keys: &[PublicKey],
+    msg: &[u8],
+    signature: &Signature,
+    dst: &[u8],
+) -> Result<(), BlsError> {
+    verify(&aggregate_public_keys(public_keys)?, msg, signature, dst)
+}
+
+/// Verifies `signatures[i]` of `msgs[i]` under `public_keys[i]` for all `i` with a single
+/// pairing check, by combining them with random scalars derived from all inputs.
+pub fn batch_verify(
+    public_keys: &[PublicKey],
+    msgs: &[&[u8]],
+    signatures: &[Signature],
+    dst: &[u8],
+) -> Result<(), BlsError> {
+    if public_keys.is_empty()
+        || public_keys.len() != msgs.len()
+        || public_keys.len() != signatures.len()
+    {
+        return Err(BlsError::InvalidInputLength);
+    }
+    let scalars = batch_scalars(public_keys.iter().zip(msgs).zip(signatures).map(
+        |((public_key, msg), signature)| {
+            (public_key.to_bytes(), msg.to_vec(), signature.to_bytes())
+        },
+    ));
+    // e(G1, sum r_i sig_i) = prod e(r_i pk_i, H(msg_i))
+    let signature = msm(
+        &scalars,
+        &signatures.iter().map(|s| s.0.clone()).collect::<Vec<_>>(),
+    );
+    let public_keys: Vec<_> = scalars
+        .iter()
+        .zip(public_keys)
+        .map(|(scalar, public_key)| Bls12_381::scalar_mul(scalar, &public_key.0))
+        .collect();
+    pairing_check(
+        public_keys
+            .iter()
+            .zip(msgs)
+            .map(|(public_key, msg)| (public_key, hash_to_g2(msg, dst))),
+        &signature,
+    )
+}
+
+/// Aggregates public keys for [fast_aggregate_verify]. Fails if there are no public keys or if
+/// the aggregate is the identity.
+pub fn aggregate_public_keys(public_keys: &[PublicKey]) -> Result<PublicKey, BlsError> {
+    if public_keys.is_empty() {
+        return Err(BlsError::InvalidInputLength);
+    }
+    let aggregate = public_keys
+        .iter()
+        .fold(G1Affine::IDENTITY, |acc, public_key| acc + &public_key.0);
+    if aggregate.is_identity() {
+        return Err(BlsError::IdentityPublicKey);
+    }
+    Ok(PublicKey(aggregate))
+}
+
+pub fn aggregate_signatures(signatures: &[Signature]) -> Result<Signature, BlsError> {
+    if signatures.is_empty() {
+        return Err(BlsError::InvalidInputLength);
+    }
+    Ok(Signature(
+        signatures
+            .iter()
+            .fold(G2Affine::IDENTITY, |acc, signature| acc + &signature.0),
+    ))
+}
+
+/// Verifies a proof of possession of the secret key of `public_key`, which signs the serialized
+/// public key with [DST_POP_PROOF].
+pub fn verify_proof_of_possession(
+    public_key: &PublicKey,
+    proof: &Signature,
+) -> Result<(), BlsError> {
+    verify(public_key, &public_key.to_bytes(), proof, DST_POP_PROOF)
+}
+
+/// Checks that `prod e(P_i, Q_i) = e(G1, signature)`.
+fn pairing_check<'a>(
+    pairs: impl Iterator<Item = (&'a G1Affine, G2Affine)>,
+    signature: &G2Affine,
+) -> Result<(), BlsError> {
+    let (mut p, mut q): (Vec<AffinePoint<Fp>>, Vec<AffinePoint<Fp2>>) =
+        pairs.map(|(p, q)| (g1_affine_point(p), q.into())).unzip();
+    p.push(g1_affine_point(&G1Affine::NEG_GENERATOR));
+    q.push(signature.clone().into());
+    Bls12_381::pairing_check(&p, &q).map_err(|_| BlsError::VerificationFailed)
+}
@@ -0,0 +1,236 @@
+//! BLS signatures over BLS12-381 as specified in
+//! [draft-irtf-cfrg-bls-signature-05](https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-bls-signature-05),
+//! in both variants:
+//! - [min_pk]: public keys in G1 and signatures in G2. This is the variant used by Ethereum.
+//! - [min_sig]: signatures in G1 and public keys in G2.
+//!
+//! Messages are hashed to the curve with [RFC 9380](https://datatracker.ietf.org/doc/html/rfc9380).
+//! Points are serialized in the compressed ZCash format, and deserialization checks that they
+//! lie in the prime order subgroup. Verification reduces to a single [PairingCheck], and scalar
+//! multiplications in G1 use the short Weierstrass intrinsics.
+//!
+//! [PairingCheck]: crate::pairing::PairingCheck
+
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+use openvm_algebra_guest::{IntMod, Sqrt};
+use openvm_ecc_guest::{weierstrass::WeierstrassPoint, AffinePoint, Group};
+use openvm_sha256_guest::sha256;
+
+use super::{Fp, Fp2, G1Affine, G2Affine, Scalar};
+
+pub mod min_pk;
+pub mod min_sig;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlsError {
+    /// The bytes are not a compressed point on the curve.
+    InvalidEncoding,
+    /// The point is not in the prime order subgroup.
+    NotInSubgroup,
+    /// The public key is the identity.
+    IdentityPublicKey,
+    /// There are no inputs, or the numbers of public keys, messages and signatures differ.
+    InvalidInputLength,
+    /// The signature does not match.
+    VerificationFailed,
+}
+
+impl core::error::Error for BlsError {}
+impl core::fmt::Display for BlsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidEncoding => write!(f, "Invalid point encoding"),
+            Self::NotInSubgroup => write!(f, "Point not in the prime order subgroup"),
+            Self::IdentityPublicKey => write!(f, "Public key is the identity"),
+            Self::InvalidInputLength => write!(f, "Invalid number of inputs"),
+            Self::VerificationFailed => write!(f, "Signature verification failed"),
+        }
+    }
+}
+
+/// Flags in the most significant bits of the first byte of the ZCash serialization.
+const COMPRESSION_FLAG: u8 = 1 << 7;
+const INFINITY_FLAG: u8 = 1 << 6;
+const SORT_FLAG: u8 = 1 << 5;
+
+/// Compressed ZCash serialization of curve points.
+trait PointEncoding: Group {
+    const COMPRESSED_SIZE: usize;
+
+    /// Deserializes a point on the curve. The subgroup is not checked.
+    fn decode(bytes: &[u8]) -> Result<Self, BlsError>;
+
+    fn encode(&self) -> Vec<u8>;
+
+    fn is_torsion_free(&self) -> bool;
+
+    /// Deserializes a point in the prime order subgroup.
+    fn decode_checked(bytes: &[u8]) -> Result<Self, BlsError> {
+        let p = Self::decode(bytes)?;
+        if !p.is_torsion_free() {
+            return Err(BlsError::NotInSubgroup);
+        }
+        Ok(p)
+    }
+}
+
+impl PointEncoding for G1Affine {
+    const COMPRESSED_SIZE: usize = 48;
+
+    fn decode(bytes: &[u8]) -> Result<Self, BlsError> {
+        let (flags, x) = split_flags::<Self>(bytes)?;
+        if flags & INFINITY_FLAG != 0 {
+            return Ok(Self::IDENTITY);
+        }
+        let x = fp_from_be_bytes(&x).ok_or(BlsError::InvalidEncoding)?;
+        let y = (&(&x * &x) * &x + &Self::CURVE_B)
+            .sqrt()
+            .ok_or(BlsError::InvalidEncoding)?;
+        let y = if fp_is_lexicographically_largest(&y) == (flags & SORT_FLAG != 0) {
+            y
+        } else {
+            -y
+        };
+        Ok(Self::from_xy_unchecked(x, y))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        if self.is_identity() {
+            return identity_encoding::<Self>();
+        }
+        let mut bytes = fp_to_be_bytes(self.x());
+        bytes[0] |= COMPRESSION_FLAG;
+        if fp_is_lexicographically_largest(self.y()) {
+            bytes[0] |= SORT_FLAG;
+        }
+        bytes
+    }
+
+    fn is_torsion_free(&self) -> bool {
+        G1Affine::is_torsion_free(self)
+    }
+}
+
+impl PointEncoding for G2Affine {
+    const COMPRESSED_SIZE: usize = 96;
+
+    fn decode(bytes: &[u8]) -> Result<Self, BlsError> {
+        let (flags, x) = split_flags::<Self>(bytes)?;
+        if flags & INFINITY_FLAG != 0 {
+            return Ok(Self::IDENTITY);
+        }
+        // The imaginary part comes first.
+        let x = Fp2::new(
+            fp_from_be_bytes(&x[48..]).ok_or(BlsError::InvalidEncoding)?,
+            fp_from_be_bytes(&x[..48]).ok_or(BlsError::InvalidEncoding)?,
+        );
+        let y = (&(&x * &x) * &x + &Self::CURVE_B)
+            .sqrt()
+            .ok_or(BlsError::InvalidEncoding)?;
+        let y = if fp2_is_lexicographically_largest(&y) == (flags & SORT_FLAG != 0) {
+            y
+        } else {
+            -y
+        };
+        Ok(Self::from_xy_unchecked(x, y))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        if self.is_identity() {
+            return identity_encoding::<Self>();
+        }
+        let mut bytes = fp_to_be_bytes(&self.x().c1);
+        bytes.extend(fp_to_be_bytes(&self.x().c0));
+        bytes[0] |= COMPRESSION_FLAG;
+        if fp2_is_lexicographically_largest(self.y()) {
+            bytes[0] |= SORT_FLAG;
+        }
+        bytes
+    }
+
+    fn is_torsion_free(&self) -> bool {
+        G2Affine::is_torsion_free(self)
+    }
+}
+
+/// Checks the length and the flags of a compressed point, and returns the flags and the
+/// coordinate bytes with the flags cleared. Identity encodings are checked to be all zeros.
+fn split_flags<P: PointEncoding>(bytes: &[u8]) -> Result<(u8, Vec<u8>), BlsError> {
+    if bytes.len() != P::COMPRESSED_SIZE {
+        return Err(BlsError::InvalidEncoding);
+    }
+    let flags = bytes[0] & (COMPRESSION_FLAG | INFINITY_FLAG | SORT_FLAG);
+    let mut x = bytes.to_vec();
+    x[0] &= !flags;
+    if flags & COMPRESSION_FLAG == 0 {
+        return Err(BlsError::InvalidEncoding);
+    }
+    if flags & INFINITY_FLAG != 0 && (flags & SORT_FLAG != 0 || x.iter().any(|&b| b != 0)) {
+        return Err(BlsError::InvalidEncoding);
+    }
+    Ok((flags, x))
+}
+
+fn identity_encoding<P: PointEncoding>() -> Vec<u8> {
+    let mut bytes = vec![0; P::COMPRESSED_SIZE];
+    bytes[0] = COMPRESSION_FLAG | INFINITY_FLAG;
+    bytes
+}
+
+/// Returns `None` if the big endian integer is not less than the modulus.
+fn fp_from_be_bytes(bytes: &[u8]) -> Option<Fp> {
+    let modulus = Fp::MODULUS;
+    (bytes.iter().cmp(modulus.as_ref().iter().rev()) == Ordering::Less)
+        .then(|| Fp::from_be_bytes(bytes))
+}
+
+fn fp_to_be_bytes(x: &Fp) -> Vec<u8> {
+    // The serialization has to be unique.
+    let mut x = x.clone();
+    x.reduce();
+    x.to_be_bytes().as_ref().to_vec()
+}
+
+/// Returns whether `y > -y`, i.e., whether `y > (p - 1) / 2`.
+fn fp_is_lexicographically_largest(y: &Fp) -> bool {
+    fp_to_be_bytes(y) > fp_to_be_bytes(&-y.clone())
+}
+
+/// Compares the imaginary parts first.
+fn fp2_is_lexicographically_largest(y: &Fp2) -> bool {
+    if y.c1 == <Fp as IntMod>::ZERO {
+        fp_is_lexicographically_largest(&y.c0)
+    } else {
+        fp_is_lexicographically_largest(&y.c1)
+    }
+}
+
+fn g1_affine_point(p: &G1Affine) -> AffinePoint<Fp> {
+    AffinePoint::new(p.x().clone(), p.y().clone())
+}
+
+/// Derives the random 128-bit scalars of batch verification by hashing all inputs, so that they
+/// are fixed before the signatures are combined.
+fn batch_scalars(items: impl Iterator<Item = (Vec<u8>, Vec<u8>, Vec<u8>)>) -> Vec<Scalar> {
+    let mut transcript = Vec::new();
+    let mut count = 0u32;
+    for (public_key, msg, signature) in items {
+        transcript.extend(public_key);
+        transcript.extend(signature);
+        transcript.extend((msg.len() as u64).to_le_bytes());
+        transcript.extend(msg);
+        count += 1;
+    }
+    let seed = sha256(&transcript);
+    (0..count)
+        .map(|i| {
+            let mut input = seed.to_vec();
+            input.extend(i.to_le_bytes());
+            let mut bytes = [0u8; 32];
+            bytes[..16].copy_from_slice(&sha256(&input)[..16]);
+            Scalar::from_le_bytes(&bytes)
+        })
+        .collect()
+}
@@ -4,11 +4,16 @@ use group::ff::Field;
 use halo2curves_axiom::bls12_381::{
     Fq, Fq12, Fq2, Fq6, G1Affine, G2Affine, G2Prepared, MillerLoopResult, FROBENIUS_COEFF_FQ12_C1,
 };
-use openvm_algebra_guest::{field::FieldExtension, IntMod};
-use openvm_ecc_guest::AffinePoint;
+use hex_literal::hex;
+use openvm_algebra_guest::{field::FieldExtension, IntMod, Sqrt};
+use openvm_ecc_guest::{weierstrass::WeierstrassPoint, AffinePoint, CyclicGroup, Group};
 use rand::{rngs::StdRng, SeedableRng};
 
-use super::{Fp, Fp12, Fp2};
+use super::{
+    hash_to_g1, hash_to_g2,
+    signature::{min_pk, min_sig, BlsError},
+    Fp, Fp12, Fp2,
+};
 use crate::{
     bls12_381::Bls12_381,
     pairing::{
@@ -269,3 +274,135 @@ fn test_bls12381_miller_loop_identity_2() {
     let compare_final = compare_miller.final_exponentiation();
     assert_eq!(final_f, compare_final);
 }
+
+#[test]
+fn test_bls12381_fp2_sqrt() {
+    let mut rng = StdRng::seed_from_u64(44);
+    for _ in 0..10 {
+        let a = convert_bls12381_halo2_fq2_to_fp2(Fq2::random(&mut rng));
+        let square = &a * &a;
+        let root = square.sqrt().unwrap();
+        assert_eq!(&root * &root, square);
+    }
+    // 1 + u is not a square
+    let non_qr = Fp2::new(Fp::ONE, Fp::ONE);
+    assert!(non_qr.sqrt().is_none());
+}
+
+// https://datatracker.ietf.org/doc/html/rfc9380#appendix-J.9.1
+#[test]
+fn test_bls12381_hash_to_g1() {
+    let p = hash_to_g1(b"", b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_");
+    assert_eq!(
+        p.x(),
+        &Fp::from_be_bytes(&hex!(
+            "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1"
+        ))
+    );
+    assert_eq!(
+        p.y(),
+        &Fp::from_be_bytes(&hex!(
+            "08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265"
+        ))
+    );
+    assert!(p.is_torsion_free());
+}
+
+// https://datatracker.ietf.org/doc/html/rfc9380#appendix-J.10.1
+#[test]
+fn test_bls12381_hash_to_g2() {
+    let p = hash_to_g2(b"", b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_");
+    let x = Fp2::new(
+        Fp::from_be_bytes(&hex!(
+            "0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a"
+        )),
+        Fp::from_be_bytes(&hex!(
+            "05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d"
+        )),
+    );
+    let y = Fp2::new(
+        Fp::from_be_bytes(&hex!(
+            "0503921d7f6a12805e72940b963c0cf3471c7b2a524950ca195d11062ee75ec076daf2d4bc358c4b190c0c98064fdd92"
+        )),
+        Fp::from_be_bytes(&hex!(
+            "12424ac32561493f3fe3c260708a12b7c620e7be00099a974e259ddc7d1f6395c3c811cdd19f1e8dbf3e9ecfdcbab8d6"
+        )),
+    );
+    assert_eq!(p.x(), &x);
+    assert_eq!(p.y(), &y);
+    assert!(p.is_torsion_free());
+}
+
+#[test]
+fn test_bls12381_signature_point_encoding() {
+    let g1 = hex!(
+        "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"
+    );
+    let g2 = hex!(
+        "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"
+        "024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"
+    );
+    let pk = min_pk::PublicKey::from_bytes(&g1).unwrap();
+    assert_eq!(pk.as_point(), &super::G1Affine::GENERATOR);
+    assert_eq!(pk.to_bytes(), g1);
+    let sig = min_pk::Signature::from_bytes(&g2).unwrap();
+    assert_eq!(sig.as_point(), &super::G2Affine::GENERATOR);
+    assert_eq!(sig.to_bytes(), g2);
+
+    // The negation flips the sort flag.
+    let neg = min_pk::Signature::from_point(-super::G2Affine::GENERATOR).unwrap();
+    assert_eq!(neg.to_bytes()[0], g2[0] ^ 0x20);
+    assert_eq!(min_pk::Signature::from_bytes(&neg.to_bytes()).unwrap(), neg);
+
+    let mut identity = [0u8; 48];
+    identity[0] = 0xc0;
+    assert_eq!(
+        min_pk::PublicKey::from_bytes(&identity),
+        Err(BlsError::IdentityPublicKey)
+    );
+    assert!(min_sig::Signature::from_bytes(&identity)
+        .unwrap()
+        .as_point()
+        .is_identity());
+
+    // Uncompressed encodings, wrong lengths and non-canonical infinity are rejected.
+    let mut bytes = g1;
+    bytes[0] &= 0x7f;
+    assert_eq!(
+        min_pk::PublicKey::from_bytes(&bytes),
+        Err(BlsError::InvalidEncoding)
+    );
+    assert_eq!(
+        min_pk::PublicKey::from_bytes(&g1[1..]),
+        Err(BlsError::InvalidEncoding)
+    );
+    identity[47] = 1;
+    assert_eq!(
+        min_sig::Signature::from_bytes(&identity),
+        Err(BlsError::InvalidEncoding)
+    );
+}
+
+#[test]
+fn test_bls12381_signature_subgroup_check() {
+    // (4, sqrt(68)) is on the curve but not in the prime order subgroup.
+    let bytes = hex!(
+        "800000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004"
+    );
+    assert_eq!(
+        min_pk::PublicKey::from_bytes(&bytes),
+        Err(BlsError::NotInSubgroup)
+    );
+    assert_eq!(
+        min_sig::Signature::from_bytes(&bytes),
+        Err(BlsError::NotInSubgroup)
+    );
+    // x = 1 is not on the curve: 1 + 4 is not a square.
+    let mut bytes = [0u8; 48];
+    bytes[0] = 0x80;
+    bytes[47] = 1;
+    assert_eq!(
+        min_pk::PublicKey::from_bytes(&bytes),
+        Err(BlsError::InvalidEncoding)
+    );
+}
@@ -0,0 +1,43 @@
+//! Hashing to BN254 G1 with the Shallue-van de Woestijne map of RFC 9380 (Section 6.6.1), which
+//! is needed because `A = 0` and RFC 9380 defines no isogeny for BN254. With SHA-256 and `Z = 1`,
+//! this matches the `BN254G1_XMD:SHA-256_SVDW_RO_` and `BN254G1_XMD:SHA-256_SVDW_NU_` suites used
+//! by gnark.
+
+use hex_literal::hex;
+use openvm_ecc_guest::{
+    hash_to_curve::{map_to_curve_svdw, HashToCurve, SvdwParams},
+    weierstrass::WeierstrassPoint,
+};
+
+use super::{Fp, G1Affine, CURVE_B};
+
+impl HashToCurve for G1Affine {
+    type Field = Fp;
+
+    fn map_to_curve(u: &Fp) -> Self {
+        let (x, y) = map_to_curve_svdw(u, &SVDW);
+        Self::from_xy_unchecked(x, y)
+    }
+
+    /// The cofactor is 1.
+    fn clear_cofactor(&self) -> Self {
+        self.clone()
+    }
+}
+
+/// Parameters of the Shallue-van de Woestijne map with `Z = 1`.
+const SVDW: SvdwParams<Fp> = SvdwParams {
+    a: Fp::from_const_u8(0),
+    b: CURVE_B,
+    z: Fp::from_const_u8(1),
+    c1: Fp::from_const_u8(4),
+    c2: Fp::from_const_bytes(hex!(
+        "a37e3e6c0b46109e46e538b448b5c0cb2eacc040db2228dc14d0987039273218"
+    )),
+    c3: Fp::from_const_bytes(hex!(
+        "faffffdfc51c8d5d3d716db3c68fc953b32e52833aaf89670100000000000000"
+    )),
+    c4: Fp::from_const_bytes(hex!(
+        "bda92948b22e606984437bcd85232bddc9728080e7c11ae80de065a07b6f2110"
+    )),
+};
@@ -3,12 +3,13 @@ use core::ops::{Add, AddAssign, Neg};
 use openvm_algebra_guest::{Field, IntMod};
 use openvm_algebra_moduli_setup::moduli_declare;
 use openvm_ecc_guest::{
-    weierstrass::{CachedMulTable, IntrinsicCurve},
+    weierstrass::{prime_order_scalar_mul, CachedMulTable, IntrinsicCurve},
     CyclicGroup, Group,
 };
 
 mod fp12;
 mod fp2;
+mod hash_to_curve;
 pub mod pairing;
 
 pub use fp12::*;
@@ -34,6 +35,8 @@ lazy_static! {
     ));
 }
 
+/// `Fp2 = Fp[u]/(u^2 - BN254_FP2_NON_RESIDUE)`
+pub const BN254_FP2_NON_RESIDUE: isize = -1;
 pub const BN254_XI_ISIZE: [isize; 2] = [9, 1];
 pub const BN254_NUM_LIMBS: usize = 32;
 pub const BN254_LIMB_BITS: usize = 8;
@@ -153,6 +156,9 @@ impl IntrinsicCurve for Bn254 {
     where
         for<'a> &'a Self::Point: Add<&'a Self::Point, Output = Self::Point>,
     {
+        if let ([coeff], [base]) = (coeffs, bases) {
+            return Self::scalar_mul(coeff, base);
+        }
         // heuristic
         if coeffs.len() < 25 {
             // BN254(Fp) is of prime order by Weil conjecture:
@@ -163,6 +169,13 @@ impl IntrinsicCurve for Bn254 {
             openvm_ecc_guest::msm(coeffs, bases)
         }
     }
+
+    fn scalar_mul(coeff: &Self::Scalar, base: &Self::Point) -> Self::Point
+    where
+        for<'a> &'a Self::Point: Add<&'a Self::Point, Output = Self::Point>,
+    {
+        prime_order_scalar_mul::<Self>(coeff, base)
+    }
 }
 
 impl PairingIntrinsics for Bn254 {
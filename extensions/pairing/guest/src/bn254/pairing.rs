@@ -14,7 +14,7 @@ use {
 use super::{Bn254, Fp, Fp12, Fp2};
 use crate::pairing::{
     Evaluatable, EvaluatedLine, FromLineDType, LineMulDType, MillerStep, MultiMillerLoop,
-    PairingCheck, PairingCheckError, PairingIntrinsics, UnevaluatedLine,
+    PairingCheck, PairingCheckError, PairingCheckHint, PairingIntrinsics, UnevaluatedLine,
 };
 
 // TODO[jpw]: make macro
@@ -300,6 +300,36 @@ impl PairingCheck for Bn254 {
     type Fp2 = Fp2;
     type Fp12 = Fp12;
 
+    fn pairing_check(
+        P: &[AffinePoint<Self::Fp>],
+        Q: &[AffinePoint<Self::Fp2>],
+    ) -> Result<(), PairingCheckError> {
+        let (c, u) = Self::pairing_check_hint(P, Q);
+        let c_inv = Fp12::ONE.div_unsafe(&c);
+
+        // f * u == c^λ
+        // f * u == c^{6x + 2 + q^3 - q^2 + q}
+        // f * c^-{6x + 2} * u * c^-{q^3 - q^2 + q} == 1
+        // where fc == f * c^-{6x + 2}
+        // c_mul = c^-{q^3 - q^2 + q}
+        let c_q3_inv = FieldExtension::frobenius_map(&c_inv, 3);
+        let c_q2 = FieldExtension::frobenius_map(&c, 2);
+        let c_q_inv = FieldExtension::frobenius_map(&c_inv, 1);
+        let c_mul = c_q3_inv * c_q2 * c_q_inv;
+
+        // Compute miller loop with c_inv
+        let fc = Self::multi_miller_loop_embedded_exp(P, Q, Some(c_inv));
+
+        if fc * c_mul * u == Fp12::ONE {
+            Ok(())
+        } else {
+            Err(PairingCheckError)
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+impl PairingCheckHint for Bn254 {
     #[allow(unused_variables)]
     fn pairing_check_hint(
         P: &[AffinePoint<Self::Fp>],
@@ -334,31 +364,4 @@ impl PairingCheck for Bn254 {
             }
         }
     }
-
-    fn pairing_check(
-        P: &[AffinePoint<Self::Fp>],
-        Q: &[AffinePoint<Self::Fp2>],
-    ) -> Result<(), PairingCheckError> {
-        let (c, u) = Self::pairing_check_hint(P, Q);
-        let c_inv = Fp12::ONE.div_unsafe(&c);
-
-        // f * u == c^λ
-        // f * u == c^{6x + 2 + q^3 - q^2 + q}
-        // f * c^-{6x + 2} * u * c^-{q^3 - q^2 + q} == 1
-        // where fc == f * c^-{6x + 2}
-        // c_mul = c^-{q^3 - q^2 + q}
-        let c_q3_inv = FieldExtension::frobenius_map(&c_inv, 3);
-        let c_q2 = FieldExtension::frobenius_map(&c, 2);
-        let c_q_inv = FieldExtension::frobenius_map(&c_inv, 1);
-        let c_mul = c_q3_inv * c_q2 * c_q_inv;
-
-        // Compute miller loop with c_inv
-        let fc = Self::multi_miller_loop_embedded_exp(P, Q, Some(c_inv));
-
-        if fc * c_mul * u == Fp12::ONE {
-            Ok(())
-        } else {
-            Err(PairingCheckError)
-        }
-    }
 }
@@ -5,8 +5,13 @@ use halo2curves_axiom::{
     bn256::{Fq, Fq12, Fq2, Fq6, G1Affine, G2Affine, G2Prepared, Gt, FROBENIUS_COEFF_FQ12_C1},
     pairing::MillerLoopResult,
 };
+use hex_literal::hex;
 use openvm_algebra_guest::{field::FieldExtension, IntMod};
-use openvm_ecc_guest::AffinePoint;
+use openvm_ecc_guest::{
+    hash_to_curve::{encode_to_curve, hash_to_curve, Sha256},
+    weierstrass::WeierstrassPoint,
+    AffinePoint,
+};
 use rand::{rngs::StdRng, SeedableRng};
 
 use super::{Fp, Fp12, Fp2};
@@ -256,3 +261,39 @@ fn test_bn254_miller_loop_identity_2() {
     let compare_final = compare_miller.final_exponentiation();
     assert_eq!(final_f, compare_final);
 }
+
+#[test]
+fn test_bn254_hash_to_curve() {
+    let dst = b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_RO_";
+    for (msg, x, y) in [
+        (
+            &b""[..],
+            hex!("0a976ab906170db1f9638d376514dbf8c42aef256a54bbd48521f20749e59e86"),
+            hex!("02925ead66b9e68bfc309b014398640ab55f6619ab59bc1fab2210ad4c4d53d5"),
+        ),
+        (
+            &b"abc"[..],
+            hex!("23f717bee89b1003957139f193e6be7da1df5f1374b26a4643b0378b5baf53d1"),
+            hex!("04142f826b71ee574452dbc47e05bc3e1a647478403a7ba38b7b93948f4e151d"),
+        ),
+    ] {
+        let p: super::G1Affine = hash_to_curve::<_, Sha256>(msg, dst);
+        assert_eq!(p.x(), &Fp::from_be_bytes(&x));
+        assert_eq!(p.y(), &Fp::from_be_bytes(&y));
+    }
+
+    let p: super::G1Affine =
+        encode_to_curve::<_, Sha256>(b"", b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_NU_");
+    assert_eq!(
+        p.x(),
+        &Fp::from_be_bytes(&hex!(
+            "1bb8810e2ceaf04786d4efd216fc2820ddd9363712efc736ada11049d8af5925"
+        ))
+    );
+    assert_eq!(
+        p.y(),
+        &Fp::from_be_bytes(&hex!(
+            "1efbf8d54c60d865cce08437668ea30f5bf90d287dbd9b5af31da852915e8f11"
+        ))
+    );
+}
@@ -41,6 +41,9 @@ pub mod halo2curves_shims;
 /// Traits for optimal Ate pairing check using intrinsic functions.
 pub mod pairing;
 
+/// Types for BLS12-377 curve with intrinsic functions.
+#[cfg(feature = "bls12_377")]
+pub mod bls12_377;
 /// Types for BLS12-381 curve with intrinsic functions.
 #[cfg(feature = "bls12_381")]
 pub mod bls12_381;
@@ -40,6 +40,15 @@ pub trait PairingCheck {
     type Fp2: Field + FieldExtension<Self::Fp> + ComplexConjugate;
     type Fp12: FieldExtension<Self::Fp2> + ComplexConjugate;
 
+    fn pairing_check(
+        P: &[AffinePoint<Self::Fp>],
+        Q: &[AffinePoint<Self::Fp2>],
+    ) -> Result<(), PairingCheckError>;
+}
+
+/// Pairing check whose final exponentiation is replaced by a hint from the VM.
+#[allow(non_snake_case)]
+pub trait PairingCheckHint: PairingCheck {
     /// Given points P[], Q[], computes the multi-Miller loop and then returns
     /// the final exponentiation hint from Novakovic-Eagon <https://eprint.iacr.org/2024/640.pdf>.
     ///
@@ -48,11 +57,6 @@ pub trait PairingCheck {
         P: &[AffinePoint<Self::Fp>],
         Q: &[AffinePoint<Self::Fp2>],
     ) -> (Self::Fp12, Self::Fp12);
-
-    fn pairing_check(
-        P: &[AffinePoint<Self::Fp>],
-        Q: &[AffinePoint<Self::Fp2>],
-    ) -> Result<(), PairingCheckError>;
 }
 
 pub const fn shifted_funct7<P: PairingIntrinsics>(funct7: PairingBaseFunct7) -> usize {
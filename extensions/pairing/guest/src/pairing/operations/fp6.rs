@@ -1,6 +1,4 @@
-use openvm_algebra_guest::{field::FieldExtension, Field, IntMod};
-
-use super::fp2_invert_assign;
+use openvm_algebra_guest::{field::FieldExtension, DivUnsafe, Field, IntMod};
 
 pub(crate) fn fp6_invert_assign<
     Fp: IntMod + Field,
@@ -44,9 +42,8 @@ pub(crate) fn fp6_invert_assign<
     tmp2 *= &c0;
     tmp1 += &tmp2;
 
-    let mut coeffs = tmp1.clone().to_coeffs();
-    fp2_invert_assign::<Fp>(&mut coeffs);
-    let tmp = Fp2::from_coeffs(coeffs);
+    // The inverse in `Fp2` is left to its own division, which knows the `Fp2` non-residue.
+    let tmp = <Fp2 as Field>::ONE.div_unsafe(&tmp1);
     let mut tmp = [tmp.clone(), tmp.clone(), tmp.clone()];
     tmp[0] *= &c0;
     tmp[1] *= &c1;
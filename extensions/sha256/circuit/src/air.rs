@@ -0,0 +1,835 @@
+use std::{array::from_fn, borrow::Borrow};
+
+use itertools::izip;
+use openvm_circuit::{
+    arch::{ExecutionBridge, ExecutionState},
+    system::memory::{
+        offline_checker::{MemoryBridge, MemoryReadAuxCols, MemoryWriteAuxCols},
+        MemoryAddress,
+    },
+};
+use openvm_circuit_primitives::{
+    bitwise_op_lookup::BitwiseOperationLookupBus,
+    utils::{assert_array_eq, not, select},
+};
+use openvm_instructions::riscv::{RV32_CELL_BITS, RV32_REGISTER_NUM_LIMBS};
+use openvm_rv32im_circuit::adapters::abstract_compose;
+use openvm_sha256_transpiler::Rv32Sha256Opcode;
+use openvm_stark_backend::{
+    interaction::InteractionBuilder,
+    p3_air::{Air, AirBuilder, BaseAir},
+    p3_field::AbstractField,
+    p3_matrix::Matrix,
+    rap::{BaseAirWithPublicValues, PartitionedBaseAir},
+};
+
+use super::{
+    columns::{Sha256VmCols, NUM_SHA256_VM_COLS, SHA256_CARRY_BITS},
+    utils::{SHA256_H, SHA256_K},
+    SHA256_BLOCK_BYTES, SHA256_BLOCK_READS, SHA256_BLOCK_WORDS, SHA256_DIGEST_WRITES,
+    SHA256_LENGTH_OFFSET, SHA256_REGISTER_READS, SHA256_ROWS_PER_BLOCK, SHA256_WORD_BITS,
+    SHA256_WORD_SIZE, SHA256_WORD_U16S,
+};
+
+#[derive(Clone, Copy, Debug, derive_new::new)]
+pub struct Sha256VmAir {
+    pub execution_bridge: ExecutionBridge,
+    pub memory_bridge: MemoryBridge,
+    /// Bus to send 8-bit range check requests to.
+    pub bitwise_lookup_bus: BitwiseOperationLookupBus,
+    /// Maximum number of bits allowed for an address pointer
+    pub ptr_max_bits: usize,
+    pub(super) offset: usize,
+}
+
+impl<F> BaseAirWithPublicValues<F> for Sha256VmAir {}
+impl<F> PartitionedBaseAir<F> for Sha256VmAir {}
+impl<F> BaseAir<F> for Sha256VmAir {
+    fn width(&self) -> usize {
+        NUM_SHA256_VM_COLS
+    }
+}
+
+impl<AB: InteractionBuilder> Air<AB> for Sha256VmAir {
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &Sha256VmCols<AB::Var> = (*local).borrow();
+        let next: &Sha256VmCols<AB::Var> = (*next).borrow();
+
+        self.eval_flags(builder, local, next);
+        self.eval_round_functions(builder, local);
+        self.constrain_round_transition(builder, local, next);
+        self.constrain_consistency_across_rows(builder, local, next);
+        self.constrain_block_start(builder, local);
+        self.constrain_padding(builder, local);
+
+        let mem = &local.mem_oc;
+        // Interactions:
+        let start_read_timestamp = self.eval_instruction(builder, local, &mem.register_aux);
+        let start_write_timestamp =
+            self.constrain_input_read(builder, local, start_read_timestamp, &mem.block_reads);
+        self.constrain_digest(
+            builder,
+            local,
+            start_write_timestamp.clone(),
+            &mem.digest_writes,
+        );
+
+        self.constrain_block_transition(builder, local, next, start_write_timestamp);
+    }
+}
+
+impl Sha256VmAir {
+    /// Constrains the row index flags and the boolean control flags.
+    pub fn eval_flags<AB: AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &Sha256VmCols<AB::Var>,
+        next: &Sha256VmCols<AB::Var>,
+    ) {
+        let row_idx = local.round.row_idx;
+        for &flag in row_idx.iter() {
+            builder.assert_bool(flag);
+        }
+        builder.assert_one(row_idx.iter().fold(AB::Expr::ZERO, |acc, &flag| acc + flag));
+        builder.when_first_row().assert_one(local.is_first_row());
+        // The row index advances by one every row, wrapping around at the end of a block
+        for i in 0..SHA256_ROWS_PER_BLOCK {
+            builder.when_transition().assert_eq(
+                next.round.row_idx[(i + 1) % SHA256_ROWS_PER_BLOCK],
+                row_idx[i],
+            );
+        }
+
+        let control = &local.control;
+        builder.assert_bool(control.is_enabled);
+        builder.assert_bool(control.is_new_start);
+        builder.assert_bool(control.is_last_block);
+        builder.assert_bool(control.has_padding);
+        builder.assert_bool(control.padding_occurred);
+        builder.assert_eq(
+            control.is_enabled_first_row,
+            control.is_enabled * local.is_first_row(),
+        );
+        // Not strictly necessary:
+        builder.when_first_row().assert_one(local.is_new_start());
+        // The last row must be a dummy row so that every enabled block ends in a block transition
+        builder.when_last_row().assert_zero(control.is_enabled);
+    }
+
+    /// Constrains the bit decompositions and the materialized bitwise functions of a row.
+    ///
+    /// These constraints hold on every row, including dummy rows, so that the round
+    /// constraints can use the materialized columns linearly.
+    pub fn eval_round_functions<AB: AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &Sha256VmCols<AB::Var>,
+    ) {
+        let round = &local.round;
+        for bits in round
+            .a_bits
+            .iter()
+            .chain(round.e_bits.iter())
+            .chain(round.w_bits.iter())
+        {
+            for &bit in bits {
+                builder.assert_bool(bit);
+            }
+        }
+        for carry in round
+            .carry_a
+            .iter()
+            .chain(round.carry_e.iter())
+            .chain(round.carry_w.iter())
+        {
+            for &bit in carry {
+                builder.assert_bool(bit);
+            }
+        }
+
+        let a = round.a_bits.map(|bits| bits.map(Into::<AB::Expr>::into));
+        let e = round.e_bits.map(|bits| bits.map(Into::<AB::Expr>::into));
+        let w_1 = round.w_bits[1].map(Into::<AB::Expr>::into);
+        let w_14 = round.w_bits[14].map(Into::<AB::Expr>::into);
+
+        assert_array_eq(builder, round.sig0_a, compose_u16s(&big_sig0(&a[0])));
+        assert_array_eq(builder, round.maj, compose_u16s(&maj(&a[0], &a[1], &a[2])));
+        assert_array_eq(builder, round.sig1_e, compose_u16s(&big_sig1(&e[0])));
+        assert_array_eq(builder, round.ch, compose_u16s(&ch(&e[0], &e[1], &e[2])));
+        assert_array_eq(builder, round.sig0_w, compose_u16s(&small_sig0(&w_1)));
+        assert_array_eq(builder, round.sig1_w, compose_u16s(&small_sig1(&w_14)));
+    }
+
+    /// Constrains one round of the compression function and one step of the message schedule
+    /// between a round row and the next row of the same block.
+    pub fn constrain_round_transition<AB: AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &Sha256VmCols<AB::Var>,
+        next: &Sha256VmCols<AB::Var>,
+    ) {
+        let round = &local.round;
+        let next_round = &next.round;
+        let is_round = local.control.is_enabled * not::<AB::Expr>(local.is_digest_row());
+        let mut transition_builder = builder.when_transition();
+        let mut round_builder = transition_builder.when(is_round);
+
+        // b, c, d <- a, b, c and f, g, h <- e, f, g
+        for i in 1..4 {
+            assert_array_eq(
+                &mut round_builder,
+                next_round.a_bits[i],
+                round.a_bits[i - 1],
+            );
+            assert_array_eq(
+                &mut round_builder,
+                next_round.e_bits[i],
+                round.e_bits[i - 1],
+            );
+        }
+        // Slide the message schedule window
+        for i in 0..SHA256_BLOCK_WORDS - 1 {
+            assert_array_eq(
+                &mut round_builder,
+                next_round.w_bits[i],
+                round.w_bits[i + 1],
+            );
+        }
+
+        let limbs =
+            |bits: [AB::Var; SHA256_WORD_BITS]| compose_u16s::<AB::Expr>(&bits.map(Into::into));
+        // The round constant is selected by the row index
+        let k: [AB::Expr; SHA256_WORD_U16S] = from_fn(|limb| {
+            SHA256_K
+                .iter()
+                .zip(round.row_idx)
+                .fold(AB::Expr::ZERO, |acc, (&k, flag)| {
+                    acc + flag * AB::F::from_canonical_u32((k >> (16 * limb)) & 0xffff)
+                })
+        });
+        let d = limbs(round.a_bits[3]);
+        let h = limbs(round.e_bits[3]);
+        let w = limbs(round.w_bits[0]);
+        let t1: [AB::Expr; SHA256_WORD_U16S] =
+            from_fn(|i| h[i].clone() + round.sig1_e[i] + round.ch[i] + k[i].clone() + w[i].clone());
+        let t2: [AB::Expr; SHA256_WORD_U16S] = from_fn(|i| round.sig0_a[i] + round.maj[i]);
+
+        // a <- T1 + T2
+        assert_add(
+            &mut round_builder,
+            from_fn(|i| t1[i].clone() + t2[i].clone()),
+            limbs(next_round.a_bits[0]),
+            round.carry_a,
+        );
+        // e <- d + T1
+        assert_add(
+            &mut round_builder,
+            from_fn(|i| d[i].clone() + t1[i].clone()),
+            limbs(next_round.e_bits[0]),
+            round.carry_e,
+        );
+        // w[r + 16] <- σ1(w[r + 14]) + w[r + 9] + σ0(w[r + 1]) + w[r]
+        let w_9 = limbs(round.w_bits[9]);
+        assert_add(
+            &mut round_builder,
+            from_fn(|i| round.sig1_w[i] + w_9[i].clone() + round.sig0_w[i] + w[i].clone()),
+            limbs(next_round.w_bits[SHA256_BLOCK_WORDS - 1]),
+            round.carry_w,
+        );
+    }
+
+    /// Many columns are expected to be the same between rows and only change per-block.
+    pub fn constrain_consistency_across_rows<AB: AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &Sha256VmCols<AB::Var>,
+        next: &Sha256VmCols<AB::Var>,
+    ) {
+        let mut transition_builder = builder.when_transition();
+        let mut block_builder = transition_builder.when(not::<AB::Expr>(local.is_digest_row()));
+        local.control.assert_eq(&mut block_builder, next.control);
+    }
+
+    /// Constrains the working variables on the first row of a block to equal the hash state
+    /// prior to the block, and the hash state of the first block to be the initial hash value.
+    pub fn constrain_block_start<AB: AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &Sha256VmCols<AB::Var>,
+    ) {
+        let control = &local.control;
+        let limbs =
+            |bits: [AB::Var; SHA256_WORD_BITS]| compose_u16s::<AB::Expr>(&bits.map(Into::into));
+        let mut first_row_builder = builder.when(control.is_enabled_first_row);
+        for (bits, prev) in local
+            .round
+            .a_bits
+            .iter()
+            .chain(local.round.e_bits.iter())
+            .zip(control.prev_hash)
+        {
+            assert_array_eq(&mut first_row_builder, limbs(*bits), prev);
+        }
+
+        let mut iv_builder = builder.when(control.is_enabled * control.is_new_start);
+        for (&word, prev) in SHA256_H.iter().zip(control.prev_hash) {
+            for (i, limb) in prev.into_iter().enumerate() {
+                iv_builder.assert_eq(limb, AB::F::from_canonical_u32((word >> (16 * i)) & 0xffff));
+            }
+        }
+    }
+
+    /// SHA-256 appends a single `1` bit, then `0` bits, then the 64-bit big-endian bit length
+    /// of the message so that the padded length is a multiple of 512 bits.
+    /// See Section 5.1.1 of https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.180-4.pdf
+    ///
+    /// The padding flags are only set on the first row of a block, where `w_bits` holds the
+    /// message block, and are all 0 on other rows so that these constraints hold vacuously.
+    /// The block-level flags are tied to the padding flags on the first row.
+    pub fn constrain_padding<AB: AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &Sha256VmCols<AB::Var>,
+    ) {
+        let is_padding_byte = local.padding.is_padding_byte;
+        let control = &local.control;
+        let padding_occurred = control.padding_occurred;
+
+        // is_padding_byte should all be boolean
+        for &is_padding_byte in is_padding_byte.iter() {
+            builder.assert_bool(is_padding_byte);
+        }
+        // is_padding_byte should transition from 0 to 1 only once and then stay 1
+        for i in 1..SHA256_BLOCK_BYTES {
+            builder
+                .when(is_padding_byte[i - 1])
+                .assert_one(is_padding_byte[i]);
+        }
+
+        // The block is final if and only if the bit length fits after the first padding byte
+        let mut first_row_builder = builder.when(control.is_enabled_first_row);
+        first_row_builder.assert_eq(
+            control.is_last_block,
+            is_padding_byte[SHA256_LENGTH_OFFSET - 1],
+        );
+        first_row_builder.assert_eq(control.has_padding, is_padding_byte[SHA256_BLOCK_BYTES - 1]);
+        // If the padding started in a previous block, this block is entirely padding
+        first_row_builder
+            .when(padding_occurred)
+            .assert_one(is_padding_byte[0]);
+
+        // is_padding_byte must be consistent with remaining_len
+        let num_padding_bytes = is_padding_byte
+            .iter()
+            .fold(AB::Expr::ZERO, |acc, &b| acc + b);
+        builder
+            .when(is_padding_byte[SHA256_BLOCK_BYTES - 1])
+            .when(not(padding_occurred))
+            .assert_eq(
+                local.remaining_len(),
+                AB::Expr::from_canonical_usize(SHA256_BLOCK_BYTES) - num_padding_bytes,
+            );
+
+        // ====== Constrain the message block is padded according to is_padding_byte =====
+        let block_bytes = message_bytes::<AB::Expr>(&local.round.w_bits);
+        let is_final_block = is_padding_byte[SHA256_LENGTH_OFFSET - 1];
+        for (i, byte) in block_bytes.into_iter().enumerate() {
+            let (is_first_padding_byte, is_zero_padding_byte): (AB::Expr, AB::Expr) = if i > 0 {
+                (
+                    is_padding_byte[i] - is_padding_byte[i - 1],
+                    is_padding_byte[i - 1].into(),
+                )
+            } else {
+                (
+                    is_padding_byte[0] * not::<AB::Expr>(padding_occurred),
+                    is_padding_byte[0] * padding_occurred,
+                )
+            };
+            // The first padding byte must be 0x80 because the padding 10* is *big-endian*
+            builder
+                .when(is_first_padding_byte)
+                .assert_eq(byte.clone(), AB::F::from_canonical_u8(0x80));
+            // The other padding bytes must be 0, except for the length in the final block
+            if i < SHA256_LENGTH_OFFSET {
+                builder.when(is_zero_padding_byte).assert_zero(byte);
+            } else {
+                builder
+                    .when(is_zero_padding_byte)
+                    .when(not(is_final_block))
+                    .assert_zero(byte);
+            }
+        }
+
+        // The final block ends with the big-endian bit length `8 * len`. Since `len` is range
+        // checked to `ptr_max_bits` bits, the upper word of the bit length is 0.
+        let mut length_builder = builder.when(is_final_block);
+        for &bit in local.round.w_bits[SHA256_BLOCK_WORDS - 2].iter() {
+            length_builder.assert_zero(bit);
+        }
+        let bit_len = local.round.w_bits[SHA256_BLOCK_WORDS - 1];
+        for &bit in bit_len[..3].iter() {
+            length_builder.assert_zero(bit);
+        }
+        let len = bit_len[3..]
+            .iter()
+            .enumerate()
+            .fold(AB::Expr::ZERO, |acc, (i, &bit)| {
+                acc + bit * AB::F::from_canonical_u32(1 << i)
+            });
+        length_builder.assert_eq(len, abstract_compose::<AB::Expr, _>(control.len));
+    }
+
+    /// Receive the instruction itself on program bus. Send+receive on execution bus.
+    /// Then does memory read in addr space 1 to get `dst, src, len` from memory.
+    ///
+    /// Adds range check interactions for the most significant limbs of the register values
+    /// using BitwiseOperationLookupBus.
+    ///
+    /// Returns `start_read_timestamp` which is only relevant when `local.control.is_enabled`.
+    /// Note that `start_read_timestamp` is a linear expression.
+    pub fn eval_instruction<AB: InteractionBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &Sha256VmCols<AB::Var>,
+        register_aux: &[MemoryReadAuxCols<AB::Var, RV32_REGISTER_NUM_LIMBS>; SHA256_REGISTER_READS],
+    ) -> AB::Expr {
+        let control = local.control;
+        // Only receive opcode if:
+        // - enabled row (not dummy row)
+        // - first row of block
+        // - is_new_start
+        let should_receive = control.is_enabled_first_row * control.is_new_start;
+
+        // The remaining length of the first block is the full length
+        builder.when(control.is_new_start).assert_eq(
+            control.remaining_len,
+            abstract_compose::<AB::Expr, _>(control.len),
+        );
+        builder
+            .when(control.is_new_start)
+            .assert_zero(control.padding_occurred);
+
+        let [dst_ptr, src_ptr, len_ptr] = [control.dst_ptr, control.src_ptr, control.len_ptr];
+        let reg_addr_sp = AB::F::ONE;
+        let timestamp_change: AB::Expr = Self::timestamp_change(control.remaining_len);
+        self.execution_bridge
+            .execute_and_increment_pc(
+                AB::Expr::from_canonical_usize(Rv32Sha256Opcode::SHA256 as usize + self.offset),
+                [
+                    dst_ptr.into(),
+                    src_ptr.into(),
+                    len_ptr.into(),
+                    reg_addr_sp.into(),
+                    control.e.into(),
+                ],
+                ExecutionState::new(control.pc, control.start_timestamp),
+                timestamp_change,
+            )
+            .eval(builder, should_receive.clone());
+
+        let mut timestamp: AB::Expr = control.start_timestamp.into();
+        // We store src_limbs[i] = [src_ptr + i + 1]_1 and recover [src_ptr]_1 from `src`
+        let src_data: [AB::Expr; RV32_REGISTER_NUM_LIMBS] = from_fn(|i| {
+            if i == 0 {
+                control.src_limbs.into_iter().enumerate().fold(
+                    Into::<AB::Expr>::into(control.src),
+                    |acc, (j, limb)| {
+                        acc - limb * AB::Expr::from_canonical_usize(1 << ((j + 1) * RV32_CELL_BITS))
+                    },
+                )
+            } else {
+                control.src_limbs[i - 1].into()
+            }
+        });
+        let dst_data = control.dst.map(Into::into);
+        let len_data = control.len.map(Into::into);
+        for (ptr, value, aux) in izip!(
+            [dst_ptr, src_ptr, len_ptr],
+            [dst_data, src_data, len_data],
+            register_aux,
+        ) {
+            self.memory_bridge
+                .read(
+                    MemoryAddress::new(reg_addr_sp, ptr),
+                    value,
+                    timestamp.clone(),
+                    aux,
+                )
+                .eval(builder, should_receive.clone());
+
+            timestamp += AB::Expr::ONE;
+        }
+        // See Rv32VecHeapAdapterAir
+        // repeat len for even number
+        // We range check `len` to `max_ptr_bits` to ensure `remaining_len` doesn't overflow
+        // and that the bit length fits in a single word.
+        let need_range_check = [
+            *control.dst.last().unwrap(),
+            *control.src_limbs.last().unwrap(),
+            *control.len.last().unwrap(),
+            *control.len.last().unwrap(),
+        ];
+        let limb_shift = AB::F::from_canonical_usize(
+            1 << (RV32_CELL_BITS * RV32_REGISTER_NUM_LIMBS - self.ptr_max_bits),
+        );
+        for pair in need_range_check.chunks_exact(2) {
+            self.bitwise_lookup_bus
+                .send_range(pair[0] * limb_shift, pair[1] * limb_shift)
+                .eval(builder, should_receive.clone());
+        }
+
+        timestamp
+    }
+
+    /// Constrain reading the message block from memory.
+    /// Reads input based on `is_padding_byte`.
+    ///
+    /// Expects `start_read_timestamp` to be a linear expression.
+    /// Returns the `start_write_timestamp` which is the timestamp to start from
+    /// for writing digest to memory.
+    pub fn constrain_input_read<AB: InteractionBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &Sha256VmCols<AB::Var>,
+        start_read_timestamp: AB::Expr,
+        mem_aux: &[MemoryReadAuxCols<AB::Var, SHA256_WORD_SIZE>; SHA256_BLOCK_READS],
+    ) -> AB::Expr {
+        let partial_block = &local.mem_oc.partial_block;
+        // Only read input from memory when it is an opcode-related row
+        // and only on the first row of block
+        let is_input = local.control.is_enabled_first_row;
+        let block_bytes = message_bytes::<AB::Expr>(&local.round.w_bits);
+
+        let mut timestamp = start_read_timestamp;
+        for (i, (input, is_padding, mem_aux)) in izip!(
+            block_bytes.chunks_exact(SHA256_WORD_SIZE),
+            local.padding.is_padding_byte.chunks_exact(SHA256_WORD_SIZE),
+            mem_aux
+        )
+        .enumerate()
+        {
+            let ptr = local.control.src + AB::F::from_canonical_usize(i * SHA256_WORD_SIZE);
+            // Only read word i if it is not entirely padding bytes
+            // count is degree 2
+            let count = is_input * not(is_padding[0]);
+            // The memory word read is partial if first byte is not padding but the last byte is
+            // padding. Since `count` is only 1 when first byte isn't padding, use check just if
+            // last byte is padding.
+            let is_partial_read = *is_padding.last().unwrap();
+            // word is degree 2
+            let word: [AB::Expr; SHA256_WORD_SIZE] = from_fn(|i| {
+                if i == 0 {
+                    // first byte is always ok
+                    input[0].clone()
+                } else {
+                    // use `partial_block` if this is a partial read, otherwise use the message block
+                    select(is_partial_read, partial_block[i - 1], input[i].clone())
+                }
+            });
+            for i in 1..SHA256_WORD_SIZE {
+                let not_padding: AB::Expr = not(is_padding[i]);
+                // When not a padding byte, the word byte and input byte must be equal
+                // This is constraint degree 3
+                builder.assert_eq(
+                    not_padding.clone() * word[i].clone(),
+                    not_padding.clone() * input[i].clone(),
+                );
+            }
+
+            self.memory_bridge
+                .read(
+                    MemoryAddress::new(local.control.e, ptr),
+                    word, // degree 2
+                    timestamp.clone(),
+                    mem_aux,
+                )
+                .eval(builder, count);
+
+            timestamp += AB::Expr::ONE;
+        }
+        timestamp
+    }
+
+    /// Constrains the hash state after the block on the digest row and writes it to memory
+    /// if this is the final block.
+    pub fn constrain_digest<AB: InteractionBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &Sha256VmCols<AB::Var>,
+        start_write_timestamp: AB::Expr,
+        mem_aux: &[MemoryWriteAuxCols<AB::Var, SHA256_WORD_SIZE>; SHA256_DIGEST_WRITES],
+    ) {
+        let control = local.control;
+        let digest = &local.digest;
+        let is_digest_row = control.is_enabled * local.is_digest_row();
+        builder.assert_eq(
+            digest.is_digest_write,
+            is_digest_row.clone() * control.is_last_block,
+        );
+        for carry in digest.carry.iter() {
+            for &bit in carry {
+                builder.assert_bool(bit);
+            }
+        }
+
+        // final_hash = prev_hash + [a, b, c, d, e, f, g, h] (mod 2^32), limb by limb
+        let limb_base = AB::Expr::from_canonical_u32(1 << 16);
+        let mut digest_builder = builder.when(is_digest_row.clone());
+        for (bits, prev, bytes, carry) in izip!(
+            local.round.a_bits.iter().chain(local.round.e_bits.iter()),
+            control.prev_hash,
+            digest.final_hash,
+            digest.carry
+        ) {
+            let state = compose_u16s(&bits.map(Into::<AB::Expr>::into));
+            let result = word_bytes_to_u16s::<AB::Expr>(bytes);
+            digest_builder.assert_eq(
+                state[0].clone() + prev[0],
+                result[0].clone() + carry[0] * limb_base.clone(),
+            );
+            digest_builder.assert_eq(
+                state[1].clone() + prev[1] + carry[0],
+                result[1].clone() + carry[1] * limb_base.clone(),
+            );
+        }
+        // The output bytes are only range checked here
+        let final_bytes = digest.final_hash.concat();
+        for pair in final_bytes.chunks_exact(2) {
+            self.bitwise_lookup_bus
+                .send_range(pair[0], pair[1])
+                .eval(builder, is_digest_row.clone());
+        }
+
+        let dst = abstract_compose::<AB::Expr, _>(control.dst);
+        for (i, (bytes, aux)) in digest.final_hash.into_iter().zip(mem_aux).enumerate() {
+            let timestamp = start_write_timestamp.clone() + AB::Expr::from_canonical_usize(i);
+            self.memory_bridge
+                .write(
+                    MemoryAddress::new(
+                        control.e,
+                        dst.clone() + AB::F::from_canonical_usize(i * SHA256_WORD_SIZE),
+                    ),
+                    bytes,
+                    timestamp,
+                    aux,
+                )
+                .eval(builder, digest.is_digest_write);
+        }
+    }
+
+    pub fn constrain_block_transition<AB: AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &Sha256VmCols<AB::Var>,
+        next: &Sha256VmCols<AB::Var>,
+        start_write_timestamp: AB::Expr,
+    ) {
+        let control = &local.control;
+        let next_control = &next.control;
+        // To enforce that the input is hashed until its final block, we require
+        // the block before a new start to be a final block
+        builder
+            .when_transition()
+            .when(local.is_digest_row())
+            .when(next.is_new_start())
+            .assert_one(control.is_last_block);
+
+        // When we transition between blocks, if the next block isn't a new block
+        // (this means it's not receiving a new opcode or starting a dummy block)
+        // then we want _parts_ of opcode instruction to stay the same
+        // between blocks.
+        let mut transition_builder = builder.when_transition();
+        let mut block_transition =
+            transition_builder.when(local.is_digest_row() * not::<AB::Expr>(next.is_new_start()));
+        // Make sure there are no blocks after the final block
+        block_transition.assert_zero(control.is_last_block);
+        block_transition.assert_eq(control.is_enabled, next_control.is_enabled);
+        // dst is only going to be used for writes in the last input block
+        assert_array_eq(&mut block_transition, control.dst, next_control.dst);
+        // len is needed for the bit length in the last input block
+        assert_array_eq(&mut block_transition, control.len, next_control.len);
+        // needed for memory reads
+        block_transition.assert_eq(control.e, next_control.e);
+        // these are not used and hence not necessary, but putting for safety until performance becomes an issue:
+        block_transition.assert_eq(control.dst_ptr, next_control.dst_ptr);
+        block_transition.assert_eq(control.src_ptr, next_control.src_ptr);
+        block_transition.assert_eq(control.len_ptr, next_control.len_ptr);
+
+        // Move the src pointer over based on the number of bytes read.
+        // This should always be BLOCK_BYTES since it's a non-final block.
+        block_transition.assert_eq(
+            next_control.src,
+            control.src + AB::F::from_canonical_usize(SHA256_BLOCK_BYTES),
+        );
+        // Advance timestamp by the number of memory accesses from reading
+        // `dst, src, len` and block input bytes.
+        block_transition.assert_eq(next_control.start_timestamp, start_write_timestamp);
+        block_transition.assert_eq(
+            next_control.remaining_len,
+            control.remaining_len - AB::F::from_canonical_usize(SHA256_BLOCK_BYTES),
+        );
+        // The 0x80 marker can only spill into the next block if this block has padding
+        block_transition.assert_eq(next_control.padding_occurred, control.has_padding);
+        // Chain the hash state
+        for (prev, bytes) in next_control
+            .prev_hash
+            .into_iter()
+            .zip(local.digest.final_hash)
+        {
+            assert_array_eq(
+                &mut block_transition,
+                prev,
+                word_bytes_to_u16s::<AB::Expr>(bytes),
+            );
+        }
+    }
+
+    /// Amount to advance timestamp by after execution of one opcode instruction.
+    /// This is an upper bound dependant on the length `len` operand, which is unbounded.
+    pub fn timestamp_change<T: AbstractField>(len: impl Into<T>) -> T {
+        // actual number is ceil((len + 9) / 64) * (3 + 16) + SHA256_DIGEST_WRITES
+        // digest writes only done on last row of multi-block
+        // add two blocks of accesses to round up so we don't deal with padding
+        len.into()
+            + T::from_canonical_usize(
+                2 * (SHA256_REGISTER_READS + SHA256_BLOCK_READS) + SHA256_DIGEST_WRITES,
+            )
+    }
+}
+
+/// Constrains `sum = result + carry * 2^16` limb by limb, propagating the carry of the low limb
+/// into the high limb. `carry_bits` are the bits of the carries.
+fn assert_add<AB: AirBuilder>(
+    builder: &mut AB,
+    sum: [AB::Expr; SHA256_WORD_U16S],
+    result: [AB::Expr; SHA256_WORD_U16S],
+    carry_bits: [[AB::Var; SHA256_CARRY_BITS]; SHA256_WORD_U16S],
+) {
+    let carry = carry_bits.map(|bits| {
+        bits.into_iter()
+            .enumerate()
+            .fold(AB::Expr::ZERO, |acc, (i, bit)| {
+                acc + bit * AB::F::from_canonical_u32(1 << i)
+            })
+    });
+    let limb_base = AB::Expr::from_canonical_u32(1 << 16);
+    let [sum_lo, sum_hi] = sum;
+    let [result_lo, result_hi] = result;
+    builder.assert_eq(sum_lo, result_lo + carry[0].clone() * limb_base.clone());
+    builder.assert_eq(
+        sum_hi + carry[0].clone(),
+        result_hi + carry[1].clone() * limb_base,
+    );
+}
+
+/// Composes the little-endian bits of a word into `u16` limbs.
+pub fn compose_u16s<T: AbstractField>(bits: &[T; SHA256_WORD_BITS]) -> [T; SHA256_WORD_U16S] {
+    from_fn(|limb| {
+        bits[limb * 16..(limb + 1) * 16]
+            .iter()
+            .enumerate()
+            .fold(T::ZERO, |acc, (i, bit)| {
+                acc + bit.clone() * T::from_canonical_u32(1 << i)
+            })
+    })
+}
+
+/// Converts the big-endian bytes of a word into `u16` limbs.
+fn word_bytes_to_u16s<T: AbstractField>(
+    bytes: [impl Into<T>; SHA256_WORD_SIZE],
+) -> [T; SHA256_WORD_U16S] {
+    let [b0, b1, b2, b3] = bytes.map(Into::into);
+    let byte_base = T::from_canonical_u32(1 << 8);
+    [b2 * byte_base.clone() + b3, b0 * byte_base + b1]
+}
+
+/// Returns the bytes of the message block held in `w_bits`, in memory order.
+/// Message words are big-endian.
+fn message_bytes<T: AbstractField>(
+    w_bits: &[[impl Into<T> + Copy; SHA256_WORD_BITS]; SHA256_BLOCK_WORDS],
+) -> Vec<T> {
+    w_bits
+        .iter()
+        .flat_map(|bits| {
+            (0..SHA256_WORD_SIZE).rev().map(move |byte_idx| {
+                bits[byte_idx * 8..(byte_idx + 1) * 8]
+                    .iter()
+                    .enumerate()
+                    .fold(T::ZERO, |acc, (i, &bit)| {
+                        acc + bit.into() * T::from_canonical_u32(1 << i)
+                    })
+            })
+        })
+        .collect()
+}
+
+// ==== Bitwise functions on bit decompositions ====
+// All inputs are assumed to be boolean.
+
+fn rotr<T: Clone>(bits: &[T; SHA256_WORD_BITS], n: usize) -> [T; SHA256_WORD_BITS] {
+    from_fn(|i| bits[(i + n) % SHA256_WORD_BITS].clone())
+}
+
+fn shr<T: AbstractField>(bits: &[T; SHA256_WORD_BITS], n: usize) -> [T; SHA256_WORD_BITS] {
+    from_fn(|i| {
+        if i + n < SHA256_WORD_BITS {
+            bits[i + n].clone()
+        } else {
+            T::ZERO
+        }
+    })
+}
+
+/// Degree 3 expression for `x ^ y ^ z`.
+fn xor3<T: AbstractField>(x: T, y: T, z: T) -> T {
+    x.clone() + y.clone() + z.clone()
+        - (x.clone() * y.clone() + x.clone() * z.clone() + y.clone() * z.clone()).double()
+        + x * y * z * T::from_canonical_u32(4)
+}
+
+fn xor3_words<T: AbstractField>(
+    x: [T; SHA256_WORD_BITS],
+    y: [T; SHA256_WORD_BITS],
+    z: [T; SHA256_WORD_BITS],
+) -> [T; SHA256_WORD_BITS] {
+    from_fn(|i| xor3(x[i].clone(), y[i].clone(), z[i].clone()))
+}
+
+fn big_sig0<T: AbstractField>(x: &[T; SHA256_WORD_BITS]) -> [T; SHA256_WORD_BITS] {
+    xor3_words(rotr(x, 2), rotr(x, 13), rotr(x, 22))
+}
+
+fn big_sig1<T: AbstractField>(x: &[T; SHA256_WORD_BITS]) -> [T; SHA256_WORD_BITS] {
+    xor3_words(rotr(x, 6), rotr(x, 11), rotr(x, 25))
+}
+
+fn small_sig0<T: AbstractField>(x: &[T; SHA256_WORD_BITS]) -> [T; SHA256_WORD_BITS] {
+    xor3_words(rotr(x, 7), rotr(x, 18), shr(x, 3))
+}
+
+fn small_sig1<T: AbstractField>(x: &[T; SHA256_WORD_BITS]) -> [T; SHA256_WORD_BITS] {
+    xor3_words(rotr(x, 17), rotr(x, 19), shr(x, 10))
+}
+
+/// Degree 2 expression for `(x & y) ^ (!x & z)`.
+fn ch<T: AbstractField>(
+    x: &[T; SHA256_WORD_BITS],
+    y: &[T; SHA256_WORD_BITS],
+    z: &[T; SHA256_WORD_BITS],
+) -> [T; SHA256_WORD_BITS] {
+    from_fn(|i| select(x[i].clone(), y[i].clone(), z[i].clone()))
+}
+
+/// Degree 3 expression for `(x & y) ^ (x & z) ^ (y & z)`.
+fn maj<T: AbstractField>(
+    x: &[T; SHA256_WORD_BITS],
+    y: &[T; SHA256_WORD_BITS],
+    z: &[T; SHA256_WORD_BITS],
+) -> [T; SHA256_WORD_BITS] {
+    from_fn(|i| {
+        let (x, y, z) = (x[i].clone(), y[i].clone(), z[i].clone());
+        x.clone() * y.clone() + x.clone() * z.clone() + y.clone() * z.clone() - (x * y * z).double()
+    })
+}
@@ -0,0 +1,211 @@
+use core::mem::size_of;
+
+use openvm_circuit::system::memory::offline_checker::{MemoryReadAuxCols, MemoryWriteAuxCols};
+use openvm_circuit_primitives::utils::assert_array_eq;
+use openvm_circuit_primitives_derive::AlignedBorrow;
+use openvm_instructions::riscv::RV32_REGISTER_NUM_LIMBS;
+use openvm_stark_backend::p3_air::AirBuilder;
+
+use super::{
+    SHA256_BLOCK_BYTES, SHA256_BLOCK_READS, SHA256_BLOCK_WORDS, SHA256_DIGEST_WRITES,
+    SHA256_HASH_WORDS, SHA256_REGISTER_READS, SHA256_ROWS_PER_BLOCK, SHA256_WORD_BITS,
+    SHA256_WORD_SIZE, SHA256_WORD_U16S,
+};
+
+/// Number of bits used to represent each carry of a 16-bit limb addition.
+/// The largest sum in a round adds 7 words, so carries are at most 6.
+pub const SHA256_CARRY_BITS: usize = 3;
+
+#[repr(C)]
+#[derive(Debug, AlignedBorrow)]
+pub struct Sha256VmCols<T> {
+    /// Columns for the compression function rounds
+    pub round: Sha256RoundCols<T>,
+    /// Columns for instruction interface and register access
+    pub control: Sha256ControlCols<T>,
+    /// Columns for padding. Only used on the first row of a block.
+    pub padding: Sha256PaddingCols<T>,
+    /// Columns for the final hash of a block. Only used on the digest row of a block.
+    pub digest: Sha256DigestCols<T>,
+    /// Auxiliary columns for offline memory checking
+    pub mem_oc: Sha256MemoryCols<T>,
+}
+
+/// Columns for a single row of the compression function.
+///
+/// Each block uses [SHA256_ROWS_PER_BLOCK] rows: row `r < 64` holds the state **before** round `r`
+/// and the last row (the digest row) holds the state after the last round.
+/// All bit decompositions are little-endian.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, AlignedBorrow)]
+pub struct Sha256RoundCols<T> {
+    /// One-hot encoding of the row index within the block.
+    pub row_idx: [T; SHA256_ROWS_PER_BLOCK],
+    /// Bits of the working variables `a, b, c, d`.
+    pub a_bits: [[T; SHA256_WORD_BITS]; 4],
+    /// Bits of the working variables `e, f, g, h`.
+    pub e_bits: [[T; SHA256_WORD_BITS]; 4],
+    /// Bits of the message schedule window `w[r..r + 16]`.
+    /// On the first row of a block this is the message block itself.
+    pub w_bits: [[T; SHA256_WORD_BITS]; SHA256_BLOCK_WORDS],
+
+    // The following are `u16` limbs of bitwise functions of the columns above. They are
+    // materialized so that the round constraints stay linear in the trace columns.
+    /// `Σ0(a)`
+    pub sig0_a: [T; SHA256_WORD_U16S],
+    /// `Maj(a, b, c)`
+    pub maj: [T; SHA256_WORD_U16S],
+    /// `Σ1(e)`
+    pub sig1_e: [T; SHA256_WORD_U16S],
+    /// `Ch(e, f, g)`
+    pub ch: [T; SHA256_WORD_U16S],
+    /// `σ0(w[r + 1])`
+    pub sig0_w: [T; SHA256_WORD_U16S],
+    /// `σ1(w[r + 14])`
+    pub sig1_w: [T; SHA256_WORD_U16S],
+
+    /// Bits of the carries of the limb additions computing `a`, `e` and `w[r + 16]` in the next row.
+    pub carry_a: [[T; SHA256_CARRY_BITS]; SHA256_WORD_U16S],
+    pub carry_e: [[T; SHA256_CARRY_BITS]; SHA256_WORD_U16S],
+    pub carry_w: [[T; SHA256_CARRY_BITS]; SHA256_WORD_U16S],
+}
+
+/// Columns for SHA256_RV32 instruction parsing.
+/// Includes columns for instruction execution and register reads.
+/// All columns except `is_enabled_first_row` are constant across the rows of a block.
+#[allow(clippy::too_many_arguments)]
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, AlignedBorrow, derive_new::new)]
+pub struct Sha256ControlCols<T> {
+    /// Program counter
+    pub pc: T,
+    /// True for all rows that are part of opcode execution.
+    /// False on dummy rows only used to pad the height.
+    pub is_enabled: T,
+    /// Is enabled and first row of block. Used to lower constraint degree.
+    /// is_enabled * round.row_idx[0]
+    pub is_enabled_first_row: T,
+    /// Whether this block is the first block of the input.
+    pub is_new_start: T,
+    /// Whether this block is the final block of the input, i.e. it holds the message bit length.
+    pub is_last_block: T,
+    /// Whether this block contains any padding byte.
+    pub has_padding: T,
+    /// Whether the `0x80` padding marker was placed in a previous block.
+    pub padding_occurred: T,
+    /// The starting timestamp to use for memory access in this block.
+    /// A single block will do multiple memory accesses.
+    pub start_timestamp: T,
+    /// Pointer to address space 1 `dst` register
+    pub dst_ptr: T,
+    /// Pointer to address space 1 `src` register
+    pub src_ptr: T,
+    /// Pointer to address space 1 `len` register
+    pub len_ptr: T,
+    /// Memory address space
+    pub e: T,
+    // Register values
+    /// dst <- [dst_ptr:4]_1
+    pub dst: [T; RV32_REGISTER_NUM_LIMBS],
+    /// src <- [src_ptr:4]_1
+    /// We store src_limbs[i] = [src_ptr + i + 1]_1 and src = u32([src_ptr:4]_1) from which [src_ptr]_1
+    /// can be recovered by linear combination.
+    /// We do this because `src` needs to be incremented between blocks.
+    pub src_limbs: [T; RV32_REGISTER_NUM_LIMBS - 1],
+    pub src: T,
+    /// len <- [len_ptr:4]_1
+    /// The full length is kept on every block because the final block must encode it.
+    pub len: [T; RV32_REGISTER_NUM_LIMBS],
+    /// The remaining length of the unpadded input, in bytes.
+    /// If `is_new_start` is true and `is_enabled` is true, this must be equal to `u32(len)`.
+    pub remaining_len: T,
+    /// The hash state at the start of this block, in `u16` limbs.
+    pub prev_hash: [[T; SHA256_WORD_U16S]; SHA256_HASH_WORDS],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, AlignedBorrow)]
+pub struct Sha256PaddingCols<T> {
+    /// Whether the current byte of the message block is a padding byte.
+    ///
+    /// If this row represents a full input block, this should contain all 0s.
+    /// This should contain all 0s on all rows except the first row of a block.
+    pub is_padding_byte: [T; SHA256_BLOCK_BYTES],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, AlignedBorrow)]
+pub struct Sha256DigestCols<T> {
+    /// Bytes of the hash state after this block, with each word in big-endian order.
+    pub final_hash: [[T; SHA256_WORD_SIZE]; SHA256_HASH_WORDS],
+    /// Carries of the `u16` limb additions of `prev_hash` and the final working variables.
+    pub carry: [[T; SHA256_WORD_U16S]; SHA256_HASH_WORDS],
+    /// is_enabled * is_last_block * round.row_idx[SHA256_ROWS_PER_BLOCK - 1]
+    pub is_digest_write: T,
+}
+
+#[repr(C)]
+#[derive(Clone, Debug, AlignedBorrow)]
+pub struct Sha256MemoryCols<T> {
+    pub register_aux: [MemoryReadAuxCols<T, RV32_REGISTER_NUM_LIMBS>; SHA256_REGISTER_READS],
+    pub block_reads: [MemoryReadAuxCols<T, SHA256_WORD_SIZE>; SHA256_BLOCK_READS],
+    pub digest_writes: [MemoryWriteAuxCols<T, SHA256_WORD_SIZE>; SHA256_DIGEST_WRITES],
+    /// The input bytes are batch read in blocks of [SHA256_WORD_SIZE] bytes. However
+    /// if the input length is not a multiple of [SHA256_WORD_SIZE], we read into
+    /// `partial_block` more bytes than we need. On the other hand the message block expects
+    /// only the partial block of bytes and then the correctly padded bytes.
+    /// We will select between `partial_block` and the message block for what to read from memory.
+    /// We never read a full padding block, so the first byte is always ok.
+    pub partial_block: [T; SHA256_WORD_SIZE - 1],
+}
+
+impl<T: Copy> Sha256VmCols<T> {
+    pub const fn remaining_len(&self) -> T {
+        self.control.remaining_len
+    }
+
+    pub const fn is_new_start(&self) -> T {
+        self.control.is_new_start
+    }
+
+    pub fn is_first_row(&self) -> T {
+        self.round.row_idx[0]
+    }
+
+    pub fn is_digest_row(&self) -> T {
+        self.round.row_idx[SHA256_ROWS_PER_BLOCK - 1]
+    }
+}
+
+impl<T: Copy> Sha256ControlCols<T> {
+    /// Constrains all columns that must be constant across the rows of a block to be equal.
+    pub fn assert_eq<AB: AirBuilder>(&self, builder: &mut AB, other: Self)
+    where
+        T: Into<AB::Expr>,
+    {
+        builder.assert_eq(self.pc, other.pc);
+        builder.assert_eq(self.is_enabled, other.is_enabled);
+        builder.assert_eq(self.is_new_start, other.is_new_start);
+        builder.assert_eq(self.is_last_block, other.is_last_block);
+        builder.assert_eq(self.has_padding, other.has_padding);
+        builder.assert_eq(self.padding_occurred, other.padding_occurred);
+        builder.assert_eq(self.start_timestamp, other.start_timestamp);
+        builder.assert_eq(self.dst_ptr, other.dst_ptr);
+        builder.assert_eq(self.src_ptr, other.src_ptr);
+        builder.assert_eq(self.len_ptr, other.len_ptr);
+        builder.assert_eq(self.e, other.e);
+        assert_array_eq(builder, self.dst, other.dst);
+        assert_array_eq(builder, self.src_limbs, other.src_limbs);
+        builder.assert_eq(self.src, other.src);
+        assert_array_eq(builder, self.len, other.len);
+        builder.assert_eq(self.remaining_len, other.remaining_len);
+        for (x, y) in self.prev_hash.into_iter().zip(other.prev_hash) {
+            assert_array_eq(builder, x, y);
+        }
+    }
+}
+
+pub const NUM_SHA256_VM_COLS: usize = size_of::<Sha256VmCols<u8>>();
+pub const NUM_SHA256_ROUND_COLS: usize = size_of::<Sha256RoundCols<u8>>();
+pub const NUM_SHA256_CONTROL_COLS: usize = size_of::<Sha256ControlCols<u8>>();
+pub const NUM_SHA256_MEMORY_COLS: usize = size_of::<Sha256MemoryCols<u8>>();
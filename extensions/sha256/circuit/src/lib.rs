@@ -0,0 +1,268 @@
+//! Stateful sha256 hasher. Handles full sha256 hashing (padding, message schedule, compression)
+//! on variable length inputs read from VM memory.
+use std::{array::from_fn, sync::Arc};
+
+use openvm_circuit_primitives::bitwise_op_lookup::BitwiseOperationLookupChip;
+use openvm_stark_backend::p3_field::PrimeField32;
+use sha2::Digest;
+use utils::num_sha256_blocks;
+
+pub mod air;
+pub mod columns;
+pub mod trace;
+pub mod utils;
+
+mod extension;
+pub use extension::*;
+
+#[cfg(test)]
+mod tests;
+
+pub use air::Sha256VmAir;
+use openvm_circuit::{
+    arch::{ExecutionBridge, ExecutionBus, ExecutionError, ExecutionState, InstructionExecutor},
+    system::{
+        memory::{MemoryControllerRef, MemoryReadRecord, MemoryWriteRecord},
+        program::ProgramBus,
+    },
+};
+use openvm_instructions::{
+    instruction::Instruction, program::DEFAULT_PC_STEP, riscv::RV32_REGISTER_NUM_LIMBS, UsizeOpcode,
+};
+use openvm_rv32im_circuit::adapters::read_rv32_register;
+use openvm_sha256_transpiler::Rv32Sha256Opcode;
+
+// ==== Constants for register/memory adapter ====
+/// Register reads to get dst, src, len
+const SHA256_REGISTER_READS: usize = 3;
+/// Number of cells to read/write in a single memory access
+const SHA256_WORD_SIZE: usize = 4;
+/// Memory reads for the message block per block
+const SHA256_BLOCK_READS: usize = SHA256_BLOCK_BYTES / SHA256_WORD_SIZE;
+/// Memory writes for digest on the last block
+const SHA256_DIGEST_WRITES: usize = SHA256_DIGEST_BYTES / SHA256_WORD_SIZE;
+
+// ==== Do not change these constants! ====
+/// Number of bytes in a message block.
+pub const SHA256_BLOCK_BYTES: usize = 64;
+/// Number of 32-bit words in a message block.
+pub const SHA256_BLOCK_WORDS: usize = SHA256_BLOCK_BYTES / 4;
+/// Number of 32-bit words in the hash state.
+pub const SHA256_HASH_WORDS: usize = 8;
+/// Number of output digest bytes.
+pub const SHA256_DIGEST_BYTES: usize = SHA256_HASH_WORDS * 4;
+/// Number of bits in a word.
+pub const SHA256_WORD_BITS: usize = 32;
+/// Number of 16-bit limbs in a word.
+pub const SHA256_WORD_U16S: usize = 2;
+/// Number of rounds of the compression function.
+pub const SHA256_ROUNDS: usize = 64;
+/// Number of trace rows per block: one row per round followed by one digest row.
+pub const SHA256_ROWS_PER_BLOCK: usize = SHA256_ROUNDS + 1;
+/// Byte offset in the final block where the 64-bit message bit length begins.
+pub const SHA256_LENGTH_OFFSET: usize = SHA256_BLOCK_BYTES - 8;
+
+#[derive(Debug)]
+pub struct Sha256VmChip<F: PrimeField32> {
+    pub air: Sha256VmAir,
+    /// IO and memory data necessary for each opcode call
+    pub records: Vec<Sha256Record<F>>,
+    pub memory_controller: MemoryControllerRef<F>,
+    pub bitwise_lookup_chip: Arc<BitwiseOperationLookupChip<8>>,
+
+    offset: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct Sha256Record<F> {
+    pub pc: F,
+    pub dst_read: MemoryReadRecord<F, RV32_REGISTER_NUM_LIMBS>,
+    pub src_read: MemoryReadRecord<F, RV32_REGISTER_NUM_LIMBS>,
+    pub len_read: MemoryReadRecord<F, RV32_REGISTER_NUM_LIMBS>,
+    pub input_blocks: Vec<Sha256InputBlock<F>>,
+    pub digest_writes: [MemoryWriteRecord<F, SHA256_WORD_SIZE>; SHA256_DIGEST_WRITES],
+}
+
+#[derive(Clone, Debug)]
+pub struct Sha256InputBlock<F> {
+    /// Memory reads for non-padding bytes in this block. Length is at most [SHA256_BLOCK_READS].
+    pub reads: Vec<MemoryReadRecord<F, SHA256_WORD_SIZE>>,
+    /// Index in `reads` of the memory read for < SHA256_WORD_SIZE bytes, if any.
+    pub partial_read_idx: Option<usize>,
+    /// Bytes with padding. Can be derived from `bytes_read` but we store for convenience.
+    pub padded_bytes: [u8; SHA256_BLOCK_BYTES],
+    /// Number of unpadded input bytes remaining at the start of this block, saturating at zero.
+    pub remaining_len: usize,
+    pub src: usize,
+    pub is_new_start: bool,
+    /// Whether the `0x80` padding marker was placed in a previous block.
+    pub padding_occurred: bool,
+}
+
+impl<F: PrimeField32> Sha256VmChip<F> {
+    pub fn new(
+        execution_bus: ExecutionBus,
+        program_bus: ProgramBus,
+        memory_controller: MemoryControllerRef<F>,
+        bitwise_lookup_chip: Arc<BitwiseOperationLookupChip<8>>,
+        offset: usize,
+    ) -> Self {
+        let ptr_max_bits = memory_controller.borrow().mem_config().pointer_max_bits;
+        let memory_bridge = memory_controller.borrow().memory_bridge();
+        Self {
+            air: Sha256VmAir::new(
+                ExecutionBridge::new(execution_bus, program_bus),
+                memory_bridge,
+                bitwise_lookup_chip.bus(),
+                ptr_max_bits,
+                offset,
+            ),
+            memory_controller,
+            bitwise_lookup_chip,
+            records: Vec::new(),
+            offset,
+        }
+    }
+}
+
+impl<F: PrimeField32> InstructionExecutor<F> for Sha256VmChip<F> {
+    fn execute(
+        &mut self,
+        instruction: Instruction<F>,
+        from_state: ExecutionState<u32>,
+    ) -> Result<ExecutionState<u32>, ExecutionError> {
+        let Instruction {
+            opcode,
+            a,
+            b,
+            c,
+            d,
+            e,
+            ..
+        } = instruction;
+        let local_opcode = Rv32Sha256Opcode::from_usize(opcode.local_opcode_idx(self.offset));
+        debug_assert_eq!(local_opcode, Rv32Sha256Opcode::SHA256);
+
+        let mut memory = self.memory_controller.borrow_mut();
+        debug_assert_eq!(from_state.timestamp, memory.timestamp());
+
+        let (dst_read, dst) = read_rv32_register(&mut memory, d, a);
+        let (src_read, src) = read_rv32_register(&mut memory, d, b);
+        let (len_read, len) = read_rv32_register(&mut memory, d, c);
+        #[cfg(debug_assertions)]
+        {
+            assert!(dst < (1 << self.air.ptr_max_bits));
+            assert!(src < (1 << self.air.ptr_max_bits));
+            assert!(len < (1 << self.air.ptr_max_bits));
+        }
+
+        let len = len as usize;
+        let num_blocks = num_sha256_blocks(len);
+        let mut input_blocks = Vec::with_capacity(num_blocks);
+        let mut hasher = sha2::Sha256::new();
+        let mut remaining_len = len;
+        let mut padding_occurred = false;
+        let mut src = src as usize;
+
+        for block_idx in 0..num_blocks {
+            if block_idx != 0 {
+                memory.increment_timestamp_by(SHA256_REGISTER_READS as u32);
+            }
+            let mut reads = Vec::with_capacity(SHA256_BLOCK_READS);
+
+            let mut partial_read_idx = None;
+            let mut bytes = [0u8; SHA256_BLOCK_BYTES];
+            for i in (0..SHA256_BLOCK_BYTES).step_by(SHA256_WORD_SIZE) {
+                if i < remaining_len {
+                    let read = memory.read(e, F::from_canonical_usize(src + i));
+                    let chunk = read.data.map(|x| {
+                        x.as_canonical_u32()
+                            .try_into()
+                            .expect("Memory cell not a byte")
+                    });
+                    let copy_len = SHA256_WORD_SIZE.min(remaining_len - i);
+                    if copy_len != SHA256_WORD_SIZE {
+                        partial_read_idx = Some(reads.len());
+                    }
+                    bytes[i..i + copy_len].copy_from_slice(&chunk[..copy_len]);
+                    reads.push(read);
+                } else {
+                    memory.increment_timestamp();
+                }
+            }
+            let num_input_bytes = remaining_len.min(SHA256_BLOCK_BYTES);
+            hasher.update(&bytes[..num_input_bytes]);
+
+            let mut block = Sha256InputBlock {
+                reads,
+                partial_read_idx,
+                padded_bytes: bytes,
+                remaining_len,
+                src,
+                is_new_start: block_idx == 0,
+                padding_occurred,
+            };
+            // handle padding here since it is convenient
+            if num_input_bytes < SHA256_BLOCK_BYTES && !padding_occurred {
+                block.padded_bytes[num_input_bytes] = 0x80;
+                padding_occurred = true;
+            }
+            if block_idx == num_blocks - 1 {
+                debug_assert!(padding_occurred);
+                let bit_len = (len as u64) << 3;
+                block.padded_bytes[SHA256_LENGTH_OFFSET..].copy_from_slice(&bit_len.to_be_bytes());
+            }
+            input_blocks.push(block);
+            src += SHA256_BLOCK_BYTES;
+            remaining_len = remaining_len.saturating_sub(SHA256_BLOCK_BYTES);
+        }
+        let output: [u8; SHA256_DIGEST_BYTES] = hasher.finalize().into();
+        let dst = dst as usize;
+        let digest_writes: [_; SHA256_DIGEST_WRITES] = from_fn(|i| {
+            memory.write::<SHA256_WORD_SIZE>(
+                e,
+                F::from_canonical_usize(dst + i * SHA256_WORD_SIZE),
+                from_fn(|j| F::from_canonical_u8(output[i * SHA256_WORD_SIZE + j])),
+            )
+        });
+        tracing::trace!("[runtime] sha256 output: {:?}", output);
+
+        let record = Sha256Record {
+            pc: F::from_canonical_u32(from_state.pc),
+            dst_read,
+            src_read,
+            len_read,
+            input_blocks,
+            digest_writes,
+        };
+
+        // Add the events to chip state for later trace generation usage
+        self.records.push(record);
+
+        // NOTE: Check this is consistent with Sha256VmAir::timestamp_change (we don't use it to avoid
+        // unnecessary conversions here)
+        let timestamp_change = len as u32
+            + (2 * (SHA256_REGISTER_READS + SHA256_BLOCK_READS) + SHA256_DIGEST_WRITES) as u32;
+        let to_timestamp = from_state.timestamp + timestamp_change;
+        memory.increase_timestamp_to(to_timestamp);
+
+        Ok(ExecutionState {
+            pc: from_state.pc + DEFAULT_PC_STEP,
+            timestamp: to_timestamp,
+        })
+    }
+
+    fn get_opcode_name(&self, _: usize) -> String {
+        "SHA256".to_string()
+    }
+}
+
+impl<F: Copy> Sha256Record<F> {
+    pub fn digest_addr_space(&self) -> F {
+        self.digest_writes[0].address_space
+    }
+
+    pub fn start_timestamp(&self) -> u32 {
+        self.dst_read.timestamp
+    }
+}
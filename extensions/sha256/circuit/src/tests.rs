@@ -0,0 +1,162 @@
+use std::{borrow::BorrowMut, sync::Arc};
+
+use hex::FromHex;
+use openvm_circuit::arch::{
+    testing::{VmChipTestBuilder, VmChipTester},
+    BITWISE_OP_LOOKUP_BUS,
+};
+use openvm_circuit_primitives::bitwise_op_lookup::{
+    BitwiseOperationLookupBus, BitwiseOperationLookupChip,
+};
+use openvm_instructions::{instruction::Instruction, VmOpcode};
+use openvm_sha256_transpiler::Rv32Sha256Opcode;
+use openvm_stark_backend::{
+    p3_field::AbstractField, utils::disable_debug_builder, verifier::VerificationError,
+};
+use openvm_stark_sdk::{
+    config::baby_bear_blake3::BabyBearBlake3Config, p3_baby_bear::BabyBear,
+    utils::create_seeded_rng,
+};
+use rand::Rng;
+
+use super::{
+    columns::Sha256VmCols,
+    utils::{num_sha256_blocks, sha256},
+    Sha256VmChip, SHA256_ROWS_PER_BLOCK, SHA256_WORD_SIZE,
+};
+
+type F = BabyBear;
+// io is vector of (input, expected_output, prank_output) where prank_output is Some if the trace
+// will be replaced
+#[allow(clippy::type_complexity)]
+fn build_sha256_test(
+    io: Vec<(Vec<u8>, Option<[u8; 32]>, Option<[u8; 32]>)>,
+) -> VmChipTester<BabyBearBlake3Config> {
+    let bitwise_bus = BitwiseOperationLookupBus::new(BITWISE_OP_LOOKUP_BUS);
+    let bitwise_chip = Arc::new(BitwiseOperationLookupChip::<8>::new(bitwise_bus));
+
+    let mut tester = VmChipTestBuilder::default();
+    let mut chip = Sha256VmChip::new(
+        tester.execution_bus(),
+        tester.program_bus(),
+        tester.memory_controller(),
+        bitwise_chip.clone(),
+        0,
+    );
+
+    let mut dst = 0;
+    let src = 0;
+
+    for (input, expected_output, prank_output) in &io {
+        let [a, b, c] = [0, 4, 8]; // space apart for register limbs
+        let [d, e] = [1, 2];
+
+        tester.write(d, a, (dst as u32).to_le_bytes().map(F::from_canonical_u8));
+        tester.write(d, b, (src as u32).to_le_bytes().map(F::from_canonical_u8));
+        tester.write(
+            d,
+            c,
+            (input.len() as u32).to_le_bytes().map(F::from_canonical_u8),
+        );
+        for (i, byte) in input.iter().enumerate() {
+            tester.write_cell(e, src + i, F::from_canonical_u8(*byte));
+        }
+
+        tester.execute(
+            &mut chip,
+            Instruction::from_isize(
+                VmOpcode::from_usize(Rv32Sha256Opcode::SHA256 as usize),
+                a as isize,
+                b as isize,
+                c as isize,
+                d as isize,
+                e as isize,
+            ),
+        );
+        if let Some(output) = expected_output {
+            for (i, byte) in output.iter().enumerate() {
+                assert_eq!(tester.read_cell(e, dst + i), F::from_canonical_u8(*byte));
+            }
+        }
+        if let Some(output) = prank_output {
+            for (i, output_byte) in output.iter().enumerate() {
+                chip.records.last_mut().unwrap().digest_writes[i / SHA256_WORD_SIZE].data
+                    [i % SHA256_WORD_SIZE] = F::from_canonical_u8(*output_byte);
+            }
+        }
+        // shift dst to not deal with timestamps for pranking
+        dst += 32;
+    }
+    let mut tester = tester.build().load(chip).load(bitwise_chip).finalize();
+
+    let sha256_trace = tester.air_proof_inputs[2].raw.common_main.as_mut().unwrap();
+    let mut row = 0;
+    for (input, _, prank_output) in io {
+        let num_blocks = num_sha256_blocks(input.len());
+        let num_rows = SHA256_ROWS_PER_BLOCK * num_blocks;
+        row += num_rows;
+        if prank_output.is_none() {
+            continue;
+        }
+        let output = prank_output.unwrap();
+        let digest_row: &mut Sha256VmCols<_> = sha256_trace.row_mut(row - 1).borrow_mut();
+        for (i, byte) in output.into_iter().enumerate() {
+            digest_row.digest.final_hash[i / SHA256_WORD_SIZE][i % SHA256_WORD_SIZE] =
+                F::from_canonical_u8(byte);
+        }
+    }
+
+    tester
+}
+
+#[test]
+fn test_sha256_negative() {
+    let mut rng = create_seeded_rng();
+    let input: Vec<_> = vec![0; 119];
+    let mut out = sha256(&input);
+    out[0] = out[0].wrapping_add(rng.gen_range(1..=255));
+    let tester = build_sha256_test(vec![(input, None, Some(out))]);
+    disable_debug_builder();
+    assert_eq!(
+        tester.simple_test().err(),
+        Some(VerificationError::OodEvaluationMismatch)
+    );
+}
+
+// Test vectors from FIPS 180-4 examples (https://csrc.nist.gov/projects/cryptographic-standards-and-guidelines/example-values)
+#[test]
+fn test_sha256_positive_kat_vectors() {
+    let test_vectors = vec![
+        ("", "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"), // Len = 0
+        ("616263", "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"), // "abc"
+        ("6162636462636465636465666465666765666768666768696768696a68696a6b696a6b6c6a6b6c6d6b6c6d6e6c6d6e6f6d6e6f706e6f7071", "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"), // Len = 448 bits
+        ("61626364656667686263646566676869636465666768696a6465666768696a6b65666768696a6b6c666768696a6b6c6d6768696a6b6c6d6e68696a6b6c6d6e6f696a6b6c6d6e6f706a6b6c6d6e6f70716b6c6d6e6f7071726c6d6e6f707172736d6e6f70717273746e6f707172737475", "cf5b16a778af8380036ce59e7b0492370b249b11e8f07a51afac45037afee9d1"), // Len = 896 bits
+    ];
+
+    let mut io = vec![];
+    for (input, output) in test_vectors {
+        let input = Vec::from_hex(input).unwrap();
+        let output = Vec::from_hex(output).unwrap();
+        io.push((input, Some(output.try_into().unwrap()), None));
+    }
+
+    let tester = build_sha256_test(io);
+    tester.simple_test().expect("Verification failed");
+}
+
+// Lengths around the block boundaries, where the padding spills into an extra block.
+#[test]
+fn test_sha256_positive_padding_boundaries() {
+    let mut rng = create_seeded_rng();
+    let io = [1, 3, 55, 56, 57, 63, 64, 65, 119, 120, 128, 200]
+        .into_iter()
+        .map(|len| {
+            let input: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+            let output = sha256(&input);
+            (input, Some(output), None)
+        })
+        .collect();
+
+    let tester = build_sha256_test(io);
+    tester.simple_test().expect("Verification failed");
+}
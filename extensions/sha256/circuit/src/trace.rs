@@ -0,0 +1,287 @@
+use std::{array::from_fn, borrow::BorrowMut, sync::Arc};
+
+use openvm_circuit::system::memory::{MemoryReadRecord, MemoryWriteRecord};
+use openvm_instructions::riscv::{RV32_CELL_BITS, RV32_REGISTER_NUM_LIMBS};
+use openvm_stark_backend::{
+    config::{StarkGenericConfig, Val},
+    p3_air::BaseAir,
+    p3_field::{AbstractField, PrimeField32},
+    p3_matrix::dense::RowMajorMatrix,
+    p3_maybe_rayon::prelude::*,
+    prover::types::AirProofInput,
+    rap::{get_air_name, AnyRap},
+    Chip, ChipUsageGetter,
+};
+
+use super::{
+    columns::{Sha256ControlCols, Sha256VmCols, SHA256_CARRY_BITS},
+    utils::{
+        big_sig0, big_sig1, ch, compress, maj, message_schedule, round_states, small_sig0,
+        small_sig1, SHA256_H, SHA256_K,
+    },
+    Sha256InputBlock, Sha256VmChip, SHA256_BLOCK_BYTES, SHA256_BLOCK_READS, SHA256_DIGEST_WRITES,
+    SHA256_HASH_WORDS, SHA256_REGISTER_READS, SHA256_ROUNDS, SHA256_ROWS_PER_BLOCK,
+    SHA256_WORD_BITS, SHA256_WORD_SIZE, SHA256_WORD_U16S,
+};
+
+impl<SC: StarkGenericConfig> Chip<SC> for Sha256VmChip<Val<SC>>
+where
+    Val<SC>: PrimeField32,
+{
+    fn air(&self) -> Arc<dyn AnyRap<SC>> {
+        Arc::new(self.air)
+    }
+
+    fn generate_air_proof_input(self) -> AirProofInput<SC> {
+        let air = self.air();
+        let trace_width = self.trace_width();
+        let records = self.records;
+        let total_num_blocks: usize = records.iter().map(|r| r.input_blocks.len()).sum();
+        let mut instruction_blocks = Vec::with_capacity(total_num_blocks);
+
+        struct BlockDiff<F> {
+            /// hash state before the block
+            prev_hash: [u32; SHA256_HASH_WORDS],
+            /// if first block
+            register_reads:
+                Option<[MemoryReadRecord<F, RV32_REGISTER_NUM_LIMBS>; SHA256_REGISTER_READS]>,
+            /// if last block
+            digest_writes: Option<[MemoryWriteRecord<F, SHA256_WORD_SIZE>; SHA256_DIGEST_WRITES]>,
+        }
+
+        for record in records {
+            let src_limbs: [_; RV32_REGISTER_NUM_LIMBS - 1] =
+                from_fn(|i| record.src_read.data[i + 1]);
+            let mut control = Sha256ControlCols {
+                pc: record.pc,
+                is_enabled: Val::<SC>::ONE,
+                is_enabled_first_row: Val::<SC>::ZERO,
+                is_new_start: Val::<SC>::ZERO,
+                is_last_block: Val::<SC>::ZERO,
+                has_padding: Val::<SC>::ZERO,
+                padding_occurred: Val::<SC>::ZERO,
+                start_timestamp: Val::<SC>::from_canonical_u32(record.start_timestamp()),
+                dst_ptr: record.dst_read.pointer,
+                src_ptr: record.src_read.pointer,
+                len_ptr: record.len_read.pointer,
+                e: record.digest_addr_space(),
+                dst: record.dst_read.data,
+                src_limbs,
+                src: Val::<SC>::from_canonical_usize(record.input_blocks[0].src),
+                len: record.len_read.data,
+                remaining_len: Val::<SC>::from_canonical_usize(
+                    record.input_blocks[0].remaining_len,
+                ),
+                prev_hash: Default::default(),
+            };
+            let mut prev_hash = SHA256_H;
+            let num_blocks = record.input_blocks.len();
+            for (idx, block) in record.input_blocks.into_iter().enumerate() {
+                control.is_new_start = Val::<SC>::from_bool(block.is_new_start);
+                control.is_last_block = Val::<SC>::from_bool(idx == num_blocks - 1);
+                control.has_padding = Val::<SC>::from_bool(
+                    block.padding_occurred || block.remaining_len < SHA256_BLOCK_BYTES,
+                );
+                control.padding_occurred = Val::<SC>::from_bool(block.padding_occurred);
+                control.prev_hash = prev_hash.map(u32_into_u16s);
+
+                let register_reads =
+                    (idx == 0).then_some([record.dst_read, record.src_read, record.len_read]);
+                let digest_writes = (idx == num_blocks - 1).then_some(record.digest_writes);
+                let diff = BlockDiff {
+                    prev_hash,
+                    register_reads,
+                    digest_writes,
+                };
+                prev_hash = compress(prev_hash, &block.padded_bytes);
+                instruction_blocks.push((control, Some(diff), Some(block)));
+
+                control.remaining_len -= Val::<SC>::from_canonical_usize(SHA256_BLOCK_BYTES);
+                control.src += Val::<SC>::from_canonical_usize(SHA256_BLOCK_BYTES);
+                control.start_timestamp +=
+                    Val::<SC>::from_canonical_usize(SHA256_REGISTER_READS + SHA256_BLOCK_READS);
+            }
+        }
+
+        // The last row must be a dummy row, so we always reserve at least one extra row
+        let num_rows = if total_num_blocks == 0 {
+            0
+        } else {
+            (total_num_blocks * SHA256_ROWS_PER_BLOCK + 1).next_power_of_two()
+        };
+        // Every `SHA256_ROWS_PER_BLOCK` rows corresponds to one input block
+        let num_blocks = num_rows.div_ceil(SHA256_ROWS_PER_BLOCK);
+        // Resize with dummy `is_enabled = 0` blocks. Dummy blocks are "new" final blocks so that
+        // block transitions into and out of them are unconstrained.
+        let dummy_control = Sha256ControlCols {
+            is_new_start: Val::<SC>::ONE,
+            is_last_block: Val::<SC>::ONE,
+            ..Default::default()
+        };
+        instruction_blocks.resize_with(num_blocks, || (dummy_control, None, None));
+
+        let aux_cols_factory = self.memory_controller.borrow().aux_cols_factory();
+
+        let mut trace =
+            RowMajorMatrix::new(Val::<SC>::zero_vec(num_rows * trace_width), trace_width);
+        let limb_shift_bits = RV32_CELL_BITS * RV32_REGISTER_NUM_LIMBS - self.air.ptr_max_bits;
+
+        trace
+            .values
+            .par_chunks_mut(trace_width * SHA256_ROWS_PER_BLOCK)
+            .zip(instruction_blocks.into_par_iter())
+            .for_each(|(rows, (control, diff, block))| {
+                for (row_idx, row) in rows.chunks_exact_mut(trace_width).enumerate() {
+                    let row_mut: &mut Sha256VmCols<Val<SC>> = row.borrow_mut();
+                    row_mut.round.row_idx[row_idx] = Val::<SC>::ONE;
+                    row_mut.control = control;
+                }
+                // Dummy blocks have all other columns zero
+                let (Some(diff), Some(block)) = (diff, block) else {
+                    return;
+                };
+                fill_round_rows(rows, trace_width, diff.prev_hash, &block);
+
+                let first_row: &mut Sha256VmCols<Val<SC>> = rows[..trace_width].borrow_mut();
+                first_row.control.is_enabled_first_row = Val::<SC>::ONE;
+                for (i, is_padding) in first_row.padding.is_padding_byte.iter_mut().enumerate() {
+                    *is_padding =
+                        Val::<SC>::from_bool(block.padding_occurred || i >= block.remaining_len);
+                }
+                if let Some(partial_read_idx) = block.partial_read_idx {
+                    first_row
+                        .mem_oc
+                        .partial_block
+                        .copy_from_slice(&block.reads[partial_read_idx].data[1..]);
+                }
+                // Make memory access aux columns. Any aux column not explicitly defined defaults to all 0s
+                if let Some(register_reads) = diff.register_reads {
+                    let need_range_check = [
+                        &register_reads[0], // dst
+                        &register_reads[1], // src
+                        &register_reads[2], // len
+                        &register_reads[2],
+                    ]
+                    .map(|r| r.data.last().unwrap().as_canonical_u32());
+                    for bytes in need_range_check.chunks(2) {
+                        self.bitwise_lookup_chip.request_range(
+                            bytes[0] << limb_shift_bits,
+                            bytes[1] << limb_shift_bits,
+                        );
+                    }
+                    for (i, record) in register_reads.into_iter().enumerate() {
+                        first_row.mem_oc.register_aux[i] =
+                            aux_cols_factory.make_read_aux_cols(record);
+                    }
+                }
+                for (i, record) in block.reads.into_iter().enumerate() {
+                    first_row.mem_oc.block_reads[i] = aux_cols_factory.make_read_aux_cols(record);
+                }
+
+                let digest_row: &mut Sha256VmCols<Val<SC>> =
+                    rows[SHA256_ROUNDS * trace_width..].borrow_mut();
+                for bytes in digest_row.digest.final_hash.concat().chunks_exact(2) {
+                    self.bitwise_lookup_chip
+                        .request_range(bytes[0].as_canonical_u32(), bytes[1].as_canonical_u32());
+                }
+                if let Some(digest_writes) = diff.digest_writes {
+                    digest_row.digest.is_digest_write = Val::<SC>::ONE;
+                    for (i, record) in digest_writes.into_iter().enumerate() {
+                        digest_row.mem_oc.digest_writes[i] =
+                            aux_cols_factory.make_write_aux_cols(record);
+                    }
+                }
+            });
+
+        AirProofInput::simple_no_pis(air, trace)
+    }
+}
+
+impl<F: PrimeField32> ChipUsageGetter for Sha256VmChip<F> {
+    fn air_name(&self) -> String {
+        get_air_name(&self.air)
+    }
+    fn current_trace_height(&self) -> usize {
+        let num_blocks: usize = self.records.iter().map(|r| r.input_blocks.len()).sum();
+        num_blocks * SHA256_ROWS_PER_BLOCK
+    }
+
+    fn trace_width(&self) -> usize {
+        BaseAir::<F>::width(&self.air)
+    }
+}
+
+/// Fills the round and digest columns of the rows of a single enabled block.
+fn fill_round_rows<F: PrimeField32>(
+    rows: &mut [F],
+    trace_width: usize,
+    prev_hash: [u32; SHA256_HASH_WORDS],
+    block: &Sha256InputBlock<F>,
+) {
+    let w = message_schedule(&block.padded_bytes);
+    let states = round_states(prev_hash, &w);
+    for (r, row) in rows.chunks_exact_mut(trace_width).enumerate() {
+        let row_mut: &mut Sha256VmCols<F> = row.borrow_mut();
+        let round = &mut row_mut.round;
+        let [a, b, c, d, e, f, g, h] = states[r];
+        for (bits, x) in round.a_bits.iter_mut().zip([a, b, c, d]) {
+            *bits = u32_into_bits(x);
+        }
+        for (bits, x) in round.e_bits.iter_mut().zip([e, f, g, h]) {
+            *bits = u32_into_bits(x);
+        }
+        for (i, bits) in round.w_bits.iter_mut().enumerate() {
+            *bits = u32_into_bits(w[r + i]);
+        }
+        let (sig0_a, maj_abc, sig1_e, ch_efg) =
+            (big_sig0(a), maj(a, b, c), big_sig1(e), ch(e, f, g));
+        let (sig0_w, sig1_w) = (small_sig0(w[r + 1]), small_sig1(w[r + 14]));
+        round.sig0_a = u32_into_u16s(sig0_a);
+        round.maj = u32_into_u16s(maj_abc);
+        round.sig1_e = u32_into_u16s(sig1_e);
+        round.ch = u32_into_u16s(ch_efg);
+        round.sig0_w = u32_into_u16s(sig0_w);
+        round.sig1_w = u32_into_u16s(sig1_w);
+
+        if r < SHA256_ROUNDS {
+            let t1 = [h, sig1_e, ch_efg, SHA256_K[r], w[r]];
+            let t2 = [sig0_a, maj_abc];
+            round.carry_a = add_carries(t1.iter().chain(t2.iter())).map(carry_into_bits);
+            round.carry_e = add_carries(t1.iter().chain([d].iter())).map(carry_into_bits);
+            round.carry_w =
+                add_carries([sig1_w, w[r + 9], sig0_w, w[r]].iter()).map(carry_into_bits);
+        } else {
+            let digest = &mut row_mut.digest;
+            for (i, (&prev, state)) in prev_hash.iter().zip(states[r]).enumerate() {
+                digest.final_hash[i] = prev
+                    .wrapping_add(state)
+                    .to_be_bytes()
+                    .map(F::from_canonical_u8);
+                digest.carry[i] = add_carries([prev, state].iter()).map(F::from_canonical_u32);
+            }
+        }
+    }
+}
+
+/// Returns the carries of adding `terms` in `u16` limbs.
+fn add_carries<'a>(terms: impl Iterator<Item = &'a u32> + Clone) -> [u32; SHA256_WORD_U16S] {
+    let lo: u32 = terms.clone().map(|&x| x & 0xffff).sum();
+    let carry_lo = lo >> 16;
+    let hi: u32 = terms.map(|&x| x >> 16).sum::<u32>() + carry_lo;
+    [carry_lo, hi >> 16]
+}
+
+fn carry_into_bits<F: AbstractField>(carry: u32) -> [F; SHA256_CARRY_BITS] {
+    debug_assert!(carry < (1 << SHA256_CARRY_BITS));
+    from_fn(|i| F::from_bool((carry >> i) & 1 == 1))
+}
+
+/// Little-endian bit decomposition of `x`.
+fn u32_into_bits<F: AbstractField>(x: u32) -> [F; SHA256_WORD_BITS] {
+    from_fn(|i| F::from_bool((x >> i) & 1 == 1))
+}
+
+/// Little-endian `u16` limbs of `x`.
+fn u32_into_u16s<F: AbstractField>(x: u32) -> [F; SHA256_WORD_U16S] {
+    from_fn(|i| F::from_canonical_u32((x >> (16 * i)) & 0xffff))
+}
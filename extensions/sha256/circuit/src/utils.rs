@@ -0,0 +1,107 @@
+use sha2::{Digest, Sha256};
+
+use super::{SHA256_BLOCK_BYTES, SHA256_BLOCK_WORDS, SHA256_HASH_WORDS, SHA256_ROUNDS};
+
+/// Round constants `K` from FIPS 180-4, Section 4.2.2.
+pub const SHA256_K: [u32; SHA256_ROUNDS] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// Initial hash value `H(0)` from FIPS 180-4, Section 5.3.3.
+pub const SHA256_H: [u32; SHA256_HASH_WORDS] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// Number of message schedule words materialized per block. The schedule is extended past the
+/// 64 words used by the rounds so that every row in a block holds a full window of 16 words.
+pub const SHA256_SCHEDULE_LEN: usize = SHA256_ROUNDS + SHA256_BLOCK_WORDS;
+
+pub fn big_sig0(x: u32) -> u32 {
+    x.rotate_right(2) ^ x.rotate_right(13) ^ x.rotate_right(22)
+}
+
+pub fn big_sig1(x: u32) -> u32 {
+    x.rotate_right(6) ^ x.rotate_right(11) ^ x.rotate_right(25)
+}
+
+pub fn small_sig0(x: u32) -> u32 {
+    x.rotate_right(7) ^ x.rotate_right(18) ^ (x >> 3)
+}
+
+pub fn small_sig1(x: u32) -> u32 {
+    x.rotate_right(17) ^ x.rotate_right(19) ^ (x >> 10)
+}
+
+pub fn ch(x: u32, y: u32, z: u32) -> u32 {
+    (x & y) ^ (!x & z)
+}
+
+pub fn maj(x: u32, y: u32, z: u32) -> u32 {
+    (x & y) ^ (x & z) ^ (y & z)
+}
+
+pub fn sha256(input: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(input);
+    hasher.finalize().into()
+}
+
+/// Number of sha256 compressions required for sha256 on
+/// input of `byte_len` bytes.
+pub fn num_sha256_blocks(byte_len: usize) -> usize {
+    // always need 1 byte for the 0x80 marker and 8 bytes for the bit length
+    (byte_len + 9).div_ceil(SHA256_BLOCK_BYTES)
+}
+
+/// Returns the message schedule of a padded block, extended to [SHA256_SCHEDULE_LEN] words.
+pub fn message_schedule(block: &[u8; SHA256_BLOCK_BYTES]) -> [u32; SHA256_SCHEDULE_LEN] {
+    let mut w = [0u32; SHA256_SCHEDULE_LEN];
+    for (i, chunk) in block.chunks_exact(4).enumerate() {
+        w[i] = u32::from_be_bytes(chunk.try_into().unwrap());
+    }
+    for i in SHA256_BLOCK_WORDS..SHA256_SCHEDULE_LEN {
+        w[i] = small_sig1(w[i - 2])
+            .wrapping_add(w[i - 7])
+            .wrapping_add(small_sig0(w[i - 15]))
+            .wrapping_add(w[i - 16]);
+    }
+    w
+}
+
+/// Returns the working variables `[a, b, c, d, e, f, g, h]` before each round, followed by the
+/// working variables after the last round.
+pub fn round_states(
+    prev_hash: [u32; SHA256_HASH_WORDS],
+    w: &[u32; SHA256_SCHEDULE_LEN],
+) -> [[u32; SHA256_HASH_WORDS]; SHA256_ROUNDS + 1] {
+    let mut states = [[0u32; SHA256_HASH_WORDS]; SHA256_ROUNDS + 1];
+    states[0] = prev_hash;
+    for r in 0..SHA256_ROUNDS {
+        let [a, b, c, d, e, f, g, h] = states[r];
+        let t1 = h
+            .wrapping_add(big_sig1(e))
+            .wrapping_add(ch(e, f, g))
+            .wrapping_add(SHA256_K[r])
+            .wrapping_add(w[r]);
+        let t2 = big_sig0(a).wrapping_add(maj(a, b, c));
+        states[r + 1] = [t1.wrapping_add(t2), a, b, c, d.wrapping_add(t1), e, f, g];
+    }
+    states
+}
+
+/// The sha256 compression function applied to a single padded block.
+pub fn compress(
+    prev_hash: [u32; SHA256_HASH_WORDS],
+    block: &[u8; SHA256_BLOCK_BYTES],
+) -> [u32; SHA256_HASH_WORDS] {
+    let w = message_schedule(block);
+    let state = round_states(prev_hash, &w)[SHA256_ROUNDS];
+    std::array::from_fn(|i| prev_hash[i].wrapping_add(state[i]))
+}
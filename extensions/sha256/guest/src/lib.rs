@@ -0,0 +1,59 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(target_os = "zkvm")]
+use core::mem::MaybeUninit;
+
+/// This is custom-0 defined in RISC-V spec document
+pub const OPCODE: u8 = 0x0b;
+/// Hash intrinsics share funct3 and are distinguished by funct7.
+pub const SHA256_FUNCT3: u8 = 0b100;
+pub const SHA256_FUNCT7: u8 = 0x1;
+
+/// The sha256 cryptographic hash function.
+#[inline(always)]
+pub fn sha256(input: &[u8]) -> [u8; 32] {
+    #[cfg(not(target_os = "zkvm"))]
+    {
+        let mut output = [0u8; 32];
+        set_sha256(input, &mut output);
+        output
+    }
+    #[cfg(target_os = "zkvm")]
+    {
+        let mut output = MaybeUninit::<[u8; 32]>::uninit();
+        native_sha256(input.as_ptr(), input.len(), output.as_mut_ptr() as *mut u8);
+        unsafe { output.assume_init() }
+    }
+}
+
+/// Native hook for sha256 for use with libraries that allow overriding the software
+/// implementation, e.g. the `sha2` crate.
+///
+/// # Safety
+///
+/// The VM accepts the preimage by pointer and length, and writes the
+/// 32-byte hash.
+/// - `bytes` must point to an input buffer at least `len` long.
+/// - `output` must point to a buffer that is at least 32-bytes long.
+///
+/// [`sha256`]: https://en.wikipedia.org/wiki/SHA-2
+/// [`sha2`]: https://docs.rs/sha2/latest/sha2/
+#[cfg(target_os = "zkvm")]
+#[inline(always)]
+#[no_mangle]
+extern "C" fn native_sha256(bytes: *const u8, len: usize, output: *mut u8) {
+    openvm_platform::custom_insn_r!(OPCODE, SHA256_FUNCT3, SHA256_FUNCT7, output, bytes, len);
+}
+
+/// Sets `output` to the sha256 hash of `input`.
+pub fn set_sha256(input: &[u8], output: &mut [u8; 32]) {
+    #[cfg(not(target_os = "zkvm"))]
+    {
+        use sha2::{Digest, Sha256};
+        let mut hasher = Sha256::new();
+        hasher.update(input);
+        output.copy_from_slice(hasher.finalize().as_ref());
+    }
+    #[cfg(target_os = "zkvm")]
+    native_sha256(input.as_ptr(), input.len(), output.as_mut_ptr() as *mut u8);
+}